@@ -0,0 +1,359 @@
+//! Command aliases.
+//!
+//! Aliases are stored in the global config under `aliases` and expanded
+//! before the command line is parsed, so `lin mine` can stand for
+//! `lin issue list --assignee me --state started`.
+//!
+//! - `$1` ... `$9` in an expansion are replaced by the alias's arguments;
+//!   arguments that aren't referenced are appended.
+//! - An expansion starting with `!` is run by `sh -c`, with the alias's
+//!   arguments as `$1`, `$2`, ... (and `"$@"`).
+//!
+//! ```json
+//! "aliases": {
+//!   "mine": "issue list --assignee me --state started",
+//!   "bug": "issue create --labels bug --priority high --title",
+//!   "open": "!lin --json issue get $1 | jq -r .data.url | xargs open"
+//! }
+//! ```
+
+use std::collections::BTreeMap;
+use std::ffi::OsString;
+use std::process::Command;
+
+use crate::Result;
+use crate::error::LinError;
+
+/// Prefix marking an alias as a shell command.
+pub const SHELL_PREFIX: char = '!';
+
+/// Global options that take a value, and so can appear before the command.
+const GLOBAL_VALUE_OPTIONS: &[&str] = &["--org", "-o"];
+
+/// What an alias expands to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expansion {
+    /// Run lin with these arguments (including the program name)
+    Args(Vec<OsString>),
+    /// Run a shell command with the alias's arguments as positional parameters
+    Shell {
+        /// Alias name (the shell's `$0`)
+        name: String,
+        /// The command, without the `!` prefix
+        command: String,
+        /// The alias's arguments
+        args: Vec<OsString>,
+    },
+}
+
+/// Check an alias name and expansion.
+///
+/// # Errors
+///
+/// Returns an error for empty names, names with whitespace or a leading
+/// `-`, and empty or unparseable expansions.
+pub fn validate(name: &str, expansion: &str) -> Result<()> {
+    if name.is_empty() || name.starts_with('-') || name.contains(char::is_whitespace) {
+        return Err(LinError::config(format!(
+            "Invalid alias name '{}': use a single word that doesn't start with '-'",
+            name
+        )));
+    }
+    match expansion.strip_prefix(SHELL_PREFIX) {
+        Some(command) if command.trim().is_empty() => Err(LinError::config(format!(
+            "Alias '{}' has an empty shell command",
+            name
+        ))),
+        Some(_) => Ok(()),
+        None => match shlex::split(expansion) {
+            Some(words) if !words.is_empty() => Ok(()),
+            Some(_) => Err(LinError::config(format!(
+                "Alias '{}' has an empty expansion",
+                name
+            ))),
+            None => Err(LinError::config(format!(
+                "Alias '{}' has unbalanced quotes: {}",
+                name, expansion
+            ))),
+        },
+    }
+}
+
+/// Position of the command word in `args` (after the program name and any
+/// global options), if there is one.
+fn command_index(args: &[OsString]) -> Option<usize> {
+    let mut i = 1;
+    while let Some(arg) = args.get(i) {
+        let arg = arg.to_str()?;
+        if GLOBAL_VALUE_OPTIONS.contains(&arg) {
+            i += 2;
+        } else if arg.starts_with('-') {
+            i += 1;
+        } else {
+            return Some(i);
+        }
+    }
+    None
+}
+
+/// Expand the alias named by the command word in `args`, if any.
+///
+/// `args` is the full command line, program name included. Built-in
+/// commands always win over aliases of the same name.
+///
+/// # Errors
+///
+/// Returns an error if the alias references `$N` but fewer arguments were
+/// given, or if its expansion can't be split into words.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::BTreeMap;
+/// use std::ffi::OsString;
+/// use lin::alias::{expand, Expansion};
+///
+/// let aliases = BTreeMap::from([("mine".to_string(), "issue list --assignee me".to_string())]);
+/// let args: Vec<OsString> = ["lin", "--json", "mine"].iter().map(OsString::from).collect();
+/// let expanded = expand(&args, &aliases, &["issue"]).unwrap();
+/// assert_eq!(
+///     expanded,
+///     Some(Expansion::Args(
+///         ["lin", "--json", "issue", "list", "--assignee", "me"]
+///             .iter()
+///             .map(OsString::from)
+///             .collect()
+///     ))
+/// );
+/// ```
+pub fn expand(
+    args: &[OsString],
+    aliases: &BTreeMap<String, String>,
+    builtins: &[&str],
+) -> Result<Option<Expansion>> {
+    let Some(index) = command_index(args) else {
+        return Ok(None);
+    };
+    let Some(name) = args[index].to_str() else {
+        return Ok(None);
+    };
+    if builtins.contains(&name) {
+        return Ok(None);
+    }
+    let Some(expansion) = aliases.get(name) else {
+        return Ok(None);
+    };
+    let rest = &args[index + 1..];
+
+    if let Some(command) = expansion.strip_prefix(SHELL_PREFIX) {
+        return Ok(Some(Expansion::Shell {
+            name: name.to_string(),
+            command: command.to_string(),
+            args: rest.to_vec(),
+        }));
+    }
+
+    let rest: Vec<String> = rest
+        .iter()
+        .map(|arg| arg.to_string_lossy().into_owned())
+        .collect();
+    let words = substitute(name, expansion, &rest)?;
+    let mut expanded = args[..index].to_vec();
+    expanded.extend(words.into_iter().map(OsString::from));
+    Ok(Some(Expansion::Args(expanded)))
+}
+
+/// Split an expansion into words and fill in `$1` ... `$9`.
+///
+/// Arguments after the highest one referenced are appended.
+fn substitute(name: &str, expansion: &str, args: &[String]) -> Result<Vec<String>> {
+    let words = shlex::split(expansion).ok_or_else(|| {
+        LinError::config(format!(
+            "Alias '{}' has unbalanced quotes: {}",
+            name, expansion
+        ))
+    })?;
+
+    let mut used = 0;
+    let mut expanded = Vec::with_capacity(words.len() + args.len());
+    for word in words {
+        let mut out = String::with_capacity(word.len());
+        let mut chars = word.chars().peekable();
+        while let Some(c) = chars.next() {
+            let digit = chars.peek().and_then(|d| d.to_digit(10)).filter(|&d| d > 0);
+            match (c, digit) {
+                ('$', Some(n)) => {
+                    chars.next();
+                    let n = n as usize;
+                    let arg = args.get(n - 1).ok_or_else(|| {
+                        LinError::config(format!(
+                            "Alias '{}' needs at least {} argument{}: {}",
+                            name,
+                            n,
+                            if n == 1 { "" } else { "s" },
+                            expansion
+                        ))
+                    })?;
+                    out.push_str(arg);
+                    used = used.max(n);
+                }
+                _ => out.push(c),
+            }
+        }
+        expanded.push(out);
+    }
+    expanded.extend(args[used..].iter().cloned());
+    Ok(expanded)
+}
+
+/// Run a shell alias, returning its exit code.
+///
+/// # Errors
+///
+/// Returns an error if `sh` can't be started.
+pub fn run_shell(name: &str, command: &str, args: &[OsString]) -> Result<i32> {
+    let status = Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        .arg(name)
+        .args(args)
+        .status()
+        .map_err(|e| LinError::config(format!("Failed to run alias '{}': {}", name, e)))?;
+    Ok(status.code().unwrap_or(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn os(args: &[&str]) -> Vec<OsString> {
+        args.iter().map(OsString::from).collect()
+    }
+
+    fn aliases() -> BTreeMap<String, String> {
+        BTreeMap::from([
+            (
+                "mine".to_string(),
+                "issue list --assignee me --state started".to_string(),
+            ),
+            (
+                "bug".to_string(),
+                "issue create --labels bug --priority high --title".to_string(),
+            ),
+            (
+                "mv".to_string(),
+                "issue update $1 --state \"$2\"".to_string(),
+            ),
+            ("issue".to_string(), "search".to_string()),
+            ("web".to_string(), "!echo \"$1\"".to_string()),
+        ])
+    }
+
+    fn expand_args(args: &[&str]) -> Option<Expansion> {
+        expand(&os(args), &aliases(), &["issue", "search"]).unwrap()
+    }
+
+    #[test]
+    fn test_expand_keeps_global_options() {
+        assert_eq!(
+            expand_args(&["lin", "--org", "work", "mine", "--limit", "5"]),
+            Some(Expansion::Args(os(&[
+                "lin",
+                "--org",
+                "work",
+                "issue",
+                "list",
+                "--assignee",
+                "me",
+                "--state",
+                "started",
+                "--limit",
+                "5"
+            ])))
+        );
+    }
+
+    #[test]
+    fn test_expand_appends_arguments_as_single_words() {
+        assert_eq!(
+            expand_args(&["lin", "bug", "Crash on login"]),
+            Some(Expansion::Args(os(&[
+                "lin",
+                "issue",
+                "create",
+                "--labels",
+                "bug",
+                "--priority",
+                "high",
+                "--title",
+                "Crash on login"
+            ])))
+        );
+    }
+
+    #[test]
+    fn test_expand_substitutes_positionals() {
+        assert_eq!(
+            expand_args(&["lin", "mv", "ENG-1", "in progress", "--json"]),
+            Some(Expansion::Args(os(&[
+                "lin",
+                "issue",
+                "update",
+                "ENG-1",
+                "--state",
+                "in progress",
+                "--json"
+            ])))
+        );
+
+        let err = expand(&os(&["lin", "mv", "ENG-1"]), &aliases(), &[]).unwrap_err();
+        assert!(err.to_string().contains("needs at least 2 arguments"));
+    }
+
+    #[test]
+    fn test_expand_ignores_builtins_and_unknown_commands() {
+        assert_eq!(expand_args(&["lin", "issue", "list"]), None);
+        assert_eq!(expand_args(&["lin", "unknown"]), None);
+        assert_eq!(expand_args(&["lin", "--help"]), None);
+        // An option value that happens to match an alias isn't a command
+        assert_eq!(expand_args(&["lin", "-o", "mine", "issue", "list"]), None);
+    }
+
+    #[test]
+    fn test_expand_shell_alias() {
+        assert_eq!(
+            expand_args(&["lin", "web", "ENG-1"]),
+            Some(Expansion::Shell {
+                name: "web".to_string(),
+                command: "echo \"$1\"".to_string(),
+                args: os(&["ENG-1"]),
+            })
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_shell_passes_arguments() {
+        assert_eq!(
+            run_shell(
+                "check",
+                "test \"$1\" = 'a b' && test $# = 2",
+                &os(&["a b", "c"])
+            )
+            .unwrap(),
+            0
+        );
+        assert_eq!(run_shell("fail", "exit 3", &[]).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(validate("mine", "issue list").is_ok());
+        assert!(validate("web", "!open https://linear.app").is_ok());
+        assert!(validate("two words", "issue list").is_err());
+        assert!(validate("-x", "issue list").is_err());
+        assert!(validate("empty", "  ").is_err());
+        assert!(validate("shell", "!").is_err());
+        assert!(validate("quotes", "issue create --title \"oops").is_err());
+    }
+}
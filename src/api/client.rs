@@ -3,6 +3,9 @@
 //! This module provides a blocking HTTP client for making GraphQL requests
 //! to the Linear API.
 
+use std::cell::RefCell;
+
+use reqwest::StatusCode;
 use reqwest::blocking::Client;
 use reqwest::header::{AUTHORIZATION, CONTENT_TYPE, HeaderMap, HeaderValue};
 use serde::de::DeserializeOwned;
@@ -46,14 +49,19 @@ pub const LINEAR_API_URL: &str = "https://api.linear.app/graphql";
 /// # }
 /// ```
 pub struct GraphQLClient {
-    /// The API token for authentication.
-    token: String,
+    /// The Authorization header value (API key or "Bearer <access token>").
+    token: RefCell<String>,
     /// The GraphQL endpoint URL.
     base_url: String,
     /// The HTTP client.
     client: Client,
+    /// Called on HTTP 401 to obtain a fresh Authorization header value.
+    refresher: Option<Box<TokenRefresher>>,
 }
 
+/// Callback that refreshes credentials and returns the new Authorization header value.
+pub type TokenRefresher = dyn Fn() -> Result<String>;
+
 /// GraphQL request body.
 #[derive(Serialize)]
 struct GraphQLRequest<'a> {
@@ -110,12 +118,26 @@ impl GraphQLClient {
     pub fn with_url(token: &str, url: &str) -> Self {
         let client = Client::new();
         Self {
-            token: token.to_string(),
+            token: RefCell::new(token.to_string()),
             base_url: url.to_string(),
             client,
+            refresher: None,
         }
     }
 
+    /// Refresh credentials transparently when a request is rejected with HTTP 401.
+    ///
+    /// The refresher is called at most once per request; the request is then
+    /// retried with the Authorization header value it returns.
+    ///
+    /// # Arguments
+    ///
+    /// * `refresher` - Returns the new Authorization header value
+    pub fn with_token_refresh(mut self, refresher: impl Fn() -> Result<String> + 'static) -> Self {
+        self.refresher = Some(Box::new(refresher));
+        self
+    }
+
     /// Execute a GraphQL query or mutation.
     ///
     /// # Arguments
@@ -155,28 +177,17 @@ impl GraphQLClient {
         query: &str,
         variables: serde_json::Value,
     ) -> Result<T> {
-        // Build headers
-        let mut headers = HeaderMap::new();
-
-        // Linear API expects just the token, not "Bearer token"
-        headers.insert(
-            AUTHORIZATION,
-            HeaderValue::from_str(&self.token)
-                .map_err(|e| LinError::api(format!("Invalid token format: {}", e)))?,
-        );
-        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
-
         // Build request body
         let request_body = GraphQLRequest { query, variables };
 
-        // Make the request
-        let response = self
-            .client
-            .post(&self.base_url)
-            .headers(headers)
-            .json(&request_body)
-            .send()
-            .map_err(|e| LinError::api(format!("Request failed: {}", e)))?;
+        // Make the request, refreshing expired OAuth credentials once
+        let mut response = self.send(&request_body)?;
+        if response.status() == StatusCode::UNAUTHORIZED
+            && let Some(refresher) = &self.refresher
+        {
+            *self.token.borrow_mut() = refresher()?;
+            response = self.send(&request_body)?;
+        }
 
         // Check HTTP status
         let status = response.status();
@@ -217,6 +228,27 @@ impl GraphQLClient {
             .data
             .ok_or_else(|| LinError::api("GraphQL response contained no data".to_string()))
     }
+
+    /// Send a request with the current Authorization header.
+    fn send(&self, request_body: &GraphQLRequest) -> Result<reqwest::blocking::Response> {
+        // Build headers
+        let mut headers = HeaderMap::new();
+
+        // Personal API keys are sent as-is; OAuth tokens carry their "Bearer " prefix
+        headers.insert(
+            AUTHORIZATION,
+            HeaderValue::from_str(&self.token.borrow())
+                .map_err(|e| LinError::api(format!("Invalid token format: {}", e)))?,
+        );
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+        self.client
+            .post(&self.base_url)
+            .headers(headers)
+            .json(request_body)
+            .send()
+            .map_err(|e| LinError::api(format!("Request failed: {}", e)))
+    }
 }
 
 #[cfg(test)]
@@ -226,14 +258,14 @@ mod tests {
     #[test]
     fn test_graphql_client_creation() {
         let client = GraphQLClient::new("test-token");
-        assert_eq!(client.token, "test-token");
+        assert_eq!(*client.token.borrow(), "test-token");
         assert_eq!(client.base_url, LINEAR_API_URL);
     }
 
     #[test]
     fn test_graphql_client_with_url() {
         let client = GraphQLClient::with_url("test-token", "http://localhost:8080/graphql");
-        assert_eq!(client.token, "test-token");
+        assert_eq!(*client.token.borrow(), "test-token");
         assert_eq!(client.base_url, "http://localhost:8080/graphql");
     }
 
@@ -596,4 +628,70 @@ mod integration_tests {
         // Verify mock was called
         mock.assert();
     }
+
+    #[test]
+    fn test_unauthorized_triggers_token_refresh_and_retry() {
+        let mut server = mockito::Server::new();
+
+        let expired = server
+            .mock("POST", "/")
+            .match_header("authorization", "Bearer expired")
+            .with_status(401)
+            .with_body(r#"{"error": "Unauthorized"}"#)
+            .expect(1)
+            .create();
+        let refreshed = server
+            .mock("POST", "/")
+            .match_header("authorization", "Bearer fresh")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"data": {"viewer": {"id": "user-123", "name": "Test", "email": "test@example.com", "displayName": null, "active": true}}}"#,
+            )
+            .expect(2)
+            .create();
+
+        let calls = std::rc::Rc::new(std::cell::Cell::new(0));
+        let counter = calls.clone();
+        let client = GraphQLClient::with_url("Bearer expired", &server.url()).with_token_refresh(
+            move || {
+                counter.set(counter.get() + 1);
+                Ok("Bearer fresh".to_string())
+            },
+        );
+
+        let first: ViewerResponse = client
+            .query("query { viewer { id } }", serde_json::json!({}))
+            .expect("Query should succeed after refresh");
+        assert_eq!(first.viewer.id, "user-123");
+
+        // The refreshed token is reused for subsequent requests
+        let _second: ViewerResponse = client
+            .query("query { viewer { id } }", serde_json::json!({}))
+            .unwrap();
+
+        assert_eq!(calls.get(), 1);
+        expired.assert();
+        refreshed.assert();
+    }
+
+    #[test]
+    fn test_unauthorized_after_refresh_is_reported() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", "/")
+            .with_status(401)
+            .with_body(r#"{"error": "Unauthorized"}"#)
+            .expect(2)
+            .create();
+
+        let client = GraphQLClient::with_url("Bearer expired", &server.url())
+            .with_token_refresh(|| Ok("Bearer revoked".to_string()));
+
+        let result: Result<ViewerResponse> =
+            client.query("query { viewer { id } }", serde_json::json!({}));
+
+        assert!(result.unwrap_err().to_string().contains("HTTP 401"));
+        mock.assert();
+    }
 }
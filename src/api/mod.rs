@@ -2,9 +2,11 @@
 //!
 //! This module provides:
 //! - [`client::GraphQLClient`] - HTTP client for making GraphQL requests
+//! - [`oauth`] - OAuth2 + PKCE browser login and token refresh
 //! - [`queries`] - GraphQL query and mutation strings
 
 pub mod client;
+pub mod oauth;
 pub mod queries;
 
 pub use client::GraphQLClient;
@@ -0,0 +1,541 @@
+//! OAuth2 authorization-code flow with PKCE for Linear.
+//!
+//! This module provides:
+//! - [`Pkce`] - code verifier and S256 challenge generation
+//! - [`CallbackServer`] - a loopback HTTP listener that receives the redirect
+//! - [`exchange_code`] and [`refresh_access_token`] - token endpoint requests
+//!
+//! Token endpoint URLs are parameters so the flow can be exercised against a
+//! local stand-in server in tests.
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::time::{Duration, Instant};
+
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use ring::digest::{SHA256, digest};
+use ring::rand::{SecureRandom, SystemRandom};
+use serde::Deserialize;
+
+use crate::Result;
+use crate::error::LinError;
+
+/// Linear's OAuth2 authorization endpoint.
+pub const LINEAR_AUTHORIZE_URL: &str = "https://linear.app/oauth/authorize";
+
+/// Linear's OAuth2 token endpoint.
+pub const LINEAR_TOKEN_URL: &str = "https://api.linear.app/oauth/token";
+
+/// Scopes requested by `lin auth login`.
+pub const DEFAULT_SCOPES: &str = "read,write";
+
+/// Path the loopback server expects the redirect on.
+const CALLBACK_PATH: &str = "/callback";
+
+/// Generate `len` cryptographically secure random bytes, base64url encoded.
+fn random_token(len: usize) -> Result<String> {
+    let mut bytes = vec![0u8; len];
+    SystemRandom::new()
+        .fill(&mut bytes)
+        .map_err(|_| LinError::config("Failed to generate random bytes"))?;
+    Ok(URL_SAFE_NO_PAD.encode(bytes))
+}
+
+/// PKCE code verifier and its S256 challenge (RFC 7636).
+#[derive(Debug, Clone)]
+pub struct Pkce {
+    /// The secret sent with the token exchange.
+    pub verifier: String,
+    /// base64url(SHA-256(verifier)), sent with the authorization request.
+    pub challenge: String,
+}
+
+impl Pkce {
+    /// Generate a fresh verifier (43 characters) and its challenge.
+    pub fn generate() -> Result<Self> {
+        Ok(Self::from_verifier(random_token(32)?))
+    }
+
+    /// Build the challenge for a known verifier.
+    pub fn from_verifier(verifier: String) -> Self {
+        let challenge = URL_SAFE_NO_PAD.encode(digest(&SHA256, verifier.as_bytes()));
+        Self {
+            verifier,
+            challenge,
+        }
+    }
+}
+
+/// Generate a random `state` value to guard the redirect against CSRF.
+pub fn generate_state() -> Result<String> {
+    random_token(16)
+}
+
+/// Build the URL the user opens to authorize lin.
+pub fn authorize_url(
+    authorize_endpoint: &str,
+    client_id: &str,
+    redirect_uri: &str,
+    state: &str,
+    challenge: &str,
+    scopes: &str,
+) -> Result<String> {
+    let mut url = url::Url::parse(authorize_endpoint)
+        .map_err(|e| LinError::config(format!("Invalid authorize URL: {}", e)))?;
+    url.query_pairs_mut()
+        .append_pair("client_id", client_id)
+        .append_pair("redirect_uri", redirect_uri)
+        .append_pair("response_type", "code")
+        .append_pair("scope", scopes)
+        .append_pair("state", state)
+        .append_pair("code_challenge", challenge)
+        .append_pair("code_challenge_method", "S256");
+    Ok(url.to_string())
+}
+
+/// Successful response from the token endpoint.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TokenResponse {
+    /// The access token.
+    pub access_token: String,
+    /// Token type (normally "Bearer").
+    #[serde(default)]
+    pub token_type: Option<String>,
+    /// Lifetime of the access token in seconds.
+    #[serde(default)]
+    pub expires_in: Option<i64>,
+    /// Refresh token, if issued.
+    #[serde(default)]
+    pub refresh_token: Option<String>,
+    /// Granted scopes.
+    #[serde(default)]
+    pub scope: Option<String>,
+}
+
+impl TokenResponse {
+    /// Expiry timestamp (RFC 3339) computed from `expires_in`.
+    pub fn expires_at(&self) -> Option<String> {
+        self.expires_in
+            .map(|secs| (chrono::Utc::now() + chrono::Duration::seconds(secs)).to_rfc3339())
+    }
+}
+
+fn request_token(token_endpoint: &str, form: &[(&str, &str)]) -> Result<TokenResponse> {
+    let response = reqwest::blocking::Client::new()
+        .post(token_endpoint)
+        .form(form)
+        .send()
+        .map_err(|e| LinError::api(format!("Token request failed: {}", e)))?;
+
+    let status = response.status();
+    let body = response
+        .text()
+        .map_err(|e| LinError::api(format!("Failed to read token response: {}", e)))?;
+    if !status.is_success() {
+        return Err(LinError::api(format!(
+            "Token request failed: HTTP {}: {}",
+            status.as_u16(),
+            body
+        )));
+    }
+    serde_json::from_str(&body)
+        .map_err(|e| LinError::parse(format!("Failed to parse token response: {}", e)))
+}
+
+/// Exchange an authorization code for tokens.
+///
+/// # Arguments
+///
+/// * `token_endpoint` - Token endpoint URL (normally [`LINEAR_TOKEN_URL`])
+/// * `client_id` - OAuth application client ID
+/// * `code` - Authorization code received on the redirect
+/// * `redirect_uri` - The redirect URI used in the authorization request
+/// * `verifier` - The PKCE code verifier
+pub fn exchange_code(
+    token_endpoint: &str,
+    client_id: &str,
+    code: &str,
+    redirect_uri: &str,
+    verifier: &str,
+) -> Result<TokenResponse> {
+    request_token(
+        token_endpoint,
+        &[
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("redirect_uri", redirect_uri),
+            ("client_id", client_id),
+            ("code_verifier", verifier),
+        ],
+    )
+}
+
+/// Exchange a refresh token for a new access token.
+///
+/// # Arguments
+///
+/// * `token_endpoint` - Token endpoint URL (normally [`LINEAR_TOKEN_URL`])
+/// * `client_id` - OAuth application client ID
+/// * `refresh_token` - The refresh token from an earlier exchange
+pub fn refresh_access_token(
+    token_endpoint: &str,
+    client_id: &str,
+    refresh_token: &str,
+) -> Result<TokenResponse> {
+    request_token(
+        token_endpoint,
+        &[
+            ("grant_type", "refresh_token"),
+            ("refresh_token", refresh_token),
+            ("client_id", client_id),
+        ],
+    )
+}
+
+/// Loopback HTTP listener that receives the OAuth redirect.
+pub struct CallbackServer {
+    listener: TcpListener,
+}
+
+impl CallbackServer {
+    /// Listen on `127.0.0.1:port`. Port 0 picks a free port.
+    pub fn bind(port: u16) -> Result<Self> {
+        let listener = TcpListener::bind(("127.0.0.1", port))?;
+        Ok(Self { listener })
+    }
+
+    /// The redirect URI to register with the authorization request.
+    pub fn redirect_uri(&self) -> Result<String> {
+        let port = self.listener.local_addr()?.port();
+        Ok(format!("http://127.0.0.1:{}{}", port, CALLBACK_PATH))
+    }
+
+    /// Wait for the redirect and return the authorization code.
+    ///
+    /// Requests to other paths (e.g., `/favicon.ico`) get a 404 and are
+    /// ignored, as are connections that fail or send nothing, like a
+    /// browser's idle preconnect. Fails if the redirect carries an error, the
+    /// `state` doesn't match, or nothing arrives within `timeout`.
+    pub fn wait_for_code(&self, expected_state: &str, timeout: Duration) -> Result<String> {
+        self.listener.set_nonblocking(true)?;
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            match self.listener.accept() {
+                Ok((stream, _)) => {
+                    // A failed connection isn't the callback; keep waiting for it
+                    if let Ok(Some(result)) = handle_callback(stream, expected_state) {
+                        return result;
+                    }
+                }
+                Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                    if Instant::now() >= deadline {
+                        return Err(LinError::config(
+                            "Timed out waiting for the browser to complete login",
+                        ));
+                    }
+                    std::thread::sleep(Duration::from_millis(50));
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+}
+
+/// Handle one request on the loopback server.
+///
+/// Returns `None` for requests that aren't the callback.
+fn handle_callback(mut stream: TcpStream, expected_state: &str) -> Result<Option<Result<String>>> {
+    stream.set_nonblocking(false)?;
+    stream.set_read_timeout(Some(Duration::from_secs(5)))?;
+    let mut request_line = String::new();
+    BufReader::new(&stream).read_line(&mut request_line)?;
+
+    let target = request_line.split_whitespace().nth(1).unwrap_or("/");
+    let url = url::Url::parse(&format!("http://127.0.0.1{}", target))
+        .map_err(|e| LinError::parse(format!("Invalid callback request: {}", e)))?;
+    if url.path() != CALLBACK_PATH {
+        respond(&mut stream, "404 Not Found", "Not found")?;
+        return Ok(None);
+    }
+
+    let param = |name: &str| {
+        url.query_pairs()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.into_owned())
+    };
+
+    let result = if let Some(error) = param("error") {
+        let description = param("error_description").unwrap_or_default();
+        Err(LinError::api(format!(
+            "Authorization denied: {} {}",
+            error, description
+        )))
+    } else if param("state").as_deref() != Some(expected_state) {
+        Err(LinError::api(
+            "Authorization response state mismatch. Please try again",
+        ))
+    } else {
+        param("code").ok_or_else(|| LinError::api("Authorization response had no code"))
+    };
+
+    // The redirect has arrived, so its result stands even if the browser
+    // doesn't stay for the response
+    let _ = match &result {
+        Ok(_) => respond(
+            &mut stream,
+            "200 OK",
+            "Logged in to lin. You can close this window.",
+        ),
+        Err(err) => respond(&mut stream, "400 Bad Request", &err.to_string()),
+    };
+    Ok(Some(result))
+}
+
+fn respond(stream: &mut TcpStream, status: &str, message: &str) -> Result<()> {
+    let body = format!(
+        "<!doctype html><html><body><p>{}</p></body></html>",
+        message.replace('<', "&lt;")
+    );
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    )?;
+    stream.flush()?;
+    Ok(())
+}
+
+/// Try to open `url` in the user's browser. Returns whether a browser was launched.
+pub fn open_browser(url: &str) -> bool {
+    use std::process::{Command, Stdio};
+
+    #[cfg(target_os = "macos")]
+    let mut command = Command::new("open");
+    #[cfg(target_os = "windows")]
+    let mut command = {
+        let mut c = Command::new("cmd");
+        c.args(["/C", "start", ""]);
+        c
+    };
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    let mut command = Command::new("xdg-open");
+
+    command
+        .arg(url)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .is_ok_and(|status| status.success())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pkce_challenge_matches_rfc7636_example() {
+        // Appendix B of RFC 7636
+        let pkce = Pkce::from_verifier("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk".to_string());
+        assert_eq!(
+            pkce.challenge,
+            "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM"
+        );
+    }
+
+    #[test]
+    fn test_pkce_generate_is_random_and_url_safe() {
+        let a = Pkce::generate().unwrap();
+        let b = Pkce::generate().unwrap();
+        assert_ne!(a.verifier, b.verifier);
+        assert_eq!(a.verifier.len(), 43);
+        assert!(
+            a.verifier
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        );
+    }
+
+    #[test]
+    fn test_authorize_url_includes_pkce_parameters() {
+        let url = authorize_url(
+            LINEAR_AUTHORIZE_URL,
+            "client-123",
+            "http://127.0.0.1:8123/callback",
+            "state-abc",
+            "challenge-xyz",
+            DEFAULT_SCOPES,
+        )
+        .unwrap();
+        assert!(url.starts_with("https://linear.app/oauth/authorize?"));
+        assert!(url.contains("client_id=client-123"));
+        assert!(url.contains("redirect_uri=http%3A%2F%2F127.0.0.1%3A8123%2Fcallback"));
+        assert!(url.contains("response_type=code"));
+        assert!(url.contains("scope=read%2Cwrite"));
+        assert!(url.contains("state=state-abc"));
+        assert!(url.contains("code_challenge=challenge-xyz"));
+        assert!(url.contains("code_challenge_method=S256"));
+    }
+
+    #[test]
+    fn test_exchange_code_posts_form_to_token_endpoint() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", "/oauth/token")
+            .match_header("content-type", "application/x-www-form-urlencoded")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded(
+                    "grant_type".to_string(),
+                    "authorization_code".to_string(),
+                ),
+                mockito::Matcher::UrlEncoded("code".to_string(), "code-123".to_string()),
+                mockito::Matcher::UrlEncoded("client_id".to_string(), "client-123".to_string()),
+                mockito::Matcher::UrlEncoded(
+                    "code_verifier".to_string(),
+                    "verifier-abc".to_string(),
+                ),
+                mockito::Matcher::UrlEncoded(
+                    "redirect_uri".to_string(),
+                    "http://127.0.0.1:8123/callback".to_string(),
+                ),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"access_token": "access-1", "token_type": "Bearer", "expires_in": 3600, "refresh_token": "refresh-1", "scope": "read write"}"#,
+            )
+            .create();
+
+        let tokens = exchange_code(
+            &format!("{}/oauth/token", server.url()),
+            "client-123",
+            "code-123",
+            "http://127.0.0.1:8123/callback",
+            "verifier-abc",
+        )
+        .unwrap();
+
+        assert_eq!(tokens.access_token, "access-1");
+        assert_eq!(tokens.refresh_token.as_deref(), Some("refresh-1"));
+        assert!(tokens.expires_at().is_some());
+        mock.assert();
+    }
+
+    #[test]
+    fn test_refresh_access_token_error_status() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", "/oauth/token")
+            .match_body(mockito::Matcher::UrlEncoded(
+                "grant_type".to_string(),
+                "refresh_token".to_string(),
+            ))
+            .with_status(400)
+            .with_body(r#"{"error": "invalid_grant"}"#)
+            .create();
+
+        let err = refresh_access_token(
+            &format!("{}/oauth/token", server.url()),
+            "client-123",
+            "stale-refresh",
+        )
+        .unwrap_err();
+
+        assert!(err.to_string().contains("HTTP 400"));
+        assert!(err.to_string().contains("invalid_grant"));
+        mock.assert();
+    }
+
+    fn send_request(redirect_uri: &str, path_and_query: &str) -> std::thread::JoinHandle<String> {
+        let addr = redirect_uri
+            .trim_start_matches("http://")
+            .trim_end_matches(CALLBACK_PATH)
+            .to_string();
+        let request = format!("GET {} HTTP/1.1\r\nHost: {}\r\n\r\n", path_and_query, addr);
+        std::thread::spawn(move || {
+            let mut stream = TcpStream::connect(addr).unwrap();
+            stream.write_all(request.as_bytes()).unwrap();
+            let mut response = String::new();
+            std::io::Read::read_to_string(&mut stream, &mut response).unwrap();
+            response
+        })
+    }
+
+    #[test]
+    fn test_callback_server_returns_code() {
+        let server = CallbackServer::bind(0).unwrap();
+        let redirect_uri = server.redirect_uri().unwrap();
+
+        let favicon = send_request(&redirect_uri, "/favicon.ico");
+        assert!(
+            server
+                .wait_for_code("state-abc", Duration::from_millis(200))
+                .is_err()
+        );
+        assert!(favicon.join().unwrap().starts_with("HTTP/1.1 404"));
+
+        let client = send_request(&redirect_uri, "/callback?code=code-123&state=state-abc");
+        let code = server
+            .wait_for_code("state-abc", Duration::from_secs(5))
+            .unwrap();
+
+        assert_eq!(code, "code-123");
+        assert!(client.join().unwrap().starts_with("HTTP/1.1 200 OK"));
+    }
+
+    #[test]
+    fn test_callback_server_ignores_broken_connections() {
+        let server = CallbackServer::bind(0).unwrap();
+        let redirect_uri = server.redirect_uri().unwrap();
+        let addr = server.listener.local_addr().unwrap();
+
+        // A preconnect that closes without a request, then an unparseable one
+        drop(TcpStream::connect(addr).unwrap());
+        let mut garbage = TcpStream::connect(addr).unwrap();
+        garbage
+            .write_all(b"GET http://[::1 HTTP/1.1\r\n\r\n")
+            .unwrap();
+
+        let client = send_request(&redirect_uri, "/callback?code=code-123&state=state-abc");
+        let code = server
+            .wait_for_code("state-abc", Duration::from_secs(5))
+            .unwrap();
+
+        assert_eq!(code, "code-123");
+        assert!(client.join().unwrap().starts_with("HTTP/1.1 200 OK"));
+    }
+
+    #[test]
+    fn test_callback_server_rejects_state_mismatch() {
+        let server = CallbackServer::bind(0).unwrap();
+        let redirect_uri = server.redirect_uri().unwrap();
+
+        let client = send_request(&redirect_uri, "/callback?code=code-123&state=forged");
+        let err = server
+            .wait_for_code("state-abc", Duration::from_secs(5))
+            .unwrap_err();
+
+        assert!(err.to_string().contains("state mismatch"));
+        assert!(client.join().unwrap().starts_with("HTTP/1.1 400"));
+    }
+
+    #[test]
+    fn test_callback_server_reports_denied_authorization() {
+        let server = CallbackServer::bind(0).unwrap();
+        let redirect_uri = server.redirect_uri().unwrap();
+
+        let client = send_request(
+            &redirect_uri,
+            "/callback?error=access_denied&error_description=User+declined&state=state-abc",
+        );
+        let err = server
+            .wait_for_code("state-abc", Duration::from_secs(5))
+            .unwrap_err();
+
+        assert!(err.to_string().contains("access_denied User declined"));
+        client.join().unwrap();
+    }
+}
@@ -52,6 +52,79 @@ query Issues($first: Int, $filter: IssueFilter, $orderBy: PaginationOrderBy) {
 }
 "#;
 
+/// Query to list issues with the fields needed for stale issue detection.
+///
+/// Variables:
+/// - `first` (Int, optional): Number of issues to fetch
+/// - `filter` (IssueFilter, optional): Filter criteria for issues
+///
+/// Returns: `IssuesResponse`
+pub const STALE_ISSUES_QUERY: &str = r#"
+query StaleIssues($first: Int, $filter: IssueFilter) {
+    issues(first: $first, filter: $filter) {
+        nodes {
+            id
+            identifier
+            title
+            priority
+            estimate
+            dueDate
+            createdAt
+            updatedAt
+            state {
+                id
+                name
+                color
+                type
+            }
+            team {
+                id
+                key
+                name
+                description
+            }
+            assignee {
+                id
+                name
+                email
+                displayName
+                active
+            }
+        }
+    }
+}
+"#;
+
+/// Query to list triage issues with their creators.
+///
+/// Variables:
+/// - `first` (Int, optional): Number of issues to fetch
+/// - `filter` (IssueFilter, optional): Filter criteria for issues
+///
+/// Returns: `TriageIssuesResponse`
+pub const TRIAGE_ISSUES_QUERY: &str = r#"
+query TriageIssues($first: Int, $filter: IssueFilter) {
+    issues(first: $first, filter: $filter) {
+        nodes {
+            id
+            identifier
+            title
+            description
+            priority
+            estimate
+            createdAt
+            creator {
+                id
+                name
+                email
+                displayName
+                active
+            }
+        }
+    }
+}
+"#;
+
 /// Query to get a single issue by ID.
 ///
 /// Variables:
@@ -89,6 +162,15 @@ query Issue($id: String!) {
             displayName
             active
         }
+        subscribers {
+            nodes {
+                id
+                name
+                email
+                displayName
+                active
+            }
+        }
         projectMilestone {
             id
             name
@@ -140,6 +222,15 @@ query IssueByIdentifier($filter: IssueFilter!) {
                 displayName
                 active
             }
+            subscribers {
+                nodes {
+                    id
+                    name
+                    email
+                    displayName
+                    active
+                }
+            }
             projectMilestone {
                 id
                 name
@@ -297,6 +388,64 @@ mutation IssueUnarchive($id: String!) {
 }
 "#;
 
+/// Mutation to subscribe a user to an issue.
+///
+/// Variables:
+/// - `id` (String!): The issue's unique identifier
+/// - `userId` (String, optional): User to subscribe (defaults to the authenticated user)
+///
+/// Returns: `IssueSubscribeResponse`
+pub const ISSUE_SUBSCRIBE_MUTATION: &str = r#"
+mutation IssueSubscribe($id: String!, $userId: String) {
+    issueSubscribe(id: $id, userId: $userId) {
+        success
+        issue {
+            id
+            identifier
+            title
+            subscribers {
+                nodes {
+                    id
+                    name
+                    email
+                    displayName
+                    active
+                }
+            }
+        }
+    }
+}
+"#;
+
+/// Mutation to unsubscribe a user from an issue.
+///
+/// Variables:
+/// - `id` (String!): The issue's unique identifier
+/// - `userId` (String, optional): User to unsubscribe (defaults to the authenticated user)
+///
+/// Returns: `IssueUnsubscribeResponse`
+pub const ISSUE_UNSUBSCRIBE_MUTATION: &str = r#"
+mutation IssueUnsubscribe($id: String!, $userId: String) {
+    issueUnsubscribe(id: $id, userId: $userId) {
+        success
+        issue {
+            id
+            identifier
+            title
+            subscribers {
+                nodes {
+                    id
+                    name
+                    email
+                    displayName
+                    active
+                }
+            }
+        }
+    }
+}
+"#;
+
 /// Query to get a single issue with its comments by ID.
 ///
 /// Variables:
@@ -334,6 +483,15 @@ query IssueWithComments($id: String!) {
             displayName
             active
         }
+        subscribers {
+            nodes {
+                id
+                name
+                email
+                displayName
+                active
+            }
+        }
         comments {
             nodes {
                 id
@@ -391,6 +549,15 @@ query IssueByIdentifierWithComments($filter: IssueFilter!) {
                 displayName
                 active
             }
+            subscribers {
+                nodes {
+                    id
+                    name
+                    email
+                    displayName
+                    active
+                }
+            }
             comments {
                 nodes {
                     id
@@ -411,6 +578,115 @@ query IssueByIdentifierWithComments($filter: IssueFilter!) {
 }
 "#;
 
+/// Query to get an issue's history and comments for building a timeline.
+///
+/// Variables:
+/// - `id` (String!): The issue's unique identifier
+/// - `first` (Int, optional): Maximum number of history entries and comments to fetch
+///
+/// Returns: `IssueHistoryResponse`
+pub const ISSUE_HISTORY_QUERY: &str = r#"
+query IssueHistory($id: String!, $first: Int) {
+    issue(id: $id) {
+        id
+        identifier
+        title
+        createdAt
+        creator {
+            id
+            name
+            email
+            displayName
+            active
+        }
+        history(first: $first) {
+            nodes {
+                id
+                createdAt
+                actor {
+                    id
+                    name
+                    email
+                    displayName
+                    active
+                }
+                fromState {
+                    id
+                    name
+                    color
+                    type
+                }
+                toState {
+                    id
+                    name
+                    color
+                    type
+                }
+                fromAssignee {
+                    id
+                    name
+                    email
+                    displayName
+                    active
+                }
+                toAssignee {
+                    id
+                    name
+                    email
+                    displayName
+                    active
+                }
+                fromPriority
+                toPriority
+                fromEstimate
+                toEstimate
+                addedLabels {
+                    id
+                    name
+                }
+                removedLabels {
+                    id
+                    name
+                }
+                fromProject {
+                    id
+                    name
+                }
+                toProject {
+                    id
+                    name
+                }
+                fromCycle {
+                    id
+                    number
+                    name
+                }
+                toCycle {
+                    id
+                    number
+                    name
+                }
+            }
+        }
+        comments(first: $first) {
+            nodes {
+                id
+                body
+                createdAt
+                updatedAt
+                user {
+                    id
+                    name
+                    email
+                    displayName
+                    active
+                }
+            }
+        }
+    }
+}
+"#;
+
 /// Query to get an issue's team information.
 ///
 /// Used to resolve which team an issue belongs to for state resolution.
@@ -472,6 +748,13 @@ query IssueRelations($id: String!) {
     issue(id: $id) {
         id
         identifier
+        title
+        state {
+            id
+            name
+            color
+            type
+        }
         relations {
             nodes {
                 id
@@ -480,6 +763,12 @@ query IssueRelations($id: String!) {
                     id
                     identifier
                     title
+                    state {
+                        id
+                        name
+                        color
+                        type
+                    }
                 }
             }
         }
@@ -491,6 +780,12 @@ query IssueRelations($id: String!) {
                     id
                     identifier
                     title
+                    state {
+                        id
+                        name
+                        color
+                        type
+                    }
                 }
             }
         }
@@ -498,12 +793,24 @@ query IssueRelations($id: String!) {
             id
             identifier
             title
+            state {
+                id
+                name
+                color
+                type
+            }
         }
         children {
             nodes {
                 id
                 identifier
                 title
+                state {
+                    id
+                    name
+                    color
+                    type
+                }
             }
         }
     }
@@ -653,6 +960,44 @@ mod tests {
         assert!(ISSUE_UNARCHIVE_MUTATION.contains("success"));
     }
 
+    #[test]
+    fn test_issue_query_includes_subscribers() {
+        assert!(ISSUE_QUERY.contains("subscribers"));
+        assert!(ISSUE_BY_IDENTIFIER_QUERY.contains("subscribers"));
+        assert!(ISSUE_WITH_COMMENTS_QUERY.contains("subscribers"));
+        assert!(ISSUE_BY_IDENTIFIER_WITH_COMMENTS_QUERY.contains("subscribers"));
+    }
+
+    #[test]
+    fn test_issue_subscribe_mutation_is_valid() {
+        assert!(ISSUE_SUBSCRIBE_MUTATION.contains("mutation IssueSubscribe"));
+        assert!(ISSUE_SUBSCRIBE_MUTATION.contains("$id: String!"));
+        assert!(ISSUE_SUBSCRIBE_MUTATION.contains("$userId: String"));
+        assert!(ISSUE_SUBSCRIBE_MUTATION.contains("issueSubscribe(id: $id, userId: $userId)"));
+        assert!(ISSUE_SUBSCRIBE_MUTATION.contains("success"));
+    }
+
+    #[test]
+    fn test_issue_unsubscribe_mutation_is_valid() {
+        assert!(ISSUE_UNSUBSCRIBE_MUTATION.contains("mutation IssueUnsubscribe"));
+        assert!(ISSUE_UNSUBSCRIBE_MUTATION.contains("$id: String!"));
+        assert!(ISSUE_UNSUBSCRIBE_MUTATION.contains("$userId: String"));
+        assert!(ISSUE_UNSUBSCRIBE_MUTATION.contains("issueUnsubscribe(id: $id, userId: $userId)"));
+        assert!(ISSUE_UNSUBSCRIBE_MUTATION.contains("success"));
+    }
+
+    #[test]
+    fn test_issue_history_query_is_valid() {
+        assert!(ISSUE_HISTORY_QUERY.contains("query IssueHistory"));
+        assert!(ISSUE_HISTORY_QUERY.contains("$id: String!"));
+        assert!(ISSUE_HISTORY_QUERY.contains("history(first: $first)"));
+        assert!(ISSUE_HISTORY_QUERY.contains("comments(first: $first)"));
+        assert!(ISSUE_HISTORY_QUERY.contains("fromState"));
+        assert!(ISSUE_HISTORY_QUERY.contains("toAssignee"));
+        assert!(ISSUE_HISTORY_QUERY.contains("addedLabels"));
+        assert!(ISSUE_HISTORY_QUERY.contains("toCycle"));
+    }
+
     #[test]
     fn test_issue_relations_query_is_valid() {
         assert!(ISSUE_RELATIONS_QUERY.contains("query IssueRelations"));
@@ -692,4 +1037,21 @@ mod tests {
         assert!(ISSUE_SET_PARENT_MUTATION.contains("success"));
         assert!(ISSUE_SET_PARENT_MUTATION.contains("parent"));
     }
+
+    #[test]
+    fn test_stale_issues_query_is_valid() {
+        assert!(STALE_ISSUES_QUERY.contains("query StaleIssues"));
+        assert!(STALE_ISSUES_QUERY.contains("$filter: IssueFilter"));
+        assert!(STALE_ISSUES_QUERY.contains("dueDate"));
+        assert!(STALE_ISSUES_QUERY.contains("updatedAt"));
+        assert!(STALE_ISSUES_QUERY.contains("assignee"));
+    }
+
+    #[test]
+    fn test_triage_issues_query_is_valid() {
+        assert!(TRIAGE_ISSUES_QUERY.contains("query TriageIssues"));
+        assert!(TRIAGE_ISSUES_QUERY.contains("$filter: IssueFilter"));
+        assert!(TRIAGE_ISSUES_QUERY.contains("creator"));
+        assert!(TRIAGE_ISSUES_QUERY.contains("createdAt"));
+    }
 }
@@ -15,6 +15,10 @@ query Labels($first: Int) {
             description
             color
             isGroup
+            parent {
+                id
+                name
+            }
             createdAt
             updatedAt
         }
@@ -40,6 +44,10 @@ query TeamLabels($teamId: String!, $first: Int) {
                 description
                 color
                 isGroup
+                parent {
+                    id
+                    name
+                }
                 createdAt
                 updatedAt
             }
@@ -62,12 +70,114 @@ query Label($id: String!) {
         description
         color
         isGroup
+        parent {
+            id
+            name
+        }
         createdAt
         updatedAt
     }
 }
 "#;
 
+/// Mutation to create a label.
+///
+/// Variables:
+/// - `input` (IssueLabelCreateInput!): Label creation input containing:
+///   - `name` (String!): Label name
+///   - `color` (String, optional): Hex color code
+///   - `description` (String, optional): Label description
+///   - `teamId` (String, optional): Team ID (workspace label if omitted)
+///   - `parentId` (String, optional): Parent label group ID
+///   - `isGroup` (Boolean, optional): Whether the label is a group
+///
+/// Returns: `LabelCreateResponse`
+pub const LABEL_CREATE_MUTATION: &str = r#"
+mutation IssueLabelCreate($input: IssueLabelCreateInput!) {
+    issueLabelCreate(input: $input) {
+        success
+        issueLabel {
+                id
+                name
+                description
+                color
+                isGroup
+                parent {
+                    id
+                    name
+                }
+                createdAt
+                updatedAt
+        }
+    }
+}
+"#;
+
+/// Mutation to update a label.
+///
+/// Variables:
+/// - `id` (String!): The label's unique identifier
+/// - `input` (IssueLabelUpdateInput!): Fields to update (name, color, description, parentId)
+///
+/// Returns: `LabelUpdateResponse`
+pub const LABEL_UPDATE_MUTATION: &str = r#"
+mutation IssueLabelUpdate($id: String!, $input: IssueLabelUpdateInput!) {
+    issueLabelUpdate(id: $id, input: $input) {
+        success
+        issueLabel {
+                id
+                name
+                description
+                color
+                isGroup
+                parent {
+                    id
+                    name
+                }
+                createdAt
+                updatedAt
+        }
+    }
+}
+"#;
+
+/// Mutation to delete a label.
+///
+/// Variables:
+/// - `id` (String!): The label's unique identifier
+///
+/// Returns: `LabelDeleteResponse`
+pub const LABEL_DELETE_MUTATION: &str = r#"
+mutation IssueLabelDelete($id: String!) {
+    issueLabelDelete(id: $id) {
+        success
+    }
+}
+"#;
+
+/// Query to list issues carrying a label, with all their label IDs.
+///
+/// Variables:
+/// - `first` (Int, optional): Number of issues to fetch
+/// - `filter` (IssueFilter, optional): Filter criteria, usually on `labels`
+///
+/// Returns: `LabeledIssuesResponse`
+pub const LABELED_ISSUES_QUERY: &str = r#"
+query LabeledIssues($first: Int, $filter: IssueFilter) {
+    issues(first: $first, filter: $filter) {
+        nodes {
+            id
+            identifier
+            labels {
+                nodes {
+                    id
+                }
+            }
+        }
+    }
+}
+"#;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -102,4 +212,40 @@ mod tests {
         assert!(LABEL_QUERY.contains("color"));
         assert!(LABEL_QUERY.contains("isGroup"));
     }
+
+    #[test]
+    fn test_label_queries_include_parent() {
+        assert!(LABELS_QUERY.contains("parent"));
+        assert!(TEAM_LABELS_QUERY.contains("parent"));
+        assert!(LABEL_QUERY.contains("parent"));
+    }
+
+    #[test]
+    fn test_label_create_mutation_is_valid() {
+        assert!(LABEL_CREATE_MUTATION.contains("mutation IssueLabelCreate"));
+        assert!(LABEL_CREATE_MUTATION.contains("$input: IssueLabelCreateInput!"));
+        assert!(LABEL_CREATE_MUTATION.contains("issueLabelCreate(input: $input)"));
+        assert!(LABEL_CREATE_MUTATION.contains("success"));
+    }
+
+    #[test]
+    fn test_label_update_mutation_is_valid() {
+        assert!(LABEL_UPDATE_MUTATION.contains("mutation IssueLabelUpdate"));
+        assert!(LABEL_UPDATE_MUTATION.contains("$id: String!"));
+        assert!(LABEL_UPDATE_MUTATION.contains("issueLabelUpdate(id: $id, input: $input)"));
+    }
+
+    #[test]
+    fn test_label_delete_mutation_is_valid() {
+        assert!(LABEL_DELETE_MUTATION.contains("mutation IssueLabelDelete"));
+        assert!(LABEL_DELETE_MUTATION.contains("issueLabelDelete(id: $id)"));
+        assert!(LABEL_DELETE_MUTATION.contains("success"));
+    }
+
+    #[test]
+    fn test_labeled_issues_query_is_valid() {
+        assert!(LABELED_ISSUES_QUERY.contains("query LabeledIssues"));
+        assert!(LABELED_ISSUES_QUERY.contains("$filter: IssueFilter"));
+        assert!(LABELED_ISSUES_QUERY.contains("labels"));
+    }
 }
@@ -15,7 +15,9 @@
 //! - [`label`] - Label queries
 //! - [`attachment`] - Attachment queries and mutations
 //! - [`workflow`] - Workflow state queries
+//! - [`notification`] - Notification (inbox) queries and mutations
 //! - [`search`] - Search queries
+//! - [`report`] - Reporting queries (flow analytics)
 
 pub mod attachment;
 pub mod comment;
@@ -23,8 +25,10 @@ pub mod cycle;
 pub mod issue;
 pub mod label;
 pub mod milestone;
+pub mod notification;
 pub mod organization;
 pub mod project;
+pub mod report;
 pub mod search;
 pub mod team;
 pub mod user;
@@ -33,10 +37,18 @@ pub mod workflow;
 #[cfg(test)]
 mod tests {
     use super::organization::VIEWER_QUERY;
-    use super::project::{PROJECT_QUERY, PROJECTS_QUERY};
-    use super::team::{TEAM_QUERY, TEAMS_QUERY};
-    use super::user::USERS_QUERY;
-    use super::workflow::WORKFLOW_STATES_QUERY;
+    use super::project::{
+        PROJECT_ISSUE_GRAPH_QUERY, PROJECT_QUERY, PROJECTS_QUERY, ROADMAP_PROJECTS_QUERY,
+    };
+    use super::team::{
+        TEAM_MEMBERSHIP_CREATE_MUTATION, TEAM_MEMBERSHIP_DELETE_MUTATION, TEAM_MEMBERSHIPS_QUERY,
+        TEAM_QUERY, TEAM_SETTINGS_QUERY, TEAM_SETTINGS_UPDATE_MUTATION, TEAMS_QUERY,
+    };
+    use super::user::{USER_WORKLOAD_QUERY, USERS_QUERY};
+    use super::workflow::{
+        WORKFLOW_STATE_ARCHIVE_MUTATION, WORKFLOW_STATE_CREATE_MUTATION,
+        WORKFLOW_STATE_UPDATE_MUTATION, WORKFLOW_STATES_QUERY,
+    };
 
     #[test]
     fn test_viewer_query_is_valid() {
@@ -96,4 +108,65 @@ mod tests {
         assert!(PROJECT_QUERY.contains("project(id: $id)"));
         assert!(PROJECT_QUERY.contains("progress"));
     }
+
+    #[test]
+    fn test_project_issue_graph_query_is_valid() {
+        assert!(PROJECT_ISSUE_GRAPH_QUERY.contains("query ProjectIssueGraph"));
+        assert!(PROJECT_ISSUE_GRAPH_QUERY.contains("$id: String!"));
+        assert!(PROJECT_ISSUE_GRAPH_QUERY.contains("issues(first: $first)"));
+        assert!(PROJECT_ISSUE_GRAPH_QUERY.contains("estimate"));
+        assert!(PROJECT_ISSUE_GRAPH_QUERY.contains("inverseRelations"));
+    }
+
+    #[test]
+    fn test_roadmap_projects_query_is_valid() {
+        assert!(ROADMAP_PROJECTS_QUERY.contains("query RoadmapProjects"));
+        assert!(ROADMAP_PROJECTS_QUERY.contains("$filter: ProjectFilter"));
+        assert!(ROADMAP_PROJECTS_QUERY.contains("startDate"));
+        assert!(ROADMAP_PROJECTS_QUERY.contains("targetDate"));
+        assert!(ROADMAP_PROJECTS_QUERY.contains("projectMilestones"));
+    }
+
+    #[test]
+    fn test_workflow_state_mutations_are_valid() {
+        assert!(WORKFLOW_STATE_CREATE_MUTATION.contains("mutation WorkflowStateCreate"));
+        assert!(WORKFLOW_STATE_CREATE_MUTATION.contains("$input: WorkflowStateCreateInput!"));
+        assert!(WORKFLOW_STATE_UPDATE_MUTATION.contains("mutation WorkflowStateUpdate"));
+        assert!(
+            WORKFLOW_STATE_UPDATE_MUTATION.contains("workflowStateUpdate(id: $id, input: $input)")
+        );
+        assert!(WORKFLOW_STATE_ARCHIVE_MUTATION.contains("mutation WorkflowStateArchive"));
+        assert!(WORKFLOW_STATE_ARCHIVE_MUTATION.contains("workflowStateArchive(id: $id)"));
+    }
+
+    #[test]
+    fn test_team_membership_queries_are_valid() {
+        assert!(TEAM_MEMBERSHIPS_QUERY.contains("query TeamMemberships"));
+        assert!(TEAM_MEMBERSHIPS_QUERY.contains("memberships(first: $first)"));
+        assert!(TEAM_MEMBERSHIPS_QUERY.contains("owner"));
+        assert!(TEAM_MEMBERSHIP_CREATE_MUTATION.contains("teamMembershipCreate(input: $input)"));
+        assert!(TEAM_MEMBERSHIP_DELETE_MUTATION.contains("teamMembershipDelete(id: $id)"));
+    }
+
+    #[test]
+    fn test_team_settings_queries_are_valid() {
+        for query in [TEAM_SETTINGS_QUERY, TEAM_SETTINGS_UPDATE_MUTATION] {
+            assert!(query.contains("cycleDuration"));
+            assert!(query.contains("cycleCooldownTime"));
+            assert!(query.contains("triageEnabled"));
+            assert!(query.contains("autoArchivePeriod"));
+            assert!(query.contains("autoClosePeriod"));
+            assert!(query.contains("defaultIssueState"));
+        }
+        assert!(TEAM_SETTINGS_UPDATE_MUTATION.contains("teamUpdate(id: $id, input: $input)"));
+    }
+
+    #[test]
+    fn test_user_workload_query_is_valid() {
+        assert!(USER_WORKLOAD_QUERY.contains("query UserWorkload"));
+        assert!(USER_WORKLOAD_QUERY.contains("activeCycle"));
+        assert!(USER_WORKLOAD_QUERY.contains("memberships(first: 250)"));
+        assert!(USER_WORKLOAD_QUERY.contains("issues(first: $first, filter: $filter)"));
+        assert!(USER_WORKLOAD_QUERY.contains("dueDate"));
+    }
 }
@@ -0,0 +1,154 @@
+//! Notification-related GraphQL queries and mutations.
+
+/// Query to list notifications in the authenticated user's inbox.
+///
+/// Issue-specific fields (`issue`, `comment`) are only present on
+/// `IssueNotification` nodes.
+///
+/// Variables:
+/// - `first` (Int, optional): Number of notifications to fetch
+/// - `after` (String, optional): Cursor from the previous page's `pageInfo.endCursor`
+///
+/// Returns: `NotificationsResponse`
+pub const NOTIFICATIONS_QUERY: &str = r#"
+query Notifications($first: Int, $after: String) {
+    notifications(first: $first, after: $after) {
+        nodes {
+            id
+            type
+            createdAt
+            readAt
+            snoozedUntilAt
+            actor {
+                id
+                name
+                email
+                displayName
+                active
+            }
+            ... on IssueNotification {
+                issue {
+                    id
+                    identifier
+                    title
+                }
+                comment {
+                    id
+                    body
+                }
+            }
+        }
+        pageInfo {
+            hasNextPage
+            endCursor
+        }
+    }
+}
+"#;
+
+/// Build a mutation that marks `count` notifications as read in one request.
+///
+/// Each notification gets an aliased `notificationUpdate` field: `n0`, `n1`, ...
+///
+/// Variables:
+/// - `readAt` (DateTime!): When the notifications were read
+/// - `id0` ... `id<count - 1>` (String!): The notifications' unique identifiers
+///
+/// Returns: an object with a `{ success }` payload per alias
+pub fn notifications_read_mutation(count: usize) -> String {
+    let variables: String = (0..count).map(|i| format!(", $id{}: String!", i)).collect();
+    let fields: String = (0..count)
+        .map(|i| {
+            format!(
+                "    n{i}: notificationUpdate(id: $id{i}, input: {{ readAt: $readAt }}) {{ success }}\n"
+            )
+        })
+        .collect();
+    format!(
+        "mutation NotificationsRead($readAt: DateTime!{}) {{\n{}}}\n",
+        variables, fields
+    )
+}
+
+/// Mutation to update a notification (mark as read, snooze).
+///
+/// Variables:
+/// - `id` (String!): The notification's unique identifier
+/// - `input` (NotificationUpdateInput!): Update input containing:
+///   - `readAt` (DateTime, optional): When the notification was read
+///   - `snoozedUntilAt` (DateTime, optional): Snooze the notification until this time
+///
+/// Returns: `NotificationUpdateResponse`
+pub const NOTIFICATION_UPDATE_MUTATION: &str = r#"
+mutation NotificationUpdate($id: String!, $input: NotificationUpdateInput!) {
+    notificationUpdate(id: $id, input: $input) {
+        success
+        notification {
+            id
+            type
+            createdAt
+            readAt
+            snoozedUntilAt
+            actor {
+                id
+                name
+                email
+                displayName
+                active
+            }
+            ... on IssueNotification {
+                issue {
+                    id
+                    identifier
+                    title
+                }
+                comment {
+                    id
+                    body
+                }
+            }
+        }
+    }
+}
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_notifications_query_is_valid() {
+        assert!(NOTIFICATIONS_QUERY.contains("query Notifications"));
+        assert!(NOTIFICATIONS_QUERY.contains("$first: Int"));
+        assert!(NOTIFICATIONS_QUERY.contains("notifications(first: $first, after: $after)"));
+        assert!(NOTIFICATIONS_QUERY.contains("hasNextPage"));
+        assert!(NOTIFICATIONS_QUERY.contains("readAt"));
+        assert!(NOTIFICATIONS_QUERY.contains("snoozedUntilAt"));
+        assert!(NOTIFICATIONS_QUERY.contains("... on IssueNotification"));
+    }
+
+    #[test]
+    fn test_notifications_read_mutation() {
+        let mutation = notifications_read_mutation(2);
+        assert!(mutation.starts_with(
+            "mutation NotificationsRead($readAt: DateTime!, $id0: String!, $id1: String!)"
+        ));
+        assert!(
+            mutation.contains(
+                "n1: notificationUpdate(id: $id1, input: { readAt: $readAt }) { success }"
+            )
+        );
+        assert!(!mutation.contains("$id2"));
+    }
+
+    #[test]
+    fn test_notification_update_mutation_is_valid() {
+        assert!(NOTIFICATION_UPDATE_MUTATION.contains("mutation NotificationUpdate"));
+        assert!(NOTIFICATION_UPDATE_MUTATION.contains("$id: String!"));
+        assert!(NOTIFICATION_UPDATE_MUTATION.contains("$input: NotificationUpdateInput!"));
+        assert!(
+            NOTIFICATION_UPDATE_MUTATION.contains("notificationUpdate(id: $id, input: $input)")
+        );
+        assert!(NOTIFICATION_UPDATE_MUTATION.contains("success"));
+    }
+}
@@ -47,3 +47,110 @@ query Project($id: String!) {
     }
 }
 "#;
+
+/// Query to get every issue in a project together with its relations.
+///
+/// Used to build dependency graphs and critical paths for a project.
+///
+/// Variables:
+/// - `id` (String!): The project's unique identifier
+/// - `first` (Int, optional): Maximum number of issues to fetch
+///
+/// Returns: `ProjectIssueGraphResponse`
+pub const PROJECT_ISSUE_GRAPH_QUERY: &str = r#"
+query ProjectIssueGraph($id: String!, $first: Int) {
+    project(id: $id) {
+        id
+        name
+        issues(first: $first) {
+            nodes {
+                id
+                identifier
+                title
+                estimate
+                state {
+                    id
+                    name
+                    color
+                    type
+                }
+                parent {
+                    id
+                    identifier
+                    title
+                    state {
+                        id
+                        name
+                        color
+                        type
+                    }
+                }
+                relations {
+                    nodes {
+                        id
+                        type
+                        relatedIssue {
+                            id
+                            identifier
+                            title
+                            state {
+                                id
+                                name
+                                color
+                                type
+                            }
+                        }
+                    }
+                }
+                inverseRelations {
+                    nodes {
+                        id
+                        type
+                        issue {
+                            id
+                            identifier
+                            title
+                            state {
+                                id
+                                name
+                                color
+                                type
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+"#;
+
+/// Query to list projects with their dates, progress, and milestones for the roadmap.
+///
+/// Variables:
+/// - `first` (Int, optional): Number of projects to fetch
+/// - `filter` (ProjectFilter, optional): Filter criteria for projects
+///
+/// Returns: `RoadmapProjectsResponse`
+pub const ROADMAP_PROJECTS_QUERY: &str = r#"
+query RoadmapProjects($first: Int, $filter: ProjectFilter) {
+    projects(first: $first, filter: $filter) {
+        nodes {
+            id
+            name
+            state
+            startDate
+            targetDate
+            progress
+            projectMilestones {
+                nodes {
+                    id
+                    name
+                    targetDate
+                    status
+                }
+            }
+        }
+    }
+}
+"#;
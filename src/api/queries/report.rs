@@ -0,0 +1,273 @@
+//! Reporting GraphQL queries.
+
+/// Query to fetch completed issues with their state transitions for flow analytics.
+///
+/// Only the state fields of each history entry are requested; entries that
+/// don't change state have null `fromState`/`toState`.
+///
+/// Variables:
+/// - `first` (Int, optional): Number of issues to fetch
+/// - `filter` (IssueFilter, optional): Filter criteria (team, completedAt range)
+/// - `historyFirst` (Int, optional): Number of history entries to fetch per issue
+///
+/// Returns: `FlowIssuesResponse`
+pub const FLOW_ISSUES_QUERY: &str = r#"
+query FlowIssues($first: Int, $filter: IssueFilter, $historyFirst: Int) {
+    issues(first: $first, filter: $filter) {
+        nodes {
+            id
+            identifier
+            title
+            estimate
+            createdAt
+            startedAt
+            completedAt
+            labels {
+                nodes {
+                    id
+                    name
+                }
+            }
+            history(first: $historyFirst) {
+                nodes {
+                    id
+                    createdAt
+                    fromState {
+                        id
+                        name
+                        color
+                        type
+                    }
+                    toState {
+                        id
+                        name
+                        color
+                        type
+                    }
+                }
+            }
+        }
+    }
+}
+"#;
+
+/// Query to fetch a team's cycles with the issues needed for velocity reporting.
+///
+/// `uncompletedIssuesUponClose` lists issues that were still open when a
+/// cycle closed (and were usually rolled over into the next cycle).
+///
+/// Variables:
+/// - `first` (Int, optional): Number of cycles to fetch
+/// - `filter` (CycleFilter, optional): Filter criteria (team, startsAt)
+/// - `issuesFirst` (Int, optional): Number of issues to fetch per cycle
+///
+/// Returns: `VelocityCyclesResponse`
+pub const VELOCITY_CYCLES_QUERY: &str = r#"
+query VelocityCycles($first: Int, $filter: CycleFilter, $issuesFirst: Int) {
+    cycles(first: $first, filter: $filter) {
+        nodes {
+            id
+            number
+            name
+            startsAt
+            endsAt
+            completedAt
+            scopeHistory
+            completedScopeHistory
+            issues(first: $issuesFirst) {
+                nodes {
+                    id
+                    identifier
+                    estimate
+                    completedAt
+                    canceledAt
+                }
+            }
+            uncompletedIssuesUponClose(first: $issuesFirst) {
+                nodes {
+                    id
+                    identifier
+                    estimate
+                    completedAt
+                    canceledAt
+                }
+            }
+        }
+    }
+}
+"#;
+
+/// Query to fetch recently updated issues with their activity for standup summaries.
+///
+/// Only state changes are requested from history; comments include their author.
+///
+/// Variables:
+/// - `first` (Int, optional): Number of issues to fetch
+/// - `filter` (IssueFilter, optional): Filter criteria (updatedAt, team, people)
+/// - `activityFirst` (Int, optional): Number of history entries and comments to fetch per issue
+///
+/// Returns: `StandupIssuesResponse`
+pub const STANDUP_ACTIVITY_QUERY: &str = r#"
+query StandupActivity($first: Int, $filter: IssueFilter, $activityFirst: Int) {
+    issues(first: $first, filter: $filter, orderBy: updatedAt) {
+        nodes {
+            id
+            identifier
+            title
+            createdAt
+            completedAt
+            state {
+                id
+                name
+                color
+                type
+            }
+            assignee {
+                id
+                name
+                email
+                displayName
+                active
+            }
+            creator {
+                id
+                name
+                email
+                displayName
+                active
+            }
+            history(first: $activityFirst) {
+                nodes {
+                    id
+                    createdAt
+                    actor {
+                        id
+                        name
+                        email
+                        displayName
+                        active
+                    }
+                    fromState {
+                        id
+                        name
+                        color
+                        type
+                    }
+                    toState {
+                        id
+                        name
+                        color
+                        type
+                    }
+                }
+            }
+            comments(first: $activityFirst) {
+                nodes {
+                    id
+                    body
+                    createdAt
+                    updatedAt
+                    user {
+                        id
+                        name
+                        email
+                        displayName
+                        active
+                    }
+                }
+            }
+        }
+    }
+}
+"#;
+
+/// Query to fetch open issues with the issues blocking them.
+///
+/// Variables:
+/// - `first` (Int, optional): Number of issues to fetch
+/// - `filter` (IssueFilter, optional): Filter criteria (assignee or team, state type)
+///
+/// Returns: `OpenIssuesResponse`
+pub const OPEN_ISSUES_WITH_BLOCKERS_QUERY: &str = r#"
+query OpenIssuesWithBlockers($first: Int, $filter: IssueFilter) {
+    issues(first: $first, filter: $filter) {
+        nodes {
+            id
+            identifier
+            title
+            state {
+                id
+                name
+                color
+                type
+            }
+            assignee {
+                id
+                name
+                email
+                displayName
+                active
+            }
+            inverseRelations {
+                nodes {
+                    id
+                    type
+                    issue {
+                        id
+                        identifier
+                        title
+                        state {
+                            id
+                            name
+                            color
+                            type
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flow_issues_query_is_valid() {
+        assert!(FLOW_ISSUES_QUERY.contains("query FlowIssues"));
+        assert!(FLOW_ISSUES_QUERY.contains("$filter: IssueFilter"));
+        assert!(FLOW_ISSUES_QUERY.contains("completedAt"));
+        assert!(FLOW_ISSUES_QUERY.contains("startedAt"));
+        assert!(FLOW_ISSUES_QUERY.contains("history(first: $historyFirst)"));
+        assert!(FLOW_ISSUES_QUERY.contains("fromState"));
+        assert!(FLOW_ISSUES_QUERY.contains("toState"));
+    }
+
+    #[test]
+    fn test_velocity_cycles_query_is_valid() {
+        assert!(VELOCITY_CYCLES_QUERY.contains("query VelocityCycles"));
+        assert!(VELOCITY_CYCLES_QUERY.contains("$filter: CycleFilter"));
+        assert!(VELOCITY_CYCLES_QUERY.contains("scopeHistory"));
+        assert!(VELOCITY_CYCLES_QUERY.contains("completedScopeHistory"));
+        assert!(VELOCITY_CYCLES_QUERY.contains("issues(first: $issuesFirst)"));
+        assert!(VELOCITY_CYCLES_QUERY.contains("uncompletedIssuesUponClose"));
+    }
+
+    #[test]
+    fn test_standup_activity_query_is_valid() {
+        assert!(STANDUP_ACTIVITY_QUERY.contains("query StandupActivity"));
+        assert!(STANDUP_ACTIVITY_QUERY.contains("$filter: IssueFilter"));
+        assert!(STANDUP_ACTIVITY_QUERY.contains("history(first: $activityFirst)"));
+        assert!(STANDUP_ACTIVITY_QUERY.contains("comments(first: $activityFirst)"));
+        assert!(STANDUP_ACTIVITY_QUERY.contains("creator"));
+    }
+
+    #[test]
+    fn test_open_issues_with_blockers_query_is_valid() {
+        assert!(OPEN_ISSUES_WITH_BLOCKERS_QUERY.contains("query OpenIssuesWithBlockers"));
+        assert!(OPEN_ISSUES_WITH_BLOCKERS_QUERY.contains("inverseRelations"));
+        assert!(OPEN_ISSUES_WITH_BLOCKERS_QUERY.contains("assignee"));
+    }
+}
@@ -15,6 +15,8 @@ query Teams($first: Int) {
             name
             description
             issueEstimationType
+            issueEstimationAllowZero
+            issueEstimationExtended
         }
     }
 }
@@ -34,6 +36,8 @@ query TeamByKey($filter: TeamFilter!) {
             key
             name
             issueEstimationType
+            issueEstimationAllowZero
+            issueEstimationExtended
         }
     }
 }
@@ -53,6 +57,144 @@ query Team($id: String!) {
         name
         description
         issueEstimationType
+        issueEstimationAllowZero
+        issueEstimationExtended
+    }
+}
+"#;
+
+/// Query to list the members of a team.
+///
+/// Variables:
+/// - `id` (String!): The team's unique identifier
+/// - `first` (Int, optional): Number of memberships to fetch
+///
+/// Returns: `TeamMembershipsResponse`
+pub const TEAM_MEMBERSHIPS_QUERY: &str = r#"
+query TeamMemberships($id: String!, $first: Int) {
+    team(id: $id) {
+        id
+        key
+        name
+        memberships(first: $first) {
+            nodes {
+                id
+                owner
+                user {
+                    id
+                    name
+                    email
+                    displayName
+                    active
+                }
+            }
+        }
+    }
+}
+"#;
+
+/// Mutation to add a user to a team.
+///
+/// Variables:
+/// - `input` (TeamMembershipCreateInput!): Membership input containing:
+///   - `teamId` (String!): The team's unique identifier
+///   - `userId` (String!): The user's unique identifier
+///   - `owner` (Boolean, optional): Whether the user is a team owner
+///
+/// Returns: `TeamMembershipCreateResponse`
+pub const TEAM_MEMBERSHIP_CREATE_MUTATION: &str = r#"
+mutation TeamMembershipCreate($input: TeamMembershipCreateInput!) {
+    teamMembershipCreate(input: $input) {
+        success
+        teamMembership {
+            id
+            owner
+            user {
+                id
+                name
+                email
+                displayName
+                active
+            }
+        }
+    }
+}
+"#;
+
+/// Mutation to remove a user from a team.
+///
+/// Variables:
+/// - `id` (String!): The team membership's unique identifier
+///
+/// Returns: `TeamMembershipDeleteResponse`
+pub const TEAM_MEMBERSHIP_DELETE_MUTATION: &str = r#"
+mutation TeamMembershipDelete($id: String!) {
+    teamMembershipDelete(id: $id) {
+        success
+    }
+}
+"#;
+
+/// Query to get a team's settings.
+///
+/// Variables:
+/// - `id` (String!): The team's unique identifier
+///
+/// Returns: `TeamSettingsResponse`
+pub const TEAM_SETTINGS_QUERY: &str = r#"
+query TeamSettings($id: String!) {
+    team(id: $id) {
+        id
+        key
+        name
+        cyclesEnabled
+        cycleDuration
+        cycleCooldownTime
+        issueEstimationType
+        triageEnabled
+        autoArchivePeriod
+        autoClosePeriod
+        defaultIssueState {
+            id
+            name
+            color
+            type
+        }
+    }
+}
+"#;
+
+/// Mutation to update a team's settings.
+///
+/// Variables:
+/// - `id` (String!): The team's unique identifier
+/// - `input` (TeamUpdateInput!): Settings to change (cycleDuration, cycleCooldownTime,
+///   issueEstimationType, triageEnabled, autoArchivePeriod, autoClosePeriod,
+///   defaultIssueStateId)
+///
+/// Returns: `TeamSettingsUpdateResponse`
+pub const TEAM_SETTINGS_UPDATE_MUTATION: &str = r#"
+mutation TeamSettingsUpdate($id: String!, $input: TeamUpdateInput!) {
+    teamUpdate(id: $id, input: $input) {
+        success
+        team {
+            id
+            key
+            name
+            cyclesEnabled
+            cycleDuration
+            cycleCooldownTime
+            issueEstimationType
+            triageEnabled
+            autoArchivePeriod
+            autoClosePeriod
+            defaultIssueState {
+                id
+                name
+                color
+                type
+            }
+        }
     }
 }
 "#;
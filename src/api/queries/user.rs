@@ -36,3 +36,78 @@ query Users($first: Int) {
     }
 }
 "#;
+
+/// Query to get a single user by ID.
+///
+/// Variables:
+/// - `id` (String!): The user's unique identifier
+///
+/// Returns: `UserResponse`
+pub const USER_QUERY: &str = r#"
+query User($id: String!) {
+    user(id: $id) {
+        id
+        name
+        email
+        displayName
+        active
+    }
+}
+"#;
+
+/// Query to fetch a team's members, active cycle and open issues for
+/// workload analysis.
+///
+/// Variables:
+/// - `teamId` (String!): The team's unique identifier or key
+/// - `first` (Int, optional): Number of issues to fetch
+/// - `filter` (IssueFilter, optional): Filter criteria for issues
+///
+/// Returns: `UserWorkloadResponse`
+pub const USER_WORKLOAD_QUERY: &str = r#"
+query UserWorkload($teamId: String!, $first: Int, $filter: IssueFilter) {
+    team(id: $teamId) {
+        id
+        key
+        name
+        activeCycle {
+            id
+            number
+            name
+        }
+        memberships(first: 250) {
+            nodes {
+                id
+                owner
+                user {
+                    id
+                    name
+                    email
+                    displayName
+                    active
+                }
+            }
+        }
+    }
+    issues(first: $first, filter: $filter) {
+        nodes {
+            id
+            identifier
+            estimate
+            dueDate
+            assignee {
+                id
+                name
+                email
+                displayName
+                active
+            }
+            cycle {
+                id
+                number
+                name
+            }
+        }
+    }
+}
+"#;
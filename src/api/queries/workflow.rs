@@ -21,3 +21,83 @@ query WorkflowStates($id: String!) {
     }
 }
 "#;
+
+/// Query to list the positions of a team's workflow states.
+///
+/// Variables:
+/// - `id` (String!): The team's unique identifier (UUID or key)
+///
+/// Returns: `WorkflowStatePositionsResponse`
+pub const WORKFLOW_STATE_POSITIONS_QUERY: &str = r#"
+query WorkflowStatePositions($id: String!) {
+    team(id: $id) {
+        states {
+            nodes {
+                id
+                position
+            }
+        }
+    }
+}
+"#;
+
+/// Mutation to create a workflow state.
+///
+/// Variables:
+/// - `input` (WorkflowStateCreateInput!): State creation input containing:
+///   - `teamId` (String!): The team's unique identifier
+///   - `name` (String!): State name
+///   - `type` (String!): State type (triage, backlog, unstarted, started, completed, canceled)
+///   - `color` (String!): Hex color code
+///   - `position` (Float, optional): Position within the workflow
+///   - `description` (String, optional): State description
+///
+/// Returns: `WorkflowStateCreateResponse`
+pub const WORKFLOW_STATE_CREATE_MUTATION: &str = r#"
+mutation WorkflowStateCreate($input: WorkflowStateCreateInput!) {
+    workflowStateCreate(input: $input) {
+        success
+        workflowState {
+            id
+            name
+            color
+            type
+        }
+    }
+}
+"#;
+
+/// Mutation to update a workflow state.
+///
+/// Variables:
+/// - `id` (String!): The state's unique identifier
+/// - `input` (WorkflowStateUpdateInput!): Fields to update (name, color, position, description)
+///
+/// Returns: `WorkflowStateUpdateResponse`
+pub const WORKFLOW_STATE_UPDATE_MUTATION: &str = r#"
+mutation WorkflowStateUpdate($id: String!, $input: WorkflowStateUpdateInput!) {
+    workflowStateUpdate(id: $id, input: $input) {
+        success
+        workflowState {
+            id
+            name
+            color
+            type
+        }
+    }
+}
+"#;
+
+/// Mutation to archive a workflow state.
+///
+/// Variables:
+/// - `id` (String!): The state's unique identifier
+///
+/// Returns: `WorkflowStateArchiveResponse`
+pub const WORKFLOW_STATE_ARCHIVE_MUTATION: &str = r#"
+mutation WorkflowStateArchive($id: String!) {
+    workflowStateArchive(id: $id) {
+        success
+    }
+}
+"#;
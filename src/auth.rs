@@ -3,11 +3,17 @@
 //! Handles API token resolution with the following priority:
 //! 1. CLI --api-token flag
 //! 2. LINEAR_API_TOKEN environment variable
-//! 3. Configuration file (per-organization tokens)
+//! 3. Configuration file (per-organization tokens, resolved through the
+//!    configured secret backend)
+//!
+//! Organizations added with `lin auth login` hold OAuth credentials; clients
+//! built by [`client_for_org`] refresh their access token on HTTP 401.
 
 use std::env;
 
 use crate::Result;
+use crate::api::GraphQLClient;
+use crate::api::oauth;
 use crate::config::Config;
 use crate::error::LinError;
 
@@ -89,6 +95,107 @@ pub fn require_api_token(
     })
 }
 
+/// Build a GraphQL client for an organization in the config.
+///
+/// The token is resolved through the configured secret backend. For OAuth
+/// organizations, the client refreshes the access token on HTTP 401 and
+/// saves the new tokens.
+///
+/// # Arguments
+///
+/// * `config` - The loaded configuration
+/// * `org` - Optional organization name. If None, uses the active organization.
+///
+/// # Errors
+///
+/// Returns an error if the organization isn't configured or its token can't be read.
+pub fn client_for_org(config: &Config, org: Option<&str>) -> Result<GraphQLClient> {
+    let org_name = match org {
+        Some(name) => name.to_string(),
+        None => config.get_active_org_name()?.to_string(),
+    };
+
+    let client = GraphQLClient::new(&config.get_authorization(Some(&org_name))?);
+    let is_oauth = config
+        .orgs
+        .get(&org_name)
+        .is_some_and(|org_config| org_config.oauth.is_some());
+    if !is_oauth {
+        return Ok(client);
+    }
+
+    Ok(client.with_token_refresh(move || {
+        let mut config = Config::load()?;
+        let authorization = refresh_org_token(&mut config, &org_name, oauth::LINEAR_TOKEN_URL)?;
+        config.save()?;
+        Ok(authorization)
+    }))
+}
+
+/// Refresh an OAuth organization's access token in place.
+///
+/// Returns the new Authorization header value. The caller is responsible
+/// for saving the config.
+///
+/// # Errors
+///
+/// Returns an error if the org has no refresh token or the refresh fails.
+pub fn refresh_org_token(config: &mut Config, org_name: &str, token_url: &str) -> Result<String> {
+    let refresh_token = config.get_refresh_token(Some(org_name))?.ok_or_else(|| {
+        LinError::config(format!(
+            "No refresh token stored. Run: lin auth login {}",
+            org_name
+        ))
+    })?;
+    let org = config
+        .orgs
+        .get_mut(org_name)
+        .ok_or_else(|| LinError::config(format!("Organization '{}' not found", org_name)))?;
+    let creds = org
+        .oauth
+        .as_mut()
+        .ok_or_else(|| LinError::config("Organization was not authenticated with OAuth"))?;
+
+    let tokens =
+        oauth::refresh_access_token(token_url, &creds.client_id, &refresh_token).map_err(|e| {
+            LinError::api(format!(
+                "Session expired and could not be refreshed. Run: lin auth login {}. Error: {}",
+                org_name, e
+            ))
+        })?;
+
+    creds.expires_at = tokens.expires_at();
+    if let Some(refresh_token) = tokens.refresh_token {
+        creds.refresh_token = Some(refresh_token);
+    }
+    org.token = tokens.access_token;
+    config.get_authorization(Some(org_name))
+}
+
+/// Get an organization's Authorization header value, refreshing its OAuth
+/// access token first if it has expired.
+///
+/// Use this when the token is handed to another program (e.g., a plugin),
+/// which can't refresh it on HTTP 401 the way [`client_for_org`] does. The
+/// caller is responsible for saving the config.
+///
+/// # Errors
+///
+/// Returns an error if the org isn't configured or an expired token can't be refreshed.
+pub fn fresh_authorization(config: &mut Config, org_name: &str, token_url: &str) -> Result<String> {
+    let expired = config
+        .orgs
+        .get(org_name)
+        .and_then(|org| org.oauth.as_ref())
+        .and_then(|creds| creds.expires_at.as_deref())
+        .and_then(|expires_at| chrono::DateTime::parse_from_rfc3339(expires_at).ok())
+        .is_some_and(|expires_at| expires_at <= chrono::Utc::now());
+    if expired {
+        return refresh_org_token(config, org_name, token_url);
+    }
+    config.get_authorization(Some(org_name))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -209,4 +316,97 @@ mod tests {
         // Clean up
         unsafe { env::remove_var(LINEAR_API_TOKEN_ENV) };
     }
+
+    #[test]
+    fn test_refresh_org_token_rotates_tokens() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", "/oauth/token")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("grant_type".to_string(), "refresh_token".to_string()),
+                mockito::Matcher::UrlEncoded("refresh_token".to_string(), "refresh-1".to_string()),
+                mockito::Matcher::UrlEncoded("client_id".to_string(), "client-123".to_string()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"access_token": "access-2", "token_type": "Bearer", "expires_in": 3600, "refresh_token": "refresh-2"}"#,
+            )
+            .create();
+
+        let mut config = Config::default();
+        config
+            .add_oauth_org(
+                "org".to_string(),
+                "access-1".to_string(),
+                crate::config::OAuthCredentials {
+                    client_id: "client-123".to_string(),
+                    refresh_token: Some("refresh-1".to_string()),
+                    expires_at: None,
+                },
+            )
+            .unwrap();
+        let authorization =
+            refresh_org_token(&mut config, "org", &format!("{}/oauth/token", server.url()))
+                .unwrap();
+
+        assert_eq!(authorization, "Bearer access-2");
+        let org = config.orgs.get("org").unwrap();
+        assert_eq!(org.token, "access-2");
+        let creds = org.oauth.as_ref().unwrap();
+        assert_eq!(creds.refresh_token.as_deref(), Some("refresh-2"));
+        assert!(creds.expires_at.is_some());
+        mock.assert();
+    }
+
+    #[test]
+    fn test_refresh_org_token_requires_oauth() {
+        let mut config = make_test_config();
+
+        let err = refresh_org_token(&mut config, "test-org", "http://127.0.0.1:1/oauth/token")
+            .unwrap_err();
+        assert!(err.to_string().contains("No refresh token"));
+    }
+
+    #[test]
+    fn test_fresh_authorization_refreshes_only_expired_tokens() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", "/oauth/token")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"access_token": "access-2", "token_type": "Bearer", "expires_in": 3600}"#,
+            )
+            .expect(1)
+            .create();
+        let token_url = format!("{}/oauth/token", server.url());
+
+        let mut config = Config::default();
+        config
+            .add_oauth_org(
+                "org".to_string(),
+                "access-1".to_string(),
+                crate::config::OAuthCredentials {
+                    client_id: "client-123".to_string(),
+                    refresh_token: Some("refresh-1".to_string()),
+                    expires_at: Some(
+                        (chrono::Utc::now() + chrono::Duration::hours(1)).to_rfc3339(),
+                    ),
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            fresh_authorization(&mut config, "org", &token_url).unwrap(),
+            "Bearer access-1"
+        );
+
+        let creds = config.orgs.get_mut("org").unwrap().oauth.as_mut().unwrap();
+        creds.expires_at = Some((chrono::Utc::now() - chrono::Duration::minutes(1)).to_rfc3339());
+        assert_eq!(
+            fresh_authorization(&mut config, "org", &token_url).unwrap(),
+            "Bearer access-2"
+        );
+        mock.assert();
+    }
 }
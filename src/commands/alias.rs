@@ -0,0 +1,247 @@
+//! Alias management commands.
+//!
+//! Aliases live in the global config under `aliases`; see [`crate::alias`]
+//! for how they are expanded.
+
+use serde::Serialize;
+
+use crate::Result;
+use crate::alias::{self, SHELL_PREFIX};
+use crate::config::Config;
+use crate::error::LinError;
+use crate::output::{OutputFormat, output};
+
+/// A defined alias.
+#[derive(Debug, Clone, Serialize)]
+pub struct Alias {
+    /// Alias name
+    pub name: String,
+    /// What the alias expands to (`!` prefix for shell commands)
+    pub expansion: String,
+}
+
+/// All defined aliases.
+#[derive(Debug, Clone, Serialize)]
+pub struct AliasList {
+    /// Aliases sorted by name
+    pub aliases: Vec<Alias>,
+}
+
+impl crate::output::HumanDisplay for AliasList {
+    fn human_fmt(&self) -> String {
+        if self.aliases.is_empty() {
+            return "No aliases defined. Add one with: lin alias set <name> '<command>'"
+                .to_string();
+        }
+        let width = self.aliases.iter().map(|a| a.name.len()).max().unwrap_or(0);
+        self.aliases
+            .iter()
+            .map(|a| format!("{:width$}  {}", a.name, a.expansion, width = width))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Result of setting or deleting an alias.
+#[derive(Debug, Clone, Serialize)]
+pub struct AliasUpdate {
+    /// "set" or "deleted"
+    pub action: String,
+    /// Alias name
+    pub name: String,
+    /// The expansion that was set or removed
+    pub expansion: String,
+}
+
+impl crate::output::HumanDisplay for AliasUpdate {
+    fn human_fmt(&self) -> String {
+        match self.action.as_str() {
+            "set" => format!("Alias '{}' set: {}", self.name, self.expansion),
+            _ => format!("Alias '{}' deleted (was: {})", self.name, self.expansion),
+        }
+    }
+}
+
+/// Check that an alias doesn't shadow a built-in command and, unless it is
+/// a shell alias, expands to one.
+fn check_alias(name: &str, expansion: &str, builtins: &[&str]) -> Result<()> {
+    alias::validate(name, expansion)?;
+    if builtins.contains(&name) {
+        return Err(LinError::config(format!(
+            "'{}' is a built-in command and can't be used as an alias name",
+            name
+        )));
+    }
+    if expansion.starts_with(SHELL_PREFIX) {
+        return Ok(());
+    }
+
+    let words = shlex::split(expansion).unwrap_or_default();
+    let command = words.iter().find(|word| !word.starts_with('-'));
+    match command {
+        Some(command) if builtins.contains(&command.as_str()) => Ok(()),
+        _ => Err(LinError::config(format!(
+            "Alias '{}' must expand to a lin command (e.g., 'issue list --assignee me'), or start with '{}' to run a shell command. Commands: {}",
+            name,
+            SHELL_PREFIX,
+            builtins.join(", ")
+        ))),
+    }
+}
+
+/// Define or replace an alias.
+///
+/// # Arguments
+///
+/// * `name` - Alias name, used as `lin <name>`
+/// * `expansion` - lin arguments (with `$1`...`$9` placeholders), or a shell command prefixed with `!`
+/// * `builtins` - Names of lin's built-in commands, which aliases can't shadow
+/// * `format` - Output format
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use lin::commands::alias::alias_set;
+/// use lin::output::OutputFormat;
+///
+/// alias_set(
+///     "mine",
+///     "issue list --assignee me --state started",
+///     &["issue"],
+///     OutputFormat::Human,
+/// )?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn alias_set(
+    name: &str,
+    expansion: &str,
+    builtins: &[&str],
+    format: OutputFormat,
+) -> Result<()> {
+    check_alias(name, expansion, builtins)?;
+
+    let mut config = Config::load_global()?;
+    config
+        .aliases
+        .insert(name.to_string(), expansion.to_string());
+    config.save()?;
+
+    let update = AliasUpdate {
+        action: "set".to_string(),
+        name: name.to_string(),
+        expansion: expansion.to_string(),
+    };
+    output(&update, format);
+    Ok(())
+}
+
+/// Delete an alias.
+///
+/// # Arguments
+///
+/// * `name` - Alias name
+/// * `format` - Output format
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use lin::commands::alias::alias_delete;
+/// use lin::output::OutputFormat;
+///
+/// alias_delete("mine", OutputFormat::Human)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn alias_delete(name: &str, format: OutputFormat) -> Result<()> {
+    let mut config = Config::load_global()?;
+    let expansion = config
+        .aliases
+        .remove(name)
+        .ok_or_else(|| LinError::config(format!("Alias '{}' not found", name)))?;
+    config.save()?;
+
+    let update = AliasUpdate {
+        action: "deleted".to_string(),
+        name: name.to_string(),
+        expansion,
+    };
+    output(&update, format);
+    Ok(())
+}
+
+/// List all aliases.
+///
+/// # Arguments
+///
+/// * `format` - Output format
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use lin::commands::alias::alias_list;
+/// use lin::output::OutputFormat;
+///
+/// alias_list(OutputFormat::Human)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn alias_list(format: OutputFormat) -> Result<()> {
+    let config = Config::load_global()?;
+    let list = AliasList {
+        aliases: config
+            .aliases
+            .into_iter()
+            .map(|(name, expansion)| Alias { name, expansion })
+            .collect(),
+    };
+    output(&list, format);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::HumanDisplay;
+
+    const BUILTINS: &[&str] = &["issue", "search", "alias"];
+
+    #[test]
+    fn test_check_alias() {
+        assert!(check_alias("mine", "issue list --assignee me", BUILTINS).is_ok());
+        assert!(check_alias("j", "--json issue list", BUILTINS).is_ok());
+        assert!(check_alias("web", "!open https://linear.app", BUILTINS).is_ok());
+
+        let err = check_alias("issue", "search foo", BUILTINS).unwrap_err();
+        assert!(err.to_string().contains("built-in command"));
+
+        let err = check_alias("mine", "isue list", BUILTINS).unwrap_err();
+        assert!(err.to_string().contains("must expand to a lin command"));
+    }
+
+    #[test]
+    fn test_alias_list_human() {
+        let list = AliasList {
+            aliases: vec![
+                Alias {
+                    name: "bug".to_string(),
+                    expansion: "issue create --title".to_string(),
+                },
+                Alias {
+                    name: "mine".to_string(),
+                    expansion: "issue list --assignee me".to_string(),
+                },
+            ],
+        };
+        assert_eq!(
+            list.human_fmt(),
+            "bug   issue create --title\nmine  issue list --assignee me"
+        );
+
+        let empty = AliasList { aliases: vec![] };
+        assert!(empty.human_fmt().contains("lin alias set"));
+    }
+}
@@ -4,13 +4,22 @@
 
 use crate::Result;
 use crate::api::GraphQLClient;
+use crate::api::oauth::{self, CallbackServer, Pkce};
 use crate::api::queries;
-use crate::config::{CachedTeam, Config};
+use crate::commands::cache;
+use crate::config::{Config, OAuthCredentials};
 use crate::error::LinError;
-use crate::models::{TeamsResponse, ViewerResponse, WorkflowStatesResponse};
+use crate::models::ViewerResponse;
 use crate::output::{OutputFormat, output};
+use crate::secrets::{self, SecretBackend};
 use serde::Serialize;
-use std::collections::HashMap;
+use std::time::Duration;
+
+/// Environment variable holding the OAuth application client ID for `lin auth login`.
+pub const LIN_OAUTH_CLIENT_ID_ENV: &str = "LIN_OAUTH_CLIENT_ID";
+
+/// How long `lin auth login` waits for the browser redirect.
+const LOGIN_TIMEOUT: Duration = Duration::from_secs(300);
 
 /// Authenticate with a Linear organization and sync data.
 ///
@@ -44,6 +53,106 @@ pub fn auth_add(name: String, token: String, format: OutputFormat) -> Result<()>
     Ok(())
 }
 
+/// Authenticate with a Linear organization through the OAuth2 browser flow.
+///
+/// Runs the authorization-code + PKCE flow: starts a loopback listener, opens
+/// the browser, exchanges the returned code, then stores the access and
+/// refresh tokens and syncs teams and workflow states like [`auth_add`].
+///
+/// # Arguments
+///
+/// * `name` - Organization name to store the credentials under
+/// * `client_id` - OAuth client ID; falls back to `LIN_OAUTH_CLIENT_ID`
+/// * `port` - Loopback port for the redirect (0 picks a free port)
+/// * `format` - Output format
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use lin::commands::auth::auth_login;
+/// use lin::output::OutputFormat;
+///
+/// auth_login("work".to_string(), None, 0, OutputFormat::Human)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn auth_login(
+    name: String,
+    client_id: Option<String>,
+    port: u16,
+    format: OutputFormat,
+) -> Result<()> {
+    let client_id = client_id
+        .or_else(|| std::env::var(LIN_OAUTH_CLIENT_ID_ENV).ok())
+        .filter(|id| !id.is_empty())
+        .ok_or_else(|| {
+            LinError::config(format!(
+                "No OAuth client ID. Pass --client-id or set {}",
+                LIN_OAUTH_CLIENT_ID_ENV
+            ))
+        })?;
+
+    // 1. Start the loopback listener and send the user to the browser
+    let server = CallbackServer::bind(port)?;
+    let redirect_uri = server.redirect_uri()?;
+    let pkce = Pkce::generate()?;
+    let state = oauth::generate_state()?;
+    let url = oauth::authorize_url(
+        oauth::LINEAR_AUTHORIZE_URL,
+        &client_id,
+        &redirect_uri,
+        &state,
+        &pkce.challenge,
+        oauth::DEFAULT_SCOPES,
+    )?;
+
+    eprintln!("Opening your browser to authorize lin. If it doesn't open, visit:");
+    eprintln!("  {}", url);
+    oauth::open_browser(&url);
+
+    // 2. Wait for the redirect and exchange the code
+    let code = server.wait_for_code(&state, LOGIN_TIMEOUT)?;
+    let tokens = oauth::exchange_code(
+        oauth::LINEAR_TOKEN_URL,
+        &client_id,
+        &code,
+        &redirect_uri,
+        &pkce.verifier,
+    )?;
+
+    // 3. Validate the access token
+    let client = GraphQLClient::new(&format!("Bearer {}", tokens.access_token));
+    validate_token(&client)?;
+
+    // 4. Store the tokens and switch to the org
+    let mut config = Config::load()?;
+    let credentials = OAuthCredentials {
+        client_id,
+        expires_at: tokens.expires_at(),
+        refresh_token: tokens.refresh_token,
+    };
+    config.add_oauth_org(name.clone(), tokens.access_token, credentials)?;
+    config.switch_org(&name)?;
+    config.save()?;
+
+    // 5. Sync all teams and their workflow states
+    let teams = sync_org_data(&client, &mut config)?;
+
+    let team_keys: Vec<String> = teams.iter().map(|t| t.0.clone()).collect();
+    let state_count: usize = teams.iter().map(|t| t.1).sum();
+
+    let response = AuthAddResponse {
+        organization: name,
+        teams: team_keys.clone(),
+        team_count: team_keys.len(),
+        state_count,
+    };
+
+    output(&response, format);
+    Ok(())
+}
+
 /// Switch to a different organization.
 pub fn auth_switch(name: String, format: OutputFormat) -> Result<()> {
     let mut config = Config::load()?;
@@ -59,7 +168,7 @@ pub fn auth_switch(name: String, format: OutputFormat) -> Result<()> {
 /// List all authenticated organizations.
 pub fn auth_list(format: OutputFormat) -> Result<()> {
     let config = Config::load()?;
-    let active = config.active_org.as_deref();
+    let active = config.get_active_org_name().ok();
 
     let orgs: Vec<AuthOrgInfo> = config
         .orgs
@@ -83,6 +192,12 @@ pub fn auth_list(format: OutputFormat) -> Result<()> {
 /// Remove an organization from the configuration.
 pub fn auth_remove(name: String, format: OutputFormat) -> Result<()> {
     let mut config = Config::load()?;
+    if let Err(err) = config.delete_org_secrets(&name) {
+        eprintln!(
+            "Warning: could not remove stored tokens for '{}': {}",
+            name, err
+        );
+    }
     config.remove_org(&name)?;
     config.save()?;
 
@@ -102,8 +217,17 @@ pub fn auth_status(format: OutputFormat) -> Result<()> {
 
     let response = AuthStatusResponse {
         organization: org_name.to_string(),
+        method: if org_config.oauth.is_some() {
+            "oauth"
+        } else {
+            "api_key"
+        },
         teams,
         last_sync: org_config.cache.last_sync.clone(),
+        token_expires_at: org_config
+            .oauth
+            .as_ref()
+            .and_then(|creds| creds.expires_at.clone()),
     };
 
     output(&response, format);
@@ -113,8 +237,7 @@ pub fn auth_status(format: OutputFormat) -> Result<()> {
 /// Manually sync the current organization's teams and states.
 pub fn auth_sync(format: OutputFormat) -> Result<()> {
     let mut config = Config::load()?;
-    let org_config = config.get_active_org()?;
-    let client = GraphQLClient::new(&org_config.token);
+    let client = crate::auth::client_for_org(&config, None)?;
 
     let teams = sync_org_data(&client, &mut config)?;
 
@@ -131,106 +254,98 @@ pub fn auth_sync(format: OutputFormat) -> Result<()> {
     Ok(())
 }
 
-/// Parse an estimate scale type string into a HashMap of name -> value mappings.
+/// Move every organization's tokens to a different secret backend.
 ///
-/// For t-shirt scales, maps ["xs","s","m","l","xl"] to [1,2,3,5,8].
-/// For numeric scales (linear, fibonacci, exponential), uses hardcoded default values.
-/// Returns an empty HashMap for "notUsed" or unknown types.
-fn parse_estimate_scale(estimate_type: &Option<String>) -> HashMap<String, f64> {
-    let Some(est_type) = estimate_type else {
-        return HashMap::new();
-    };
+/// Reads each token through the current backend, switches the config to the
+/// new backend and saves (which stores the tokens there), then removes them
+/// from the old backend.
+///
+/// # Arguments
+///
+/// * `backend` - The backend to move tokens to
+/// * `format` - Output format
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use lin::commands::auth::auth_migrate_secrets;
+/// use lin::output::OutputFormat;
+/// use lin::secrets::SecretBackend;
+///
+/// auth_migrate_secrets(SecretBackend::SecretService, OutputFormat::Human)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn auth_migrate_secrets(backend: SecretBackend, format: OutputFormat) -> Result<()> {
+    let mut config = Config::load()?;
+    let previous = config.secret_backend.clone();
+    if previous == backend {
+        return Err(LinError::config(format!(
+            "Tokens are already stored in the {} backend",
+            backend.name()
+        )));
+    }
 
-    match est_type.as_str() {
-        "tShirt" => {
-            let names = ["xs", "s", "m", "l", "xl"];
-            let values = [1.0, 2.0, 3.0, 5.0, 8.0];
-            names
-                .iter()
-                .zip(values.iter())
-                .map(|(&name, &val)| (name.to_string(), val))
-                .collect()
+    // Pull every secret into memory through the current backend
+    let names: Vec<String> = config.orgs.keys().cloned().collect();
+    for name in &names {
+        let token = config.get_token(Some(name))?;
+        let refresh_token = config.get_refresh_token(Some(name))?;
+        if let Some(org) = config.orgs.get_mut(name) {
+            org.token = token;
+            if let Some(creds) = org.oauth.as_mut() {
+                creds.refresh_token = refresh_token;
+            }
         }
-        "linear" => [1.0, 2.0, 3.0, 4.0, 5.0]
-            .iter()
-            .map(|&val| (format!("{}", val as i64), val))
-            .collect(),
-        "fibonacci" => [1.0, 2.0, 3.0, 5.0, 8.0, 13.0, 21.0]
-            .iter()
-            .map(|&val| (format!("{}", val as i64), val))
-            .collect(),
-        "exponential" => [1.0, 2.0, 4.0, 8.0, 16.0, 32.0, 64.0]
-            .iter()
-            .map(|&val| (format!("{}", val as i64), val))
-            .collect(),
-        _ => HashMap::new(),
     }
-}
 
-/// Sync all teams and their workflow states for the active organization.
-///
-/// Returns a vector of (team_key, state_count) tuples.
-fn sync_org_data(client: &GraphQLClient, config: &mut Config) -> Result<Vec<(String, usize)>> {
-    // Query all teams
-    let teams_response: TeamsResponse = client.query(
-        queries::team::TEAMS_QUERY,
-        serde_json::json!({ "first": 100 }),
-    )?;
-
-    let mut results = Vec::new();
-
-    // For each team, query and cache workflow states
-    for team in teams_response.teams.nodes {
-        // Query workflow states for this team
-        let states_response: WorkflowStatesResponse = client.query(
-            queries::workflow::WORKFLOW_STATES_QUERY,
-            serde_json::json!({ "id": team.id }),
-        )?;
-
-        // Build state name -> state ID map (lowercase for case-insensitive lookup)
-        let states: HashMap<String, String> = states_response
-            .team
-            .states
-            .nodes
-            .into_iter()
-            .map(|s| (s.name.to_lowercase(), s.id))
-            .collect();
-
-        let state_count = states.len();
-
-        // Cache the team
-        let cached_team = CachedTeam {
-            id: team.id,
-            name: team.name,
-            states,
-            estimates: parse_estimate_scale(&team.issue_estimate_type),
-        };
+    config.secret_backend = backend;
+    config.save()?;
 
-        config.cache_team(team.key.clone(), cached_team)?;
-        results.push((team.key, state_count));
+    if let Some(store) = previous.open() {
+        for name in &names {
+            let removed = store
+                .delete(&secrets::token_key(name))
+                .and_then(|_| store.delete(&secrets::refresh_token_key(name)));
+            if let Err(err) = removed {
+                eprintln!(
+                    "Warning: could not remove old tokens for '{}' from the {} backend: {}",
+                    name,
+                    previous.name(),
+                    err
+                );
+            }
+        }
     }
 
-    // Query and cache all projects
-    use crate::models::ProjectsResponse;
-    let projects_response: ProjectsResponse = client.query(
-        queries::project::PROJECTS_QUERY,
-        serde_json::json!({ "first": 250 }),
-    )?;
-
-    let projects: Vec<(String, String)> = projects_response
-        .projects
-        .nodes
-        .into_iter()
-        .map(|p| (p.id, p.name))
-        .collect();
+    let mut organizations = names;
+    organizations.sort();
+    let response = AuthMigrateSecretsResponse {
+        from: previous.name().to_string(),
+        to: config.secret_backend.name().to_string(),
+        organizations,
+    };
 
-    config.cache_projects(projects)?;
+    output(&response, format);
+    Ok(())
+}
 
-    // Update last sync time
-    config.update_last_sync()?;
-    config.save()?;
+/// Sync all cached data (teams, states, labels, users, projects) for the active organization.
+///
+/// Returns a vector of (team_key, state_count) tuples, sorted by team key.
+fn sync_org_data(client: &GraphQLClient, config: &mut Config) -> Result<Vec<(String, usize)>> {
+    cache::refresh_cache(client, config, None)?;
 
-    Ok(results)
+    let mut teams: Vec<(String, usize)> = config
+        .get_active_org()?
+        .cache
+        .teams
+        .iter()
+        .map(|(key, team)| (key.clone(), team.states.len()))
+        .collect();
+    teams.sort();
+    Ok(teams)
 }
 
 /// Validate a token by querying the viewer endpoint.
@@ -333,17 +448,28 @@ impl crate::output::HumanDisplay for AuthRemoveResponse {
 #[derive(Debug, Serialize)]
 struct AuthStatusResponse {
     organization: String,
+    method: &'static str,
     teams: Vec<String>,
     last_sync: Option<String>,
+    token_expires_at: Option<String>,
 }
 
 impl crate::output::HumanDisplay for AuthStatusResponse {
     fn human_fmt(&self) -> String {
         let mut lines = vec![format!("Active organization: {}", self.organization)];
+        let method = if self.method == "oauth" {
+            "OAuth"
+        } else {
+            "API key"
+        };
+        lines.push(format!("Authenticated with: {}", method));
         lines.push(format!("Teams: {}", self.teams.join(", ")));
         if let Some(last_sync) = &self.last_sync {
             lines.push(format!("Last sync: {}", last_sync));
         }
+        if let Some(expires_at) = &self.token_expires_at {
+            lines.push(format!("Access token expires: {}", expires_at));
+        }
         lines.join("\n")
     }
 }
@@ -366,6 +492,28 @@ impl crate::output::HumanDisplay for AuthSyncResponse {
     }
 }
 
+#[derive(Debug, Serialize)]
+struct AuthMigrateSecretsResponse {
+    from: String,
+    to: String,
+    organizations: Vec<String>,
+}
+
+impl crate::output::HumanDisplay for AuthMigrateSecretsResponse {
+    fn human_fmt(&self) -> String {
+        if self.organizations.is_empty() {
+            return format!("✓ Secret backend set to {}", self.to);
+        }
+        format!(
+            "✓ Moved tokens for {} organizations from {} to {}: {}",
+            self.organizations.len(),
+            self.from,
+            self.to,
+            self.organizations.join(", ")
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -424,70 +572,4 @@ mod tests {
         assert!(output.contains("3 teams"));
         assert!(output.contains("5 teams"));
     }
-
-    #[test]
-    fn test_parse_estimate_scale_tshirt() {
-        let est = Some("tShirt".to_string());
-        let scale = parse_estimate_scale(&est);
-        assert_eq!(scale.len(), 5);
-        assert_eq!(scale["xs"], 1.0);
-        assert_eq!(scale["s"], 2.0);
-        assert_eq!(scale["m"], 3.0);
-        assert_eq!(scale["l"], 5.0);
-        assert_eq!(scale["xl"], 8.0);
-    }
-
-    #[test]
-    fn test_parse_estimate_scale_linear() {
-        let est = Some("linear".to_string());
-        let scale = parse_estimate_scale(&est);
-        assert_eq!(scale.len(), 5);
-        assert_eq!(scale["1"], 1.0);
-        assert_eq!(scale["2"], 2.0);
-        assert_eq!(scale["3"], 3.0);
-        assert_eq!(scale["4"], 4.0);
-        assert_eq!(scale["5"], 5.0);
-    }
-
-    #[test]
-    fn test_parse_estimate_scale_fibonacci() {
-        let est = Some("fibonacci".to_string());
-        let scale = parse_estimate_scale(&est);
-        assert_eq!(scale.len(), 7);
-        assert_eq!(scale["1"], 1.0);
-        assert_eq!(scale["2"], 2.0);
-        assert_eq!(scale["3"], 3.0);
-        assert_eq!(scale["5"], 5.0);
-        assert_eq!(scale["8"], 8.0);
-        assert_eq!(scale["13"], 13.0);
-        assert_eq!(scale["21"], 21.0);
-    }
-
-    #[test]
-    fn test_parse_estimate_scale_exponential() {
-        let est = Some("exponential".to_string());
-        let scale = parse_estimate_scale(&est);
-        assert_eq!(scale.len(), 7);
-        assert_eq!(scale["1"], 1.0);
-        assert_eq!(scale["2"], 2.0);
-        assert_eq!(scale["4"], 4.0);
-        assert_eq!(scale["8"], 8.0);
-        assert_eq!(scale["16"], 16.0);
-        assert_eq!(scale["32"], 32.0);
-        assert_eq!(scale["64"], 64.0);
-    }
-
-    #[test]
-    fn test_parse_estimate_scale_not_used() {
-        let est = Some("notUsed".to_string());
-        let scale = parse_estimate_scale(&est);
-        assert!(scale.is_empty());
-    }
-
-    #[test]
-    fn test_parse_estimate_scale_none() {
-        let est: Option<String> = None;
-        let scale = parse_estimate_scale(&est);
-        assert!(scale.is_empty());
-    }
 }
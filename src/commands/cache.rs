@@ -0,0 +1,449 @@
+//! Cache management commands.
+//!
+//! lin caches each organization's teams, workflow states, labels, users and
+//! projects in the config file so names can be resolved without API calls.
+//! The cache is refreshed lazily once it is older than `cache.ttl_hours`
+//! (see [`refresh_if_stale`]), and resolvers re-sync the relevant part once
+//! when a name isn't found.
+
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use crate::Result;
+use crate::api::GraphQLClient;
+use crate::api::queries;
+use crate::commands::resolvers;
+use crate::config::{CachedLabel, CachedUser, Config};
+use crate::error::LinError;
+use crate::models::{
+    LabelsResponse, ProjectsResponse, TeamsResponse, UsersResponse, WorkflowStatesResponse,
+};
+use crate::output::{OutputFormat, output};
+
+/// A part of the cache that can be refreshed on its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheResource {
+    /// Teams with their workflow states and estimate scales
+    Teams,
+    /// Workflow states (refreshed together with teams)
+    States,
+    /// Workspace and team labels
+    Labels,
+    /// Organization users
+    Users,
+    /// Projects
+    Projects,
+}
+
+impl CacheResource {
+    /// Names accepted by `lin cache refresh --only`.
+    pub const NAMES: [&'static str; 5] = ["teams", "states", "labels", "users", "projects"];
+
+    /// Parse a resource name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for names not in [`CacheResource::NAMES`].
+    pub fn from_name(name: &str) -> Result<Self> {
+        match name {
+            "teams" => Ok(Self::Teams),
+            "states" => Ok(Self::States),
+            "labels" => Ok(Self::Labels),
+            "users" => Ok(Self::Users),
+            "projects" => Ok(Self::Projects),
+            other => Err(LinError::config(format!(
+                "Unknown cache resource '{}'. Valid resources: {}",
+                other,
+                Self::NAMES.join(", ")
+            ))),
+        }
+    }
+
+    fn includes(only: Option<Self>, resource: Self) -> bool {
+        match only {
+            None => true,
+            Some(Self::States) => resource == Self::Teams,
+            Some(only) => only == resource,
+        }
+    }
+}
+
+/// Counts of cached items for an organization.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CacheCounts {
+    /// Cached teams
+    pub teams: usize,
+    /// Cached workflow states across all teams
+    pub states: usize,
+    /// Cached labels
+    pub labels: usize,
+    /// Cached users
+    pub users: usize,
+    /// Cached projects
+    pub projects: usize,
+}
+
+impl CacheCounts {
+    fn for_active_org(config: &Config) -> Result<Self> {
+        let cache = &config.get_active_org()?.cache;
+        Ok(Self {
+            teams: cache.teams.len(),
+            states: cache.teams.values().map(|t| t.states.len()).sum(),
+            labels: cache.labels.len(),
+            users: cache.users.len(),
+            projects: cache.projects.len(),
+        })
+    }
+
+    fn human_lines(&self) -> Vec<String> {
+        vec![
+            format!("Teams: {}", self.teams),
+            format!("States: {}", self.states),
+            format!("Labels: {}", self.labels),
+            format!("Users: {}", self.users),
+            format!("Projects: {}", self.projects),
+        ]
+    }
+}
+
+/// Response for `lin cache status`.
+#[derive(Debug, Clone, Serialize)]
+pub struct CacheStatus {
+    /// Organization name
+    pub organization: String,
+    /// Last full sync (ISO 8601 timestamp)
+    pub last_sync: Option<String>,
+    /// TTL in hours (None if the cache never expires)
+    pub ttl_hours: Option<u64>,
+    /// Whether the next command will refresh the cache
+    pub stale: bool,
+    /// Cached item counts
+    #[serde(flatten)]
+    pub counts: CacheCounts,
+}
+
+impl crate::output::HumanDisplay for CacheStatus {
+    fn human_fmt(&self) -> String {
+        let mut lines = vec![format!("Organization: {}", self.organization)];
+        lines.push(format!(
+            "Last sync: {}",
+            self.last_sync.as_deref().unwrap_or("never")
+        ));
+        lines.push(match self.ttl_hours {
+            Some(hours) => format!("TTL: {}h", hours),
+            None => "TTL: never expires".to_string(),
+        });
+        lines.push(format!(
+            "Status: {}",
+            if self.stale {
+                "stale (refreshes on next command)"
+            } else {
+                "fresh"
+            }
+        ));
+        lines.extend(self.counts.human_lines());
+        lines.join("\n")
+    }
+}
+
+/// Response for `lin cache refresh` and `lin cache clear`.
+#[derive(Debug, Clone, Serialize)]
+pub struct CacheUpdate {
+    /// Organization name
+    pub organization: String,
+    /// What happened ("refreshed" or "cleared")
+    pub action: String,
+    /// Resources affected
+    pub resources: Vec<String>,
+    /// Cached item counts afterwards
+    #[serde(flatten)]
+    pub counts: CacheCounts,
+}
+
+impl crate::output::HumanDisplay for CacheUpdate {
+    fn human_fmt(&self) -> String {
+        let mut lines = vec![format!(
+            "Cache {} for '{}' ({})",
+            self.action,
+            self.organization,
+            self.resources.join(", ")
+        )];
+        lines.extend(self.counts.human_lines());
+        lines.join("\n")
+    }
+}
+
+/// Re-sync part or all of the active organization's cache and save the config.
+///
+/// A full refresh (`only` is `None`) also resets the TTL by recording the
+/// sync time. `config` is reloaded before saving so credentials refreshed
+/// during the sync aren't overwritten.
+///
+/// # Arguments
+///
+/// * `client` - GraphQL client for API queries
+/// * `config` - Configuration holding the cache
+/// * `only` - Refresh only this resource
+pub fn refresh_cache(
+    client: &GraphQLClient,
+    config: &mut Config,
+    only: Option<CacheResource>,
+) -> Result<()> {
+    if CacheResource::includes(only, CacheResource::Teams) {
+        let teams_response: TeamsResponse = client.query(
+            queries::team::TEAMS_QUERY,
+            serde_json::json!({ "first": 100 }),
+        )?;
+
+        let mut teams = HashMap::new();
+        for team in teams_response.teams.nodes {
+            let states_response: WorkflowStatesResponse = client.query(
+                queries::workflow::WORKFLOW_STATES_QUERY,
+                serde_json::json!({ "id": team.id }),
+            )?;
+            let cached_team = resolvers::cached_team(&team, states_response.team.states.nodes);
+            teams.insert(team.key, cached_team);
+        }
+        config.get_active_org_mut()?.cache.teams = teams;
+    }
+
+    if CacheResource::includes(only, CacheResource::Projects) {
+        let projects_response: ProjectsResponse = client.query(
+            queries::project::PROJECTS_QUERY,
+            serde_json::json!({ "first": 250 }),
+        )?;
+        let projects: Vec<(String, String)> = projects_response
+            .projects
+            .nodes
+            .into_iter()
+            .map(|p| (p.id, p.name))
+            .collect();
+        config.cache_projects(projects)?;
+    }
+
+    if CacheResource::includes(only, CacheResource::Labels) {
+        let labels_response: LabelsResponse = client.query(
+            queries::label::LABELS_QUERY,
+            serde_json::json!({ "first": 250 }),
+        )?;
+        config.cache_labels(
+            labels_response
+                .issue_labels
+                .nodes
+                .into_iter()
+                .map(|l| CachedLabel {
+                    id: l.id,
+                    name: l.name,
+                })
+                .collect(),
+        )?;
+    }
+
+    if CacheResource::includes(only, CacheResource::Users) {
+        let users_response: UsersResponse = client.query(
+            queries::user::USERS_QUERY,
+            serde_json::json!({ "first": 250 }),
+        )?;
+        config.cache_users(
+            users_response
+                .users
+                .nodes
+                .into_iter()
+                .map(|u| CachedUser {
+                    id: u.id,
+                    name: u.name,
+                    email: u.email,
+                    display_name: u.display_name,
+                })
+                .collect(),
+        )?;
+    }
+
+    if only.is_none() {
+        config.update_last_sync()?;
+    }
+
+    // The queries above may have rotated an OAuth token and saved it, so
+    // write the new cache onto the config as it is now on disk.
+    let cache = config.get_active_org()?.cache.clone();
+    *config = Config::load()?;
+    config.get_active_org_mut()?.cache = cache;
+    config.save()
+}
+
+/// Refresh the active organization's cache if it has outlived the TTL.
+///
+/// Failures are reported as a warning so a flaky refresh never blocks the
+/// command that triggered it. Returns whether the cache was refreshed.
+///
+/// # Arguments
+///
+/// * `client` - GraphQL client for API queries
+/// * `config` - Configuration holding the cache
+pub fn refresh_if_stale(client: &GraphQLClient, config: &mut Config) -> bool {
+    if !config.cache_is_stale() {
+        return false;
+    }
+    match refresh_cache(client, config, None) {
+        Ok(()) => true,
+        Err(err) => {
+            eprintln!("Warning: could not refresh the cache: {}", err);
+            false
+        }
+    }
+}
+
+/// Show the active organization's cache contents and freshness.
+///
+/// # Arguments
+///
+/// * `format` - Output format
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use lin::commands::cache::cache_status;
+/// use lin::output::OutputFormat;
+///
+/// cache_status(OutputFormat::Human)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn cache_status(format: OutputFormat) -> Result<()> {
+    let config = Config::load()?;
+    let status = CacheStatus {
+        organization: config.get_active_org_name()?.to_string(),
+        last_sync: config.get_active_org()?.cache.last_sync.clone(),
+        ttl_hours: config.cache.ttl().map(|ttl| ttl.num_hours() as u64),
+        stale: config.cache_is_stale(),
+        counts: CacheCounts::for_active_org(&config)?,
+    };
+    output(&status, format);
+    Ok(())
+}
+
+/// Remove all cached data for the active organization.
+///
+/// The next command that needs the cache re-syncs it.
+///
+/// # Arguments
+///
+/// * `format` - Output format
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use lin::commands::cache::cache_clear;
+/// use lin::output::OutputFormat;
+///
+/// cache_clear(OutputFormat::Human)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn cache_clear(format: OutputFormat) -> Result<()> {
+    let mut config = Config::load()?;
+    config.clear_cache()?;
+    config.save()?;
+
+    let update = CacheUpdate {
+        organization: config.get_active_org_name()?.to_string(),
+        action: "cleared".to_string(),
+        resources: CacheResource::NAMES.iter().map(|s| s.to_string()).collect(),
+        counts: CacheCounts::default(),
+    };
+    output(&update, format);
+    Ok(())
+}
+
+/// Re-sync the active organization's cache now.
+///
+/// # Arguments
+///
+/// * `client` - GraphQL client for API queries
+/// * `only` - Refresh only this resource
+/// * `format` - Output format
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use lin::api::GraphQLClient;
+/// use lin::commands::cache::{CacheResource, cache_refresh};
+/// use lin::output::OutputFormat;
+///
+/// let client = GraphQLClient::new("lin_api_xxxxx");
+/// cache_refresh(&client, Some(CacheResource::Labels), OutputFormat::Human)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn cache_refresh(
+    client: &GraphQLClient,
+    only: Option<CacheResource>,
+    format: OutputFormat,
+) -> Result<()> {
+    let mut config = Config::load()?;
+    refresh_cache(client, &mut config, only)?;
+
+    let resources: &[&str] = match only {
+        None => &CacheResource::NAMES,
+        Some(CacheResource::Teams | CacheResource::States) => &["teams", "states"],
+        Some(CacheResource::Labels) => &["labels"],
+        Some(CacheResource::Users) => &["users"],
+        Some(CacheResource::Projects) => &["projects"],
+    };
+    let update = CacheUpdate {
+        organization: config.get_active_org_name()?.to_string(),
+        action: "refreshed".to_string(),
+        resources: resources.iter().map(|s| s.to_string()).collect(),
+        counts: CacheCounts::for_active_org(&config)?,
+    };
+    output(&update, format);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::HumanDisplay;
+
+    #[test]
+    fn test_cache_resource_from_name() {
+        assert_eq!(
+            CacheResource::from_name("labels").unwrap(),
+            CacheResource::Labels
+        );
+        let err = CacheResource::from_name("cycles").unwrap_err();
+        assert!(err.to_string().contains("Valid resources"));
+    }
+
+    #[test]
+    fn test_states_refresh_with_teams() {
+        let only = Some(CacheResource::States);
+        assert!(CacheResource::includes(only, CacheResource::Teams));
+        assert!(!CacheResource::includes(only, CacheResource::Labels));
+        assert!(CacheResource::includes(None, CacheResource::Users));
+    }
+
+    #[test]
+    fn test_cache_status_human_fmt() {
+        let status = CacheStatus {
+            organization: "work".to_string(),
+            last_sync: None,
+            ttl_hours: Some(24),
+            stale: true,
+            counts: CacheCounts {
+                teams: 2,
+                states: 12,
+                ..Default::default()
+            },
+        };
+        let human = status.human_fmt();
+        assert!(human.contains("Last sync: never"));
+        assert!(human.contains("TTL: 24h"));
+        assert!(human.contains("stale"));
+        assert!(human.contains("States: 12"));
+    }
+}
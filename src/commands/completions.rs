@@ -1,11 +1,244 @@
 //! Shell completion generation commands.
 //!
 //! Commands for generating shell completion scripts for various shells.
+//!
+//! The default scripts are dynamic: on each TAB they call back into lin
+//! (with `COMPLETE=<shell>` set), which completes team keys, state names,
+//! project slugs, label names, users and recently viewed issues from the
+//! cache. Static scripts only know the command tree.
 
 use clap::Command;
+use clap_complete::engine::{ArgValueCompleter, CompletionCandidate};
+use clap_complete::env::{Bash, Elvish, EnvCompleter, Fish, Powershell, Zsh};
 use clap_complete::{Shell, generate};
+use std::cell::RefCell;
+use std::env;
+use std::ffi::{OsStr, OsString};
 use std::io;
 
+use crate::Result;
+use crate::config::{Config, OrgCache};
+use crate::error::LinError;
+
+/// Environment variable the dynamic scripts set when asking lin for completions.
+pub const COMPLETE_ENV: &str = "COMPLETE";
+
+thread_local! {
+    /// Command line being completed when it isn't the process's own (`lin shell`).
+    static COMPLETION_ARGS: RefCell<Option<Vec<OsString>>> = const { RefCell::new(None) };
+}
+
+/// A kind of value lin can complete from the cache.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValueKind {
+    /// Team keys
+    Team,
+    /// State names of the team given with `--team`, or the current team
+    State,
+    /// Project slugs
+    Project,
+    /// Label names
+    Label,
+    /// User emails (and "me")
+    User,
+    /// Recently viewed issue identifiers
+    Issue,
+}
+
+impl ValueKind {
+    /// The kind of value an argument takes, if lin can complete it.
+    ///
+    /// `command` is the top-level command the argument belongs to, since
+    /// `identifier` means an issue under `lin issue` but a team under `lin team`.
+    pub fn for_arg(command: &str, arg: &str) -> Option<Self> {
+        match arg {
+            "team" => Some(ValueKind::Team),
+            "state" => Some(ValueKind::State),
+            "project" => Some(ValueKind::Project),
+            "label" | "labels" => Some(ValueKind::Label),
+            "user" | "assignee" | "accept_all_from" => Some(ValueKind::User),
+            "issue" | "related_issue" => Some(ValueKind::Issue),
+            "identifier" if command == "issue" => Some(ValueKind::Issue),
+            "identifier" if command == "team" => Some(ValueKind::Team),
+            _ => None,
+        }
+    }
+}
+
+/// Completion candidates of `kind` from an organization's cache.
+///
+/// Values are matched case-insensitively against the `current` prefix.
+/// `team` is the team whose states are completed.
+pub fn cached_candidates(
+    kind: ValueKind,
+    cache: &OrgCache,
+    team: Option<&str>,
+    current: &str,
+) -> Vec<CompletionCandidate> {
+    let mut values: Vec<(String, Option<String>)> = match kind {
+        ValueKind::Team => cache
+            .teams
+            .iter()
+            .map(|(key, team)| (key.clone(), Some(team.name.clone())))
+            .collect(),
+        ValueKind::State => team
+            .and_then(|team| cache.teams.get(&team.to_uppercase()))
+            .map(|team| team.states.keys().map(|s| (s.clone(), None)).collect())
+            .unwrap_or_default(),
+        ValueKind::Project => cache.projects.keys().map(|p| (p.clone(), None)).collect(),
+        ValueKind::Label => cache
+            .labels
+            .iter()
+            .map(|l| (l.name.clone(), None))
+            .collect(),
+        ValueKind::User => cache
+            .users
+            .iter()
+            .map(|u| (u.email.clone(), Some(u.name.clone())))
+            .collect(),
+        ValueKind::Issue => cache
+            .recent_issues
+            .iter()
+            .map(|i| (i.clone(), None))
+            .collect(),
+    };
+    // Recent issues keep their most-recent-first order
+    if kind != ValueKind::Issue {
+        values.sort();
+        values.dedup_by(|a, b| a.0 == b.0);
+    }
+    if kind == ValueKind::User {
+        values.insert(0, ("me".to_string(), Some("You".to_string())));
+    }
+
+    let current = current.to_lowercase();
+    values
+        .into_iter()
+        .filter(|(value, _)| value.to_lowercase().starts_with(&current))
+        .map(|(value, help)| CompletionCandidate::new(value).help(help.map(Into::into)))
+        .collect()
+}
+
+/// Value of `--team` on a command line, if given.
+fn team_from_args(args: &[OsString]) -> Option<String> {
+    let args: Vec<&str> = args.iter().filter_map(|arg| arg.to_str()).collect();
+    args.iter().enumerate().find_map(|(i, arg)| match *arg {
+        "--team" => args.get(i + 1).map(|team| team.to_string()),
+        _ => arg.strip_prefix("--team=").map(str::to_string),
+    })
+}
+
+/// Complete a value of `kind` from the active organization's cache.
+///
+/// States are completed for `team`, falling back to the current team.
+pub fn complete_cached(
+    kind: ValueKind,
+    current: &str,
+    team: Option<&str>,
+) -> Vec<CompletionCandidate> {
+    let Ok(config) = Config::load() else {
+        return Vec::new();
+    };
+    let Ok(org) = config.get_active_org() else {
+        return Vec::new();
+    };
+    let team = match kind {
+        ValueKind::State => team.map(str::to_string).or(config.get_current_team()),
+        _ => None,
+    };
+    cached_candidates(kind, &org.cache, team.as_deref(), current)
+}
+
+/// Completer attached to arguments by [`add_dynamic_completers`].
+fn complete_value(kind: ValueKind, current: &OsStr) -> Vec<CompletionCandidate> {
+    let Some(current) = current.to_str() else {
+        return Vec::new();
+    };
+    let args = COMPLETION_ARGS
+        .with_borrow(|args| args.clone())
+        .unwrap_or_else(|| env::args_os().collect());
+    complete_cached(kind, current, team_from_args(&args).as_deref())
+}
+
+/// Complete the word at `index` in `args` (program name first), as the shell
+/// scripts would.
+///
+/// `team` is used for state names when `args` has no `--team`.
+pub fn complete_args(
+    cmd: &mut Command,
+    args: Vec<OsString>,
+    index: usize,
+    team: Option<&str>,
+) -> Vec<CompletionCandidate> {
+    let mut context = args.clone();
+    if let Some(team) = team
+        && team_from_args(&args).is_none()
+    {
+        context.extend([OsString::from("--team"), OsString::from(team)]);
+    }
+    COMPLETION_ARGS.set(Some(context));
+    let candidates = clap_complete::engine::complete(cmd, args, index, None).unwrap_or_default();
+    COMPLETION_ARGS.set(None);
+    candidates
+}
+
+/// Attach cache-backed value completers to every argument lin can complete.
+pub fn add_dynamic_completers(cmd: Command) -> Command {
+    let names: Vec<String> = cmd
+        .get_subcommands()
+        .map(|sub| sub.get_name().to_string())
+        .collect();
+    names.iter().fold(cmd, |cmd, name| {
+        cmd.mut_subcommand(name, |sub| add_completers_to(sub, name))
+    })
+}
+
+fn add_completers_to(cmd: Command, top: &str) -> Command {
+    // `mut_args` keeps argument order, which positional indexes depend on
+    let cmd = cmd.mut_args(|arg| match ValueKind::for_arg(top, arg.get_id().as_str()) {
+        Some(kind) => arg.add(ArgValueCompleter::new(move |current: &OsStr| {
+            complete_value(kind, current)
+        })),
+        None => arg,
+    });
+    let names: Vec<String> = cmd
+        .get_subcommands()
+        .map(|sub| sub.get_name().to_string())
+        .collect();
+    names.iter().fold(cmd, |cmd, name| {
+        cmd.mut_subcommand(name, |sub| add_completers_to(sub, top))
+    })
+}
+
+/// Write the dynamic completion script for a shell.
+///
+/// The script calls back into the current lin executable on each TAB.
+///
+/// # Errors
+///
+/// Returns an error for shells without dynamic completion support, or if
+/// the script can't be written.
+pub fn generate_dynamic_completions(shell: Shell, buf: &mut dyn io::Write) -> Result<()> {
+    let completer: &dyn EnvCompleter = match shell {
+        Shell::Bash => &Bash,
+        Shell::Zsh => &Zsh,
+        Shell::Fish => &Fish,
+        Shell::Elvish => &Elvish,
+        Shell::PowerShell => &Powershell,
+        _ => {
+            return Err(LinError::config(format!(
+                "Dynamic completions aren't supported for {}. Use: lin completions {} --static",
+                shell, shell
+            )));
+        }
+    };
+    let exe = env::current_exe()
+        .map(|path| path.to_string_lossy().into_owned())
+        .unwrap_or_else(|_| "lin".to_string());
+    completer.write_registration(COMPLETE_ENV, "lin", "lin", &exe, buf)?;
+    Ok(())
+}
+
 /// Generate shell completion scripts.
 ///
 /// Outputs completion script for the specified shell to stdout.
@@ -126,6 +359,156 @@ mod tests {
         );
     }
 
+    fn test_cache() -> OrgCache {
+        let mut cache = OrgCache::default();
+        cache.teams.insert(
+            "ENG".to_string(),
+            crate::config::CachedTeam {
+                id: "team-1".to_string(),
+                name: "Engineering".to_string(),
+                states: [("in progress", "s1"), ("done", "s2"), ("todo", "s3")]
+                    .into_iter()
+                    .map(|(name, id)| (name.to_string(), id.to_string()))
+                    .collect(),
+                estimates: Default::default(),
+                estimate_type: None,
+                state_types: Default::default(),
+            },
+        );
+        cache.labels = ["Bug", "Backend", "Bug"]
+            .iter()
+            .enumerate()
+            .map(|(i, name)| crate::config::CachedLabel {
+                id: format!("label-{}", i),
+                name: name.to_string(),
+            })
+            .collect();
+        cache.users = vec![crate::config::CachedUser {
+            id: "user-1".to_string(),
+            name: "Alice Smith".to_string(),
+            email: "alice@example.com".to_string(),
+            display_name: None,
+        }];
+        cache.recent_issues = vec!["ENG-9".to_string(), "ENG-12".to_string()];
+        cache
+    }
+
+    fn values(candidates: Vec<CompletionCandidate>) -> Vec<String> {
+        candidates
+            .iter()
+            .map(|c| c.get_value().to_string_lossy().into_owned())
+            .collect()
+    }
+
+    #[test]
+    fn test_value_kind_for_arg() {
+        assert_eq!(
+            ValueKind::for_arg("issue", "identifier"),
+            Some(ValueKind::Issue)
+        );
+        assert_eq!(
+            ValueKind::for_arg("team", "identifier"),
+            Some(ValueKind::Team)
+        );
+        assert_eq!(
+            ValueKind::for_arg("label", "labels"),
+            Some(ValueKind::Label)
+        );
+        assert_eq!(ValueKind::for_arg("issue", "title"), None);
+    }
+
+    #[test]
+    fn test_cached_candidates() {
+        let cache = test_cache();
+        assert_eq!(
+            values(cached_candidates(ValueKind::State, &cache, Some("eng"), "")),
+            vec!["done", "in progress", "todo"]
+        );
+        assert!(cached_candidates(ValueKind::State, &cache, None, "").is_empty());
+        assert_eq!(
+            values(cached_candidates(ValueKind::Label, &cache, None, "b")),
+            vec!["Backend", "Bug"]
+        );
+        assert_eq!(
+            values(cached_candidates(ValueKind::User, &cache, None, "")),
+            vec!["me", "alice@example.com"]
+        );
+        assert_eq!(
+            values(cached_candidates(ValueKind::Issue, &cache, None, "eng")),
+            vec!["ENG-9", "ENG-12"]
+        );
+        assert_eq!(
+            values(cached_candidates(ValueKind::Team, &cache, None, "E")),
+            vec!["ENG"]
+        );
+    }
+
+    #[test]
+    fn test_team_from_args() {
+        let args = |args: &[&str]| args.iter().map(OsString::from).collect::<Vec<_>>();
+        assert_eq!(
+            team_from_args(&args(&["lin", "issue", "list", "--team", "OPS", "--state"])),
+            Some("OPS".to_string())
+        );
+        assert_eq!(
+            team_from_args(&args(&["lin", "issue", "list", "--team=OPS"])),
+            Some("OPS".to_string())
+        );
+        assert_eq!(team_from_args(&args(&["lin", "issue", "list"])), None);
+    }
+
+    #[test]
+    fn test_add_dynamic_completers() {
+        let cmd = add_dynamic_completers(Command::new("lin").subcommand(
+            Command::new("issue").subcommand(Command::new("get").arg(clap::Arg::new("identifier"))),
+        ));
+        let issue = cmd.find_subcommand("issue").unwrap();
+        let get = issue.find_subcommand("get").unwrap();
+        let arg = get.get_arguments().next().unwrap();
+        assert!(arg.get::<ArgValueCompleter>().is_some());
+    }
+
+    #[test]
+    fn test_add_dynamic_completers_keeps_positional_order() {
+        let mut cmd = add_dynamic_completers(
+            Command::new("lin").subcommand(
+                Command::new("issue").subcommand(
+                    Command::new("link-branch")
+                        .arg(clap::Arg::new("identifier").required(true))
+                        .arg(clap::Arg::new("branch")),
+                ),
+            ),
+        );
+        cmd.build();
+        cmd.debug_assert();
+    }
+
+    #[test]
+    fn test_complete_args_completes_subcommands() {
+        let mut cmd = Command::new("lin").subcommand(
+            Command::new("issue")
+                .subcommand(Command::new("list"))
+                .subcommand(Command::new("get")),
+        );
+        let args = ["lin", "issue", "l"].iter().map(OsString::from).collect();
+        assert_eq!(values(complete_args(&mut cmd, args, 2, None)), vec!["list"]);
+    }
+
+    #[test]
+    fn test_generate_dynamic_completions() {
+        for (shell, marker) in [
+            (Shell::Bash, "complete -o"),
+            (Shell::Zsh, "compdef"),
+            (Shell::Fish, "complete"),
+        ] {
+            let mut output = Vec::new();
+            generate_dynamic_completions(shell, &mut output).unwrap();
+            let output = String::from_utf8(output).unwrap();
+            assert!(output.contains(COMPLETE_ENV), "{}", shell);
+            assert!(output.contains(marker), "{}", shell);
+        }
+    }
+
     #[test]
     fn test_completions_include_subcommands() {
         let mut cmd = build_test_command();
@@ -0,0 +1,599 @@
+//! Configuration commands.
+//!
+//! Shows the effective configuration after merging the repository's
+//! `.lin.toml`, the global config file, environment variables and defaults,
+//! and reads or changes individual keys of the global config file.
+
+use std::fs;
+use std::io::{BufRead, Write};
+use std::path::Path;
+use std::process::Command;
+
+use serde::Serialize;
+
+use crate::Result;
+use crate::auth::LINEAR_API_TOKEN_ENV;
+use crate::config::Config;
+use crate::error::LinError;
+use crate::output::{OutputFormat, output};
+use crate::repo_config::DEFAULT_BRANCH_TEMPLATE;
+
+/// A single effective configuration value.
+#[derive(Debug, Clone, Serialize)]
+pub struct ConfigValue {
+    /// Setting name
+    pub key: String,
+    /// Effective value (null when unset)
+    pub value: serde_json::Value,
+    /// Where the value came from (only with `--origin`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub origin: Option<String>,
+}
+
+/// The effective configuration.
+#[derive(Debug, Clone, Serialize)]
+pub struct ConfigShow {
+    /// Effective values in display order
+    pub values: Vec<ConfigValue>,
+}
+
+/// Format a config value for human output.
+fn display_value(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Null => "(not set)".to_string(),
+        serde_json::Value::String(s) => s.clone(),
+        serde_json::Value::Array(items) => items
+            .iter()
+            .map(|item| item.as_str().map_or(item.to_string(), str::to_string))
+            .collect::<Vec<_>>()
+            .join(", "),
+        other => other.to_string(),
+    }
+}
+
+impl crate::output::HumanDisplay for ConfigValue {
+    fn human_fmt(&self) -> String {
+        display_value(&self.value)
+    }
+}
+
+impl crate::output::HumanDisplay for ConfigShow {
+    fn human_fmt(&self) -> String {
+        self.values
+            .iter()
+            .map(|entry| {
+                let value = display_value(&entry.value);
+                match entry.origin.as_ref().filter(|_| !entry.value.is_null()) {
+                    Some(origin) => format!("{} = {}  ({})", entry.key, value, origin),
+                    None => format!("{} = {}", entry.key, value),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Where an effective value came from.
+enum Origin<'a> {
+    Env(&'static str),
+    File(&'a Path),
+    SecretBackend(&'static str),
+    Default,
+    Unset,
+}
+
+impl Origin<'_> {
+    fn describe(&self) -> String {
+        match self {
+            Origin::Env(name) => format!("{} environment variable", name),
+            Origin::File(path) => path.display().to_string(),
+            Origin::SecretBackend(name) => format!("{} secret backend", name),
+            Origin::Default => "default".to_string(),
+            Origin::Unset => "not set".to_string(),
+        }
+    }
+}
+
+/// Compute the effective configuration and where each value came from.
+///
+/// Precedence, highest first: environment variables, the repository's
+/// `.lin.toml`, the global config file, built-in defaults.
+///
+/// # Arguments
+///
+/// * `config` - The loaded configuration (including any `.lin.toml`)
+/// * `global_path` - Path of the global config file, reported as an origin
+/// * `env_token` - Whether `LINEAR_API_TOKEN` is set
+pub fn effective_config(config: &Config, global_path: &Path, env_token: bool) -> ConfigShow {
+    let repo = config.repo.as_ref().map(|r| &r.config);
+    let repo_origin = |is_set: fn(&crate::repo_config::RepoConfig) -> bool| {
+        config.repo_pin(is_set).map(Origin::File)
+    };
+
+    let mut values: Vec<(&str, serde_json::Value, Origin)> = Vec::new();
+
+    let org = config.get_active_org_name().ok();
+    let org_origin = repo_origin(|r| r.org.is_some()).unwrap_or(if org.is_some() {
+        Origin::File(global_path)
+    } else {
+        Origin::Unset
+    });
+    values.push(("org", serde_json::json!(org), org_origin));
+
+    let token_origin = if env_token {
+        Origin::Env(LINEAR_API_TOKEN_ENV)
+    } else if config.get_active_org().is_err() {
+        Origin::Unset
+    } else if config.secret_backend.is_plaintext() {
+        Origin::File(global_path)
+    } else {
+        Origin::SecretBackend(config.secret_backend.name())
+    };
+    let token_value = match &token_origin {
+        Origin::Unset => serde_json::Value::Null,
+        _ => serde_json::json!("(set)"),
+    };
+    values.push(("token", token_value, token_origin));
+
+    values.push((
+        "secret_backend",
+        serde_json::json!(config.secret_backend.name()),
+        if config.secret_backend.is_plaintext() {
+            Origin::Default
+        } else {
+            Origin::File(global_path)
+        },
+    ));
+
+    let team = config.get_current_team();
+    let team_origin = repo_origin(|r| r.team.is_some()).unwrap_or(if team.is_some() {
+        Origin::File(global_path)
+    } else {
+        Origin::Unset
+    });
+    values.push(("team", serde_json::json!(team), team_origin));
+
+    let project = repo.and_then(|r| r.project.clone());
+    values.push((
+        "project",
+        serde_json::json!(project),
+        repo_origin(|r| r.project.is_some()).unwrap_or(Origin::Unset),
+    ));
+
+    let labels = repo.map(|r| r.labels.clone()).unwrap_or_default();
+    values.push((
+        "labels",
+        serde_json::json!(labels),
+        repo_origin(|r| !r.labels.is_empty()).unwrap_or(Origin::Unset),
+    ));
+
+    let branch_template = repo.map_or(DEFAULT_BRANCH_TEMPLATE, |r| r.branch_template());
+    values.push((
+        "branch_template",
+        serde_json::json!(branch_template),
+        repo_origin(|r| r.branch_template.is_some()).unwrap_or(Origin::Default),
+    ));
+
+    let issue_patterns = repo
+        .map(|r| r.issue_patterns.clone())
+        .filter(|patterns| !patterns.is_empty())
+        .unwrap_or_else(|| vec!["TEAM-{number}".to_string()]);
+    values.push((
+        "issue_patterns",
+        serde_json::json!(issue_patterns),
+        repo_origin(|r| !r.issue_patterns.is_empty()).unwrap_or(Origin::Default),
+    ));
+
+    ConfigShow {
+        values: values
+            .into_iter()
+            .map(|(key, value, origin)| ConfigValue {
+                key: key.to_string(),
+                value,
+                origin: Some(origin.describe()),
+            })
+            .collect(),
+    }
+}
+
+/// Show the effective configuration.
+///
+/// # Arguments
+///
+/// * `origin` - Include where each value came from
+/// * `format` - Output format
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use lin::commands::config::config_show;
+/// use lin::output::OutputFormat;
+///
+/// config_show(true, OutputFormat::Human)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn config_show(origin: bool, format: OutputFormat) -> Result<()> {
+    let config = Config::load()?;
+    let env_token = std::env::var(LINEAR_API_TOKEN_ENV).is_ok_and(|token| !token.is_empty());
+
+    let mut show = effective_config(&config, &Config::config_path(), env_token);
+    if !origin {
+        for value in &mut show.values {
+            value.origin = None;
+        }
+    }
+
+    output(&show, format);
+    Ok(())
+}
+
+/// Get a key from the global config file.
+///
+/// # Arguments
+///
+/// * `key` - Dotted key (e.g., `orgs.work.current_team`)
+/// * `format` - Output format
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use lin::commands::config::config_get;
+/// use lin::output::OutputFormat;
+///
+/// config_get("defaults.issue.priority", OutputFormat::Human)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn config_get(key: &str, format: OutputFormat) -> Result<()> {
+    let config = Config::load_global()?;
+    let value = ConfigValue {
+        key: key.to_string(),
+        value: config.get_key(key)?,
+        origin: None,
+    };
+    output(&value, format);
+    Ok(())
+}
+
+/// Set a key in the global config file.
+///
+/// The value is type-checked against the config format and validated before
+/// anything is written.
+///
+/// # Arguments
+///
+/// * `key` - Dotted key (e.g., `defaults.issue.priority`)
+/// * `value` - New value (parsed as JSON when possible, otherwise a string)
+/// * `format` - Output format
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use lin::commands::config::config_set;
+/// use lin::output::OutputFormat;
+///
+/// config_set("defaults.issue.priority", "3", OutputFormat::Human)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn config_set(key: &str, value: &str, format: OutputFormat) -> Result<()> {
+    let mut config = Config::load_global()?;
+    config.set_key(key, value)?;
+    config.save()?;
+
+    let value = ConfigValue {
+        key: key.to_string(),
+        value: config.get_key(key)?,
+        origin: None,
+    };
+    output(&value, format);
+    Ok(())
+}
+
+/// Remove a key from the global config file.
+///
+/// # Arguments
+///
+/// * `key` - Dotted key (e.g., `defaults.issue.state`)
+/// * `format` - Output format
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use lin::commands::config::config_unset;
+/// use lin::output::OutputFormat;
+///
+/// config_unset("defaults.issue.state", OutputFormat::Human)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn config_unset(key: &str, format: OutputFormat) -> Result<()> {
+    let mut config = Config::load_global()?;
+    config.unset_key(key)?;
+    config.save()?;
+
+    let value = ConfigValue {
+        key: key.to_string(),
+        value: config.get_key(key)?,
+        origin: None,
+    };
+    output(&value, format);
+    Ok(())
+}
+
+/// List every key set in the global config file.
+///
+/// # Arguments
+///
+/// * `format` - Output format
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use lin::commands::config::config_list;
+/// use lin::output::OutputFormat;
+///
+/// config_list(OutputFormat::Human)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn config_list(format: OutputFormat) -> Result<()> {
+    let config = Config::load_global()?;
+    let show = ConfigShow {
+        values: config
+            .list_keys()?
+            .into_iter()
+            .map(|(key, value)| ConfigValue {
+                key,
+                value,
+                origin: None,
+            })
+            .collect(),
+    };
+    output(&show, format);
+    Ok(())
+}
+
+/// Result of `lin config edit`.
+#[derive(Debug, Clone, Serialize)]
+pub struct ConfigEdit {
+    /// Path of the global config file
+    pub path: String,
+    /// Whether the file was changed
+    pub changed: bool,
+}
+
+impl crate::output::HumanDisplay for ConfigEdit {
+    fn human_fmt(&self) -> String {
+        if self.changed {
+            format!("Saved {}", self.path)
+        } else {
+            "No changes".to_string()
+        }
+    }
+}
+
+/// The user's editor: `$VISUAL`, then `$EDITOR`, then a platform default.
+fn editor() -> String {
+    ["VISUAL", "EDITOR"]
+        .iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .find(|editor| !editor.trim().is_empty())
+        .unwrap_or_else(|| if cfg!(windows) { "notepad" } else { "vi" }.to_string())
+}
+
+/// Open `path` in `editor`, which may include arguments (e.g., `code --wait`).
+fn run_editor(editor: &str, path: &Path) -> Result<()> {
+    let status = if cfg!(windows) {
+        Command::new(editor).arg(path).status()
+    } else {
+        Command::new("sh")
+            .arg("-c")
+            .arg(format!("{} \"$1\"", editor))
+            .arg("sh")
+            .arg(path)
+            .status()
+    }
+    .map_err(|e| LinError::config(format!("Failed to run editor '{}': {}", editor, e)))?;
+
+    if !status.success() {
+        return Err(LinError::config(format!(
+            "Editor '{}' exited with {}",
+            editor, status
+        )));
+    }
+    Ok(())
+}
+
+/// Parse and validate edited config file contents.
+fn parse_edited(contents: &str) -> Result<Config> {
+    let config = Config::parse(contents)?;
+    config.validate()?;
+    Ok(config)
+}
+
+/// Open the global config file in `$EDITOR`, validating it before saving.
+///
+/// The file is edited as a private temporary copy. If the edited file is
+/// invalid, the user can edit it again or discard the changes.
+///
+/// # Arguments
+///
+/// * `format` - Output format
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use lin::commands::config::config_edit;
+/// use lin::output::OutputFormat;
+///
+/// config_edit(OutputFormat::Human)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn config_edit(format: OutputFormat) -> Result<()> {
+    let config = Config::load_global()?;
+    let path = Config::config_path();
+    let original = serde_json::to_string_pretty(&config)
+        .map_err(|e| LinError::parse(format!("Failed to serialize config: {}", e)))?;
+
+    let dir = path.parent().unwrap_or_else(|| Path::new("."));
+    fs::create_dir_all(dir)?;
+    let mut temp_file = tempfile::Builder::new()
+        .prefix("config.edit.")
+        .suffix(".json")
+        .tempfile_in(dir)?;
+    temp_file.write_all(original.as_bytes())?;
+    temp_file.flush()?;
+
+    let editor = editor();
+    let edited = loop {
+        run_editor(&editor, temp_file.path())?;
+        let contents = fs::read_to_string(temp_file.path())?;
+        if contents == original {
+            output(
+                &ConfigEdit {
+                    path: path.display().to_string(),
+                    changed: false,
+                },
+                format,
+            );
+            return Ok(());
+        }
+
+        match parse_edited(&contents) {
+            Ok(edited) => break edited,
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                eprint!("Edit again? [Y/n] ");
+                std::io::stderr().flush()?;
+                let mut answer = String::new();
+                std::io::stdin().lock().read_line(&mut answer)?;
+                if answer.trim().eq_ignore_ascii_case("n") {
+                    return Err(LinError::config("Config not saved; changes discarded"));
+                }
+            }
+        }
+    };
+
+    edited.save()?;
+    output(
+        &ConfigEdit {
+            path: path.display().to_string(),
+            changed: true,
+        },
+        format,
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::HumanDisplay;
+    use crate::repo_config::{RepoConfig, RepoConfigFile};
+    use std::path::PathBuf;
+
+    fn find<'a>(show: &'a ConfigShow, key: &str) -> &'a ConfigValue {
+        show.values.iter().find(|v| v.key == key).unwrap()
+    }
+
+    fn global_config() -> Config {
+        let mut config = Config::default();
+        config
+            .add_org("work".to_string(), "token".to_string())
+            .unwrap();
+        config
+            .add_org("personal".to_string(), "token".to_string())
+            .unwrap();
+        config.get_active_org_mut().unwrap().current_team = Some("ENG".to_string());
+        config
+    }
+
+    #[test]
+    fn test_effective_config_global_only() {
+        let config = global_config();
+        let show = effective_config(&config, Path::new("/home/u/config.json"), false);
+
+        assert_eq!(find(&show, "org").value, "work");
+        assert_eq!(
+            find(&show, "org").origin.as_deref(),
+            Some("/home/u/config.json")
+        );
+        assert_eq!(find(&show, "team").value, "ENG");
+        assert_eq!(find(&show, "project").value, serde_json::Value::Null);
+        assert_eq!(find(&show, "project").origin.as_deref(), Some("not set"));
+        assert_eq!(
+            find(&show, "branch_template").origin.as_deref(),
+            Some("default")
+        );
+    }
+
+    #[test]
+    fn test_effective_config_repo_overrides_global() {
+        let mut config = global_config();
+        config.repo = Some(RepoConfigFile {
+            path: PathBuf::from("/src/api/.lin.toml"),
+            config: RepoConfig {
+                org: Some("personal".to_string()),
+                team: Some("ops".to_string()),
+                project: Some("api-v2".to_string()),
+                labels: vec!["backend".to_string()],
+                ..Default::default()
+            },
+        });
+
+        let show = effective_config(&config, Path::new("/home/u/config.json"), true);
+
+        assert_eq!(find(&show, "org").value, "personal");
+        assert_eq!(
+            find(&show, "org").origin.as_deref(),
+            Some("/src/api/.lin.toml")
+        );
+        assert_eq!(find(&show, "team").value, "OPS");
+        assert_eq!(find(&show, "project").value, "api-v2");
+        assert_eq!(
+            find(&show, "token").origin.as_deref(),
+            Some("LINEAR_API_TOKEN environment variable")
+        );
+
+        let human = show.human_fmt();
+        assert!(human.contains("labels = backend  (/src/api/.lin.toml)"));
+    }
+
+    #[test]
+    fn test_parse_edited_validates() {
+        let config = parse_edited(
+            r#"{"active_org": "work", "orgs": {"work": {}}, "defaults": {"issue": {"priority": 2}}}"#,
+        )
+        .unwrap();
+        assert_eq!(config.defaults.issue.priority, Some(2));
+
+        let err = parse_edited(r#"{"active_org": "work", "orgs": {}}"#).unwrap_err();
+        assert!(err.to_string().contains("not configured"));
+
+        let err = parse_edited(r#"{"orgs": {}, "defaults": {"issue": {"priority": "high"}}}"#)
+            .unwrap_err();
+        assert!(err.to_string().contains("Failed to parse config file"));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_run_editor_with_arguments() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.json");
+        fs::write(&path, "{}").unwrap();
+
+        run_editor("sed -i.bak s/{}/[]/", &path).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap().trim(), "[]");
+
+        assert!(run_editor("false", &path).is_err());
+    }
+}
@@ -2,18 +2,22 @@
 //!
 //! Commands for linking git branches and pull requests to Linear issues.
 //! Linear tracks these links through its attachment system, recognizing
-//! specific URL patterns for branches and PRs.
+//! specific URL patterns for branches and PRs. Generated branch names follow
+//! the `branch_template` in the repository's `.lin.toml`, and its
+//! `issue_patterns` pick the issue out of the current branch's name.
 
 use crate::Result;
 use crate::api::GraphQLClient;
 use crate::api::queries::attachment::{ATTACHMENT_CREATE_MUTATION, ISSUE_GIT_LINKS_QUERY};
 use crate::api::queries::issue::ISSUE_BY_IDENTIFIER_QUERY;
 use crate::commands::issue::is_uuid;
+use crate::config::Config;
 use crate::error::LinError;
 use crate::models::{
     Attachment, AttachmentCreateResponse, IssueAttachmentsResponse, IssuesResponse,
 };
 use crate::output::{HumanDisplay, OutputFormat, output};
+use crate::repo_config::RepoConfig;
 use serde::Serialize;
 
 /// A git link (branch or PR) attached to an issue.
@@ -144,6 +148,73 @@ fn resolve_issue_id(client: &GraphQLClient, identifier: &str) -> Result<String>
         .ok_or_else(|| LinError::api(format!("Issue '{}' not found", identifier)))
 }
 
+/// Generate a branch name for an issue from the repository's branch template.
+///
+/// Uses `branch_template` from `.lin.toml` if present, otherwise
+/// `{identifier}-{title}` (e.g., "eng-123-fix-login-redirect").
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the API request
+/// * `issue_identifier` - The issue ID or identifier (e.g., "ENG-123")
+pub fn default_branch_name(client: &GraphQLClient, issue_identifier: &str) -> Result<String> {
+    let variables = if is_uuid(issue_identifier) {
+        serde_json::json!({ "filter": { "id": { "eq": issue_identifier } } })
+    } else {
+        serde_json::json!({ "filter": { "identifier": { "eq": issue_identifier } } })
+    };
+    let response: IssuesResponse = client.query(ISSUE_BY_IDENTIFIER_QUERY, variables)?;
+    let issue = response
+        .issues
+        .nodes
+        .first()
+        .ok_or_else(|| LinError::api(format!("Issue '{}' not found", issue_identifier)))?;
+
+    let repo_config = Config::load()?
+        .repo
+        .map(|repo| repo.config)
+        .unwrap_or_default();
+    Ok(repo_config.branch_name(&issue.identifier, &issue.title))
+}
+
+/// Infer the issue from the current git branch's name.
+///
+/// The branch is matched against `issue_patterns` from `.lin.toml`, or any
+/// `TEAM-123` style identifier when none are set.
+///
+/// # Errors
+///
+/// Returns an error if git can't report the current branch or the branch
+/// name contains no issue identifier.
+pub fn issue_from_current_branch() -> Result<String> {
+    let output = std::process::Command::new("git")
+        .args(["symbolic-ref", "--short", "HEAD"])
+        .output()
+        .map_err(|e| LinError::config(format!("Failed to run git: {}", e)))?;
+    if !output.status.success() {
+        return Err(LinError::config(
+            "Not on a git branch. Pass an issue identifier (e.g., ENG-123)",
+        ));
+    }
+    let branch = String::from_utf8_lossy(&output.stdout).trim().to_string();
+
+    let repo_config = Config::load()?
+        .repo
+        .map(|repo| repo.config)
+        .unwrap_or_default();
+    issue_from_branch(&repo_config, &branch)
+}
+
+/// Find the issue identifier in a branch name.
+fn issue_from_branch(repo_config: &RepoConfig, branch: &str) -> Result<String> {
+    repo_config.find_issue_identifier(branch).ok_or_else(|| {
+        LinError::config(format!(
+            "No issue identifier found in branch '{}'. Pass one (e.g., ENG-123)",
+            branch
+        ))
+    })
+}
+
 /// Check if a URL is a git-related link.
 fn is_git_link(url: &str) -> bool {
     let url_lower = url.to_lowercase();
@@ -443,6 +514,25 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_issue_from_branch_uses_issue_patterns() {
+        let repo_config = RepoConfig {
+            issue_patterns: vec!["ENG-{number}".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(
+            issue_from_branch(&repo_config, "alice/eng-42-fix-login").unwrap(),
+            "ENG-42"
+        );
+        let err = issue_from_branch(&repo_config, "ops-7-deploy").unwrap_err();
+        assert!(err.to_string().contains("No issue identifier found"));
+
+        assert_eq!(
+            issue_from_branch(&RepoConfig::default(), "ops-7-deploy").unwrap(),
+            "OPS-7"
+        );
+    }
+
     #[test]
     fn test_is_git_link_non_git() {
         assert!(!is_git_link("https://example.com/document.pdf"));
@@ -565,6 +655,47 @@ mod tests {
         attachment_mock.assert();
     }
 
+    #[test]
+    fn test_default_branch_name_uses_issue_title() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::PartialJsonString(
+                r#"{"variables": {"filter": {"identifier": {"eq": "ENG-456"}}}}"#.to_string(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{
+                    "data": {
+                        "issues": {
+                            "nodes": [
+                                {
+                                    "id": "issue-123",
+                                    "identifier": "ENG-456",
+                                    "title": "Fix OAuth login redirect",
+                                    "description": null,
+                                    "priority": 0,
+                                    "state": null,
+                                    "team": null,
+                                    "assignee": null,
+                                    "createdAt": "2024-01-01T00:00:00.000Z",
+                                    "updatedAt": "2024-01-01T00:00:00.000Z"
+                                }
+                            ]
+                        }
+                    }
+                }"#,
+            )
+            .create();
+
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let branch = default_branch_name(&client, "ENG-456").unwrap();
+
+        assert_eq!(branch, "eng-456-fix-oauth-login-redirect");
+        mock.assert();
+    }
+
     #[test]
     fn test_link_pr_success() {
         let mut server = mockito::Server::new();
@@ -0,0 +1,717 @@
+//! Notification inbox commands.
+//!
+//! Commands for listing, reading, snoozing, and watching notifications in the
+//! authenticated user's Linear inbox.
+
+use std::collections::{BTreeMap, HashSet};
+use std::time::Duration;
+
+use chrono::{DateTime, Local, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::Result;
+use crate::api::GraphQLClient;
+use crate::api::queries::notification::{
+    NOTIFICATION_UPDATE_MUTATION, NOTIFICATIONS_QUERY, notifications_read_mutation,
+};
+use crate::error::LinError;
+use crate::models::{Notification, NotificationUpdateResponse, NotificationsResponse};
+use crate::output::{HumanDisplay, OutputFormat, output};
+
+/// Notifications requested per page while paging through the inbox.
+const PAGE_SIZE: usize = 100;
+
+/// Notifications marked read per request by [`mark_all_read`].
+const READ_BATCH_SIZE: usize = 50;
+
+/// Options for listing notifications.
+#[derive(Debug, Clone)]
+pub struct InboxListOptions {
+    /// Include notifications that have already been read.
+    pub include_read: bool,
+    /// Maximum number of notifications to list (default 50).
+    pub limit: Option<i32>,
+}
+
+impl Default for InboxListOptions {
+    fn default() -> Self {
+        Self {
+            include_read: false,
+            limit: Some(50),
+        }
+    }
+}
+
+/// List notifications in the inbox.
+///
+/// By default only unread notifications that are not currently snoozed are shown.
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the API request
+/// * `options` - Filter options for the inbox
+/// * `format` - The output format (Human or Json)
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use lin::api::GraphQLClient;
+/// use lin::commands::inbox::{list_notifications, InboxListOptions};
+/// use lin::output::OutputFormat;
+///
+/// let client = GraphQLClient::new("lin_api_xxxxx");
+/// list_notifications(&client, InboxListOptions::default(), OutputFormat::Human)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn list_notifications(
+    client: &GraphQLClient,
+    options: InboxListOptions,
+    format: OutputFormat,
+) -> Result<()> {
+    let notifications = fetch_inbox(client, options.include_read, Some(list_limit(&options)))?;
+    output(&notifications, format);
+    Ok(())
+}
+
+/// Mark a single notification as read.
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the API request
+/// * `id` - The notification's unique identifier
+/// * `format` - The output format (Human or Json)
+pub fn mark_read(client: &GraphQLClient, id: &str, format: OutputFormat) -> Result<()> {
+    let notification = update_notification(
+        client,
+        id,
+        serde_json::json!({ "readAt": Utc::now().to_rfc3339() }),
+    )?;
+    output(&notification, format);
+    Ok(())
+}
+
+/// Mark every unread notification in the inbox as read.
+///
+/// Pages through the whole inbox, then marks the unread notifications in
+/// batches of one request each.
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the API request
+/// * `format` - The output format (Human or Json)
+pub fn mark_all_read(client: &GraphQLClient, format: OutputFormat) -> Result<()> {
+    let unread = fetch_inbox(client, false, None)?;
+    let read_at = Utc::now().to_rfc3339();
+
+    let mut ids = Vec::with_capacity(unread.len());
+    for batch in unread.chunks(READ_BATCH_SIZE) {
+        mark_batch_read(client, batch, &read_at)?;
+        ids.extend(batch.iter().map(|n| n.id.clone()));
+    }
+
+    let response = InboxReadAllResponse {
+        marked_read: ids.len(),
+        ids,
+    };
+    output(&response, format);
+    Ok(())
+}
+
+/// Snooze a notification until the given time.
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the API request
+/// * `id` - The notification's unique identifier
+/// * `until` - When the notification should reappear (see [`parse_snooze_until`])
+/// * `format` - The output format (Human or Json)
+pub fn snooze_notification(
+    client: &GraphQLClient,
+    id: &str,
+    until: &str,
+    format: OutputFormat,
+) -> Result<()> {
+    let until = parse_snooze_until(until, Utc::now())?;
+    let notification = update_notification(
+        client,
+        id,
+        serde_json::json!({ "snoozedUntilAt": until.to_rfc3339() }),
+    )?;
+    output(&notification, format);
+    Ok(())
+}
+
+/// Poll the inbox and print new notifications as they arrive.
+///
+/// Prints the current inbox first, then checks for new notifications every
+/// `interval`. This function only returns on error (e.g., network failure).
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the API request
+/// * `options` - Filter options for the inbox
+/// * `interval` - Time to wait between polls
+/// * `format` - The output format (Human or Json)
+pub fn watch_notifications(
+    client: &GraphQLClient,
+    options: InboxListOptions,
+    interval: Duration,
+    format: OutputFormat,
+) -> Result<()> {
+    let mut seen: HashSet<String> = HashSet::new();
+
+    loop {
+        let notifications = fetch_inbox(client, options.include_read, Some(list_limit(&options)))?;
+        // Print oldest first so the newest notification ends up at the bottom
+        for notification in take_unseen(&mut seen, notifications).into_iter().rev() {
+            output(&notification, format);
+            if format == OutputFormat::Human {
+                println!();
+            }
+        }
+        std::thread::sleep(interval);
+    }
+}
+
+/// Parse a snooze target into a UTC timestamp.
+///
+/// Accepts:
+/// - A date (`YYYY-MM-DD`), meaning the start of that day in local time
+/// - An RFC 3339 timestamp (e.g., `2024-01-15T09:00:00Z`)
+/// - A relative duration from `now`: `<N>m`, `<N>h`, `<N>d` or `<N>w`
+///
+/// # Example
+///
+/// ```
+/// use lin::commands::inbox::parse_snooze_until;
+///
+/// let now = "2024-01-01T12:00:00Z".parse().unwrap();
+/// let until = parse_snooze_until("2h", now).unwrap();
+/// assert_eq!(until.to_rfc3339(), "2024-01-01T14:00:00+00:00");
+/// ```
+pub fn parse_snooze_until(s: &str, now: DateTime<Utc>) -> Result<DateTime<Utc>> {
+    let s = s.trim();
+
+    if let Ok(ts) = DateTime::parse_from_rfc3339(s) {
+        return Ok(ts.with_timezone(&Utc));
+    }
+
+    if let Ok(date) = NaiveDate::parse_from_str(s, "%Y-%m-%d") {
+        return date
+            .and_hms_opt(0, 0, 0)
+            .and_then(|dt| dt.and_local_timezone(Local).earliest())
+            .map(|dt| dt.with_timezone(&Utc))
+            .ok_or_else(|| LinError::parse(format!("Invalid snooze date '{}'", s)));
+    }
+
+    if s.len() >= 2 {
+        let (amount, unit) = s.split_at(s.len() - 1);
+        if let Ok(amount) = amount.parse::<i64>() {
+            let delta = match unit {
+                "m" => Some(chrono::Duration::minutes(amount)),
+                "h" => Some(chrono::Duration::hours(amount)),
+                "d" => Some(chrono::Duration::days(amount)),
+                "w" => Some(chrono::Duration::weeks(amount)),
+                _ => None,
+            };
+            if let Some(delta) = delta.filter(|_| amount > 0) {
+                return Ok(now + delta);
+            }
+        }
+    }
+
+    Err(LinError::parse(format!(
+        "Invalid snooze time '{}': expected YYYY-MM-DD, an RFC 3339 timestamp, or a duration like 2h, 3d, 1w",
+        s
+    )))
+}
+
+/// Number of notifications to list for `options`.
+fn list_limit(options: &InboxListOptions) -> usize {
+    usize::try_from(options.limit.unwrap_or(50)).unwrap_or(0)
+}
+
+/// Fetch notifications that pass the inbox filters.
+///
+/// Read and snoozed notifications are only known to be filtered out once
+/// fetched, so this pages through the inbox until `limit` notifications pass
+/// (or to the end, with no limit).
+fn fetch_inbox(
+    client: &GraphQLClient,
+    include_read: bool,
+    limit: Option<usize>,
+) -> Result<Vec<Notification>> {
+    let now = Utc::now();
+    let mut notifications = Vec::new();
+    let mut after: Option<String> = None;
+    loop {
+        let variables = serde_json::json!({
+            "first": PAGE_SIZE,
+            "after": after
+        });
+        let response: NotificationsResponse = client.query(NOTIFICATIONS_QUERY, variables)?;
+        let page = response.notifications;
+        notifications.extend(filter_inbox(page.nodes, include_read, &now));
+
+        if let Some(limit) = limit
+            && notifications.len() >= limit
+        {
+            notifications.truncate(limit);
+            break;
+        }
+        match page.page_info.end_cursor {
+            Some(cursor) if page.page_info.has_next_page => after = Some(cursor),
+            _ => break,
+        }
+    }
+    Ok(notifications)
+}
+
+/// Payload of each aliased update in [`notifications_read_mutation`].
+#[derive(Debug, Deserialize)]
+struct MarkReadPayload {
+    success: bool,
+}
+
+/// Mark a batch of notifications read with a single request.
+fn mark_batch_read(client: &GraphQLClient, batch: &[Notification], read_at: &str) -> Result<()> {
+    let mut variables = serde_json::Map::new();
+    variables.insert("readAt".to_string(), serde_json::json!(read_at));
+    for (i, notification) in batch.iter().enumerate() {
+        variables.insert(format!("id{}", i), serde_json::json!(notification.id));
+    }
+
+    let response: BTreeMap<String, MarkReadPayload> = client.query(
+        &notifications_read_mutation(batch.len()),
+        serde_json::Value::Object(variables),
+    )?;
+    for (i, notification) in batch.iter().enumerate() {
+        if !response.get(&format!("n{}", i)).is_some_and(|p| p.success) {
+            return Err(LinError::api(format!(
+                "Failed to update notification '{}'",
+                notification.id
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Drop read (unless requested) and currently snoozed notifications.
+fn filter_inbox(
+    notifications: Vec<Notification>,
+    include_read: bool,
+    now: &DateTime<Utc>,
+) -> Vec<Notification> {
+    notifications
+        .into_iter()
+        .filter(|n| include_read || n.is_unread())
+        .filter(|n| !n.is_snoozed_at(now))
+        .collect()
+}
+
+/// Return notifications not seen before, recording them as seen.
+fn take_unseen(seen: &mut HashSet<String>, notifications: Vec<Notification>) -> Vec<Notification> {
+    notifications
+        .into_iter()
+        .filter(|n| seen.insert(n.id.clone()))
+        .collect()
+}
+
+/// Run the notification update mutation and return the updated notification.
+fn update_notification(
+    client: &GraphQLClient,
+    id: &str,
+    input: serde_json::Value,
+) -> Result<Notification> {
+    let variables = serde_json::json!({
+        "id": id,
+        "input": input
+    });
+
+    let response: NotificationUpdateResponse =
+        client.query(NOTIFICATION_UPDATE_MUTATION, variables)?;
+
+    if !response.notification_update.success {
+        return Err(LinError::api(format!(
+            "Failed to update notification '{}'",
+            id
+        )));
+    }
+
+    response
+        .notification_update
+        .notification
+        .ok_or_else(|| LinError::api("Notification update succeeded but no notification returned"))
+}
+
+// Response types for JSON output
+
+#[derive(Debug, Serialize)]
+struct InboxReadAllResponse {
+    marked_read: usize,
+    ids: Vec<String>,
+}
+
+impl HumanDisplay for InboxReadAllResponse {
+    fn human_fmt(&self) -> String {
+        match self.marked_read {
+            0 => "Inbox is already clear.".to_string(),
+            1 => "✓ Marked 1 notification as read".to_string(),
+            n => format!("✓ Marked {} notifications as read", n),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn notification_json(id: &str, read_at: Option<&str>, snoozed: Option<&str>) -> String {
+        let read_at = read_at
+            .map(|s| format!("\"{}\"", s))
+            .unwrap_or_else(|| "null".to_string());
+        let snoozed = snoozed
+            .map(|s| format!("\"{}\"", s))
+            .unwrap_or_else(|| "null".to_string());
+        format!(
+            r#"{{
+                "id": "{}",
+                "type": "issueAssignedToYou",
+                "createdAt": "2024-01-01T10:00:00.000Z",
+                "readAt": {},
+                "snoozedUntilAt": {},
+                "actor": null,
+                "issue": {{ "id": "issue-1", "identifier": "ENG-1", "title": "Issue" }}
+            }}"#,
+            id, read_at, snoozed
+        )
+    }
+
+    fn notification(id: &str, read_at: Option<&str>, snoozed: Option<&str>) -> Notification {
+        serde_json::from_str(&notification_json(id, read_at, snoozed)).unwrap()
+    }
+
+    #[test]
+    fn test_list_notifications_success() {
+        let mut server = mockito::Server::new();
+
+        let body = format!(
+            r#"{{ "data": {{ "notifications": {{ "nodes": [{}, {}] }} }} }}"#,
+            notification_json("notif-1", None, None),
+            notification_json("notif-2", Some("2024-01-02T00:00:00.000Z"), None)
+        );
+        let mock = server
+            .mock("POST", "/")
+            .match_header("authorization", "test-token")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(body)
+            .create();
+
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let result = list_notifications(&client, InboxListOptions::default(), OutputFormat::Json);
+        assert!(result.is_ok());
+        mock.assert();
+    }
+
+    #[test]
+    fn test_list_notifications_api_error() {
+        let mut server = mockito::Server::new();
+
+        let mock = server
+            .mock("POST", "/")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"data": null, "errors": [{"message": "Not authenticated"}]}"#)
+            .create();
+
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let result = list_notifications(&client, InboxListOptions::default(), OutputFormat::Human);
+        assert!(result.is_err());
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("Not authenticated")
+        );
+        mock.assert();
+    }
+
+    #[test]
+    fn test_mark_read_success() {
+        let mut server = mockito::Server::new();
+
+        let body = format!(
+            r#"{{ "data": {{ "notificationUpdate": {{ "success": true, "notification": {} }} }} }}"#,
+            notification_json("notif-1", Some("2024-01-02T00:00:00.000Z"), None)
+        );
+        let mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::PartialJsonString(
+                r#"{"variables": {"id": "notif-1"}}"#.to_string(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(body)
+            .create();
+
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let result = mark_read(&client, "notif-1", OutputFormat::Human);
+        assert!(result.is_ok());
+        mock.assert();
+    }
+
+    #[test]
+    fn test_mark_read_failure() {
+        let mut server = mockito::Server::new();
+
+        let mock = server
+            .mock("POST", "/")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"data": {"notificationUpdate": {"success": false, "notification": null}}}"#,
+            )
+            .create();
+
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let result = mark_read(&client, "notif-1", OutputFormat::Human);
+        assert!(result.is_err());
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("Failed to update notification")
+        );
+        mock.assert();
+    }
+
+    #[test]
+    fn test_list_notifications_pages_until_limit_unread() {
+        let mut server = mockito::Server::new();
+
+        // The first page is all read, so the unread notification on the
+        // second page must still be listed
+        let first_page = format!(
+            r#"{{ "data": {{ "notifications": {{ "nodes": [{}, {}], "pageInfo": {{ "hasNextPage": true, "endCursor": "cursor-1" }} }} }} }}"#,
+            notification_json("notif-1", Some("2024-01-02T00:00:00.000Z"), None),
+            notification_json("notif-2", Some("2024-01-02T00:00:00.000Z"), None)
+        );
+        let first_mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::Regex(r#""after":null"#.to_string()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(first_page)
+            .create();
+        let second_page = format!(
+            r#"{{ "data": {{ "notifications": {{ "nodes": [{}, {}], "pageInfo": {{ "hasNextPage": true, "endCursor": "cursor-2" }} }} }} }}"#,
+            notification_json("notif-3", None, None),
+            notification_json("notif-4", None, None)
+        );
+        let second_mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::Regex(r#""after":"cursor-1""#.to_string()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(second_page)
+            .create();
+
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let unread = fetch_inbox(&client, false, Some(1)).unwrap();
+        let ids: Vec<&str> = unread.iter().map(|n| n.id.as_str()).collect();
+        assert_eq!(ids, vec!["notif-3"]);
+        first_mock.assert();
+        second_mock.assert();
+    }
+
+    #[test]
+    fn test_mark_all_read_batches_unread_across_pages() {
+        let mut server = mockito::Server::new();
+
+        let first_page = format!(
+            r#"{{ "data": {{ "notifications": {{ "nodes": [{}, {}], "pageInfo": {{ "hasNextPage": true, "endCursor": "cursor-1" }} }} }} }}"#,
+            notification_json("notif-1", None, None),
+            notification_json("notif-2", Some("2024-01-02T00:00:00.000Z"), None)
+        );
+        let first_mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::Regex(r#""after":null"#.to_string()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(first_page)
+            .create();
+        let second_page = format!(
+            r#"{{ "data": {{ "notifications": {{ "nodes": [{}], "pageInfo": {{ "hasNextPage": false, "endCursor": null }} }} }} }}"#,
+            notification_json("notif-3", None, None)
+        );
+        let second_mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::Regex(r#""after":"cursor-1""#.to_string()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(second_page)
+            .create();
+
+        let update_mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::Regex("mutation NotificationsRead".to_string()),
+                mockito::Matcher::PartialJsonString(
+                    r#"{"variables": {"id0": "notif-1", "id1": "notif-3"}}"#.to_string(),
+                ),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"data": {"n0": {"success": true}, "n1": {"success": true}}}"#)
+            .expect(1)
+            .create();
+
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let result = mark_all_read(&client, OutputFormat::Human);
+        assert!(result.is_ok());
+        first_mock.assert();
+        second_mock.assert();
+        update_mock.assert();
+    }
+
+    #[test]
+    fn test_mark_batch_read_reports_failed_update() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", "/")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"data": {"n0": {"success": true}, "n1": {"success": false}}}"#)
+            .create();
+
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let batch = vec![
+            notification("notif-1", None, None),
+            notification("notif-2", None, None),
+        ];
+        let err = mark_batch_read(&client, &batch, "2024-01-02T00:00:00Z").unwrap_err();
+        assert!(err.to_string().contains("notif-2"));
+        mock.assert();
+    }
+
+    #[test]
+    fn test_snooze_notification_invalid_until() {
+        let server = mockito::Server::new();
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let result = snooze_notification(&client, "notif-1", "someday", OutputFormat::Human);
+        assert!(result.is_err());
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("Invalid snooze time")
+        );
+    }
+
+    #[test]
+    fn test_parse_snooze_until_relative() {
+        let now: DateTime<Utc> = "2024-01-01T12:00:00Z".parse().unwrap();
+        assert_eq!(
+            parse_snooze_until("30m", now).unwrap().to_rfc3339(),
+            "2024-01-01T12:30:00+00:00"
+        );
+        assert_eq!(
+            parse_snooze_until("3d", now).unwrap().to_rfc3339(),
+            "2024-01-04T12:00:00+00:00"
+        );
+        assert_eq!(
+            parse_snooze_until("1w", now).unwrap().to_rfc3339(),
+            "2024-01-08T12:00:00+00:00"
+        );
+    }
+
+    #[test]
+    fn test_parse_snooze_until_timestamp() {
+        let now: DateTime<Utc> = "2024-01-01T12:00:00Z".parse().unwrap();
+        assert_eq!(
+            parse_snooze_until("2024-02-01T09:00:00+01:00", now)
+                .unwrap()
+                .to_rfc3339(),
+            "2024-02-01T08:00:00+00:00"
+        );
+    }
+
+    #[test]
+    fn test_parse_snooze_until_date() {
+        let now: DateTime<Utc> = "2024-01-01T12:00:00Z".parse().unwrap();
+        let until = parse_snooze_until("2024-02-01", now).unwrap();
+        let local = until.with_timezone(&Local);
+        assert_eq!(
+            local.format("%Y-%m-%d %H:%M").to_string(),
+            "2024-02-01 00:00"
+        );
+    }
+
+    #[test]
+    fn test_parse_snooze_until_invalid() {
+        let now = Utc::now();
+        assert!(parse_snooze_until("", now).is_err());
+        assert!(parse_snooze_until("tomorrow", now).is_err());
+        assert!(parse_snooze_until("0d", now).is_err());
+        assert!(parse_snooze_until("-2h", now).is_err());
+        assert!(parse_snooze_until("5y", now).is_err());
+    }
+
+    #[test]
+    fn test_filter_inbox() {
+        let now: DateTime<Utc> = "2024-01-03T00:00:00Z".parse().unwrap();
+        let notifications = vec![
+            notification("unread", None, None),
+            notification("read", Some("2024-01-02T00:00:00.000Z"), None),
+            notification("snoozed", None, Some("2024-01-05T00:00:00.000Z")),
+            notification("woke-up", None, Some("2024-01-02T00:00:00.000Z")),
+        ];
+
+        let ids: Vec<String> = filter_inbox(notifications.clone(), false, &now)
+            .into_iter()
+            .map(|n| n.id)
+            .collect();
+        assert_eq!(ids, vec!["unread", "woke-up"]);
+
+        let ids: Vec<String> = filter_inbox(notifications, true, &now)
+            .into_iter()
+            .map(|n| n.id)
+            .collect();
+        assert_eq!(ids, vec!["unread", "read", "woke-up"]);
+    }
+
+    #[test]
+    fn test_take_unseen() {
+        let mut seen = HashSet::new();
+        let first = take_unseen(
+            &mut seen,
+            vec![notification("a", None, None), notification("b", None, None)],
+        );
+        assert_eq!(first.len(), 2);
+
+        let second = take_unseen(
+            &mut seen,
+            vec![notification("c", None, None), notification("a", None, None)],
+        );
+        assert_eq!(second.len(), 1);
+        assert_eq!(second[0].id, "c");
+    }
+
+    #[test]
+    fn test_read_all_response_human_display() {
+        let none = InboxReadAllResponse {
+            marked_read: 0,
+            ids: vec![],
+        };
+        assert_eq!(none.human_fmt(), "Inbox is already clear.");
+
+        let some = InboxReadAllResponse {
+            marked_read: 3,
+            ids: vec!["a".into(), "b".into(), "c".into()],
+        };
+        assert!(some.human_fmt().contains("3 notifications"));
+    }
+}
@@ -0,0 +1,1076 @@
+//! Issue dependency graph export.
+//!
+//! Walks issue relations transitively and renders them as Graphviz DOT or
+//! Mermaid flowcharts, highlighting circular blocking chains.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use serde::Serialize;
+
+use crate::Result;
+use crate::api::GraphQLClient;
+use crate::api::queries::issue::ISSUE_RELATIONS_QUERY;
+use crate::api::queries::project::PROJECT_ISSUE_GRAPH_QUERY;
+use crate::config::Config;
+use crate::error::LinError;
+use crate::models::{
+    GraphIssue, InverseIssueRelation, IssueRelation, IssueRelationsResponse,
+    ProjectIssueGraphResponse, RelatedIssue, WorkflowState,
+};
+use crate::output::{HumanDisplay, OutputFormat, output};
+
+use super::resolve_issue_id;
+
+/// Maximum number of issues fetched while walking relations.
+const MAX_WALK_ISSUES: usize = 200;
+
+/// Maximum number of issues fetched for a project graph.
+const MAX_PROJECT_ISSUES: usize = 250;
+
+/// Maximum title length shown inside a node.
+const MAX_LABEL_TITLE: usize = 40;
+
+/// Colour used for edges that are part of a blocking cycle.
+const CYCLE_COLOR: &str = "#e5484d";
+
+/// Output syntax for a rendered graph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GraphFormat {
+    /// Graphviz DOT.
+    Dot,
+    /// Mermaid flowchart.
+    Mermaid,
+}
+
+impl GraphFormat {
+    /// Parse a graph format from a string.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "dot" | "graphviz" => Some(Self::Dot),
+            "mermaid" | "mmd" => Some(Self::Mermaid),
+            _ => None,
+        }
+    }
+}
+
+/// Kind of edge between two issues.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EdgeKind {
+    /// `from` blocks `to`.
+    Blocks,
+    /// `from` and `to` are related (undirected).
+    Related,
+    /// `from` is a duplicate of `to`.
+    Duplicate,
+    /// `from` is the parent of `to`.
+    Parent,
+}
+
+impl EdgeKind {
+    fn from_api_type(type_: &str) -> Option<Self> {
+        match type_ {
+            "blocks" => Some(Self::Blocks),
+            "related" | "similar" => Some(Self::Related),
+            "duplicate" => Some(Self::Duplicate),
+            _ => None,
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            Self::Blocks => "blocks",
+            Self::Related => "related",
+            Self::Duplicate => "duplicate",
+            Self::Parent => "parent",
+        }
+    }
+}
+
+/// An issue in the graph.
+#[derive(Debug, Clone, Serialize)]
+pub struct GraphNode {
+    /// Issue UUID.
+    pub id: String,
+    /// Human-readable identifier (e.g., "ENG-123").
+    pub identifier: String,
+    /// Issue title.
+    pub title: String,
+    /// Workflow state name, if known.
+    pub state: Option<String>,
+    /// Workflow state type (backlog, unstarted, started, ...), if known.
+    pub state_type: Option<String>,
+    /// Estimate in points, if known.
+    pub estimate: Option<f64>,
+}
+
+impl GraphNode {
+    /// Whether the issue is still open (not completed or canceled).
+    pub fn is_open(&self) -> bool {
+        !matches!(
+            self.state_type.as_deref(),
+            Some("completed") | Some("canceled")
+        )
+    }
+}
+
+/// A directed edge between two issues, keyed by identifier.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GraphEdge {
+    /// Source issue identifier.
+    pub from: String,
+    /// Target issue identifier.
+    pub to: String,
+    /// Relation kind.
+    pub kind: EdgeKind,
+    /// Whether this edge is part of a circular blocking chain.
+    pub in_cycle: bool,
+}
+
+/// A graph of issues and their relations.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct IssueGraph {
+    /// Identifier of the issue the walk started from, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub root: Option<String>,
+    /// Issues in insertion order.
+    pub nodes: Vec<GraphNode>,
+    /// Relations between issues.
+    pub edges: Vec<GraphEdge>,
+    /// Groups of issues that block each other in a loop.
+    pub blocking_cycles: Vec<Vec<String>>,
+    #[serde(skip)]
+    index: HashMap<String, usize>,
+}
+
+impl IssueGraph {
+    /// Look up a node by identifier.
+    pub fn node(&self, identifier: &str) -> Option<&GraphNode> {
+        self.index.get(identifier).map(|&i| &self.nodes[i])
+    }
+
+    /// Add a node, filling in any fields missing from an earlier sighting.
+    fn upsert_node(
+        &mut self,
+        id: &str,
+        identifier: &str,
+        title: &str,
+        state: Option<&WorkflowState>,
+        estimate: Option<f64>,
+    ) {
+        if let Some(&i) = self.index.get(identifier) {
+            let node = &mut self.nodes[i];
+            if node.title.is_empty() {
+                node.title = title.to_string();
+            }
+            if node.state.is_none()
+                && let Some(state) = state
+            {
+                node.state = Some(state.name.clone());
+                node.state_type = Some(state.type_.clone());
+            }
+            if node.estimate.is_none() {
+                node.estimate = estimate;
+            }
+            return;
+        }
+        self.index.insert(identifier.to_string(), self.nodes.len());
+        self.nodes.push(GraphNode {
+            id: id.to_string(),
+            identifier: identifier.to_string(),
+            title: title.to_string(),
+            state: state.map(|s| s.name.clone()),
+            state_type: state.map(|s| s.type_.clone()),
+            estimate,
+        });
+    }
+
+    fn add_related(&mut self, issue: &RelatedIssue) {
+        self.upsert_node(
+            &issue.id,
+            &issue.identifier,
+            &issue.title,
+            issue.state.as_ref(),
+            None,
+        );
+    }
+
+    /// Add an edge unless an equivalent one already exists.
+    fn add_edge(&mut self, from: &str, to: &str, kind: EdgeKind) {
+        let exists = self.edges.iter().any(|e| {
+            e.kind == kind
+                && ((e.from == from && e.to == to)
+                    || (kind == EdgeKind::Related && e.from == to && e.to == from))
+        });
+        if !exists {
+            self.edges.push(GraphEdge {
+                from: from.to_string(),
+                to: to.to_string(),
+                kind,
+                in_cycle: false,
+            });
+        }
+    }
+
+    /// Add the relations of `identifier` and return the issues on the other side.
+    fn add_relations(
+        &mut self,
+        identifier: &str,
+        parent: Option<&RelatedIssue>,
+        children: &[RelatedIssue],
+        relations: &[IssueRelation],
+        inverse_relations: &[InverseIssueRelation],
+    ) -> Vec<RelatedIssue> {
+        let mut neighbours = Vec::new();
+
+        if let Some(parent) = parent {
+            self.add_related(parent);
+            self.add_edge(&parent.identifier, identifier, EdgeKind::Parent);
+            neighbours.push(parent.clone());
+        }
+        for child in children {
+            self.add_related(child);
+            self.add_edge(identifier, &child.identifier, EdgeKind::Parent);
+            neighbours.push(child.clone());
+        }
+        for rel in relations {
+            let (Some(kind), Some(other)) = (
+                EdgeKind::from_api_type(&rel.type_),
+                rel.related_issue.as_ref(),
+            ) else {
+                continue;
+            };
+            self.add_related(other);
+            self.add_edge(identifier, &other.identifier, kind);
+            neighbours.push(other.clone());
+        }
+        for rel in inverse_relations {
+            let (Some(kind), Some(other)) =
+                (EdgeKind::from_api_type(&rel.type_), rel.issue.as_ref())
+            else {
+                continue;
+            };
+            self.add_related(other);
+            self.add_edge(&other.identifier, identifier, kind);
+            neighbours.push(other.clone());
+        }
+
+        neighbours
+    }
+
+    /// Build a graph from a project's issues.
+    pub fn from_project_issues(issues: &[GraphIssue]) -> Self {
+        let mut graph = Self::default();
+        for issue in issues {
+            graph.upsert_node(
+                &issue.id,
+                &issue.identifier,
+                &issue.title,
+                issue.state.as_ref(),
+                issue.estimate,
+            );
+        }
+        for issue in issues {
+            graph.add_relations(
+                &issue.identifier,
+                issue.parent.as_ref(),
+                &[],
+                &issue.relations.nodes,
+                &issue.inverse_relations.nodes,
+            );
+        }
+        graph.detect_cycles();
+        graph
+    }
+
+    /// Keep only open issues and the blocking edges between them.
+    pub fn open_blocking_subgraph(&self) -> IssueGraph {
+        let mut sub = IssueGraph {
+            root: self.root.clone(),
+            ..Default::default()
+        };
+        for node in self.nodes.iter().filter(|n| n.is_open()) {
+            sub.index.insert(node.identifier.clone(), sub.nodes.len());
+            sub.nodes.push(node.clone());
+        }
+        for edge in &self.edges {
+            if edge.kind == EdgeKind::Blocks
+                && sub.index.contains_key(&edge.from)
+                && sub.index.contains_key(&edge.to)
+            {
+                sub.add_edge(&edge.from, &edge.to, EdgeKind::Blocks);
+            }
+        }
+        sub.detect_cycles();
+        sub
+    }
+
+    /// Find strongly connected components of the blocking subgraph and flag
+    /// every blocking edge inside one.
+    pub fn detect_cycles(&mut self) {
+        let n = self.nodes.len();
+        let mut adjacency: Vec<Vec<usize>> = vec![Vec::new(); n];
+        for edge in self.edges.iter().filter(|e| e.kind == EdgeKind::Blocks) {
+            if let (Some(&a), Some(&b)) = (self.index.get(&edge.from), self.index.get(&edge.to)) {
+                adjacency[a].push(b);
+            }
+        }
+
+        let components = strongly_connected_components(&adjacency);
+        let mut component_of = vec![usize::MAX; n];
+        let mut cycles = Vec::new();
+        for (c, members) in components.iter().enumerate() {
+            let self_loop = members.len() == 1 && adjacency[members[0]].contains(&members[0]);
+            if members.len() > 1 || self_loop {
+                for &m in members {
+                    component_of[m] = c;
+                }
+                let mut ids: Vec<String> = members
+                    .iter()
+                    .map(|&m| self.nodes[m].identifier.clone())
+                    .collect();
+                ids.sort();
+                cycles.push(ids);
+            }
+        }
+        cycles.sort();
+
+        for edge in &mut self.edges {
+            edge.in_cycle = edge.kind == EdgeKind::Blocks
+                && match (self.index.get(&edge.from), self.index.get(&edge.to)) {
+                    (Some(&a), Some(&b)) => {
+                        component_of[a] != usize::MAX && component_of[a] == component_of[b]
+                    }
+                    _ => false,
+                };
+        }
+        self.blocking_cycles = cycles;
+    }
+
+    /// Render the graph as Graphviz DOT.
+    pub fn to_dot(&self) -> String {
+        let mut out = String::from("digraph issues {\n");
+        out.push_str("    rankdir=LR;\n");
+        out.push_str("    node [shape=box, style=\"rounded,filled\", fontname=\"Helvetica\"];\n");
+        out.push_str("    edge [fontname=\"Helvetica\", fontsize=10];\n");
+        for node in &self.nodes {
+            let (fill, font) = state_colors(node.state_type.as_deref());
+            let mut label = format!(
+                "{}\\n{}",
+                node.identifier,
+                dot_escape(&truncate(&node.title))
+            );
+            if let Some(state) = &node.state {
+                label.push_str(&format!("\\n({})", dot_escape(state)));
+            }
+            let root = if self.root.as_deref() == Some(node.identifier.as_str()) {
+                ", penwidth=3"
+            } else {
+                ""
+            };
+            out.push_str(&format!(
+                "    \"{}\" [label=\"{}\", fillcolor=\"{}\", fontcolor=\"{}\"{}];\n",
+                node.identifier, label, fill, font, root
+            ));
+        }
+        for edge in &self.edges {
+            let mut attrs = vec![format!("label=\"{}\"", edge.kind.label())];
+            match edge.kind {
+                EdgeKind::Blocks => {}
+                EdgeKind::Related => {
+                    attrs.push("style=dotted".to_string());
+                    attrs.push("dir=none".to_string());
+                }
+                EdgeKind::Duplicate => attrs.push("style=dotted".to_string()),
+                EdgeKind::Parent => attrs.push("style=dashed".to_string()),
+            }
+            if edge.in_cycle {
+                attrs.push(format!("color=\"{}\"", CYCLE_COLOR));
+                attrs.push(format!("fontcolor=\"{}\"", CYCLE_COLOR));
+                attrs.push("penwidth=2".to_string());
+            }
+            out.push_str(&format!(
+                "    \"{}\" -> \"{}\" [{}];\n",
+                edge.from,
+                edge.to,
+                attrs.join(", ")
+            ));
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    /// Render the graph as a Mermaid flowchart.
+    pub fn to_mermaid(&self) -> String {
+        let mut out = String::from("flowchart LR\n");
+        for node in &self.nodes {
+            let mut label = format!(
+                "{}: {}",
+                node.identifier,
+                mermaid_escape(&truncate(&node.title))
+            );
+            if let Some(state) = &node.state {
+                label.push_str(&format!("<br/>({})", mermaid_escape(state)));
+            }
+            out.push_str(&format!(
+                "    {}[\"{}\"]\n",
+                mermaid_id(&node.identifier),
+                label
+            ));
+        }
+
+        let mut cycle_links = Vec::new();
+        for (i, edge) in self.edges.iter().enumerate() {
+            let arrow = match edge.kind {
+                EdgeKind::Blocks => "-->",
+                EdgeKind::Related => "---",
+                EdgeKind::Duplicate | EdgeKind::Parent => "-.->",
+            };
+            out.push_str(&format!(
+                "    {} {}|{}| {}\n",
+                mermaid_id(&edge.from),
+                arrow,
+                edge.kind.label(),
+                mermaid_id(&edge.to)
+            ));
+            if edge.in_cycle {
+                cycle_links.push(i.to_string());
+            }
+        }
+
+        let mut classes: Vec<(&str, Vec<String>)> = Vec::new();
+        for node in &self.nodes {
+            let class = node.state_type.as_deref().unwrap_or("unknown");
+            let id = mermaid_id(&node.identifier);
+            match classes.iter_mut().find(|(c, _)| *c == class) {
+                Some((_, ids)) => ids.push(id),
+                None => classes.push((class, vec![id])),
+            }
+        }
+        for (class, ids) in &classes {
+            let (fill, font) = state_colors(Some(class));
+            out.push_str(&format!(
+                "    classDef {} fill:{},color:{}\n",
+                class, fill, font
+            ));
+            out.push_str(&format!("    class {} {}\n", ids.join(","), class));
+        }
+        if let Some(root) = &self.root {
+            out.push_str(&format!(
+                "    style {} stroke-width:3px\n",
+                mermaid_id(root)
+            ));
+        }
+        if !cycle_links.is_empty() {
+            out.push_str(&format!(
+                "    linkStyle {} stroke:{},stroke-width:2px\n",
+                cycle_links.join(","),
+                CYCLE_COLOR
+            ));
+        }
+        out
+    }
+}
+
+/// Tarjan's algorithm over an adjacency list.
+fn strongly_connected_components(adjacency: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    struct State<'a> {
+        adjacency: &'a [Vec<usize>],
+        index: Vec<Option<usize>>,
+        lowlink: Vec<usize>,
+        on_stack: Vec<bool>,
+        stack: Vec<usize>,
+        next: usize,
+        components: Vec<Vec<usize>>,
+    }
+
+    fn visit(state: &mut State, v: usize) {
+        state.index[v] = Some(state.next);
+        state.lowlink[v] = state.next;
+        state.next += 1;
+        state.stack.push(v);
+        state.on_stack[v] = true;
+
+        for &w in &state.adjacency[v] {
+            match state.index[w] {
+                None => {
+                    visit(state, w);
+                    state.lowlink[v] = state.lowlink[v].min(state.lowlink[w]);
+                }
+                Some(index) if state.on_stack[w] => {
+                    state.lowlink[v] = state.lowlink[v].min(index);
+                }
+                Some(_) => {}
+            }
+        }
+
+        if Some(state.lowlink[v]) == state.index[v] {
+            let mut component = Vec::new();
+            while let Some(w) = state.stack.pop() {
+                state.on_stack[w] = false;
+                component.push(w);
+                if w == v {
+                    break;
+                }
+            }
+            state.components.push(component);
+        }
+    }
+
+    let n = adjacency.len();
+    let mut state = State {
+        adjacency,
+        index: vec![None; n],
+        lowlink: vec![0; n],
+        on_stack: vec![false; n],
+        stack: Vec::new(),
+        next: 0,
+        components: Vec::new(),
+    };
+    for v in 0..n {
+        if state.index[v].is_none() {
+            visit(&mut state, v);
+        }
+    }
+    state.components
+}
+
+/// Fill and font colours for a workflow state type.
+fn state_colors(state_type: Option<&str>) -> (&'static str, &'static str) {
+    match state_type {
+        Some("triage") => ("#fc7840", "#000000"),
+        Some("backlog") => ("#bec2c8", "#000000"),
+        Some("unstarted") => ("#e2e2e2", "#000000"),
+        Some("started") => ("#f2c94c", "#000000"),
+        Some("completed") => ("#5e6ad2", "#ffffff"),
+        Some("canceled") => ("#95a2b3", "#ffffff"),
+        _ => ("#ffffff", "#000000"),
+    }
+}
+
+fn truncate(title: &str) -> String {
+    if title.chars().count() > MAX_LABEL_TITLE {
+        let cut: String = title.chars().take(MAX_LABEL_TITLE - 3).collect();
+        format!("{}...", cut)
+    } else {
+        title.to_string()
+    }
+}
+
+fn dot_escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn mermaid_escape(s: &str) -> String {
+    s.replace('"', "#quot;")
+}
+
+/// Mermaid node IDs cannot contain dashes.
+fn mermaid_id(identifier: &str) -> String {
+    identifier
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+/// Walk relations breadth-first from an issue up to `depth` hops.
+pub fn walk_issue_graph(
+    client: &GraphQLClient,
+    id_or_identifier: &str,
+    depth: usize,
+) -> Result<IssueGraph> {
+    let root_id = resolve_issue_id(client, id_or_identifier)?;
+    let mut graph = IssueGraph::default();
+    let mut seen: HashSet<String> = HashSet::from([root_id.clone()]);
+    let mut queue = VecDeque::from([(root_id, 0usize)]);
+    let mut fetched = 0;
+
+    while let Some((issue_id, distance)) = queue.pop_front() {
+        if fetched >= MAX_WALK_ISSUES {
+            break;
+        }
+        fetched += 1;
+
+        let variables = serde_json::json!({ "id": issue_id });
+        let response: IssueRelationsResponse = client.query(ISSUE_RELATIONS_QUERY, variables)?;
+        let issue = response.issue;
+        if graph.root.is_none() {
+            graph.root = Some(issue.identifier.clone());
+        }
+        graph.upsert_node(
+            &issue.id,
+            &issue.identifier,
+            &issue.title,
+            issue.state.as_ref(),
+            None,
+        );
+
+        let neighbours = graph.add_relations(
+            &issue.identifier,
+            issue.parent.as_ref(),
+            &issue.children.nodes,
+            &issue.relations.nodes,
+            &issue.inverse_relations.nodes,
+        );
+        if distance + 1 < depth {
+            for neighbour in neighbours {
+                if seen.insert(neighbour.id.clone()) {
+                    queue.push_back((neighbour.id, distance + 1));
+                }
+            }
+        }
+    }
+
+    graph.detect_cycles();
+    Ok(graph)
+}
+
+/// Fetch a project's issues and build their relation graph.
+///
+/// Returns the project name alongside the graph.
+pub fn fetch_project_graph(
+    client: &GraphQLClient,
+    slug_or_id: &str,
+) -> Result<(String, IssueGraph)> {
+    let project_id = Config::load()
+        .ok()
+        .and_then(|config| config.get_project_id(slug_or_id))
+        .unwrap_or_else(|| slug_or_id.to_string());
+
+    let variables = serde_json::json!({
+        "id": project_id,
+        "first": MAX_PROJECT_ISSUES
+    });
+    let response: ProjectIssueGraphResponse = client.query(PROJECT_ISSUE_GRAPH_QUERY, variables)?;
+    let project = response.project;
+
+    Ok((
+        project.name,
+        IssueGraph::from_project_issues(&project.issues.nodes),
+    ))
+}
+
+/// Options for `lin issue graph`.
+#[derive(Debug, Clone)]
+pub struct IssueGraphOptions {
+    /// Issue to start from (ignored when `project` is set).
+    pub identifier: Option<String>,
+    /// Project slug or ID to render instead of a single issue.
+    pub project: Option<String>,
+    /// Number of relation hops to follow from the issue.
+    pub depth: usize,
+    /// Output syntax.
+    pub graph_format: GraphFormat,
+}
+
+/// A rendered graph, with its structure for JSON output.
+#[derive(Debug, Serialize)]
+pub struct RenderedGraph {
+    /// Syntax of `source`.
+    pub format: GraphFormat,
+    /// Graph structure.
+    #[serde(flatten)]
+    pub graph: IssueGraph,
+    /// Rendered DOT or Mermaid source.
+    pub source: String,
+}
+
+impl HumanDisplay for RenderedGraph {
+    fn human_fmt(&self) -> String {
+        self.source.trim_end().to_string()
+    }
+}
+
+/// Export an issue's relation graph (or a whole project's) as DOT or Mermaid.
+///
+/// Nodes are coloured by workflow state type. Blocking edges that form a
+/// cycle are drawn in red and reported on stderr.
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for API requests
+/// * `options` - Start issue or project, walk depth, and output syntax
+/// * `format` - The output format (Human prints the graph source, Json adds structure)
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use lin::api::GraphQLClient;
+/// use lin::commands::issue::graph::{GraphFormat, IssueGraphOptions, issue_graph};
+/// use lin::output::OutputFormat;
+///
+/// let client = GraphQLClient::new("lin_api_xxxxx");
+/// let options = IssueGraphOptions {
+///     identifier: Some("ENG-123".to_string()),
+///     project: None,
+///     depth: 3,
+///     graph_format: GraphFormat::Dot,
+/// };
+/// issue_graph(&client, options, OutputFormat::Human)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn issue_graph(
+    client: &GraphQLClient,
+    options: IssueGraphOptions,
+    format: OutputFormat,
+) -> Result<()> {
+    let graph = match (&options.project, &options.identifier) {
+        (Some(project), _) => fetch_project_graph(client, project)?.1,
+        (None, Some(identifier)) => walk_issue_graph(client, identifier, options.depth)?,
+        (None, None) => {
+            return Err(LinError::config("Specify an issue identifier or --project"));
+        }
+    };
+
+    if format == OutputFormat::Human {
+        for cycle in &graph.blocking_cycles {
+            eprintln!("Warning: circular blocking between {}", cycle.join(", "));
+        }
+    }
+
+    let source = match options.graph_format {
+        GraphFormat::Dot => graph.to_dot(),
+        GraphFormat::Mermaid => graph.to_mermaid(),
+    };
+    let rendered = RenderedGraph {
+        format: options.graph_format,
+        graph,
+        source,
+    };
+    output(&rendered, format);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{InverseIssueRelationConnection, IssueRelationConnection};
+
+    fn state(type_: &str) -> WorkflowState {
+        WorkflowState {
+            id: format!("state-{}", type_),
+            name: type_.to_string(),
+            color: "#000000".to_string(),
+            type_: type_.to_string(),
+        }
+    }
+
+    fn related(identifier: &str) -> RelatedIssue {
+        RelatedIssue {
+            id: format!("id-{}", identifier),
+            identifier: identifier.to_string(),
+            title: format!("Title {}", identifier),
+            state: None,
+        }
+    }
+
+    fn issue(identifier: &str, state_type: &str, blocks: &[&str]) -> GraphIssue {
+        GraphIssue {
+            id: format!("id-{}", identifier),
+            identifier: identifier.to_string(),
+            title: format!("Title {}", identifier),
+            estimate: Some(1.0),
+            state: Some(state(state_type)),
+            parent: None,
+            relations: IssueRelationConnection {
+                nodes: blocks
+                    .iter()
+                    .map(|b| IssueRelation {
+                        id: format!("rel-{}-{}", identifier, b),
+                        type_: "blocks".to_string(),
+                        related_issue: Some(related(b)),
+                    })
+                    .collect(),
+            },
+            inverse_relations: InverseIssueRelationConnection { nodes: vec![] },
+        }
+    }
+
+    #[test]
+    fn test_graph_format_parse() {
+        assert_eq!(GraphFormat::parse("dot"), Some(GraphFormat::Dot));
+        assert_eq!(GraphFormat::parse("Mermaid"), Some(GraphFormat::Mermaid));
+        assert_eq!(GraphFormat::parse("svg"), None);
+    }
+
+    #[test]
+    fn test_from_project_issues_dedupes_edges() {
+        let mut b = issue("ENG-2", "unstarted", &[]);
+        // The same relation seen from the other side.
+        b.inverse_relations.nodes.push(InverseIssueRelation {
+            id: "rel-ENG-1-ENG-2".to_string(),
+            type_: "blocks".to_string(),
+            issue: Some(related("ENG-1")),
+        });
+        let graph = IssueGraph::from_project_issues(&[issue("ENG-1", "started", &["ENG-2"]), b]);
+
+        assert_eq!(graph.nodes.len(), 2);
+        assert_eq!(graph.edges.len(), 1);
+        assert_eq!(graph.edges[0].from, "ENG-1");
+        assert_eq!(graph.edges[0].to, "ENG-2");
+        assert!(graph.blocking_cycles.is_empty());
+    }
+
+    #[test]
+    fn test_detect_blocking_cycle() {
+        let issues = vec![
+            issue("ENG-1", "started", &["ENG-2"]),
+            issue("ENG-2", "unstarted", &["ENG-3"]),
+            issue("ENG-3", "unstarted", &["ENG-1", "ENG-4"]),
+            issue("ENG-4", "backlog", &[]),
+        ];
+        let graph = IssueGraph::from_project_issues(&issues);
+
+        assert_eq!(
+            graph.blocking_cycles,
+            vec![vec![
+                "ENG-1".to_string(),
+                "ENG-2".to_string(),
+                "ENG-3".to_string()
+            ]]
+        );
+        let outside = graph.edges.iter().find(|e| e.to == "ENG-4").unwrap();
+        assert!(!outside.in_cycle);
+        assert_eq!(graph.edges.iter().filter(|e| e.in_cycle).count(), 3);
+    }
+
+    #[test]
+    fn test_related_edges_do_not_form_cycles() {
+        let mut a = issue("ENG-1", "started", &[]);
+        a.relations.nodes.push(IssueRelation {
+            id: "rel-1".to_string(),
+            type_: "related".to_string(),
+            related_issue: Some(related("ENG-2")),
+        });
+        let mut b = issue("ENG-2", "started", &[]);
+        b.relations.nodes.push(IssueRelation {
+            id: "rel-2".to_string(),
+            type_: "related".to_string(),
+            related_issue: Some(related("ENG-1")),
+        });
+        let graph = IssueGraph::from_project_issues(&[a, b]);
+
+        assert_eq!(graph.edges.len(), 1);
+        assert!(graph.blocking_cycles.is_empty());
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let mut graph = IssueGraph::from_project_issues(&[
+            issue("ENG-1", "started", &["ENG-2"]),
+            issue("ENG-2", "completed", &["ENG-1"]),
+        ]);
+        graph.nodes[0].title = "Say \"hi\"".to_string();
+        let dot = graph.to_dot();
+
+        assert!(dot.starts_with("digraph issues {"));
+        assert!(dot.contains(
+            "\"ENG-1\" [label=\"ENG-1\\nSay \\\"hi\\\"\\n(started)\", fillcolor=\"#f2c94c\""
+        ));
+        assert!(dot.contains("fillcolor=\"#5e6ad2\", fontcolor=\"#ffffff\""));
+        assert!(dot.contains("\"ENG-1\" -> \"ENG-2\" [label=\"blocks\", color=\"#e5484d\""));
+        assert!(dot.trim_end().ends_with('}'));
+    }
+
+    #[test]
+    fn test_to_mermaid() {
+        let mut child = issue("ENG-3", "backlog", &[]);
+        child.parent = Some(related("ENG-1"));
+        let graph = IssueGraph::from_project_issues(&[
+            issue("ENG-1", "started", &["ENG-2"]),
+            issue("ENG-2", "started", &[]),
+            child,
+        ]);
+        let mermaid = graph.to_mermaid();
+
+        assert!(mermaid.starts_with("flowchart LR\n"));
+        assert!(mermaid.contains("    ENG_1[\"ENG-1: Title ENG-1<br/>(started)\"]"));
+        assert!(mermaid.contains("    ENG_1 -->|blocks| ENG_2"));
+        assert!(mermaid.contains("    ENG_1 -.->|parent| ENG_3"));
+        assert!(mermaid.contains("    class ENG_1,ENG_2 started"));
+        assert!(mermaid.contains("classDef backlog fill:#bec2c8"));
+        assert!(!mermaid.contains("linkStyle"));
+    }
+
+    #[test]
+    fn test_truncate_long_titles() {
+        let title = "a".repeat(60);
+        let truncated = truncate(&title);
+        assert_eq!(truncated.chars().count(), MAX_LABEL_TITLE);
+        assert!(truncated.ends_with("..."));
+        assert_eq!(truncate("short"), "short");
+    }
+
+    #[test]
+    fn test_walk_issue_graph_respects_depth() {
+        let mut server = mockito::Server::new();
+
+        let root_mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::PartialJsonString(
+                r#"{"variables": {"id": "550e8400-e29b-41d4-a716-446655440000"}}"#.to_string(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r##"{
+                    "data": {
+                        "issue": {
+                            "id": "550e8400-e29b-41d4-a716-446655440000",
+                            "identifier": "ENG-1",
+                            "title": "Root",
+                            "state": {"id": "s1", "name": "In Progress", "color": "#f2c94c", "type": "started"},
+                            "relations": {
+                                "nodes": [
+                                    {
+                                        "id": "rel-1",
+                                        "type": "blocks",
+                                        "relatedIssue": {
+                                            "id": "issue-2",
+                                            "identifier": "ENG-2",
+                                            "title": "Second",
+                                            "state": {"id": "s2", "name": "Todo", "color": "#e2e2e2", "type": "unstarted"}
+                                        }
+                                    }
+                                ]
+                            },
+                            "inverseRelations": {"nodes": []},
+                            "parent": null,
+                            "children": {"nodes": []}
+                        }
+                    }
+                }"##,
+            )
+            .expect(1)
+            .create();
+
+        let second_mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::PartialJsonString(
+                r#"{"variables": {"id": "issue-2"}}"#.to_string(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r##"{
+                    "data": {
+                        "issue": {
+                            "id": "issue-2",
+                            "identifier": "ENG-2",
+                            "title": "Second",
+                            "state": {"id": "s2", "name": "Todo", "color": "#e2e2e2", "type": "unstarted"},
+                            "relations": {
+                                "nodes": [
+                                    {
+                                        "id": "rel-2",
+                                        "type": "blocks",
+                                        "relatedIssue": {
+                                            "id": "550e8400-e29b-41d4-a716-446655440000",
+                                            "identifier": "ENG-1",
+                                            "title": "Root"
+                                        }
+                                    }
+                                ]
+                            },
+                            "inverseRelations": {
+                                "nodes": [
+                                    {
+                                        "id": "rel-1",
+                                        "type": "blocks",
+                                        "issue": {
+                                            "id": "550e8400-e29b-41d4-a716-446655440000",
+                                            "identifier": "ENG-1",
+                                            "title": "Root"
+                                        }
+                                    }
+                                ]
+                            },
+                            "parent": null,
+                            "children": {
+                                "nodes": [
+                                    {"id": "issue-3", "identifier": "ENG-3", "title": "Child"}
+                                ]
+                            }
+                        }
+                    }
+                }"##,
+            )
+            .expect(1)
+            .create();
+
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let graph = walk_issue_graph(&client, "550e8400-e29b-41d4-a716-446655440000", 2).unwrap();
+
+        root_mock.assert();
+        second_mock.assert();
+        assert_eq!(graph.root.as_deref(), Some("ENG-1"));
+        assert_eq!(graph.nodes.len(), 3);
+        assert_eq!(
+            graph.node("ENG-2").unwrap().state_type.as_deref(),
+            Some("unstarted")
+        );
+        assert_eq!(graph.edges.len(), 3);
+        assert_eq!(
+            graph.blocking_cycles,
+            vec![vec!["ENG-1".to_string(), "ENG-2".to_string()]]
+        );
+    }
+
+    #[test]
+    fn test_issue_graph_requires_target() {
+        let client = GraphQLClient::with_url("test-token", "http://localhost:1");
+        let options = IssueGraphOptions {
+            identifier: None,
+            project: None,
+            depth: 3,
+            graph_format: GraphFormat::Dot,
+        };
+        assert!(issue_graph(&client, options, OutputFormat::Json).is_err());
+    }
+
+    #[test]
+    fn test_fetch_project_graph() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::Regex("query ProjectIssueGraph".to_string()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r##"{
+                    "data": {
+                        "project": {
+                            "id": "project-1",
+                            "name": "Launch",
+                            "issues": {
+                                "nodes": [
+                                    {
+                                        "id": "issue-1",
+                                        "identifier": "ENG-1",
+                                        "title": "First",
+                                        "estimate": 3,
+                                        "state": {"id": "s1", "name": "Todo", "color": "#e2e2e2", "type": "unstarted"},
+                                        "parent": null,
+                                        "relations": {"nodes": []},
+                                        "inverseRelations": {"nodes": []}
+                                    }
+                                ]
+                            }
+                        }
+                    }
+                }"##,
+            )
+            .create();
+
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let (name, graph) = fetch_project_graph(&client, "project-1").unwrap();
+
+        mock.assert();
+        assert_eq!(name, "Launch");
+        assert_eq!(graph.nodes.len(), 1);
+        assert_eq!(graph.nodes[0].estimate, Some(3.0));
+    }
+}
@@ -0,0 +1,174 @@
+//! Activity history for issues.
+
+use crate::Result;
+use crate::api::GraphQLClient;
+use crate::api::queries::issue::ISSUE_HISTORY_QUERY;
+use crate::models::{IssueHistoryResponse, IssueTimeline};
+use crate::output::{OutputFormat, output};
+
+use super::resolve_issue_id;
+
+/// Show the activity timeline of an issue.
+///
+/// Fetches the issue's history and comments and renders them as a single
+/// chronological timeline of state, assignee, priority, estimate, label,
+/// project and cycle changes interleaved with comments.
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the API request
+/// * `id_or_identifier` - The issue's UUID or human-readable identifier
+/// * `limit` - Maximum number of history entries and comments to fetch
+/// * `format` - The output format (Human or Json)
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use lin::api::GraphQLClient;
+/// use lin::commands::issue::history::issue_history;
+/// use lin::output::OutputFormat;
+///
+/// let client = GraphQLClient::new("lin_api_xxxxx");
+/// issue_history(&client, "ENG-123", 100, OutputFormat::Human)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn issue_history(
+    client: &GraphQLClient,
+    id_or_identifier: &str,
+    limit: u32,
+    format: OutputFormat,
+) -> Result<()> {
+    let timeline = fetch_issue_timeline(client, id_or_identifier, limit)?;
+    output(&timeline, format);
+    Ok(())
+}
+
+/// Fetch an issue's history and comments as a chronological timeline.
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the API request
+/// * `id_or_identifier` - The issue's UUID or human-readable identifier
+/// * `limit` - Maximum number of history entries and comments to fetch
+pub fn fetch_issue_timeline(
+    client: &GraphQLClient,
+    id_or_identifier: &str,
+    limit: u32,
+) -> Result<IssueTimeline> {
+    let issue_id = resolve_issue_id(client, id_or_identifier)?;
+
+    let variables = serde_json::json!({
+        "id": issue_id,
+        "first": limit
+    });
+    let response: IssueHistoryResponse = client.query(ISSUE_HISTORY_QUERY, variables)?;
+
+    Ok(IssueTimeline::from(response.issue))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::TimelineEventKind;
+
+    const ISSUE_UUID: &str = "550e8400-e29b-41d4-a716-446655440000";
+
+    fn history_body() -> &'static str {
+        r##"{
+            "data": {
+                "issue": {
+                    "id": "550e8400-e29b-41d4-a716-446655440000",
+                    "identifier": "ENG-123",
+                    "title": "Fix login",
+                    "createdAt": "2024-01-01T09:00:00.000Z",
+                    "creator": null,
+                    "history": {
+                        "nodes": [
+                            {
+                                "id": "hist-1",
+                                "createdAt": "2024-01-02T12:00:00.000Z",
+                                "actor": null,
+                                "fromState": { "id": "s1", "name": "Todo", "color": "#ccc", "type": "unstarted" },
+                                "toState": { "id": "s2", "name": "Done", "color": "#0f0", "type": "completed" },
+                                "fromAssignee": null,
+                                "toAssignee": null,
+                                "fromPriority": null,
+                                "toPriority": null,
+                                "fromEstimate": null,
+                                "toEstimate": null,
+                                "addedLabels": null,
+                                "removedLabels": null,
+                                "fromProject": null,
+                                "toProject": null,
+                                "fromCycle": null,
+                                "toCycle": null
+                            }
+                        ]
+                    },
+                    "comments": { "nodes": [] }
+                }
+            }
+        }"##
+    }
+
+    #[test]
+    fn test_issue_history_success() {
+        let mut server = mockito::Server::new();
+
+        let mock = server
+            .mock("POST", "/")
+            .match_header("authorization", "test-token")
+            .match_body(mockito::Matcher::PartialJsonString(format!(
+                r#"{{"variables": {{"id": "{}", "first": 50}}}}"#,
+                ISSUE_UUID
+            )))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(history_body())
+            .create();
+
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let result = issue_history(&client, ISSUE_UUID, 50, OutputFormat::Human);
+        assert!(result.is_ok());
+        mock.assert();
+    }
+
+    #[test]
+    fn test_fetch_issue_timeline() {
+        let mut server = mockito::Server::new();
+
+        let mock = server
+            .mock("POST", "/")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(history_body())
+            .create();
+
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let timeline = fetch_issue_timeline(&client, ISSUE_UUID, 50).unwrap();
+        assert_eq!(timeline.identifier, "ENG-123");
+        assert_eq!(timeline.events.len(), 2);
+        assert_eq!(timeline.events[1].kind, TimelineEventKind::Change);
+        mock.assert();
+    }
+
+    #[test]
+    fn test_issue_history_api_error() {
+        let mut server = mockito::Server::new();
+
+        let mock = server
+            .mock("POST", "/")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"data": null, "errors": [{"message": "Entity not found"}]}"#)
+            .create();
+
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let result = issue_history(&client, ISSUE_UUID, 50, OutputFormat::Json);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Entity not found"));
+        mock.assert();
+    }
+}
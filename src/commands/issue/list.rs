@@ -18,7 +18,7 @@ use super::{IssueListOptions, is_uuid};
 /// # Arguments
 ///
 /// * `client` - The GraphQL client to use for the API request
-/// * `viewer_id` - The current user's ID (used if assignee is "me")
+/// * `viewer_id` - The current user's ID (used if assignee is "me" or `subscribed` is set)
 /// * `options` - Filter options for the query
 /// * `format` - The output format (Human or Json)
 ///
@@ -49,7 +49,54 @@ pub fn list_issues(
     options: IssueListOptions,
     format: OutputFormat,
 ) -> Result<()> {
-    // Build the filter object
+    let filter = build_issue_filter(client, viewer_id, &options)?;
+
+    // Build variables
+    let mut variables = serde_json::Map::new();
+    variables.insert(
+        "first".to_string(),
+        serde_json::json!(options.limit.unwrap_or(50)),
+    );
+
+    if !filter.is_empty() {
+        variables.insert("filter".to_string(), serde_json::Value::Object(filter));
+    }
+
+    // Add sorting if specified
+    if let Some(sort_field) = &options.sort_by {
+        variables.insert(
+            "orderBy".to_string(),
+            serde_json::json!(sort_field.to_graphql_field()),
+        );
+    }
+
+    let response: IssuesResponse =
+        client.query(ISSUES_QUERY, serde_json::Value::Object(variables))?;
+
+    // If we have a sort order that differs from the default, we need to reverse the results
+    // because Linear's API doesn't support explicit sort direction via GraphQL
+    let mut issues = response.issues.nodes;
+    if let Some(sort_field) = &options.sort_by {
+        let default_order = sort_field.default_order();
+        let requested_order = options.sort_order.unwrap_or(default_order);
+        if requested_order != default_order {
+            issues.reverse();
+        }
+    }
+
+    output(&issues, format);
+    Ok(())
+}
+
+/// Build an `IssueFilter` object from list options.
+///
+/// Resolves "me", project slugs and milestone names along the way. Shared by
+/// other commands that query issues with extra criteria on top.
+pub(crate) fn build_issue_filter(
+    client: &GraphQLClient,
+    viewer_id: Option<&str>,
+    options: &IssueListOptions,
+) -> Result<serde_json::Map<String, serde_json::Value>> {
     let mut filter = serde_json::Map::new();
 
     // Add team filter if specified
@@ -79,6 +126,19 @@ pub fn list_issues(
         );
     }
 
+    // Add subscriber filter if requested
+    if options.subscribed {
+        let viewer_id = viewer_id.ok_or_else(|| {
+            LinError::config(
+                "Cannot filter by subscription without viewer ID. Please authenticate first.",
+            )
+        })?;
+        filter.insert(
+            "subscribers".to_string(),
+            serde_json::json!({ "id": { "eq": viewer_id } }),
+        );
+    }
+
     // Add state filter if specified
     if let Some(state_name) = &options.state {
         filter.insert(
@@ -183,41 +243,7 @@ pub fn list_issues(
         );
     }
 
-    // Build variables
-    let mut variables = serde_json::Map::new();
-    variables.insert(
-        "first".to_string(),
-        serde_json::json!(options.limit.unwrap_or(50)),
-    );
-
-    if !filter.is_empty() {
-        variables.insert("filter".to_string(), serde_json::Value::Object(filter));
-    }
-
-    // Add sorting if specified
-    if let Some(sort_field) = &options.sort_by {
-        variables.insert(
-            "orderBy".to_string(),
-            serde_json::json!(sort_field.to_graphql_field()),
-        );
-    }
-
-    let response: IssuesResponse =
-        client.query(ISSUES_QUERY, serde_json::Value::Object(variables))?;
-
-    // If we have a sort order that differs from the default, we need to reverse the results
-    // because Linear's API doesn't support explicit sort direction via GraphQL
-    let mut issues = response.issues.nodes;
-    if let Some(sort_field) = &options.sort_by {
-        let default_order = sort_field.default_order();
-        let requested_order = options.sort_order.unwrap_or(default_order);
-        if requested_order != default_order {
-            issues.reverse();
-        }
-    }
-
-    output(&issues, format);
-    Ok(())
+    Ok(filter)
 }
 
 #[cfg(test)]
@@ -365,6 +391,51 @@ mod tests {
         assert!(err.to_string().contains("Cannot use 'me'"));
     }
 
+    #[test]
+    fn test_list_issues_subscribed() {
+        let mut server = mockito::Server::new();
+
+        let mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::PartialJsonString(
+                r#"{"variables": {"filter": {"subscribers": {"id": {"eq": "user-123"}}}}}"#
+                    .to_string(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"data": {"issues": {"nodes": []}}}"#)
+            .create();
+
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let options = IssueListOptions {
+            subscribed: true,
+            ..Default::default()
+        };
+
+        let result = list_issues(&client, Some("user-123"), options, OutputFormat::Human);
+        assert!(result.is_ok());
+        mock.assert();
+    }
+
+    #[test]
+    fn test_list_issues_subscribed_no_viewer() {
+        let server = mockito::Server::new();
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let options = IssueListOptions {
+            subscribed: true,
+            ..Default::default()
+        };
+
+        let result = list_issues(&client, None, options, OutputFormat::Human);
+        assert!(result.is_err());
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("Cannot filter by subscription")
+        );
+    }
+
     #[test]
     fn test_list_issues_with_state_filter() {
         let mut server = mockito::Server::new();
@@ -1141,6 +1212,7 @@ mod tests {
             label: Some("label-abc".to_string()),
             milestone: None,
             priority: Some(PriorityFilter::Urgent),
+            subscribed: false,
             limit: Some(25),
             created_after: Some("2024-01-01".to_string()),
             created_before: Some("2024-12-31".to_string()),
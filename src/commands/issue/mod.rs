@@ -5,7 +5,11 @@
 pub mod create;
 pub mod delete;
 pub mod get;
+pub mod graph;
+pub mod history;
 pub mod list;
+pub mod stale;
+pub mod subscribe;
 pub mod update;
 
 use crate::Result;
@@ -144,6 +148,8 @@ pub struct IssueListOptions {
     pub milestone: Option<String>,
     /// Filter by priority level.
     pub priority: Option<PriorityFilter>,
+    /// Only include issues the current user is subscribed to.
+    pub subscribed: bool,
     /// Maximum number of issues to return (default 50).
     pub limit: Option<i32>,
     /// Filter issues created after this date (YYYY-MM-DD format).
@@ -0,0 +1,745 @@
+//! Stale and neglected issue detection.
+
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use colored::Colorize;
+use serde::Serialize;
+
+use crate::Result;
+use crate::api::GraphQLClient;
+use crate::api::queries::comment::COMMENT_CREATE_MUTATION;
+use crate::api::queries::issue::{ISSUE_UPDATE_MUTATION, STALE_ISSUES_QUERY};
+use crate::commands::resolvers;
+use crate::error::LinError;
+use crate::models::{CommentCreateResponse, Issue, IssueUpdateResponse, IssuesResponse};
+use crate::output::{HumanDisplay, OutputFormat, output};
+
+use super::IssueListOptions;
+use super::list::build_issue_filter;
+
+/// Workflow state types that count as open work.
+pub(crate) const OPEN_STATE_TYPES: [&str; 3] = ["backlog", "unstarted", "started"];
+
+/// Default comment posted by `--ping`.
+pub const DEFAULT_PING_MESSAGE: &str =
+    "This issue looks stale. Is it still relevant? Please update it or close it if not.";
+
+/// Why an issue was flagged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum StaleReason {
+    /// In progress but not updated for the in-progress threshold.
+    InProgressIdle,
+    /// Not started and not updated for the general threshold.
+    NoActivity,
+    /// Urgent or high priority with nobody assigned.
+    UnassignedHighPriority,
+    /// Due date in the past.
+    Overdue,
+}
+
+impl StaleReason {
+    /// All reasons, in display order.
+    pub const ALL: [StaleReason; 4] = [
+        StaleReason::Overdue,
+        StaleReason::UnassignedHighPriority,
+        StaleReason::InProgressIdle,
+        StaleReason::NoActivity,
+    ];
+
+    /// Parse a reason from a string.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().replace('_', "-").as_str() {
+            "in-progress" | "in-progress-idle" => Some(StaleReason::InProgressIdle),
+            "no-activity" | "idle" => Some(StaleReason::NoActivity),
+            "unassigned" | "unassigned-high-priority" => Some(StaleReason::UnassignedHighPriority),
+            "overdue" => Some(StaleReason::Overdue),
+            _ => None,
+        }
+    }
+
+    fn title(&self) -> &'static str {
+        match self {
+            StaleReason::InProgressIdle => "In progress but untouched",
+            StaleReason::NoActivity => "No activity",
+            StaleReason::UnassignedHighPriority => "Unassigned high priority",
+            StaleReason::Overdue => "Overdue",
+        }
+    }
+}
+
+/// Options for stale issue detection.
+#[derive(Debug, Clone)]
+pub struct StaleIssueOptions {
+    /// Team key (e.g., "ENG"). Uses the current team if not specified.
+    pub team: Option<String>,
+    /// Days without updates before an open issue counts as stale.
+    pub days: u32,
+    /// Days without updates before an in-progress issue counts as stale.
+    pub in_progress_days: u32,
+    /// Only report (and act on) issues flagged for this reason.
+    pub reason: Option<StaleReason>,
+    /// Maximum number of issues to fetch per check.
+    pub limit: u32,
+    /// Comment to post on every flagged issue.
+    pub ping: Option<String>,
+    /// Move every flagged issue to the team's triage state.
+    pub move_to_triage: bool,
+}
+
+/// A flagged issue.
+#[derive(Debug, Clone, Serialize)]
+pub struct StaleIssue {
+    /// Human-readable identifier (e.g., "ENG-123").
+    pub identifier: String,
+    /// Issue title.
+    pub title: String,
+    /// Current workflow state name.
+    pub state: Option<String>,
+    /// Assignee name.
+    pub assignee: Option<String>,
+    /// Priority (0 = none, 1 = urgent, 2 = high, 3 = normal, 4 = low).
+    pub priority: i32,
+    /// Due date (YYYY-MM-DD).
+    pub due_date: Option<String>,
+    /// Whole days since the last update.
+    pub days_idle: i64,
+}
+
+/// Flagged issues for one reason.
+#[derive(Debug, Clone, Serialize)]
+pub struct StaleGroup {
+    /// Why the issues were flagged.
+    pub reason: StaleReason,
+    /// Flagged issues, longest idle first.
+    pub issues: Vec<StaleIssue>,
+}
+
+/// Result of stale issue detection and any bulk actions.
+#[derive(Debug, Clone, Serialize)]
+pub struct StaleReport {
+    /// Team key.
+    pub team: String,
+    /// Threshold for open issues, in days.
+    pub days: u32,
+    /// Threshold for in-progress issues, in days.
+    pub in_progress_days: u32,
+    /// Flagged issues grouped by reason (empty groups omitted).
+    pub groups: Vec<StaleGroup>,
+    /// Issues that received a ping comment.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub pinged: Vec<String>,
+    /// Issues moved to triage.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub moved_to_triage: Vec<String>,
+}
+
+/// Find stale and neglected open issues for a team.
+///
+/// Flags open issues that haven't been updated for `days` (`in_progress_days`
+/// for started issues), unassigned urgent/high-priority issues, and issues
+/// past their due date. Optionally comments on or moves every flagged issue.
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the API request
+/// * `options` - Thresholds, filters and bulk actions
+/// * `use_cache` - Whether to use cached workflow states when moving to triage
+/// * `format` - The output format (Human or Json)
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use lin::api::GraphQLClient;
+/// use lin::commands::issue::stale::{stale_issues, StaleIssueOptions};
+/// use lin::output::OutputFormat;
+///
+/// let client = GraphQLClient::new("lin_api_xxxxx");
+/// let options = StaleIssueOptions {
+///     team: Some("ENG".to_string()),
+///     days: 30,
+///     in_progress_days: 30,
+///     reason: None,
+///     limit: 100,
+///     ping: None,
+///     move_to_triage: false,
+/// };
+/// stale_issues(&client, options, true, OutputFormat::Human)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn stale_issues(
+    client: &GraphQLClient,
+    options: StaleIssueOptions,
+    use_cache: bool,
+    format: OutputFormat,
+) -> Result<()> {
+    let team_key = resolvers::resolve_team_key_or_current(options.team.as_deref())?;
+    let now = Utc::now();
+    let wants = |reason: StaleReason| options.reason.is_none_or(|r| r == reason);
+
+    let mut candidates: Vec<Issue> = Vec::new();
+
+    // Idle issues: not updated since the shorter of the two thresholds
+    if wants(StaleReason::NoActivity) || wants(StaleReason::InProgressIdle) {
+        let idle_days = match options.reason {
+            Some(StaleReason::NoActivity) => options.days,
+            Some(StaleReason::InProgressIdle) => options.in_progress_days,
+            _ => options.days.min(options.in_progress_days),
+        };
+        let cutoff = now - Duration::days(idle_days as i64);
+        candidates.extend(fetch_open_issues(
+            client,
+            &team_key,
+            IssueListOptions {
+                updated_before: Some(cutoff.to_rfc3339()),
+                ..Default::default()
+            },
+            None,
+            options.limit,
+        )?);
+    }
+
+    if wants(StaleReason::UnassignedHighPriority) {
+        candidates.extend(fetch_open_issues(
+            client,
+            &team_key,
+            IssueListOptions::default(),
+            Some(serde_json::json!({
+                "priority": { "in": [1, 2] },
+                "assignee": { "null": true }
+            })),
+            options.limit,
+        )?);
+    }
+
+    if wants(StaleReason::Overdue) {
+        candidates.extend(fetch_open_issues(
+            client,
+            &team_key,
+            IssueListOptions::default(),
+            Some(serde_json::json!({
+                "dueDate": { "lt": now.format("%Y-%m-%d").to_string() }
+            })),
+            options.limit,
+        )?);
+    }
+
+    let mut groups =
+        classify_stale_issues(&candidates, now, options.days, options.in_progress_days);
+    if let Some(reason) = options.reason {
+        groups.retain(|g| g.reason == reason);
+    }
+
+    // Each flagged issue is acted on once, even if flagged for several reasons
+    let mut flagged: Vec<&Issue> = Vec::new();
+    for group in &groups {
+        for stale in &group.issues {
+            if !flagged.iter().any(|i| i.identifier == stale.identifier) {
+                if let Some(issue) = candidates.iter().find(|i| i.identifier == stale.identifier) {
+                    flagged.push(issue);
+                }
+            }
+        }
+    }
+
+    let mut pinged = Vec::new();
+    if let Some(message) = &options.ping {
+        for issue in &flagged {
+            let variables = serde_json::json!({
+                "input": { "issueId": issue.id, "body": message }
+            });
+            let response: CommentCreateResponse =
+                client.query(COMMENT_CREATE_MUTATION, variables)?;
+            if !response.comment_create.success {
+                return Err(LinError::api(format!(
+                    "Failed to comment on {}",
+                    issue.identifier
+                )));
+            }
+            pinged.push(issue.identifier.clone());
+        }
+    }
+
+    let mut moved_to_triage = Vec::new();
+    if options.move_to_triage && !flagged.is_empty() {
+        let triage_state_id = resolve_triage_state_id(client, &team_key, use_cache)?;
+        for issue in &flagged {
+            let variables = serde_json::json!({
+                "id": issue.id,
+                "input": { "stateId": triage_state_id }
+            });
+            let response: IssueUpdateResponse = client.query(ISSUE_UPDATE_MUTATION, variables)?;
+            if !response.issue_update.success {
+                return Err(LinError::api(format!(
+                    "Failed to move {} to triage",
+                    issue.identifier
+                )));
+            }
+            moved_to_triage.push(issue.identifier.clone());
+        }
+    }
+
+    let report = StaleReport {
+        team: team_key,
+        days: options.days,
+        in_progress_days: options.in_progress_days,
+        groups,
+        pinged,
+        moved_to_triage,
+    };
+    output(&report, format);
+    Ok(())
+}
+
+/// Fetch a team's open issues matching list options plus extra filter criteria.
+fn fetch_open_issues(
+    client: &GraphQLClient,
+    team_key: &str,
+    list_options: IssueListOptions,
+    extra: Option<serde_json::Value>,
+    limit: u32,
+) -> Result<Vec<Issue>> {
+    let list_options = IssueListOptions {
+        team: Some(team_key.to_string()),
+        ..list_options
+    };
+    let mut filter = build_issue_filter(client, None, &list_options)?;
+    filter.insert(
+        "state".to_string(),
+        serde_json::json!({ "type": { "in": OPEN_STATE_TYPES } }),
+    );
+    if let Some(serde_json::Value::Object(extra)) = extra {
+        filter.extend(extra);
+    }
+
+    let variables = serde_json::json!({
+        "first": limit,
+        "filter": filter
+    });
+    let response: IssuesResponse = client.query(STALE_ISSUES_QUERY, variables)?;
+    Ok(response.issues.nodes)
+}
+
+/// Find the team's triage state.
+fn resolve_triage_state_id(
+    client: &GraphQLClient,
+    team_key: &str,
+    use_cache: bool,
+) -> Result<String> {
+    let state_types = resolvers::resolve_state_types(client, team_key, use_cache)?;
+    let triage = state_types
+        .iter()
+        .find(|(_, state_type)| state_type.as_str() == "triage")
+        .map(|(name, _)| name.clone())
+        .ok_or_else(|| {
+            LinError::api(format!(
+                "Team '{}' has no triage state. Enable triage in the team's Linear settings",
+                team_key
+            ))
+        })?;
+    resolvers::resolve_state_id(client, team_key, &triage, use_cache)
+}
+
+fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .ok()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+/// Work out which reasons apply to each issue and group them.
+///
+/// Issues are deduplicated by identifier since the same issue can be
+/// returned by several checks.
+pub fn classify_stale_issues(
+    issues: &[Issue],
+    now: DateTime<Utc>,
+    days: u32,
+    in_progress_days: u32,
+) -> Vec<StaleGroup> {
+    let today = now.date_naive();
+    let mut groups: Vec<StaleGroup> = StaleReason::ALL
+        .iter()
+        .map(|reason| StaleGroup {
+            reason: *reason,
+            issues: Vec::new(),
+        })
+        .collect();
+
+    let mut seen: Vec<&str> = Vec::new();
+    for issue in issues {
+        if seen.contains(&issue.identifier.as_str()) {
+            continue;
+        }
+        seen.push(&issue.identifier);
+
+        let days_idle = parse_timestamp(&issue.updated_at)
+            .map(|t| (now - t).num_days())
+            .unwrap_or_default();
+        let state_type = issue.state.as_ref().map(|s| s.type_.as_str());
+
+        let mut reasons = Vec::new();
+        if state_type == Some("started") {
+            if days_idle >= in_progress_days as i64 {
+                reasons.push(StaleReason::InProgressIdle);
+            }
+        } else if days_idle >= days as i64 {
+            reasons.push(StaleReason::NoActivity);
+        }
+        if issue.assignee.is_none() && matches!(issue.priority, 1 | 2) {
+            reasons.push(StaleReason::UnassignedHighPriority);
+        }
+        let overdue = issue
+            .due_date
+            .as_deref()
+            .and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+            .is_some_and(|due| due < today);
+        if overdue {
+            reasons.push(StaleReason::Overdue);
+        }
+
+        for reason in reasons {
+            if let Some(group) = groups.iter_mut().find(|g| g.reason == reason) {
+                group.issues.push(StaleIssue {
+                    identifier: issue.identifier.clone(),
+                    title: issue.title.clone(),
+                    state: issue.state.as_ref().map(|s| s.name.clone()),
+                    assignee: issue.assignee.as_ref().map(|a| a.name.clone()),
+                    priority: issue.priority,
+                    due_date: issue.due_date.clone(),
+                    days_idle,
+                });
+            }
+        }
+    }
+
+    for group in &mut groups {
+        group.issues.sort_by_key(|i| std::cmp::Reverse(i.days_idle));
+    }
+    groups.retain(|g| !g.issues.is_empty());
+    groups
+}
+
+impl HumanDisplay for StaleReport {
+    fn human_fmt(&self) -> String {
+        let total: usize = self.groups.iter().map(|g| g.issues.len()).sum();
+        let mut parts = vec![format!(
+            "{} {}: {} flagged",
+            "Stale issues for".bold(),
+            self.team.bold().cyan(),
+            total
+        )];
+
+        if self.groups.is_empty() {
+            parts.push("  Nothing stale. Nice!".dimmed().to_string());
+        }
+
+        for group in &self.groups {
+            parts.push(String::new());
+            parts.push(format!(
+                "  {} ({})",
+                group.reason.title().bold(),
+                group.issues.len()
+            ));
+            for issue in &group.issues {
+                let mut details = vec![format!("{}d idle", issue.days_idle)];
+                if let Some(state) = &issue.state {
+                    details.push(state.clone());
+                }
+                if let Some(due) = &issue.due_date {
+                    details.push(format!("due {}", due));
+                }
+                details.push(
+                    issue
+                        .assignee
+                        .clone()
+                        .unwrap_or_else(|| "unassigned".to_string()),
+                );
+                parts.push(format!(
+                    "    {} {} {}",
+                    issue.identifier.cyan(),
+                    issue.title,
+                    format!("({})", details.join(", ")).dimmed()
+                ));
+            }
+        }
+
+        if !self.pinged.is_empty() {
+            parts.push(String::new());
+            parts.push(format!(
+                "  {} {} issue(s)",
+                "Pinged".green(),
+                self.pinged.len()
+            ));
+        }
+        if !self.moved_to_triage.is_empty() {
+            parts.push(format!(
+                "  {} {} issue(s) to triage",
+                "Moved".green(),
+                self.moved_to_triage.len()
+            ));
+        }
+
+        parts.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn now() -> DateTime<Utc> {
+        parse_timestamp("2024-03-01T00:00:00Z").unwrap()
+    }
+
+    fn issue(
+        identifier: &str,
+        state_type: &str,
+        updated_at: &str,
+        priority: i32,
+        assigned: bool,
+        due_date: Option<&str>,
+    ) -> Issue {
+        let assignee = if assigned {
+            r#"{ "id": "u1", "name": "Jane Doe", "email": "jane@example.com", "displayName": null, "active": true }"#
+        } else {
+            "null"
+        };
+        let json = format!(
+            r##"{{
+                "id": "id-{id}", "identifier": "{id}", "title": "Issue {id}", "description": null,
+                "priority": {priority}, "estimate": null,
+                "state": {{ "id": "s", "name": "State", "color": "#000", "type": "{state_type}" }},
+                "team": null, "assignee": {assignee}, "projectMilestone": null, "subscribers": null,
+                "dueDate": {due},
+                "createdAt": "2023-01-01T00:00:00Z", "updatedAt": "{updated_at}"
+            }}"##,
+            id = identifier,
+            priority = priority,
+            state_type = state_type,
+            assignee = assignee,
+            due = due_date
+                .map(|d| format!("\"{}\"", d))
+                .unwrap_or_else(|| "null".to_string()),
+            updated_at = updated_at,
+        );
+        serde_json::from_str(&json).unwrap()
+    }
+
+    fn identifiers(group: &StaleGroup) -> Vec<&str> {
+        group.issues.iter().map(|i| i.identifier.as_str()).collect()
+    }
+
+    #[test]
+    fn test_classify_stale_issues() {
+        let issues = vec![
+            issue("ENG-1", "unstarted", "2024-01-01T00:00:00Z", 3, true, None),
+            issue("ENG-2", "started", "2024-02-20T00:00:00Z", 3, true, None),
+            issue("ENG-3", "started", "2024-02-27T00:00:00Z", 3, true, None),
+            issue("ENG-4", "backlog", "2024-02-28T00:00:00Z", 1, false, None),
+            issue(
+                "ENG-5",
+                "unstarted",
+                "2024-02-28T00:00:00Z",
+                3,
+                true,
+                Some("2024-02-15"),
+            ),
+            // Returned by two checks; must only be counted once
+            issue("ENG-1", "unstarted", "2024-01-01T00:00:00Z", 3, true, None),
+        ];
+        let groups = classify_stale_issues(&issues, now(), 30, 7);
+
+        let reasons: Vec<StaleReason> = groups.iter().map(|g| g.reason).collect();
+        assert_eq!(
+            reasons,
+            vec![
+                StaleReason::Overdue,
+                StaleReason::UnassignedHighPriority,
+                StaleReason::InProgressIdle,
+                StaleReason::NoActivity
+            ]
+        );
+        assert_eq!(identifiers(&groups[0]), vec!["ENG-5"]);
+        assert_eq!(identifiers(&groups[1]), vec!["ENG-4"]);
+        assert_eq!(identifiers(&groups[2]), vec!["ENG-2"]);
+        assert_eq!(identifiers(&groups[3]), vec!["ENG-1"]);
+        assert_eq!(groups[3].issues[0].days_idle, 60);
+    }
+
+    #[test]
+    fn test_stale_reason_parse() {
+        assert_eq!(StaleReason::parse("overdue"), Some(StaleReason::Overdue));
+        assert_eq!(
+            StaleReason::parse("in_progress"),
+            Some(StaleReason::InProgressIdle)
+        );
+        assert_eq!(
+            StaleReason::parse("unassigned"),
+            Some(StaleReason::UnassignedHighPriority)
+        );
+        assert_eq!(StaleReason::parse("bogus"), None);
+    }
+
+    #[test]
+    fn test_stale_report_human_display() {
+        let issues = vec![issue(
+            "ENG-1",
+            "unstarted",
+            "2024-01-01T00:00:00Z",
+            3,
+            false,
+            None,
+        )];
+        let report = StaleReport {
+            team: "ENG".to_string(),
+            days: 30,
+            in_progress_days: 7,
+            groups: classify_stale_issues(&issues, now(), 30, 7),
+            pinged: vec!["ENG-1".to_string()],
+            moved_to_triage: Vec::new(),
+        };
+        let output = report.human_fmt();
+        assert!(output.contains("1 flagged"));
+        assert!(output.contains("No activity"));
+        assert!(output.contains("ENG-1"));
+        assert!(output.contains("60d idle"));
+        assert!(output.contains("unassigned"));
+        assert!(output.contains("Pinged"));
+    }
+
+    fn options() -> StaleIssueOptions {
+        StaleIssueOptions {
+            team: Some("ENG".to_string()),
+            days: 30,
+            in_progress_days: 30,
+            reason: None,
+            limit: 50,
+            ping: None,
+            move_to_triage: false,
+        }
+    }
+
+    #[test]
+    fn test_stale_issues_runs_each_check() {
+        let mut server = mockito::Server::new();
+
+        let mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::Regex("query StaleIssues".to_string()),
+                mockito::Matcher::PartialJsonString(
+                    r#"{"variables": {"filter": {"team": {"key": {"eq": "ENG"}}, "state": {"type": {"in": ["backlog", "unstarted", "started"]}}}}}"#
+                        .to_string(),
+                ),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"data": {"issues": {"nodes": []}}}"#)
+            .expect(3)
+            .create();
+
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let result = stale_issues(&client, options(), false, OutputFormat::Json);
+        assert!(result.is_ok());
+        mock.assert();
+    }
+
+    #[test]
+    fn test_stale_issues_reason_filter_and_ping() {
+        let mut server = mockito::Server::new();
+
+        let overdue = r##"{"data": {"issues": {"nodes": [{
+            "id": "issue-1", "identifier": "ENG-1", "title": "Late", "description": null,
+            "priority": 3, "estimate": null,
+            "state": { "id": "s", "name": "Todo", "color": "#000", "type": "unstarted" },
+            "team": null, "assignee": null, "projectMilestone": null, "subscribers": null,
+            "dueDate": "2020-01-01",
+            "createdAt": "2020-01-01T00:00:00Z", "updatedAt": "2099-01-01T00:00:00Z"
+        }]}}}"##;
+        let query_mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::Regex("query StaleIssues".to_string()),
+                mockito::Matcher::Regex("dueDate".to_string()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(overdue)
+            .expect(1)
+            .create();
+        let comment_mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::Regex("mutation CommentCreate".to_string()),
+                mockito::Matcher::PartialJsonString(
+                    r#"{"variables": {"input": {"issueId": "issue-1", "body": "Still needed?"}}}"#
+                        .to_string(),
+                ),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"data": {"commentCreate": {"success": true, "comment": null}}}"#)
+            .expect(1)
+            .create();
+
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let options = StaleIssueOptions {
+            reason: Some(StaleReason::Overdue),
+            ping: Some("Still needed?".to_string()),
+            ..options()
+        };
+        let result = stale_issues(&client, options, false, OutputFormat::Human);
+        assert!(result.is_ok());
+        query_mock.assert();
+        comment_mock.assert();
+    }
+
+    #[test]
+    fn test_stale_issues_move_to_triage_without_triage_state() {
+        let mut server = mockito::Server::new();
+
+        let _issues = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::Regex("query StaleIssues".to_string()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r##"{"data": {"issues": {"nodes": [{
+                    "id": "issue-1", "identifier": "ENG-1", "title": "Late", "description": null,
+                    "priority": 1, "estimate": null,
+                    "state": { "id": "s", "name": "Todo", "color": "#000", "type": "unstarted" },
+                    "team": null, "assignee": null, "projectMilestone": null, "subscribers": null,
+                    "createdAt": "2020-01-01T00:00:00Z", "updatedAt": "2020-01-01T00:00:00Z"
+                }]}}}"##,
+            )
+            .create();
+        let _team = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::Regex("query TeamByKey".to_string()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"data": {"teams": {"nodes": [{"id": "team-1", "key": "ENG", "name": "Engineering", "issueEstimationType": null}]}}}"#,
+            )
+            .create();
+        let _states = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::Regex("query WorkflowStates".to_string()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r##"{"data": {"team": {"id": "team-1", "states": {"nodes": [{"id": "s1", "name": "Todo", "color": "#ccc", "type": "unstarted"}]}}}}"##,
+            )
+            .create();
+
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let options = StaleIssueOptions {
+            move_to_triage: true,
+            ..options()
+        };
+        let result = stale_issues(&client, options, false, OutputFormat::Human);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("no triage state"));
+    }
+}
@@ -0,0 +1,248 @@
+//! Subscribe and unsubscribe operations for issues.
+
+use crate::Result;
+use crate::api::GraphQLClient;
+use crate::api::queries::issue::{ISSUE_SUBSCRIBE_MUTATION, ISSUE_UNSUBSCRIBE_MUTATION};
+use crate::commands::resolvers;
+use crate::error::LinError;
+use crate::models::{IssueSubscribeResponse, IssueUnsubscribeResponse};
+use crate::output::{OutputFormat, output};
+
+use super::{MessageResponse, resolve_issue_id};
+
+/// Subscribe a user to an issue.
+///
+/// Subscribes the authenticated user, or the given user, to notifications
+/// for an issue identified by ID or identifier (e.g., "ENG-123").
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the API request
+/// * `id_or_identifier` - The issue's UUID or human-readable identifier
+/// * `user` - Optional user to subscribe (name, email, UUID or "me"); defaults to the current user
+/// * `use_cache` - Whether to use cached users when resolving `user`
+/// * `format` - The output format (Human or Json)
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use lin::api::GraphQLClient;
+/// use lin::commands::issue::subscribe::subscribe_issue;
+/// use lin::output::OutputFormat;
+///
+/// let client = GraphQLClient::new("lin_api_xxxxx");
+/// subscribe_issue(&client, "ENG-123", Some("alice"), true, OutputFormat::Human)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn subscribe_issue(
+    client: &GraphQLClient,
+    id_or_identifier: &str,
+    user: Option<&str>,
+    use_cache: bool,
+    format: OutputFormat,
+) -> Result<()> {
+    let issue_id = resolve_issue_id(client, id_or_identifier)?;
+    let variables = subscription_variables(client, &issue_id, user, use_cache)?;
+
+    let response: IssueSubscribeResponse = client.query(ISSUE_SUBSCRIBE_MUTATION, variables)?;
+
+    if !response.issue_subscribe.success {
+        return Err(LinError::api("Failed to subscribe to issue"));
+    }
+
+    let message = MessageResponse {
+        message: format!(
+            "Subscribed {} to issue '{}'",
+            user.unwrap_or("you"),
+            id_or_identifier
+        ),
+    };
+    output(&message, format);
+    Ok(())
+}
+
+/// Unsubscribe a user from an issue.
+///
+/// Unsubscribes the authenticated user, or the given user, from an issue
+/// identified by ID or identifier (e.g., "ENG-123").
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the API request
+/// * `id_or_identifier` - The issue's UUID or human-readable identifier
+/// * `user` - Optional user to unsubscribe (name, email, UUID or "me"); defaults to the current user
+/// * `use_cache` - Whether to use cached users when resolving `user`
+/// * `format` - The output format (Human or Json)
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use lin::api::GraphQLClient;
+/// use lin::commands::issue::subscribe::unsubscribe_issue;
+/// use lin::output::OutputFormat;
+///
+/// let client = GraphQLClient::new("lin_api_xxxxx");
+/// unsubscribe_issue(&client, "ENG-123", None, true, OutputFormat::Human)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn unsubscribe_issue(
+    client: &GraphQLClient,
+    id_or_identifier: &str,
+    user: Option<&str>,
+    use_cache: bool,
+    format: OutputFormat,
+) -> Result<()> {
+    let issue_id = resolve_issue_id(client, id_or_identifier)?;
+    let variables = subscription_variables(client, &issue_id, user, use_cache)?;
+
+    let response: IssueUnsubscribeResponse = client.query(ISSUE_UNSUBSCRIBE_MUTATION, variables)?;
+
+    if !response.issue_unsubscribe.success {
+        return Err(LinError::api("Failed to unsubscribe from issue"));
+    }
+
+    let message = MessageResponse {
+        message: format!(
+            "Unsubscribed {} from issue '{}'",
+            user.unwrap_or("you"),
+            id_or_identifier
+        ),
+    };
+    output(&message, format);
+    Ok(())
+}
+
+/// Build mutation variables, resolving the user reference if one was given.
+///
+/// Without a user the API subscribes the authenticated user, so `userId` is omitted.
+fn subscription_variables(
+    client: &GraphQLClient,
+    issue_id: &str,
+    user: Option<&str>,
+    use_cache: bool,
+) -> Result<serde_json::Value> {
+    let mut variables = serde_json::json!({ "id": issue_id });
+    if let Some(user) = user {
+        let user_id = resolvers::resolve_user_id(client, user, use_cache)?;
+        variables["userId"] = serde_json::json!(user_id);
+    }
+    Ok(variables)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::GraphQLClient;
+    use crate::output::OutputFormat;
+
+    const ISSUE_UUID: &str = "550e8400-e29b-41d4-a716-446655440000";
+    const USER_UUID: &str = "660e8400-e29b-41d4-a716-446655440000";
+
+    #[test]
+    fn test_subscribe_issue_self() {
+        let mut server = mockito::Server::new();
+
+        // No userId should be sent when subscribing the current user
+        let mock = server
+            .mock("POST", "/")
+            .match_header("authorization", "test-token")
+            .match_body(mockito::Matcher::Regex(
+                r#""variables":\{"id":"550e8400-e29b-41d4-a716-446655440000"\}"#.to_string(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{
+                    "data": {
+                        "issueSubscribe": {
+                            "success": true,
+                            "issue": {
+                                "id": "550e8400-e29b-41d4-a716-446655440000",
+                                "identifier": "ENG-1",
+                                "title": "Test",
+                                "subscribers": { "nodes": [] }
+                            }
+                        }
+                    }
+                }"#,
+            )
+            .create();
+
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let result = subscribe_issue(&client, ISSUE_UUID, None, false, OutputFormat::Human);
+        assert!(result.is_ok());
+        mock.assert();
+    }
+
+    #[test]
+    fn test_subscribe_issue_other_user() {
+        let mut server = mockito::Server::new();
+
+        let mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::PartialJsonString(format!(
+                r#"{{"variables": {{"id": "{}", "userId": "{}"}}}}"#,
+                ISSUE_UUID, USER_UUID
+            )))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"data": {"issueSubscribe": {"success": true, "issue": null}}}"#)
+            .create();
+
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let result = subscribe_issue(
+            &client,
+            ISSUE_UUID,
+            Some(USER_UUID),
+            false,
+            OutputFormat::Json,
+        );
+        assert!(result.is_ok());
+        mock.assert();
+    }
+
+    #[test]
+    fn test_subscribe_issue_failure() {
+        let mut server = mockito::Server::new();
+
+        let mock = server
+            .mock("POST", "/")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"data": {"issueSubscribe": {"success": false, "issue": null}}}"#)
+            .create();
+
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let result = subscribe_issue(&client, ISSUE_UUID, None, false, OutputFormat::Human);
+        assert!(result.is_err());
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("Failed to subscribe")
+        );
+        mock.assert();
+    }
+
+    #[test]
+    fn test_unsubscribe_issue_success() {
+        let mut server = mockito::Server::new();
+
+        let mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::Regex("issueUnsubscribe".to_string()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"data": {"issueUnsubscribe": {"success": true, "issue": null}}}"#)
+            .create();
+
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let result = unsubscribe_issue(&client, ISSUE_UUID, None, false, OutputFormat::Human);
+        assert!(result.is_ok());
+        mock.assert();
+    }
+}
@@ -1,12 +1,31 @@
 //! Label management commands.
 //!
-//! Commands for listing and viewing label information from Linear.
+//! Commands for listing, viewing, creating, updating, deleting and merging
+//! labels in Linear.
+
+use colored::Colorize;
+use serde::Serialize;
 
 use crate::Result;
 use crate::api::GraphQLClient;
-use crate::api::queries::label::{LABEL_QUERY, LABELS_QUERY, TEAM_LABELS_QUERY};
-use crate::models::{LabelResponse, LabelsResponse, TeamLabelsResponse};
-use crate::output::{OutputFormat, output};
+use crate::api::queries::issue::ISSUE_UPDATE_MUTATION;
+use crate::api::queries::label::{
+    LABEL_CREATE_MUTATION, LABEL_DELETE_MUTATION, LABEL_QUERY, LABEL_UPDATE_MUTATION,
+    LABELED_ISSUES_QUERY, LABELS_QUERY, TEAM_LABELS_QUERY,
+};
+use crate::commands::resolvers;
+use crate::error::LinError;
+use crate::models::{
+    IssueUpdateResponse, Label, LabelCreateResponse, LabelDeleteResponse, LabelResponse,
+    LabelUpdateResponse, LabeledIssuesResponse, LabelsResponse, TeamLabelsResponse,
+};
+use crate::output::{HumanDisplay, OutputFormat, output};
+
+/// Maximum number of labels fetched for `label list`.
+const LABEL_LIST_LIMIT: u32 = 250;
+
+/// Number of issues relabeled per batch during a merge.
+const MERGE_BATCH_SIZE: u32 = 100;
 
 /// Options for listing labels.
 #[derive(Debug, Clone, Default)]
@@ -17,7 +36,8 @@ pub struct LabelListOptions {
 
 /// List labels in the workspace or for a specific team.
 ///
-/// Fetches labels from the Linear API and outputs them.
+/// Fetches labels from the Linear API and outputs them. Human output shows
+/// label groups as a tree; JSON output is a flat list.
 ///
 /// # Arguments
 ///
@@ -47,14 +67,16 @@ pub fn list_labels(
     if let Some(team_id) = options.team_id {
         // Query team-specific labels
         let variables = serde_json::json!({
-            "teamId": team_id
+            "teamId": team_id,
+            "first": LABEL_LIST_LIMIT
         });
         let response: TeamLabelsResponse = client.query(TEAM_LABELS_QUERY, variables)?;
-        output(&response.team.labels.nodes, format);
+        output(&LabelTree(response.team.labels.nodes), format);
     } else {
         // Query workspace labels
-        let response: LabelsResponse = client.query(LABELS_QUERY, serde_json::json!({}))?;
-        output(&response.issue_labels.nodes, format);
+        let variables = serde_json::json!({ "first": LABEL_LIST_LIMIT });
+        let response: LabelsResponse = client.query(LABELS_QUERY, variables)?;
+        output(&LabelTree(response.issue_labels.nodes), format);
     }
     Ok(())
 }
@@ -91,6 +113,446 @@ pub fn get_label(client: &GraphQLClient, id: &str, format: OutputFormat) -> Resu
     Ok(())
 }
 
+/// Labels displayed as a tree of groups and their members.
+///
+/// Serializes as the plain list of labels.
+#[derive(Debug, Clone, Serialize)]
+#[serde(transparent)]
+pub struct LabelTree(pub Vec<Label>);
+
+/// Format a label's color as a swatch followed by the hex code.
+fn color_swatch(color: &str) -> String {
+    let hex = color.trim_start_matches('#');
+    let rgb = (hex.len() == 6)
+        .then(|| {
+            Some((
+                u8::from_str_radix(&hex[0..2], 16).ok()?,
+                u8::from_str_radix(&hex[2..4], 16).ok()?,
+                u8::from_str_radix(&hex[4..6], 16).ok()?,
+            ))
+        })
+        .flatten();
+    match rgb {
+        Some((r, g, b)) => format!("{} {}", "●".truecolor(r, g, b), color.dimmed()),
+        None => color.dimmed().to_string(),
+    }
+}
+
+impl HumanDisplay for LabelTree {
+    fn human_fmt(&self) -> String {
+        if self.0.is_empty() {
+            return "No results found.".dimmed().to_string();
+        }
+
+        let line = |label: &Label| {
+            let group = if label.is_group {
+                format!(" {}", "[Group]".cyan())
+            } else {
+                String::new()
+            };
+            format!(
+                "{}{}  {}  {}",
+                label.name.bold(),
+                group,
+                color_swatch(&label.color),
+                label.id.dimmed()
+            )
+        };
+
+        let is_root = |label: &Label| {
+            label
+                .parent
+                .as_ref()
+                .is_none_or(|p| !self.0.iter().any(|l| l.id == p.id))
+        };
+        let mut roots: Vec<&Label> = self.0.iter().filter(|l| is_root(l)).collect();
+        roots.sort_by_key(|l| l.name.to_lowercase());
+
+        let mut lines = Vec::new();
+        for root in roots {
+            lines.push(line(root));
+            let mut children: Vec<&Label> = self
+                .0
+                .iter()
+                .filter(|l| l.parent.as_ref().is_some_and(|p| p.id == root.id))
+                .collect();
+            children.sort_by_key(|l| l.name.to_lowercase());
+            for (i, child) in children.iter().enumerate() {
+                let branch = if i + 1 == children.len() {
+                    "└── "
+                } else {
+                    "├── "
+                };
+                lines.push(format!("{}{}", branch.dimmed(), line(child)));
+            }
+        }
+        lines.join("\n")
+    }
+}
+
+/// Normalize a hex color to `#rrggbb`, accepting an optional leading `#`.
+pub fn normalize_color(color: &str) -> Result<String> {
+    let hex = color.trim().trim_start_matches('#');
+    if hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        Ok(format!("#{}", hex.to_lowercase()))
+    } else {
+        Err(LinError::parse(format!(
+            "Invalid color '{}'. Expected a hex color like #ff0000",
+            color
+        )))
+    }
+}
+
+/// Resolve a label group by name or ID, rejecting non-group labels.
+fn resolve_group_id(client: &GraphQLClient, group: &str) -> Result<String> {
+    let label = resolvers::resolve_label(client, group)?;
+    if !label.is_group {
+        return Err(LinError::config(format!(
+            "Label '{}' is not a label group",
+            label.name
+        )));
+    }
+    Ok(label.id)
+}
+
+/// Options for creating a label.
+#[derive(Debug, Clone, Default)]
+pub struct LabelCreateOptions {
+    /// Label name.
+    pub name: String,
+    /// Hex color code (e.g., "#ff0000").
+    pub color: Option<String>,
+    /// Label description.
+    pub description: Option<String>,
+    /// Team key or UUID. Creates a workspace label if not set.
+    pub team: Option<String>,
+    /// Label group name or UUID to place the label in.
+    pub parent: Option<String>,
+    /// Create the label as a group.
+    pub is_group: bool,
+}
+
+/// Create a new label.
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the API request
+/// * `options` - The label's name, color, team and group
+/// * `use_cache` - Whether to use cached team data when resolving the team
+/// * `format` - The output format (Human or Json)
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use lin::api::GraphQLClient;
+/// use lin::commands::label::{create_label, LabelCreateOptions};
+/// use lin::output::OutputFormat;
+///
+/// let client = GraphQLClient::new("lin_api_xxxxx");
+/// let options = LabelCreateOptions {
+///     name: "Backend".to_string(),
+///     color: Some("#4ea7fc".to_string()),
+///     team: Some("ENG".to_string()),
+///     parent: Some("Area".to_string()),
+///     ..Default::default()
+/// };
+/// create_label(&client, options, true, OutputFormat::Human)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn create_label(
+    client: &GraphQLClient,
+    options: LabelCreateOptions,
+    use_cache: bool,
+    format: OutputFormat,
+) -> Result<()> {
+    let mut input = serde_json::Map::new();
+    input.insert("name".to_string(), serde_json::json!(options.name));
+
+    if let Some(color) = &options.color {
+        input.insert(
+            "color".to_string(),
+            serde_json::json!(normalize_color(color)?),
+        );
+    }
+    if let Some(description) = options.description {
+        input.insert("description".to_string(), serde_json::json!(description));
+    }
+    if let Some(team) = &options.team {
+        let team_id = resolvers::resolve_team_id(client, team, use_cache)?;
+        input.insert("teamId".to_string(), serde_json::json!(team_id));
+    }
+    if let Some(parent) = &options.parent {
+        let parent_id = resolve_group_id(client, parent)?;
+        input.insert("parentId".to_string(), serde_json::json!(parent_id));
+    }
+    if options.is_group {
+        input.insert("isGroup".to_string(), serde_json::json!(true));
+    }
+
+    let variables = serde_json::json!({ "input": input });
+    let response: LabelCreateResponse = client.query(LABEL_CREATE_MUTATION, variables)?;
+
+    if !response.issue_label_create.success {
+        return Err(LinError::api("Failed to create label"));
+    }
+    let label = response
+        .issue_label_create
+        .issue_label
+        .ok_or_else(|| LinError::api("No label returned in response"))?;
+
+    output(&label, format);
+    Ok(())
+}
+
+/// Options for updating a label.
+#[derive(Debug, Clone, Default)]
+pub struct LabelUpdateOptions {
+    /// New label name.
+    pub name: Option<String>,
+    /// New hex color code.
+    pub color: Option<String>,
+    /// New description.
+    pub description: Option<String>,
+    /// Label group name or UUID to move the label into.
+    pub parent: Option<String>,
+}
+
+/// Update an existing label.
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the API request
+/// * `label` - The label's name or UUID
+/// * `options` - Fields to change
+/// * `format` - The output format (Human or Json)
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use lin::api::GraphQLClient;
+/// use lin::commands::label::{update_label, LabelUpdateOptions};
+/// use lin::output::OutputFormat;
+///
+/// let client = GraphQLClient::new("lin_api_xxxxx");
+/// let options = LabelUpdateOptions {
+///     color: Some("#eb5757".to_string()),
+///     ..Default::default()
+/// };
+/// update_label(&client, "Bug", options, OutputFormat::Human)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn update_label(
+    client: &GraphQLClient,
+    label: &str,
+    options: LabelUpdateOptions,
+    format: OutputFormat,
+) -> Result<()> {
+    let mut input = serde_json::Map::new();
+    if let Some(name) = options.name {
+        input.insert("name".to_string(), serde_json::json!(name));
+    }
+    if let Some(color) = &options.color {
+        input.insert(
+            "color".to_string(),
+            serde_json::json!(normalize_color(color)?),
+        );
+    }
+    if let Some(description) = options.description {
+        input.insert("description".to_string(), serde_json::json!(description));
+    }
+    if let Some(parent) = &options.parent {
+        let parent_id = resolve_group_id(client, parent)?;
+        input.insert("parentId".to_string(), serde_json::json!(parent_id));
+    }
+    if input.is_empty() {
+        return Err(LinError::config(
+            "Nothing to update. Pass at least one of --name, --color, --description or --parent",
+        ));
+    }
+
+    let label_id = resolvers::resolve_label(client, label)?.id;
+    let variables = serde_json::json!({
+        "id": label_id,
+        "input": input
+    });
+    let response: LabelUpdateResponse = client.query(LABEL_UPDATE_MUTATION, variables)?;
+
+    if !response.issue_label_update.success {
+        return Err(LinError::api("Failed to update label"));
+    }
+    let label = response
+        .issue_label_update
+        .issue_label
+        .ok_or_else(|| LinError::api("No label returned in response"))?;
+
+    output(&label, format);
+    Ok(())
+}
+
+fn delete_label_by_id(client: &GraphQLClient, id: &str) -> Result<()> {
+    let response: LabelDeleteResponse =
+        client.query(LABEL_DELETE_MUTATION, serde_json::json!({ "id": id }))?;
+    if !response.issue_label_delete.success {
+        return Err(LinError::api("Failed to delete label"));
+    }
+    Ok(())
+}
+
+/// Delete a label.
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the API request
+/// * `label` - The label's name or UUID
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use lin::api::GraphQLClient;
+/// use lin::commands::label::delete_label;
+///
+/// let client = GraphQLClient::new("lin_api_xxxxx");
+/// delete_label(&client, "Obsolete")?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn delete_label(client: &GraphQLClient, label: &str) -> Result<()> {
+    let label = resolvers::resolve_label(client, label)?;
+    delete_label_by_id(client, &label.id)?;
+    println!("Label '{}' deleted successfully", label.name);
+    Ok(())
+}
+
+/// Result of merging one label into another.
+#[derive(Debug, Clone, Serialize)]
+pub struct LabelMergeResult {
+    /// The label that was merged and deleted.
+    pub from: Label,
+    /// The label that remains.
+    pub into: Label,
+    /// Identifiers of the issues that were relabeled.
+    pub relabeled: Vec<String>,
+}
+
+impl HumanDisplay for LabelMergeResult {
+    fn human_fmt(&self) -> String {
+        let mut parts = vec![format!(
+            "Merged {} into {}",
+            self.from.name.bold(),
+            self.into.name.bold()
+        )];
+        parts.push(format!(
+            "  {}: {}",
+            "Relabeled issues".dimmed(),
+            self.relabeled.len()
+        ));
+        if !self.relabeled.is_empty() {
+            parts.push(format!("  {}", self.relabeled.join(", ").dimmed()));
+        }
+        parts.push(format!("  Label '{}' deleted", self.from.name));
+        parts.join("\n")
+    }
+}
+
+/// Merge one label into another.
+///
+/// Every issue carrying `from` gets `into` instead, then `from` is deleted.
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the API requests
+/// * `from` - Name or UUID of the label to merge away
+/// * `into` - Name or UUID of the label to keep
+/// * `format` - The output format (Human or Json)
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use lin::api::GraphQLClient;
+/// use lin::commands::label::merge_labels;
+/// use lin::output::OutputFormat;
+///
+/// let client = GraphQLClient::new("lin_api_xxxxx");
+/// merge_labels(&client, "bugfix", "Bug", OutputFormat::Human)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn merge_labels(
+    client: &GraphQLClient,
+    from: &str,
+    into: &str,
+    format: OutputFormat,
+) -> Result<()> {
+    let from = resolvers::resolve_label(client, from)?;
+    let into = resolvers::resolve_label(client, into)?;
+    if from.id == into.id {
+        return Err(LinError::config("Cannot merge a label into itself"));
+    }
+    if from.is_group || into.is_group {
+        return Err(LinError::config("Label groups cannot be merged"));
+    }
+
+    let mut relabeled: Vec<String> = Vec::new();
+    loop {
+        let variables = serde_json::json!({
+            "first": MERGE_BATCH_SIZE,
+            "filter": { "labels": { "id": { "eq": from.id } } }
+        });
+        let response: LabeledIssuesResponse = client.query(LABELED_ISSUES_QUERY, variables)?;
+        if response.issues.nodes.is_empty() {
+            break;
+        }
+
+        for issue in response.issues.nodes {
+            // An issue seen twice still has the old label; stop instead of looping
+            if relabeled.contains(&issue.identifier) {
+                return Err(LinError::api(format!(
+                    "Label '{}' could not be removed from {}",
+                    from.name, issue.identifier
+                )));
+            }
+
+            let mut label_ids: Vec<String> = issue
+                .labels
+                .nodes
+                .into_iter()
+                .map(|l| l.id)
+                .filter(|id| *id != from.id && *id != into.id)
+                .collect();
+            label_ids.push(into.id.clone());
+
+            let variables = serde_json::json!({
+                "id": issue.id,
+                "input": { "labelIds": label_ids }
+            });
+            let response: IssueUpdateResponse = client.query(ISSUE_UPDATE_MUTATION, variables)?;
+            if !response.issue_update.success {
+                return Err(LinError::api(format!(
+                    "Failed to relabel {}",
+                    issue.identifier
+                )));
+            }
+            relabeled.push(issue.identifier);
+        }
+    }
+
+    delete_label_by_id(client, &from.id)?;
+
+    let result = LabelMergeResult {
+        from,
+        into,
+        relabeled,
+    };
+    output(&result, format);
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -486,4 +948,337 @@ mod tests {
         // Verify mock was called
         mock.assert();
     }
+
+    fn label(id: &str, name: &str, is_group: bool, parent: Option<&str>) -> Label {
+        Label {
+            id: id.to_string(),
+            name: name.to_string(),
+            description: None,
+            color: "#ff0000".to_string(),
+            is_group,
+            parent: parent.map(|p| crate::models::LabelRef {
+                id: p.to_string(),
+                name: String::new(),
+            }),
+            created_at: "2024-01-01T00:00:00.000Z".to_string(),
+            updated_at: "2024-01-01T00:00:00.000Z".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_label_tree_groups_children() {
+        let tree = LabelTree(vec![
+            label("l1", "Bug", false, None),
+            label("l3", "Frontend", false, Some("g1")),
+            label("g1", "Area", true, None),
+            label("l2", "Backend", false, Some("g1")),
+            label("l4", "Orphan", false, Some("missing")),
+        ]);
+        let output = tree.human_fmt();
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(lines.len(), 5);
+        assert!(lines[0].contains("Area") && lines[0].contains("[Group]"));
+        assert!(lines[1].contains("├── ") && lines[1].contains("Backend"));
+        assert!(lines[2].contains("└── ") && lines[2].contains("Frontend"));
+        assert!(lines[3].contains("Bug"));
+        assert!(lines[4].contains("Orphan"));
+    }
+
+    #[test]
+    fn test_label_tree_serializes_as_list() {
+        let tree = LabelTree(vec![label("l1", "Bug", false, None)]);
+        let json = serde_json::to_value(&tree).unwrap();
+        assert!(json.is_array());
+        assert_eq!(json[0]["name"], "Bug");
+    }
+
+    #[test]
+    fn test_normalize_color() {
+        assert_eq!(normalize_color("#FF0000").unwrap(), "#ff0000");
+        assert_eq!(normalize_color("4ea7fc").unwrap(), "#4ea7fc");
+        assert!(normalize_color("red").is_err());
+        assert!(normalize_color("#fff").is_err());
+    }
+
+    #[test]
+    fn test_create_label_in_group() {
+        let mut server = mockito::Server::new();
+
+        let lookup_mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::Regex("query Labels".to_string()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r##"{
+                    "data": {
+                        "issueLabels": {
+                            "nodes": [
+                                {
+                                    "id": "group-1",
+                                    "name": "Area",
+                                    "description": null,
+                                    "color": "#000000",
+                                    "isGroup": true,
+                                    "parent": null,
+                                    "createdAt": "2024-01-01T00:00:00.000Z",
+                                    "updatedAt": "2024-01-01T00:00:00.000Z"
+                                }
+                            ]
+                        }
+                    }
+                }"##,
+            )
+            .create();
+
+        let create_mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::Regex("mutation IssueLabelCreate".to_string()),
+                mockito::Matcher::PartialJsonString(
+                    r##"{"variables": {"input": {"name": "Backend", "color": "#4ea7fc", "parentId": "group-1"}}}"##
+                        .to_string(),
+                ),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r##"{
+                    "data": {
+                        "issueLabelCreate": {
+                            "success": true,
+                            "issueLabel": {
+                                "id": "label-9",
+                                "name": "Backend",
+                                "description": null,
+                                "color": "#4ea7fc",
+                                "isGroup": false,
+                                "parent": { "id": "group-1", "name": "Area" },
+                                "createdAt": "2024-01-01T00:00:00.000Z",
+                                "updatedAt": "2024-01-01T00:00:00.000Z"
+                            }
+                        }
+                    }
+                }"##,
+            )
+            .create();
+
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let options = LabelCreateOptions {
+            name: "Backend".to_string(),
+            color: Some("4EA7FC".to_string()),
+            parent: Some("area".to_string()),
+            ..Default::default()
+        };
+        let result = create_label(&client, options, false, OutputFormat::Json);
+
+        assert!(result.is_ok());
+        lookup_mock.assert();
+        create_mock.assert();
+    }
+
+    #[test]
+    fn test_create_label_rejects_non_group_parent() {
+        let mut server = mockito::Server::new();
+        let _mock = server
+            .mock("POST", "/")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r##"{
+                    "data": {
+                        "issueLabels": {
+                            "nodes": [
+                                {
+                                    "id": "label-1",
+                                    "name": "Bug",
+                                    "description": null,
+                                    "color": "#ff0000",
+                                    "isGroup": false,
+                                    "parent": null,
+                                    "createdAt": "2024-01-01T00:00:00.000Z",
+                                    "updatedAt": "2024-01-01T00:00:00.000Z"
+                                }
+                            ]
+                        }
+                    }
+                }"##,
+            )
+            .create();
+
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let options = LabelCreateOptions {
+            name: "Crash".to_string(),
+            parent: Some("Bug".to_string()),
+            ..Default::default()
+        };
+        let err = create_label(&client, options, false, OutputFormat::Json).unwrap_err();
+        assert!(err.to_string().contains("not a label group"));
+    }
+
+    #[test]
+    fn test_update_label_requires_changes() {
+        let client = GraphQLClient::with_url("test-token", "http://localhost:1");
+        let result = update_label(
+            &client,
+            "Bug",
+            LabelUpdateOptions::default(),
+            OutputFormat::Json,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_merge_labels_relabels_then_deletes() {
+        let mut server = mockito::Server::new();
+        let from_id = "11111111-1111-1111-1111-111111111111";
+        let into_id = "22222222-2222-2222-2222-222222222222";
+        let label_body = |id: &str, name: &str| {
+            format!(
+                r##"{{
+                    "data": {{
+                        "issueLabel": {{
+                            "id": "{}",
+                            "name": "{}",
+                            "description": null,
+                            "color": "#ff0000",
+                            "isGroup": false,
+                            "parent": null,
+                            "createdAt": "2024-01-01T00:00:00.000Z",
+                            "updatedAt": "2024-01-01T00:00:00.000Z"
+                        }}
+                    }}
+                }}"##,
+                id, name
+            )
+        };
+
+        let from_mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::Regex("query Label\\(".to_string()),
+                mockito::Matcher::PartialJsonString(format!(
+                    r#"{{"variables": {{"id": "{}"}}}}"#,
+                    from_id
+                )),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(label_body(from_id, "bugfix"))
+            .create();
+        let into_mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::Regex("query Label\\(".to_string()),
+                mockito::Matcher::PartialJsonString(format!(
+                    r#"{{"variables": {{"id": "{}"}}}}"#,
+                    into_id
+                )),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(label_body(into_id, "Bug"))
+            .create();
+
+        let issues_mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::Regex("query LabeledIssues".to_string()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(format!(
+                r#"{{
+                    "data": {{
+                        "issues": {{
+                            "nodes": [
+                                {{
+                                    "id": "issue-1",
+                                    "identifier": "ENG-1",
+                                    "labels": {{ "nodes": [{{ "id": "{}" }}, {{ "id": "other" }}] }}
+                                }}
+                            ]
+                        }}
+                    }}
+                }}"#,
+                from_id
+            ))
+            .expect(1)
+            .create();
+        let empty_mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::Regex("query LabeledIssues".to_string()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"data": {"issues": {"nodes": []}}}"#)
+            .expect(1)
+            .create();
+
+        let update_mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::Regex("mutation IssueUpdate".to_string()),
+                mockito::Matcher::PartialJsonString(format!(
+                    r#"{{"variables": {{"id": "issue-1", "input": {{"labelIds": ["other", "{}"]}}}}}}"#,
+                    into_id
+                )),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"data": {"issueUpdate": {"success": true, "issue": null}}}"#)
+            .expect(1)
+            .create();
+
+        let delete_mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::Regex(
+                "mutation IssueLabelDelete".to_string(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"data": {"issueLabelDelete": {"success": true}}}"#)
+            .expect(1)
+            .create();
+
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let result = merge_labels(&client, from_id, into_id, OutputFormat::Json);
+
+        assert!(result.is_ok(), "{:?}", result);
+        from_mock.assert();
+        into_mock.assert();
+        issues_mock.assert();
+        empty_mock.assert();
+        update_mock.assert();
+        delete_mock.assert();
+    }
+
+    #[test]
+    fn test_merge_label_into_itself_fails() {
+        let mut server = mockito::Server::new();
+        let id = "11111111-1111-1111-1111-111111111111";
+        let _mock = server
+            .mock("POST", "/")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(format!(
+                r##"{{
+                    "data": {{
+                        "issueLabel": {{
+                            "id": "{}",
+                            "name": "Bug",
+                            "description": null,
+                            "color": "#ff0000",
+                            "isGroup": false,
+                            "createdAt": "2024-01-01T00:00:00.000Z",
+                            "updatedAt": "2024-01-01T00:00:00.000Z"
+                        }}
+                    }}
+                }}"##,
+                id
+            ))
+            .create();
+
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let err = merge_labels(&client, id, id, OutputFormat::Json).unwrap_err();
+        assert!(err.to_string().contains("itself"));
+    }
 }
@@ -3,20 +3,30 @@
 //! This module contains the actual implementation of CLI commands,
 //! separated from the argument parsing in main.rs.
 
+pub mod alias;
 pub mod attachment;
 pub mod auth;
+pub mod cache;
 pub mod comment;
 pub mod completions;
+pub mod config;
 pub mod cycle;
 pub mod git;
+pub mod inbox;
 pub mod issue;
 pub mod label;
 pub mod milestone;
+pub mod plugin;
 pub mod project;
 pub mod relation;
+pub mod report;
 pub mod resolvers;
+pub mod roadmap;
 pub mod search;
 pub mod self_update;
+pub mod shell;
+pub mod standup;
 pub mod team;
+pub mod triage;
 pub mod user;
 pub mod workflow;
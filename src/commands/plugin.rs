@@ -0,0 +1,237 @@
+//! Plugin commands.
+//!
+//! See [`crate::plugin`] for how plugins are found and what they receive.
+
+use std::env;
+use std::ffi::OsString;
+
+use serde::Serialize;
+
+use crate::Result;
+use crate::api::oauth;
+use crate::auth::{self, LINEAR_API_TOKEN_ENV};
+use crate::config::Config;
+use crate::error::LinError;
+use crate::output::{OutputFormat, output};
+use crate::plugin::{self, PLUGIN_PREFIX, Plugin, PluginContext};
+
+/// Plugins found on `PATH`.
+#[derive(Debug, Clone, Serialize)]
+pub struct PluginList {
+    /// Plugins sorted by name
+    pub plugins: Vec<Plugin>,
+}
+
+impl crate::output::HumanDisplay for PluginList {
+    fn human_fmt(&self) -> String {
+        if self.plugins.is_empty() {
+            return format!(
+                "No plugins found. Plugins are executables named {}<name> on PATH.",
+                PLUGIN_PREFIX
+            );
+        }
+        let width = self.plugins.iter().map(|p| p.name.len()).max().unwrap_or(0);
+        self.plugins
+            .iter()
+            .map(|p| format!("{:width$}  {}", p.name, p.path.display(), width = width))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// List the plugins found on `PATH`.
+///
+/// # Arguments
+///
+/// * `format` - Output format
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use lin::commands::plugin::plugin_list;
+/// use lin::output::OutputFormat;
+///
+/// plugin_list(OutputFormat::Human)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn plugin_list(format: OutputFormat) -> Result<()> {
+    let list = PluginList {
+        plugins: plugin::discover(env::var_os("PATH").as_deref()),
+    };
+    output(&list, format);
+    Ok(())
+}
+
+/// Resolve the organization, team and token a plugin runs with.
+///
+/// A missing token isn't an error here: not every plugin talks to Linear.
+fn plugin_context(org: Option<&str>, format: OutputFormat) -> Result<PluginContext> {
+    let mut config = Config::load()?;
+    let active = config.get_active_org_name().ok().map(str::to_string);
+    let org = org.map(str::to_string).or(active.clone());
+    let team = match &org {
+        Some(org) if active.as_ref() != Some(org) => config
+            .orgs
+            .get(org)
+            .and_then(|org_config| org_config.current_team.clone()),
+        _ => config.get_current_team(),
+    };
+
+    let token = match env::var(LINEAR_API_TOKEN_ENV) {
+        Ok(token) if !token.is_empty() => Some(token),
+        _ => match &org {
+            Some(org) if config.orgs.contains_key(org) => {
+                let stored = config.get_authorization(Some(org)).ok();
+                match auth::fresh_authorization(&mut config, org, oauth::LINEAR_TOKEN_URL) {
+                    Ok(authorization) => {
+                        if stored.as_ref() != Some(&authorization) {
+                            config.save()?;
+                        }
+                        Some(authorization)
+                    }
+                    Err(e) => {
+                        eprintln!("Warning: could not resolve a token for '{}': {}", org, e);
+                        None
+                    }
+                }
+            }
+            _ => None,
+        },
+    };
+
+    Ok(PluginContext {
+        token,
+        org,
+        team,
+        format,
+    })
+}
+
+/// Run the `lin-<name>` plugin from `PATH`, returning its exit code.
+///
+/// # Arguments
+///
+/// * `name` - Subcommand name (e.g., `deploy-notes` for `lin-deploy-notes`)
+/// * `args` - Arguments after the subcommand
+/// * `builtins` - Built-in subcommand names, to suggest one for a typo
+/// * `org` - Organization from `--org` (uses the active one if None)
+/// * `format` - Output format, passed to the plugin as `LIN_OUTPUT`
+///
+/// # Errors
+///
+/// Returns an error if no such plugin is on `PATH` or it can't be started.
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use lin::commands::plugin::run_plugin;
+/// use lin::output::OutputFormat;
+///
+/// let builtins = ["issue", "team", "project"];
+/// let code = run_plugin(
+///     "deploy-notes",
+///     &["v1.2.0".into()],
+///     &builtins,
+///     None,
+///     OutputFormat::Human,
+/// )?;
+/// std::process::exit(code);
+/// # }
+/// ```
+pub fn run_plugin(
+    name: &str,
+    args: &[OsString],
+    builtins: &[&str],
+    org: Option<&str>,
+    format: OutputFormat,
+) -> Result<i32> {
+    let path = plugin::find(name, env::var_os("PATH").as_deref()).ok_or_else(|| {
+        if let Some(builtin) = closest_builtin(name, builtins) {
+            return LinError::config(format!(
+                "Unknown command '{}'. Did you mean '{}'?",
+                name, builtin
+            ));
+        }
+        LinError::config(format!(
+            "Unknown command '{}'. No {}{} plugin found on PATH. Run 'lin --help' for commands or 'lin plugin list' for plugins.",
+            name, PLUGIN_PREFIX, name
+        ))
+    })?;
+    let plugin = Plugin {
+        name: name.to_string(),
+        path,
+    };
+    let context = plugin_context(org, format)?;
+    plugin::run(&plugin, args, &context)
+}
+
+/// Find the built-in subcommand closest to a mistyped name, if any is close.
+///
+/// Allows one edit for short names and two for longer ones.
+fn closest_builtin<'a>(name: &str, builtins: &[&'a str]) -> Option<&'a str> {
+    let max_distance = if name.chars().count() <= 4 { 1 } else { 2 };
+    builtins
+        .iter()
+        .map(|builtin| (edit_distance(name, builtin), *builtin))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, builtin)| builtin)
+}
+
+/// Levenshtein distance between two strings, counted in characters.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::HumanDisplay;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_plugin_list_human() {
+        let list = PluginList {
+            plugins: vec![
+                Plugin {
+                    name: "deploy-notes".to_string(),
+                    path: PathBuf::from("/usr/local/bin/lin-deploy-notes"),
+                },
+                Plugin {
+                    name: "sla".to_string(),
+                    path: PathBuf::from("/opt/bin/lin-sla"),
+                },
+            ],
+        };
+        assert_eq!(
+            list.human_fmt(),
+            "deploy-notes  /usr/local/bin/lin-deploy-notes\nsla           /opt/bin/lin-sla"
+        );
+
+        let empty = PluginList { plugins: vec![] };
+        assert!(empty.human_fmt().contains("lin-<name>"));
+    }
+
+    #[test]
+    fn test_closest_builtin() {
+        let builtins = ["issue", "team", "project", "cycle", "help"];
+        assert_eq!(closest_builtin("isue", &builtins), Some("issue"));
+        assert_eq!(closest_builtin("porject", &builtins), Some("project"));
+        assert_eq!(closest_builtin("tema", &builtins), None);
+        assert_eq!(closest_builtin("deploy-notes", &builtins), None);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+}
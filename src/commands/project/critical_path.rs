@@ -0,0 +1,498 @@
+//! Blocked-issue analysis and critical path for a project.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use colored::Colorize;
+use serde::Serialize;
+
+use crate::Result;
+use crate::api::GraphQLClient;
+use crate::commands::issue::graph::{EdgeKind, IssueGraph, fetch_project_graph};
+use crate::output::{HumanDisplay, OutputFormat, output};
+
+/// Number of top blockers to report.
+const TOP_BLOCKERS: usize = 10;
+
+/// An issue on the critical path.
+#[derive(Debug, Clone, Serialize)]
+pub struct PathIssue {
+    /// Human-readable identifier (e.g., "ENG-123").
+    pub identifier: String,
+    /// Issue title.
+    pub title: String,
+    /// Workflow state name, if known.
+    pub state: Option<String>,
+    /// Estimate in points, if set.
+    pub estimate: Option<f64>,
+}
+
+/// An open issue that holds up other open work.
+#[derive(Debug, Clone, Serialize)]
+pub struct Blocker {
+    /// Human-readable identifier (e.g., "ENG-123").
+    pub identifier: String,
+    /// Issue title.
+    pub title: String,
+    /// Workflow state name, if known.
+    pub state: Option<String>,
+    /// Open issues this one blocks directly.
+    pub blocks_directly: usize,
+    /// Open issues blocked directly or through a chain.
+    pub blocks_total: usize,
+    /// Estimated points of all issues blocked directly or through a chain.
+    pub blocked_points: f64,
+}
+
+/// Critical path analysis for a project.
+#[derive(Debug, Clone, Serialize)]
+pub struct CriticalPathReport {
+    /// Project name.
+    pub project: String,
+    /// Open issues considered, including open blockers outside the project.
+    pub open_issues: usize,
+    /// Blocking relations between open issues.
+    pub blocking_relations: usize,
+    /// Longest chain of open blocking issues, first blocker first.
+    pub critical_path: Vec<PathIssue>,
+    /// Sum of estimates along the critical path.
+    pub critical_path_points: f64,
+    /// Issues that hold up the most open work.
+    pub top_blockers: Vec<Blocker>,
+    /// Groups of open issues that block each other in a loop.
+    pub cycles: Vec<Vec<String>>,
+}
+
+/// Analyze a project graph for its critical path, top blockers, and cycles.
+///
+/// Only open issues and the `blocks` relations between them are considered.
+/// The critical path is the chain with the highest total estimate; ties go
+/// to the longer chain, and unestimated issues count as zero points. Edges
+/// inside a blocking cycle are left out of the path search.
+pub fn analyze_critical_path(project: &str, graph: &IssueGraph) -> CriticalPathReport {
+    let open = graph.open_blocking_subgraph();
+    let n = open.nodes.len();
+    let index: HashMap<&str, usize> = open
+        .nodes
+        .iter()
+        .enumerate()
+        .map(|(i, node)| (node.identifier.as_str(), i))
+        .collect();
+    let weights: Vec<f64> = open
+        .nodes
+        .iter()
+        .map(|node| node.estimate.unwrap_or(0.0))
+        .collect();
+
+    let mut successors: Vec<Vec<usize>> = vec![Vec::new(); n];
+    let mut acyclic: Vec<Vec<usize>> = vec![Vec::new(); n];
+    let mut in_degree = vec![0usize; n];
+    for edge in open.edges.iter().filter(|e| e.kind == EdgeKind::Blocks) {
+        let (from, to) = (index[edge.from.as_str()], index[edge.to.as_str()]);
+        successors[from].push(to);
+        if !edge.in_cycle {
+            acyclic[from].push(to);
+            in_degree[to] += 1;
+        }
+    }
+
+    // Longest weighted path over the acyclic edges, in topological order
+    let mut best: Vec<(f64, usize)> = weights.iter().map(|&w| (w, 1)).collect();
+    let mut previous: Vec<Option<usize>> = vec![None; n];
+    let mut queue: VecDeque<usize> = (0..n).filter(|&i| in_degree[i] == 0).collect();
+    while let Some(u) = queue.pop_front() {
+        for &v in &acyclic[u] {
+            let candidate = (best[u].0 + weights[v], best[u].1 + 1);
+            if candidate.0 > best[v].0 || (candidate.0 == best[v].0 && candidate.1 > best[v].1) {
+                best[v] = candidate;
+                previous[v] = Some(u);
+            }
+            in_degree[v] -= 1;
+            if in_degree[v] == 0 {
+                queue.push_back(v);
+            }
+        }
+    }
+
+    let end = (0..n).filter(|&i| previous[i].is_some()).max_by(|&a, &b| {
+        best[a]
+            .0
+            .total_cmp(&best[b].0)
+            .then(best[a].1.cmp(&best[b].1))
+            .then(b.cmp(&a))
+    });
+    let mut path_indices = Vec::new();
+    let mut cursor = end;
+    while let Some(i) = cursor {
+        path_indices.push(i);
+        cursor = previous[i];
+    }
+    path_indices.reverse();
+
+    let critical_path: Vec<PathIssue> = path_indices
+        .iter()
+        .map(|&i| {
+            let node = &open.nodes[i];
+            PathIssue {
+                identifier: node.identifier.clone(),
+                title: node.title.clone(),
+                state: node.state.clone(),
+                estimate: node.estimate,
+            }
+        })
+        .collect();
+    let critical_path_points = path_indices.iter().map(|&i| weights[i]).sum();
+
+    let mut top_blockers: Vec<Blocker> = (0..n)
+        .filter(|&i| !successors[i].is_empty())
+        .map(|i| {
+            let downstream = reachable(&successors, i);
+            let node = &open.nodes[i];
+            Blocker {
+                identifier: node.identifier.clone(),
+                title: node.title.clone(),
+                state: node.state.clone(),
+                blocks_directly: successors[i].len(),
+                blocks_total: downstream.len(),
+                blocked_points: downstream.iter().map(|&j| weights[j]).sum(),
+            }
+        })
+        .collect();
+    top_blockers.sort_by(|a, b| {
+        b.blocks_total
+            .cmp(&a.blocks_total)
+            .then(b.blocked_points.total_cmp(&a.blocked_points))
+            .then(a.identifier.cmp(&b.identifier))
+    });
+    top_blockers.truncate(TOP_BLOCKERS);
+
+    CriticalPathReport {
+        project: project.to_string(),
+        open_issues: n,
+        blocking_relations: open.edges.len(),
+        critical_path,
+        critical_path_points,
+        top_blockers,
+        cycles: open.blocking_cycles.clone(),
+    }
+}
+
+/// All nodes reachable from `start`, excluding `start` itself.
+fn reachable(successors: &[Vec<usize>], start: usize) -> HashSet<usize> {
+    let mut seen = HashSet::new();
+    let mut queue = VecDeque::from([start]);
+    while let Some(u) = queue.pop_front() {
+        for &v in &successors[u] {
+            if v != start && seen.insert(v) {
+                queue.push_back(v);
+            }
+        }
+    }
+    seen
+}
+
+fn format_points(points: f64) -> String {
+    if points.fract() == 0.0 {
+        format!("{}", points as i64)
+    } else {
+        format!("{:.1}", points)
+    }
+}
+
+impl HumanDisplay for CriticalPathReport {
+    fn human_fmt(&self) -> String {
+        let mut parts = vec![
+            format!(
+                "{} {}",
+                "Critical path for".bold(),
+                self.project.bold().cyan()
+            ),
+            format!(
+                "  {} open issues, {} blocking relations",
+                self.open_issues, self.blocking_relations
+            )
+            .dimmed()
+            .to_string(),
+            String::new(),
+        ];
+
+        if self.critical_path.is_empty() {
+            parts.push(
+                "  No open issues are blocking each other."
+                    .dimmed()
+                    .to_string(),
+            );
+        } else {
+            parts.push(format!(
+                "{} ({} points, {} issues)",
+                "Longest blocking chain".bold(),
+                format_points(self.critical_path_points),
+                self.critical_path.len()
+            ));
+            for (i, issue) in self.critical_path.iter().enumerate() {
+                if i > 0 {
+                    parts.push(format!("    {}", "↓".dimmed()));
+                }
+                let estimate = issue
+                    .estimate
+                    .map(|e| format!("{} pts", format_points(e)))
+                    .unwrap_or_else(|| "unestimated".to_string());
+                parts.push(format!(
+                    "  {:<10} {} {} {}",
+                    issue.identifier.cyan(),
+                    issue.title,
+                    format!("[{}]", issue.state.as_deref().unwrap_or("?")).dimmed(),
+                    estimate.dimmed()
+                ));
+            }
+        }
+
+        if !self.top_blockers.is_empty() {
+            parts.push(String::new());
+            parts.push("Top blockers".bold().to_string());
+            for blocker in &self.top_blockers {
+                parts.push(format!(
+                    "  {:<10} blocks {} directly, {} in total ({} pts)  {}",
+                    blocker.identifier.cyan(),
+                    blocker.blocks_directly,
+                    blocker.blocks_total,
+                    format_points(blocker.blocked_points),
+                    blocker.title.dimmed()
+                ));
+            }
+        }
+
+        if !self.cycles.is_empty() {
+            parts.push(String::new());
+            parts.push("Circular blocking".bold().red().to_string());
+            for cycle in &self.cycles {
+                parts.push(format!("  {}", cycle.join(" ↔ ").red()));
+            }
+        }
+
+        parts.join("\n")
+    }
+}
+
+/// Show the critical path, top blockers, and blocking cycles of a project.
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the API request
+/// * `slug_or_id` - The project's slug or UUID
+/// * `format` - The output format (Human or Json)
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use lin::api::GraphQLClient;
+/// use lin::commands::project::critical_path::critical_path;
+/// use lin::output::OutputFormat;
+///
+/// let client = GraphQLClient::new("lin_api_xxxxx");
+/// critical_path(&client, "q1-backend", OutputFormat::Human)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn critical_path(client: &GraphQLClient, slug_or_id: &str, format: OutputFormat) -> Result<()> {
+    let (project, graph) = fetch_project_graph(client, slug_or_id)?;
+    let report = analyze_critical_path(&project, &graph);
+    output(&report, format);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{
+        GraphIssue, InverseIssueRelationConnection, IssueRelation, IssueRelationConnection,
+        RelatedIssue, WorkflowState,
+    };
+
+    fn issue(
+        identifier: &str,
+        state_type: &str,
+        estimate: Option<f64>,
+        blocks: &[&str],
+    ) -> GraphIssue {
+        GraphIssue {
+            id: format!("id-{}", identifier),
+            identifier: identifier.to_string(),
+            title: format!("Title {}", identifier),
+            estimate,
+            state: Some(WorkflowState {
+                id: format!("state-{}", state_type),
+                name: state_type.to_string(),
+                color: "#000000".to_string(),
+                type_: state_type.to_string(),
+            }),
+            parent: None,
+            relations: IssueRelationConnection {
+                nodes: blocks
+                    .iter()
+                    .map(|b| IssueRelation {
+                        id: format!("rel-{}-{}", identifier, b),
+                        type_: "blocks".to_string(),
+                        related_issue: Some(RelatedIssue {
+                            id: format!("id-{}", b),
+                            identifier: b.to_string(),
+                            title: format!("Title {}", b),
+                            state: None,
+                        }),
+                    })
+                    .collect(),
+            },
+            inverse_relations: InverseIssueRelationConnection { nodes: vec![] },
+        }
+    }
+
+    fn path_ids(report: &CriticalPathReport) -> Vec<&str> {
+        report
+            .critical_path
+            .iter()
+            .map(|i| i.identifier.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_critical_path_weighted_by_estimate() {
+        // ENG-1 -> ENG-2 -> ENG-4 (1 + 1 + 2 = 4)
+        // ENG-1 -> ENG-3 -> ENG-4 (1 + 5 + 2 = 8)
+        let graph = IssueGraph::from_project_issues(&[
+            issue("ENG-1", "started", Some(1.0), &["ENG-2", "ENG-3"]),
+            issue("ENG-2", "unstarted", Some(1.0), &["ENG-4"]),
+            issue("ENG-3", "unstarted", Some(5.0), &["ENG-4"]),
+            issue("ENG-4", "backlog", Some(2.0), &[]),
+        ]);
+        let report = analyze_critical_path("Launch", &graph);
+
+        assert_eq!(path_ids(&report), vec!["ENG-1", "ENG-3", "ENG-4"]);
+        assert_eq!(report.critical_path_points, 8.0);
+        assert_eq!(report.open_issues, 4);
+        assert_eq!(report.blocking_relations, 4);
+        assert!(report.cycles.is_empty());
+    }
+
+    #[test]
+    fn test_critical_path_ignores_finished_issues() {
+        let graph = IssueGraph::from_project_issues(&[
+            issue("ENG-1", "completed", Some(8.0), &["ENG-2"]),
+            issue("ENG-2", "started", Some(1.0), &["ENG-3"]),
+            issue("ENG-3", "unstarted", None, &[]),
+        ]);
+        let report = analyze_critical_path("Launch", &graph);
+
+        assert_eq!(path_ids(&report), vec!["ENG-2", "ENG-3"]);
+        assert_eq!(report.critical_path_points, 1.0);
+        assert_eq!(report.open_issues, 2);
+    }
+
+    #[test]
+    fn test_no_blocking_relations() {
+        let graph = IssueGraph::from_project_issues(&[
+            issue("ENG-1", "started", Some(3.0), &[]),
+            issue("ENG-2", "unstarted", Some(1.0), &[]),
+        ]);
+        let report = analyze_critical_path("Launch", &graph);
+
+        assert!(report.critical_path.is_empty());
+        assert!(report.top_blockers.is_empty());
+        assert!(
+            report
+                .human_fmt()
+                .contains("No open issues are blocking each other")
+        );
+    }
+
+    #[test]
+    fn test_top_blockers_ranked_by_downstream_work() {
+        let graph = IssueGraph::from_project_issues(&[
+            issue("ENG-1", "started", Some(1.0), &["ENG-2"]),
+            issue("ENG-2", "unstarted", Some(2.0), &["ENG-3", "ENG-4"]),
+            issue("ENG-3", "unstarted", Some(3.0), &[]),
+            issue("ENG-4", "unstarted", Some(5.0), &[]),
+            issue("ENG-5", "unstarted", Some(1.0), &["ENG-4"]),
+        ]);
+        let report = analyze_critical_path("Launch", &graph);
+
+        let first = &report.top_blockers[0];
+        assert_eq!(first.identifier, "ENG-1");
+        assert_eq!(first.blocks_directly, 1);
+        assert_eq!(first.blocks_total, 3);
+        assert_eq!(first.blocked_points, 10.0);
+        assert_eq!(report.top_blockers[1].identifier, "ENG-2");
+        assert_eq!(report.top_blockers.len(), 3);
+    }
+
+    #[test]
+    fn test_circular_blocking_detected() {
+        let graph = IssueGraph::from_project_issues(&[
+            issue("ENG-1", "started", Some(1.0), &["ENG-2"]),
+            issue("ENG-2", "unstarted", Some(1.0), &["ENG-1", "ENG-3"]),
+            issue("ENG-3", "unstarted", Some(1.0), &[]),
+        ]);
+        let report = analyze_critical_path("Launch", &graph);
+
+        assert_eq!(
+            report.cycles,
+            vec![vec!["ENG-1".to_string(), "ENG-2".to_string()]]
+        );
+        // The cycle edges are skipped, leaving ENG-2 -> ENG-3
+        assert_eq!(path_ids(&report), vec!["ENG-2", "ENG-3"]);
+        assert!(report.human_fmt().contains("Circular blocking"));
+    }
+
+    #[test]
+    fn test_critical_path_command() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::Regex("query ProjectIssueGraph".to_string()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r##"{
+                    "data": {
+                        "project": {
+                            "id": "project-1",
+                            "name": "Launch",
+                            "issues": {
+                                "nodes": [
+                                    {
+                                        "id": "issue-1",
+                                        "identifier": "ENG-1",
+                                        "title": "First",
+                                        "estimate": 3,
+                                        "state": {"id": "s1", "name": "Todo", "color": "#e2e2e2", "type": "unstarted"},
+                                        "parent": null,
+                                        "relations": {
+                                            "nodes": [
+                                                {
+                                                    "id": "rel-1",
+                                                    "type": "blocks",
+                                                    "relatedIssue": {
+                                                        "id": "issue-2",
+                                                        "identifier": "ENG-2",
+                                                        "title": "Second",
+                                                        "state": {"id": "s1", "name": "Todo", "color": "#e2e2e2", "type": "unstarted"}
+                                                    }
+                                                }
+                                            ]
+                                        },
+                                        "inverseRelations": {"nodes": []}
+                                    }
+                                ]
+                            }
+                        }
+                    }
+                }"##,
+            )
+            .create();
+
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let result = critical_path(&client, "project-1", OutputFormat::Json);
+
+        assert!(result.is_ok());
+        mock.assert();
+    }
+}
@@ -2,6 +2,8 @@
 //!
 //! Commands for listing and viewing project information from Linear.
 
+pub mod critical_path;
+
 use crate::Result;
 use crate::api::GraphQLClient;
 use crate::api::queries::project::{PROJECT_QUERY, PROJECTS_QUERY};
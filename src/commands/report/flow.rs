@@ -0,0 +1,1011 @@
+//! Flow analytics: lead time, cycle time and time in state.
+//!
+//! Lead time runs from issue creation to completion. Cycle time runs from the
+//! first move into a "started" state to completion. Time in state is measured
+//! by replaying each issue's state transitions from its history.
+
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::{DateTime, Duration, Utc};
+use colored::Colorize;
+use serde::Serialize;
+
+use crate::Result;
+use crate::api::GraphQLClient;
+use crate::api::queries::report::FLOW_ISSUES_QUERY;
+use crate::commands::resolvers;
+use crate::error::LinError;
+use crate::models::{FlowIssue, FlowIssuesResponse, WorkflowState};
+use crate::output::{HumanDisplay, OutputFormat, output};
+
+use super::{format_hours, parse_report_date, percentile};
+
+/// Number of history entries fetched per issue.
+const HISTORY_PER_ISSUE: u32 = 100;
+
+/// Histogram buckets for cycle time, as (label, lower bound in days, upper bound in days).
+const HISTOGRAM_BUCKETS: &[(&str, f64, f64)] = &[
+    ("< 1d", 0.0, 1.0),
+    ("1-2d", 1.0, 2.0),
+    ("2-4d", 2.0, 4.0),
+    ("4-7d", 4.0, 7.0),
+    ("1-2w", 7.0, 14.0),
+    ("2-4w", 14.0, 28.0),
+    ("4w+", 28.0, f64::INFINITY),
+];
+
+/// How to group issues in the flow report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlowGroupBy {
+    /// Group by label (an issue with several labels counts in each group).
+    Label,
+    /// Group by estimate value.
+    Estimate,
+}
+
+impl FlowGroupBy {
+    /// Parse a group-by field from a string.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "label" | "labels" => Some(Self::Label),
+            "estimate" | "estimates" => Some(Self::Estimate),
+            _ => None,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Label => "label",
+            Self::Estimate => "estimate",
+        }
+    }
+}
+
+/// Options for the flow report.
+#[derive(Debug, Clone)]
+pub struct FlowReportOptions {
+    /// Team key (e.g., "ENG"). Uses the current team if not specified.
+    pub team: Option<String>,
+    /// Only include issues completed on or after this date (YYYY-MM-DD).
+    pub since: String,
+    /// Only include issues completed on or before this date (YYYY-MM-DD).
+    pub until: Option<String>,
+    /// Optional grouping for per-group statistics.
+    pub group_by: Option<FlowGroupBy>,
+    /// Maximum number of completed issues to analyze.
+    pub limit: u32,
+}
+
+/// Generate a flow report (lead time, cycle time, time in state) for a team.
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the API request
+/// * `options` - Report options (team, date range, grouping)
+/// * `use_cache` - Whether to use cached workflow state types
+/// * `format` - The output format (Human or Json)
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use lin::api::GraphQLClient;
+/// use lin::commands::report::flow::{flow_report, FlowReportOptions};
+/// use lin::output::OutputFormat;
+///
+/// let client = GraphQLClient::new("lin_api_xxxxx");
+/// let options = FlowReportOptions {
+///     team: Some("ENG".to_string()),
+///     since: "2026-07-01".to_string(),
+///     until: None,
+///     group_by: None,
+///     limit: 250,
+/// };
+/// flow_report(&client, options, true, OutputFormat::Human)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn flow_report(
+    client: &GraphQLClient,
+    options: FlowReportOptions,
+    use_cache: bool,
+    format: OutputFormat,
+) -> Result<()> {
+    let team_key = resolvers::resolve_team_key_or_current(options.team.as_deref())?;
+    let since = parse_report_date(&options.since, "--since")?;
+    let until = options
+        .until
+        .as_deref()
+        .map(|u| parse_report_date(u, "--until"))
+        .transpose()?;
+
+    if let Some(until) = until {
+        if until < since {
+            return Err(LinError::parse("--until must not be before --since"));
+        }
+    }
+
+    let state_types = resolvers::resolve_state_types(client, &team_key, use_cache)?;
+
+    let mut completed_at = serde_json::json!({ "gte": since.format("%Y-%m-%d").to_string() });
+    if let Some(until) = until {
+        // Include the whole `until` day
+        let end = until + Duration::days(1);
+        completed_at["lt"] = serde_json::json!(end.format("%Y-%m-%d").to_string());
+    }
+
+    let variables = serde_json::json!({
+        "first": options.limit,
+        "historyFirst": HISTORY_PER_ISSUE,
+        "filter": {
+            "team": { "key": { "eq": team_key } },
+            "completedAt": completed_at
+        }
+    });
+    let response: FlowIssuesResponse = client.query(FLOW_ISSUES_QUERY, variables)?;
+
+    let flows: Vec<IssueFlow> = response
+        .issues
+        .nodes
+        .iter()
+        .filter_map(|issue| compute_issue_flow(issue, &state_types))
+        .collect();
+
+    let report = build_flow_report(
+        team_key,
+        since.format("%Y-%m-%d").to_string(),
+        until.map(|u| u.format("%Y-%m-%d").to_string()),
+        flows,
+        options.group_by,
+    );
+    output(&report, format);
+    Ok(())
+}
+
+// =============================================================================
+// Report types
+// =============================================================================
+
+/// Time an issue spent in a single workflow state.
+#[derive(Debug, Clone, Serialize)]
+pub struct StateDuration {
+    /// Workflow state name.
+    pub state: String,
+    /// Workflow state type (backlog, unstarted, started, ...).
+    pub state_type: String,
+    /// Hours spent in the state.
+    pub hours: f64,
+}
+
+/// Flow metrics for a single completed issue.
+#[derive(Debug, Clone, Serialize)]
+pub struct IssueFlow {
+    /// Human-readable identifier (e.g., "ENG-123").
+    pub identifier: String,
+    /// Issue title.
+    pub title: String,
+    /// Estimate value.
+    pub estimate: Option<f64>,
+    /// Label names.
+    pub labels: Vec<String>,
+    /// ISO 8601 timestamp of completion.
+    pub completed_at: String,
+    /// Hours from creation to completion.
+    pub lead_time_hours: f64,
+    /// Hours from first started state to completion (None if never started).
+    pub cycle_time_hours: Option<f64>,
+    /// Time spent in each state, in order of first entry.
+    pub time_in_state: Vec<StateDuration>,
+}
+
+/// Summary statistics for a set of durations, in hours.
+#[derive(Debug, Clone, Serialize)]
+pub struct DurationStats {
+    /// Number of values.
+    pub count: usize,
+    /// Arithmetic mean.
+    pub mean_hours: f64,
+    /// Minimum value.
+    pub min_hours: f64,
+    /// 50th percentile (median).
+    pub p50_hours: f64,
+    /// 75th percentile.
+    pub p75_hours: f64,
+    /// 90th percentile.
+    pub p90_hours: f64,
+    /// 95th percentile.
+    pub p95_hours: f64,
+    /// Maximum value.
+    pub max_hours: f64,
+}
+
+impl DurationStats {
+    /// Compute statistics for the given values. Returns None for an empty set.
+    fn from_values(values: &[f64]) -> Option<Self> {
+        if values.is_empty() {
+            return None;
+        }
+        let mut sorted = values.to_vec();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        Some(Self {
+            count: sorted.len(),
+            mean_hours: sorted.iter().sum::<f64>() / sorted.len() as f64,
+            min_hours: sorted[0],
+            p50_hours: percentile(&sorted, 50.0),
+            p75_hours: percentile(&sorted, 75.0),
+            p90_hours: percentile(&sorted, 90.0),
+            p95_hours: percentile(&sorted, 95.0),
+            max_hours: sorted[sorted.len() - 1],
+        })
+    }
+}
+
+/// Aggregate time spent in a workflow state across all issues.
+#[derive(Debug, Clone, Serialize)]
+pub struct StateTime {
+    /// Workflow state name.
+    pub state: String,
+    /// Workflow state type.
+    pub state_type: String,
+    /// Number of issues that spent time in this state.
+    pub issue_count: usize,
+    /// Total hours across all issues.
+    pub total_hours: f64,
+    /// Mean hours per issue that passed through the state.
+    pub mean_hours: f64,
+}
+
+/// A cycle time histogram bucket.
+#[derive(Debug, Clone, Serialize)]
+pub struct HistogramBucket {
+    /// Bucket label (e.g., "2-4d").
+    pub label: String,
+    /// Number of issues in the bucket.
+    pub count: usize,
+}
+
+/// Flow statistics for a group of issues.
+#[derive(Debug, Clone, Serialize)]
+pub struct FlowGroup {
+    /// Group name (label name or estimate value).
+    pub name: String,
+    /// Number of issues in the group.
+    pub count: usize,
+    /// Lead time statistics.
+    pub lead_time: Option<DurationStats>,
+    /// Cycle time statistics.
+    pub cycle_time: Option<DurationStats>,
+}
+
+/// The complete flow report.
+#[derive(Debug, Clone, Serialize)]
+pub struct FlowReport {
+    /// Team key.
+    pub team: String,
+    /// Start of the completion window (YYYY-MM-DD).
+    pub since: String,
+    /// End of the completion window (YYYY-MM-DD), if bounded.
+    pub until: Option<String>,
+    /// Number of completed issues analyzed.
+    pub issue_count: usize,
+    /// Lead time statistics.
+    pub lead_time: Option<DurationStats>,
+    /// Cycle time statistics.
+    pub cycle_time: Option<DurationStats>,
+    /// Time spent in each workflow state.
+    pub time_in_state: Vec<StateTime>,
+    /// Cycle time histogram.
+    pub histogram: Vec<HistogramBucket>,
+    /// Cycle time above which an issue counts as an outlier.
+    pub outlier_threshold_hours: Option<f64>,
+    /// Issues with unusually long cycle times, longest first.
+    pub outliers: Vec<IssueFlow>,
+    /// Grouping used for `groups` (label or estimate).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_by: Option<String>,
+    /// Per-group statistics.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub groups: Vec<FlowGroup>,
+    /// Per-issue metrics.
+    pub issues: Vec<IssueFlow>,
+}
+
+// =============================================================================
+// Computation
+// =============================================================================
+
+fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .ok()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+fn hours_between(start: DateTime<Utc>, end: DateTime<Utc>) -> f64 {
+    ((end - start).num_seconds() as f64 / 3600.0).max(0.0)
+}
+
+/// Sort key for workflow state types, following Linear's board order.
+fn state_type_order(state_type: &str) -> u8 {
+    match state_type {
+        "triage" => 0,
+        "backlog" => 1,
+        "unstarted" => 2,
+        "started" => 3,
+        "completed" => 4,
+        "canceled" => 5,
+        _ => 6,
+    }
+}
+
+/// Compute flow metrics for a completed issue.
+///
+/// State types come from the team's cached workflow states (`state_types`,
+/// keyed by lowercase state name), falling back to the type recorded in the
+/// history entry for states that no longer exist.
+///
+/// Returns None if the issue isn't completed or has unparseable timestamps.
+pub fn compute_issue_flow(
+    issue: &FlowIssue,
+    state_types: &HashMap<String, String>,
+) -> Option<IssueFlow> {
+    let created = parse_timestamp(&issue.created_at)?;
+    let completed_at = issue.completed_at.as_deref()?;
+    let completed = parse_timestamp(completed_at)?;
+
+    let type_of = |state: &WorkflowState| {
+        state_types
+            .get(&state.name.to_lowercase())
+            .cloned()
+            .unwrap_or_else(|| state.type_.clone())
+    };
+
+    let mut transitions: Vec<(DateTime<Utc>, Option<&WorkflowState>, &WorkflowState)> = issue
+        .history
+        .nodes
+        .iter()
+        .filter_map(|entry| {
+            let to = entry.to_state.as_ref()?;
+            Some((
+                parse_timestamp(&entry.created_at)?,
+                entry.from_state.as_ref(),
+                to,
+            ))
+        })
+        .collect();
+    transitions.sort_by_key(|(at, _, _)| *at);
+
+    let mut time_in_state: Vec<StateDuration> = Vec::new();
+    let mut add_time = |state: &str, state_type: &str, hours: f64| {
+        if hours <= 0.0 {
+            return;
+        }
+        match time_in_state.iter_mut().find(|d| d.state == state) {
+            Some(existing) => existing.hours += hours,
+            None => time_in_state.push(StateDuration {
+                state: state.to_string(),
+                state_type: state_type.to_string(),
+                hours,
+            }),
+        }
+    };
+
+    // The issue starts in the first transition's source state
+    let mut current: Option<(String, String)> = transitions
+        .first()
+        .and_then(|(_, from, _)| *from)
+        .map(|s| (s.name.clone(), type_of(s)));
+    let mut entered = created;
+    let mut started: Option<DateTime<Utc>> = None;
+
+    for (at, _, to) in &transitions {
+        // Ignore anything after completion (e.g., reopened then re-closed later)
+        let at = (*at).clamp(created, completed);
+        if let Some((name, state_type)) = &current {
+            add_time(name, state_type, hours_between(entered, at));
+        }
+        let to_type = type_of(to);
+        if started.is_none() && to_type == "started" {
+            started = Some(at);
+        }
+        current = Some((to.name.clone(), to_type));
+        entered = at;
+    }
+    if let Some((name, state_type)) = &current {
+        add_time(name, state_type, hours_between(entered, completed));
+    }
+
+    // Fall back to Linear's startedAt when history doesn't show a started transition
+    let started = started.or_else(|| issue.started_at.as_deref().and_then(parse_timestamp));
+
+    Some(IssueFlow {
+        identifier: issue.identifier.clone(),
+        title: issue.title.clone(),
+        estimate: issue.estimate,
+        labels: issue.labels.nodes.iter().map(|l| l.name.clone()).collect(),
+        completed_at: completed_at.to_string(),
+        lead_time_hours: hours_between(created, completed),
+        cycle_time_hours: started
+            .filter(|s| *s <= completed)
+            .map(|s| hours_between(s, completed)),
+        time_in_state,
+    })
+}
+
+fn format_estimate_group(estimate: Option<f64>) -> String {
+    match estimate {
+        Some(value) if value.fract() == 0.0 => format!("{}", value as i64),
+        Some(value) => format!("{}", value),
+        None => "(no estimate)".to_string(),
+    }
+}
+
+fn build_groups(flows: &[IssueFlow], group_by: FlowGroupBy) -> Vec<FlowGroup> {
+    let mut grouped: BTreeMap<String, Vec<&IssueFlow>> = BTreeMap::new();
+    for flow in flows {
+        match group_by {
+            FlowGroupBy::Label if flow.labels.is_empty() => {
+                grouped
+                    .entry("(no label)".to_string())
+                    .or_default()
+                    .push(flow);
+            }
+            FlowGroupBy::Label => {
+                for label in &flow.labels {
+                    grouped.entry(label.clone()).or_default().push(flow);
+                }
+            }
+            FlowGroupBy::Estimate => {
+                grouped
+                    .entry(format_estimate_group(flow.estimate))
+                    .or_default()
+                    .push(flow);
+            }
+        }
+    }
+
+    let mut groups: Vec<FlowGroup> = grouped
+        .into_iter()
+        .map(|(name, members)| {
+            let lead: Vec<f64> = members.iter().map(|f| f.lead_time_hours).collect();
+            let cycle: Vec<f64> = members.iter().filter_map(|f| f.cycle_time_hours).collect();
+            FlowGroup {
+                name,
+                count: members.len(),
+                lead_time: DurationStats::from_values(&lead),
+                cycle_time: DurationStats::from_values(&cycle),
+            }
+        })
+        .collect();
+
+    // Order estimate groups numerically rather than lexically
+    if group_by == FlowGroupBy::Estimate {
+        groups.sort_by(|a, b| {
+            let key = |g: &FlowGroup| g.name.parse::<f64>().unwrap_or(f64::INFINITY);
+            key(a)
+                .partial_cmp(&key(b))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+    }
+    groups
+}
+
+/// Aggregate per-issue flow metrics into a report.
+pub fn build_flow_report(
+    team: String,
+    since: String,
+    until: Option<String>,
+    flows: Vec<IssueFlow>,
+    group_by: Option<FlowGroupBy>,
+) -> FlowReport {
+    let lead: Vec<f64> = flows.iter().map(|f| f.lead_time_hours).collect();
+    let cycle: Vec<f64> = flows.iter().filter_map(|f| f.cycle_time_hours).collect();
+
+    // Time in state across all issues
+    let mut states: HashMap<String, StateTime> = HashMap::new();
+    for flow in &flows {
+        for duration in &flow.time_in_state {
+            let entry = states
+                .entry(duration.state.clone())
+                .or_insert_with(|| StateTime {
+                    state: duration.state.clone(),
+                    state_type: duration.state_type.clone(),
+                    issue_count: 0,
+                    total_hours: 0.0,
+                    mean_hours: 0.0,
+                });
+            entry.issue_count += 1;
+            entry.total_hours += duration.hours;
+        }
+    }
+    let mut time_in_state: Vec<StateTime> = states
+        .into_values()
+        .map(|mut s| {
+            s.mean_hours = s.total_hours / s.issue_count as f64;
+            s
+        })
+        .collect();
+    time_in_state.sort_by(|a, b| {
+        state_type_order(&a.state_type)
+            .cmp(&state_type_order(&b.state_type))
+            .then_with(|| a.state.cmp(&b.state))
+    });
+
+    // Cycle time histogram
+    let histogram = HISTOGRAM_BUCKETS
+        .iter()
+        .map(|(label, low, high)| HistogramBucket {
+            label: label.to_string(),
+            count: cycle
+                .iter()
+                .filter(|h| {
+                    let days = **h / 24.0;
+                    days >= *low && days < *high
+                })
+                .count(),
+        })
+        .collect();
+
+    // Outliers: cycle time above Q3 + 1.5 * IQR (needs a few data points to be meaningful)
+    let outlier_threshold_hours = if cycle.len() >= 4 {
+        let mut sorted = cycle.clone();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        let q1 = percentile(&sorted, 25.0);
+        let q3 = percentile(&sorted, 75.0);
+        Some(q3 + 1.5 * (q3 - q1))
+    } else {
+        None
+    };
+    let mut outliers: Vec<IssueFlow> = match outlier_threshold_hours {
+        Some(threshold) => flows
+            .iter()
+            .filter(|f| f.cycle_time_hours.is_some_and(|h| h > threshold))
+            .cloned()
+            .collect(),
+        None => Vec::new(),
+    };
+    outliers.sort_by(|a, b| {
+        b.cycle_time_hours
+            .partial_cmp(&a.cycle_time_hours)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    let groups = group_by
+        .map(|g| build_groups(&flows, g))
+        .unwrap_or_default();
+
+    FlowReport {
+        team,
+        since,
+        until,
+        issue_count: flows.len(),
+        lead_time: DurationStats::from_values(&lead),
+        cycle_time: DurationStats::from_values(&cycle),
+        time_in_state,
+        histogram,
+        outlier_threshold_hours,
+        outliers,
+        group_by: group_by.map(|g| g.as_str().to_string()),
+        groups,
+        issues: flows,
+    }
+}
+
+// =============================================================================
+// Human output
+// =============================================================================
+
+fn stats_row(label: &str, stats: &Option<DurationStats>) -> String {
+    match stats {
+        Some(s) => format!(
+            "  {:<12} {:>7} {:>7} {:>7} {:>7} {:>7} {:>7}",
+            label,
+            format_hours(s.p50_hours),
+            format_hours(s.p75_hours),
+            format_hours(s.p90_hours),
+            format_hours(s.p95_hours),
+            format_hours(s.mean_hours),
+            format_hours(s.max_hours)
+        ),
+        None => format!("  {:<12} {}", label, "no data".dimmed()),
+    }
+}
+
+impl HumanDisplay for FlowReport {
+    fn human_fmt(&self) -> String {
+        let window = match &self.until {
+            Some(until) => format!("{} to {}", self.since, until),
+            None => format!("since {}", self.since),
+        };
+        let mut parts = vec![format!(
+            "{} {} ({}): {} completed issues",
+            "Flow report for".bold(),
+            self.team.bold().cyan(),
+            window,
+            self.issue_count
+        )];
+
+        if self.issue_count == 0 {
+            parts.push("  No issues completed in this window.".dimmed().to_string());
+            return parts.join("\n");
+        }
+
+        parts.push(String::new());
+        parts.push(
+            format!(
+                "  {:<12} {:>7} {:>7} {:>7} {:>7} {:>7} {:>7}",
+                "", "p50", "p75", "p90", "p95", "mean", "max"
+            )
+            .dimmed()
+            .to_string(),
+        );
+        parts.push(stats_row("Lead time", &self.lead_time));
+        parts.push(stats_row("Cycle time", &self.cycle_time));
+
+        if !self.time_in_state.is_empty() {
+            parts.push(String::new());
+            parts.push(format!("  {}", "Time in state".bold()));
+            let width = self
+                .time_in_state
+                .iter()
+                .map(|s| s.state.chars().count())
+                .max()
+                .unwrap_or(0);
+            for state in &self.time_in_state {
+                parts.push(format!(
+                    "    {:<width$}  {:<10} {:>3} issues  avg {:>7}  total {:>7}",
+                    state.state,
+                    state.state_type.dimmed(),
+                    state.issue_count,
+                    format_hours(state.mean_hours),
+                    format_hours(state.total_hours),
+                    width = width
+                ));
+            }
+        }
+
+        let max_count = self.histogram.iter().map(|b| b.count).max().unwrap_or(0);
+        if max_count > 0 {
+            parts.push(String::new());
+            parts.push(format!("  {}", "Cycle time distribution".bold()));
+            for bucket in &self.histogram {
+                // Scale bars to at most 40 characters
+                let bar_len = (bucket.count * 40).div_ceil(max_count);
+                parts.push(format!(
+                    "    {:<5} {} {}",
+                    bucket.label,
+                    "█".repeat(bar_len).cyan(),
+                    bucket.count
+                ));
+            }
+        }
+
+        if let Some(threshold) = self.outlier_threshold_hours {
+            if !self.outliers.is_empty() {
+                parts.push(String::new());
+                parts.push(format!(
+                    "  {} (cycle time > {})",
+                    "Outliers".bold(),
+                    format_hours(threshold)
+                ));
+                for flow in &self.outliers {
+                    parts.push(format!(
+                        "    {} {} {}",
+                        flow.identifier.cyan(),
+                        format_hours(flow.cycle_time_hours.unwrap_or_default()).yellow(),
+                        flow.title
+                    ));
+                }
+            }
+        }
+
+        if let Some(group_by) = &self.group_by {
+            parts.push(String::new());
+            parts.push(format!("  {}", format!("By {}", group_by).bold()));
+            let width = self
+                .groups
+                .iter()
+                .map(|g| g.name.chars().count())
+                .max()
+                .unwrap_or(0);
+            for group in &self.groups {
+                let cycle = group
+                    .cycle_time
+                    .as_ref()
+                    .map(|s| {
+                        format!(
+                            "cycle p50 {} p90 {}",
+                            format_hours(s.p50_hours),
+                            format_hours(s.p90_hours)
+                        )
+                    })
+                    .unwrap_or_else(|| "cycle n/a".to_string());
+                let lead = group
+                    .lead_time
+                    .as_ref()
+                    .map(|s| format!("lead p50 {}", format_hours(s.p50_hours)))
+                    .unwrap_or_default();
+                parts.push(format!(
+                    "    {:<width$} {:>3} issues  {}  {}",
+                    group.name,
+                    group.count,
+                    cycle,
+                    lead,
+                    width = width
+                ));
+            }
+        }
+
+        parts.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn flow_issue(json: &str) -> FlowIssue {
+        serde_json::from_str(json).unwrap()
+    }
+
+    fn state(name: &str, type_: &str) -> String {
+        format!(
+            r##"{{ "id": "{}", "name": "{}", "color": "#000", "type": "{}" }}"##,
+            name.to_lowercase(),
+            name,
+            type_
+        )
+    }
+
+    fn transition(at: &str, from: &str, to: &str) -> String {
+        format!(
+            r#"{{ "id": "h-{}", "createdAt": "{}", "fromState": {}, "toState": {} }}"#,
+            at, at, from, to
+        )
+    }
+
+    fn sample_issue() -> FlowIssue {
+        let todo = state("Todo", "unstarted");
+        let doing = state("In Progress", "started");
+        let review = state("In Review", "started");
+        let done = state("Done", "completed");
+        flow_issue(&format!(
+            r#"{{
+                "id": "issue-1",
+                "identifier": "ENG-1",
+                "title": "Fix login",
+                "estimate": 3,
+                "createdAt": "2024-01-01T00:00:00.000Z",
+                "startedAt": "2024-01-02T00:00:00.000Z",
+                "completedAt": "2024-01-05T00:00:00.000Z",
+                "labels": {{ "nodes": [{{ "id": "l1", "name": "bug" }}] }},
+                "history": {{ "nodes": [{}, {}, {}] }}
+            }}"#,
+            // Deliberately out of order: history is returned newest first
+            transition("2024-01-05T00:00:00.000Z", &review, &done),
+            transition("2024-01-02T00:00:00.000Z", &todo, &doing),
+            transition("2024-01-04T00:00:00.000Z", &doing, &review),
+        ))
+    }
+
+    fn simple_flow(id: &str, lead_days: f64, cycle_days: Option<f64>) -> IssueFlow {
+        IssueFlow {
+            identifier: id.to_string(),
+            title: format!("Issue {}", id),
+            estimate: None,
+            labels: Vec::new(),
+            completed_at: "2024-01-10T00:00:00.000Z".to_string(),
+            lead_time_hours: lead_days * 24.0,
+            cycle_time_hours: cycle_days.map(|d| d * 24.0),
+            time_in_state: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_compute_issue_flow() {
+        let flow = compute_issue_flow(&sample_issue(), &HashMap::new()).unwrap();
+        assert_eq!(flow.lead_time_hours, 96.0);
+        assert_eq!(flow.cycle_time_hours, Some(72.0));
+
+        let times: Vec<(&str, f64)> = flow
+            .time_in_state
+            .iter()
+            .map(|d| (d.state.as_str(), d.hours))
+            .collect();
+        assert_eq!(
+            times,
+            vec![("Todo", 24.0), ("In Progress", 48.0), ("In Review", 24.0)]
+        );
+    }
+
+    #[test]
+    fn test_compute_issue_flow_uses_cached_state_types() {
+        // The cached team config treats "In Progress" as unstarted, so cycle time starts at review
+        let mut state_types = HashMap::new();
+        state_types.insert("in progress".to_string(), "unstarted".to_string());
+        state_types.insert("in review".to_string(), "started".to_string());
+
+        let flow = compute_issue_flow(&sample_issue(), &state_types).unwrap();
+        assert_eq!(flow.cycle_time_hours, Some(24.0));
+        assert_eq!(flow.time_in_state[1].state_type, "unstarted");
+    }
+
+    #[test]
+    fn test_compute_issue_flow_not_completed() {
+        let mut issue = sample_issue();
+        issue.completed_at = None;
+        assert!(compute_issue_flow(&issue, &HashMap::new()).is_none());
+    }
+
+    #[test]
+    fn test_compute_issue_flow_without_history_falls_back_to_started_at() {
+        let mut issue = sample_issue();
+        issue.history.nodes.clear();
+        let flow = compute_issue_flow(&issue, &HashMap::new()).unwrap();
+        assert_eq!(flow.cycle_time_hours, Some(72.0));
+        assert!(flow.time_in_state.is_empty());
+    }
+
+    #[test]
+    fn test_build_flow_report_stats_and_outliers() {
+        let flows = vec![
+            simple_flow("ENG-1", 2.0, Some(1.0)),
+            simple_flow("ENG-2", 3.0, Some(1.5)),
+            simple_flow("ENG-3", 4.0, Some(2.0)),
+            simple_flow("ENG-4", 5.0, Some(2.5)),
+            simple_flow("ENG-5", 40.0, Some(30.0)),
+            simple_flow("ENG-6", 1.0, None),
+        ];
+        let report = build_flow_report("ENG".into(), "2024-01-01".into(), None, flows, None);
+
+        assert_eq!(report.issue_count, 6);
+        assert_eq!(report.lead_time.as_ref().unwrap().count, 6);
+        let cycle = report.cycle_time.as_ref().unwrap();
+        assert_eq!(cycle.count, 5);
+        assert_eq!(cycle.p50_hours, 48.0);
+        assert_eq!(cycle.max_hours, 720.0);
+
+        assert_eq!(report.outliers.len(), 1);
+        assert_eq!(report.outliers[0].identifier, "ENG-5");
+
+        let counts: Vec<usize> = report.histogram.iter().map(|b| b.count).collect();
+        assert_eq!(counts, vec![0, 2, 2, 0, 0, 0, 1]);
+        assert!(report.groups.is_empty());
+    }
+
+    #[test]
+    fn test_build_flow_report_groups() {
+        let mut a = simple_flow("ENG-1", 2.0, Some(1.0));
+        a.labels = vec!["bug".into(), "ui".into()];
+        a.estimate = Some(3.0);
+        let mut b = simple_flow("ENG-2", 4.0, Some(2.0));
+        b.labels = vec!["bug".into()];
+        b.estimate = Some(13.0);
+        let c = simple_flow("ENG-3", 6.0, None);
+
+        let flows = vec![a, b, c];
+        let by_label = build_flow_report(
+            "ENG".into(),
+            "2024-01-01".into(),
+            None,
+            flows.clone(),
+            Some(FlowGroupBy::Label),
+        );
+        let names: Vec<(&str, usize)> = by_label
+            .groups
+            .iter()
+            .map(|g| (g.name.as_str(), g.count))
+            .collect();
+        assert_eq!(names, vec![("(no label)", 1), ("bug", 2), ("ui", 1)]);
+
+        let by_estimate = build_flow_report(
+            "ENG".into(),
+            "2024-01-01".into(),
+            None,
+            flows,
+            Some(FlowGroupBy::Estimate),
+        );
+        let names: Vec<&str> = by_estimate.groups.iter().map(|g| g.name.as_str()).collect();
+        assert_eq!(names, vec!["3", "13", "(no estimate)"]);
+    }
+
+    #[test]
+    fn test_flow_report_human_display() {
+        let flow = compute_issue_flow(&sample_issue(), &HashMap::new()).unwrap();
+        let report = build_flow_report(
+            "ENG".into(),
+            "2024-01-01".into(),
+            Some("2024-01-31".into()),
+            vec![flow],
+            Some(FlowGroupBy::Label),
+        );
+        let output = report.human_fmt();
+        assert!(output.contains("ENG"));
+        assert!(output.contains("2024-01-01 to 2024-01-31"));
+        assert!(output.contains("Lead time"));
+        assert!(output.contains("4.0d"));
+        assert!(output.contains("In Progress"));
+        assert!(output.contains("Cycle time distribution"));
+        assert!(output.contains("By label"));
+        assert!(output.contains("bug"));
+    }
+
+    #[test]
+    fn test_flow_report_human_display_empty() {
+        let report = build_flow_report("ENG".into(), "2024-01-01".into(), None, vec![], None);
+        assert!(report.human_fmt().contains("No issues completed"));
+    }
+
+    #[test]
+    fn test_flow_group_by_parse() {
+        assert_eq!(FlowGroupBy::parse("label"), Some(FlowGroupBy::Label));
+        assert_eq!(FlowGroupBy::parse("Estimate"), Some(FlowGroupBy::Estimate));
+        assert_eq!(FlowGroupBy::parse("assignee"), None);
+    }
+
+    #[test]
+    fn test_flow_report_api() {
+        let mut server = mockito::Server::new();
+
+        let team_mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::Regex("query TeamByKey".to_string()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"data": {"teams": {"nodes": [{"id": "team-1", "key": "ENG", "name": "Engineering", "issueEstimationType": null}]}}}"#,
+            )
+            .create();
+        let states_mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::Regex("query WorkflowStates".to_string()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r##"{"data": {"team": {"id": "team-1", "states": {"nodes": [{"id": "s1", "name": "In Progress", "color": "#ff0", "type": "started"}]}}}}"##,
+            )
+            .create();
+        let issues_mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::Regex("query FlowIssues".to_string()),
+                mockito::Matcher::PartialJsonString(
+                    r#"{"variables": {"filter": {"completedAt": {"gte": "2024-01-01", "lt": "2024-02-01"}}}}"#
+                        .to_string(),
+                ),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"data": {"issues": {"nodes": []}}}"#)
+            .create();
+
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let options = FlowReportOptions {
+            team: Some("ENG".to_string()),
+            since: "2024-01-01".to_string(),
+            until: Some("2024-01-31".to_string()),
+            group_by: None,
+            limit: 50,
+        };
+        let result = flow_report(&client, options, false, OutputFormat::Json);
+        assert!(result.is_ok());
+        team_mock.assert();
+        states_mock.assert();
+        issues_mock.assert();
+    }
+
+    #[test]
+    fn test_flow_report_invalid_range() {
+        let server = mockito::Server::new();
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let options = FlowReportOptions {
+            team: Some("ENG".to_string()),
+            since: "2024-02-01".to_string(),
+            until: Some("2024-01-01".to_string()),
+            group_by: None,
+            limit: 50,
+        };
+        let result = flow_report(&client, options, false, OutputFormat::Human);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("--until"));
+    }
+}
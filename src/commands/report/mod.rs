@@ -0,0 +1,78 @@
+//! Reporting commands.
+//!
+//! Commands that aggregate issue data into team-level reports
+//! (flow analytics, velocity).
+
+pub mod flow;
+pub mod velocity;
+
+use chrono::NaiveDate;
+
+use crate::Result;
+use crate::error::LinError;
+
+/// Parse a report date in YYYY-MM-DD format.
+pub(crate) fn parse_report_date(value: &str, flag: &str) -> Result<NaiveDate> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(|_| {
+        LinError::parse(format!(
+            "Invalid {} date '{}'. Expected YYYY-MM-DD",
+            flag, value
+        ))
+    })
+}
+
+/// Format a duration in hours for display (e.g., "5.0h", "3.2d").
+pub(crate) fn format_hours(hours: f64) -> String {
+    if hours < 24.0 {
+        format!("{:.1}h", hours)
+    } else {
+        format!("{:.1}d", hours / 24.0)
+    }
+}
+
+/// Compute a percentile of sorted values using linear interpolation.
+///
+/// `p` is in the range 0.0..=100.0. Returns 0.0 for an empty slice.
+pub(crate) fn percentile(sorted: &[f64], p: f64) -> f64 {
+    match sorted.len() {
+        0 => 0.0,
+        1 => sorted[0],
+        n => {
+            let rank = (p / 100.0).clamp(0.0, 1.0) * (n - 1) as f64;
+            let lower = rank.floor() as usize;
+            let upper = rank.ceil() as usize;
+            let weight = rank - lower as f64;
+            sorted[lower] + (sorted[upper] - sorted[lower]) * weight
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percentile() {
+        let values = [1.0, 2.0, 3.0, 4.0, 5.0];
+        assert_eq!(percentile(&values, 0.0), 1.0);
+        assert_eq!(percentile(&values, 50.0), 3.0);
+        assert_eq!(percentile(&values, 100.0), 5.0);
+        assert_eq!(percentile(&values, 75.0), 4.0);
+        assert_eq!(percentile(&[1.0, 2.0], 50.0), 1.5);
+        assert_eq!(percentile(&[], 50.0), 0.0);
+        assert_eq!(percentile(&[7.0], 90.0), 7.0);
+    }
+
+    #[test]
+    fn test_format_hours() {
+        assert_eq!(format_hours(5.0), "5.0h");
+        assert_eq!(format_hours(36.0), "1.5d");
+    }
+
+    #[test]
+    fn test_parse_report_date() {
+        assert!(parse_report_date("2026-07-01", "--since").is_ok());
+        let err = parse_report_date("07/01/2026", "--since").unwrap_err();
+        assert!(err.to_string().contains("Invalid --since date"));
+    }
+}
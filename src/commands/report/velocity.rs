@@ -0,0 +1,580 @@
+//! Velocity report: completed points, carry-over and scope change per cycle.
+
+use chrono::{DateTime, Duration, Utc};
+use colored::Colorize;
+use serde::Serialize;
+
+use crate::Result;
+use crate::api::GraphQLClient;
+use crate::api::queries::report::VELOCITY_CYCLES_QUERY;
+use crate::commands::resolvers;
+use crate::error::LinError;
+use crate::models::{VelocityCycle, VelocityCyclesResponse};
+use crate::output::{HumanDisplay, OutputFormat, output};
+
+/// Longest cycle duration Linear allows, used to bound the cycle query.
+const MAX_CYCLE_WEEKS: i64 = 8;
+
+/// Number of issues fetched per cycle.
+const ISSUES_PER_CYCLE: u32 = 250;
+
+/// Characters used to draw sparklines, lowest to highest.
+const SPARK_CHARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Options for the velocity report.
+#[derive(Debug, Clone)]
+pub struct VelocityReportOptions {
+    /// Team key (e.g., "ENG"). Uses the current team if not specified.
+    pub team: Option<String>,
+    /// Number of most recent cycles to include.
+    pub cycles: u32,
+    /// Print the report as CSV instead of a table.
+    pub csv: bool,
+}
+
+/// Generate a velocity report across a team's most recent cycles.
+///
+/// Includes every cycle that has started, including the active one. The
+/// active cycle is shown but excluded from the averages.
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the API request
+/// * `options` - Report options (team, number of cycles, CSV output)
+/// * `format` - The output format (Human or Json); ignored for CSV output
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use lin::api::GraphQLClient;
+/// use lin::commands::report::velocity::{velocity_report, VelocityReportOptions};
+/// use lin::output::OutputFormat;
+///
+/// let client = GraphQLClient::new("lin_api_xxxxx");
+/// let options = VelocityReportOptions {
+///     team: Some("ENG".to_string()),
+///     cycles: 6,
+///     csv: false,
+/// };
+/// velocity_report(&client, options, OutputFormat::Human)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn velocity_report(
+    client: &GraphQLClient,
+    options: VelocityReportOptions,
+    format: OutputFormat,
+) -> Result<()> {
+    if options.cycles == 0 {
+        return Err(LinError::parse("--cycles must be at least 1"));
+    }
+    let team_key = resolvers::resolve_team_key_or_current(options.team.as_deref())?;
+    let now = Utc::now();
+
+    // Cycles are contiguous and at most MAX_CYCLE_WEEKS long, so the N most
+    // recent started cycles all begin within this window.
+    let window_start = now - Duration::weeks(MAX_CYCLE_WEEKS * options.cycles as i64);
+    let variables = serde_json::json!({
+        "first": (options.cycles * MAX_CYCLE_WEEKS as u32).min(250),
+        "issuesFirst": ISSUES_PER_CYCLE,
+        "filter": {
+            "team": { "key": { "eq": team_key } },
+            "startsAt": {
+                "gte": window_start.to_rfc3339(),
+                "lte": now.to_rfc3339()
+            }
+        }
+    });
+    let response: VelocityCyclesResponse = client.query(VELOCITY_CYCLES_QUERY, variables)?;
+
+    let report = build_velocity_report(
+        team_key,
+        response.cycles.nodes,
+        options.cycles as usize,
+        now,
+    );
+    if options.csv {
+        print!("{}", report.to_csv());
+    } else {
+        output(&report, format);
+    }
+    Ok(())
+}
+
+/// Velocity metrics for a single cycle.
+#[derive(Debug, Clone, Serialize)]
+pub struct CycleVelocity {
+    /// The cycle's number within the team.
+    pub number: i32,
+    /// Optional cycle name.
+    pub name: Option<String>,
+    /// ISO 8601 timestamp of when the cycle starts.
+    pub starts_at: String,
+    /// ISO 8601 timestamp of when the cycle ends.
+    pub ends_at: String,
+    /// Whether the cycle is still in progress.
+    pub active: bool,
+    /// Scope (points) at the start of the cycle.
+    pub committed_points: f64,
+    /// Points added after the cycle started.
+    pub scope_added_points: f64,
+    /// Scope (points) at the end of the cycle (or today, if active).
+    pub final_scope_points: f64,
+    /// Estimate points of completed issues.
+    pub completed_points: f64,
+    /// Number of completed issues.
+    pub completed_issues: usize,
+    /// Number of issues left open (rolled over, or still open if active).
+    pub carry_over_issues: usize,
+    /// Estimate points of carried-over issues.
+    pub carry_over_points: f64,
+    /// Completed points as a fraction of final scope.
+    pub completion_ratio: Option<f64>,
+    /// Completed points as a fraction of the initial commitment.
+    pub commitment_ratio: Option<f64>,
+}
+
+/// The complete velocity report.
+#[derive(Debug, Clone, Serialize)]
+pub struct VelocityReport {
+    /// Team key.
+    pub team: String,
+    /// Cycles in chronological order.
+    pub cycles: Vec<CycleVelocity>,
+    /// Mean completed points across closed cycles.
+    pub average_velocity: Option<f64>,
+    /// Mean completion ratio across closed cycles.
+    pub average_completion_ratio: Option<f64>,
+    /// Mean carry-over issues across closed cycles.
+    pub average_carry_over: Option<f64>,
+}
+
+fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .ok()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+fn ratio(numerator: f64, denominator: f64) -> Option<f64> {
+    (denominator > 0.0).then(|| numerator / denominator)
+}
+
+fn mean(values: impl Iterator<Item = f64>) -> Option<f64> {
+    let values: Vec<f64> = values.collect();
+    (!values.is_empty()).then(|| values.iter().sum::<f64>() / values.len() as f64)
+}
+
+/// Compute velocity metrics for a cycle.
+pub fn compute_cycle_velocity(cycle: &VelocityCycle, now: DateTime<Utc>) -> CycleVelocity {
+    let ends_at = parse_timestamp(&cycle.ends_at);
+    let active = cycle.completed_at.is_none() && ends_at.is_none_or(|end| end > now);
+
+    let completed: Vec<_> = cycle
+        .issues
+        .nodes
+        .iter()
+        .filter(|i| i.completed_at.is_some())
+        .collect();
+    let completed_points: f64 = completed.iter().filter_map(|i| i.estimate).sum();
+
+    // Closed cycles move open issues out, so use the snapshot taken at close
+    let carried: Vec<_> = if active {
+        cycle
+            .issues
+            .nodes
+            .iter()
+            .filter(|i| i.completed_at.is_none() && i.canceled_at.is_none())
+            .collect()
+    } else {
+        cycle
+            .uncompleted_issues_upon_close
+            .nodes
+            .iter()
+            .filter(|i| i.canceled_at.is_none())
+            .collect()
+    };
+    let carry_over_points: f64 = carried.iter().filter_map(|i| i.estimate).sum();
+
+    let committed_points = cycle.scope_history.first().copied().unwrap_or(0.0);
+    let final_scope_points = cycle
+        .scope_history
+        .last()
+        .copied()
+        .unwrap_or(completed_points + carry_over_points);
+    let scope_added_points: f64 = cycle
+        .scope_history
+        .windows(2)
+        .map(|w| (w[1] - w[0]).max(0.0))
+        .sum();
+
+    CycleVelocity {
+        number: cycle.number,
+        name: cycle.name.clone(),
+        starts_at: cycle.starts_at.clone(),
+        ends_at: cycle.ends_at.clone(),
+        active,
+        committed_points,
+        scope_added_points,
+        final_scope_points,
+        completed_points,
+        completed_issues: completed.len(),
+        carry_over_issues: carried.len(),
+        carry_over_points,
+        completion_ratio: ratio(completed_points, final_scope_points),
+        commitment_ratio: ratio(completed_points, committed_points),
+    }
+}
+
+/// Build a velocity report from the most recent `limit` cycles.
+pub fn build_velocity_report(
+    team: String,
+    mut cycles: Vec<VelocityCycle>,
+    limit: usize,
+    now: DateTime<Utc>,
+) -> VelocityReport {
+    cycles.sort_by(|a, b| a.starts_at.cmp(&b.starts_at));
+    let skip = cycles.len().saturating_sub(limit);
+    let cycles: Vec<CycleVelocity> = cycles
+        .iter()
+        .skip(skip)
+        .map(|c| compute_cycle_velocity(c, now))
+        .collect();
+
+    let closed = || cycles.iter().filter(|c| !c.active);
+    VelocityReport {
+        average_velocity: mean(closed().map(|c| c.completed_points)),
+        average_completion_ratio: mean(closed().filter_map(|c| c.completion_ratio)),
+        average_carry_over: mean(closed().map(|c| c.carry_over_issues as f64)),
+        team,
+        cycles,
+    }
+}
+
+/// Render values as a sparkline scaled to the maximum value.
+pub fn sparkline(values: &[f64]) -> String {
+    let max = values.iter().copied().fold(0.0, f64::max);
+    values
+        .iter()
+        .map(|v| {
+            if max <= 0.0 {
+                SPARK_CHARS[0]
+            } else {
+                let index = ((v / max) * (SPARK_CHARS.len() - 1) as f64).round() as usize;
+                SPARK_CHARS[index.min(SPARK_CHARS.len() - 1)]
+            }
+        })
+        .collect()
+}
+
+/// Quote a CSV field if it contains separators, quotes or newlines.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn format_points(points: f64) -> String {
+    if points.fract() == 0.0 {
+        format!("{}", points as i64)
+    } else {
+        format!("{:.1}", points)
+    }
+}
+
+fn format_ratio(ratio: Option<f64>) -> String {
+    ratio
+        .map(|r| format!("{:.0}%", r * 100.0))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+impl VelocityReport {
+    /// Render the report as CSV, one row per cycle.
+    pub fn to_csv(&self) -> String {
+        let mut lines = vec![
+            "cycle,name,starts_at,ends_at,active,committed_points,scope_added_points,final_scope_points,completed_points,completed_issues,carry_over_issues,carry_over_points,completion_ratio,commitment_ratio".to_string(),
+        ];
+        for c in &self.cycles {
+            let ratio = |r: Option<f64>| r.map(|r| format!("{:.3}", r)).unwrap_or_default();
+            lines.push(
+                [
+                    c.number.to_string(),
+                    csv_field(c.name.as_deref().unwrap_or_default()),
+                    c.starts_at.clone(),
+                    c.ends_at.clone(),
+                    c.active.to_string(),
+                    c.committed_points.to_string(),
+                    c.scope_added_points.to_string(),
+                    c.final_scope_points.to_string(),
+                    c.completed_points.to_string(),
+                    c.completed_issues.to_string(),
+                    c.carry_over_issues.to_string(),
+                    c.carry_over_points.to_string(),
+                    ratio(c.completion_ratio),
+                    ratio(c.commitment_ratio),
+                ]
+                .join(","),
+            );
+        }
+        lines.join("\n") + "\n"
+    }
+}
+
+impl HumanDisplay for VelocityReport {
+    fn human_fmt(&self) -> String {
+        let mut parts = vec![format!(
+            "{} {} (last {} cycles)",
+            "Velocity for".bold(),
+            self.team.bold().cyan(),
+            self.cycles.len()
+        )];
+
+        if self.cycles.is_empty() {
+            parts.push("  No started cycles found.".dimmed().to_string());
+            return parts.join("\n");
+        }
+
+        parts.push(String::new());
+        parts.push(
+            format!(
+                "  {:<10} {:>10} {:>9} {:>7} {:>9} {:>7} {:>11} {:>7}",
+                "Cycle", "Committed", "Added", "Scope", "Completed", "Done%", "Carry-over", "Plan%"
+            )
+            .dimmed()
+            .to_string(),
+        );
+        for c in &self.cycles {
+            // Pad before colouring so escape codes don't break alignment
+            let label = format!("{:<10}", format!("#{}", c.number));
+            let label = if c.active {
+                label.yellow().to_string()
+            } else {
+                label
+            };
+            parts.push(format!(
+                "  {} {:>10} {:>9} {:>7} {:>9} {:>7} {:>11} {:>7}{}",
+                label,
+                format_points(c.committed_points),
+                format!("+{}", format_points(c.scope_added_points)),
+                format_points(c.final_scope_points),
+                format_points(c.completed_points),
+                format_ratio(c.completion_ratio),
+                c.carry_over_issues,
+                format_ratio(c.commitment_ratio),
+                if c.active {
+                    format!(" {}", "(active)".dimmed())
+                } else {
+                    String::new()
+                }
+            ));
+        }
+
+        let completed: Vec<f64> = self.cycles.iter().map(|c| c.completed_points).collect();
+        parts.push(String::new());
+        parts.push(format!(
+            "  {}  {}",
+            "Completed".dimmed(),
+            sparkline(&completed).cyan()
+        ));
+        if let Some(velocity) = self.average_velocity {
+            parts.push(format!(
+                "  {}: {} points/cycle",
+                "Average velocity".dimmed(),
+                format_points((velocity * 10.0).round() / 10.0).bold()
+            ));
+        }
+        if let Some(ratio) = self.average_completion_ratio {
+            parts.push(format!(
+                "  {}: {}",
+                "Average completion".dimmed(),
+                format_ratio(Some(ratio))
+            ));
+        }
+        if let Some(carry_over) = self.average_carry_over {
+            parts.push(format!(
+                "  {}: {:.1} issues/cycle",
+                "Average carry-over".dimmed(),
+                carry_over
+            ));
+        }
+
+        parts.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{VelocityIssue, VelocityIssueConnection};
+
+    fn issue(id: &str, estimate: Option<f64>, completed: bool, canceled: bool) -> VelocityIssue {
+        VelocityIssue {
+            id: id.to_string(),
+            identifier: format!("ENG-{}", id),
+            estimate,
+            completed_at: completed.then(|| "2024-01-10T00:00:00.000Z".to_string()),
+            canceled_at: canceled.then(|| "2024-01-10T00:00:00.000Z".to_string()),
+        }
+    }
+
+    fn cycle(number: i32, starts_at: &str, closed: bool) -> VelocityCycle {
+        VelocityCycle {
+            id: format!("cycle-{}", number),
+            number,
+            name: None,
+            starts_at: starts_at.to_string(),
+            ends_at: "2024-01-15T00:00:00.000Z".to_string(),
+            completed_at: closed.then(|| "2024-01-15T00:00:00.000Z".to_string()),
+            scope_history: vec![10.0, 12.0, 11.0, 14.0],
+            completed_scope_history: vec![0.0, 3.0, 6.0, 8.0],
+            issues: VelocityIssueConnection {
+                nodes: vec![
+                    issue("1", Some(5.0), true, false),
+                    issue("2", Some(3.0), true, false),
+                    issue("3", None, true, false),
+                    issue("4", Some(2.0), false, false),
+                    issue("5", Some(1.0), false, true),
+                ],
+            },
+            uncompleted_issues_upon_close: VelocityIssueConnection {
+                nodes: vec![
+                    issue("6", Some(3.0), false, false),
+                    issue("7", Some(1.0), false, true),
+                ],
+            },
+        }
+    }
+
+    fn now() -> DateTime<Utc> {
+        parse_timestamp("2024-03-01T00:00:00.000Z").unwrap()
+    }
+
+    #[test]
+    fn test_compute_cycle_velocity_closed() {
+        let v = compute_cycle_velocity(&cycle(1, "2024-01-01T00:00:00.000Z", true), now());
+        assert!(!v.active);
+        assert_eq!(v.committed_points, 10.0);
+        assert_eq!(v.scope_added_points, 5.0);
+        assert_eq!(v.final_scope_points, 14.0);
+        assert_eq!(v.completed_points, 8.0);
+        assert_eq!(v.completed_issues, 3);
+        assert_eq!(v.carry_over_issues, 1);
+        assert_eq!(v.carry_over_points, 3.0);
+        assert_eq!(v.completion_ratio, Some(8.0 / 14.0));
+        assert_eq!(v.commitment_ratio, Some(0.8));
+    }
+
+    #[test]
+    fn test_compute_cycle_velocity_active() {
+        let early = parse_timestamp("2024-01-05T00:00:00.000Z").unwrap();
+        let v = compute_cycle_velocity(&cycle(1, "2024-01-01T00:00:00.000Z", false), early);
+        assert!(v.active);
+        // Open, uncanceled issues still in the cycle
+        assert_eq!(v.carry_over_issues, 1);
+        assert_eq!(v.carry_over_points, 2.0);
+    }
+
+    #[test]
+    fn test_compute_cycle_velocity_empty_scope_history() {
+        let mut c = cycle(1, "2024-01-01T00:00:00.000Z", true);
+        c.scope_history.clear();
+        let v = compute_cycle_velocity(&c, now());
+        assert_eq!(v.committed_points, 0.0);
+        assert_eq!(v.final_scope_points, 11.0);
+        assert_eq!(v.commitment_ratio, None);
+    }
+
+    #[test]
+    fn test_build_velocity_report_limits_and_orders_cycles() {
+        let cycles = vec![
+            cycle(3, "2024-01-29T00:00:00.000Z", true),
+            cycle(1, "2024-01-01T00:00:00.000Z", true),
+            cycle(2, "2024-01-15T00:00:00.000Z", true),
+        ];
+        let report = build_velocity_report("ENG".into(), cycles, 2, now());
+        let numbers: Vec<i32> = report.cycles.iter().map(|c| c.number).collect();
+        assert_eq!(numbers, vec![2, 3]);
+        assert_eq!(report.average_velocity, Some(8.0));
+        assert_eq!(report.average_carry_over, Some(1.0));
+    }
+
+    #[test]
+    fn test_sparkline() {
+        assert_eq!(sparkline(&[0.0, 7.0, 14.0]), "▁▅█");
+        assert_eq!(sparkline(&[0.0, 0.0]), "▁▁");
+        assert_eq!(sparkline(&[]), "");
+    }
+
+    #[test]
+    fn test_velocity_report_csv() {
+        let mut c = cycle(1, "2024-01-01T00:00:00.000Z", true);
+        c.name = Some("Sprint, the first".to_string());
+        let report = build_velocity_report("ENG".into(), vec![c], 6, now());
+        let csv = report.to_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("cycle,name,starts_at"));
+        assert!(lines[1].starts_with("1,\"Sprint, the first\",2024-01-01"));
+        assert!(lines[1].ends_with(",0.571,0.800"));
+    }
+
+    #[test]
+    fn test_velocity_report_human_display() {
+        let report = build_velocity_report(
+            "ENG".into(),
+            vec![cycle(4, "2024-01-01T00:00:00.000Z", true)],
+            6,
+            now(),
+        );
+        let output = report.human_fmt();
+        assert!(output.contains("Velocity for"));
+        assert!(output.contains("#4"));
+        assert!(output.contains("+5"));
+        assert!(output.contains("57%"));
+        assert!(output.contains("Average velocity"));
+        assert!(output.contains("8 points/cycle"));
+    }
+
+    #[test]
+    fn test_velocity_report_api() {
+        let mut server = mockito::Server::new();
+
+        let mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::Regex("query VelocityCycles".to_string()),
+                mockito::Matcher::PartialJsonString(
+                    r#"{"variables": {"first": 48, "filter": {"team": {"key": {"eq": "ENG"}}}}}"#
+                        .to_string(),
+                ),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"data": {"cycles": {"nodes": []}}}"#)
+            .create();
+
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let options = VelocityReportOptions {
+            team: Some("eng".to_string()),
+            cycles: 6,
+            csv: false,
+        };
+        let result = velocity_report(&client, options, OutputFormat::Json);
+        assert!(result.is_ok());
+        mock.assert();
+    }
+
+    #[test]
+    fn test_velocity_report_zero_cycles() {
+        let server = mockito::Server::new();
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let options = VelocityReportOptions {
+            team: Some("ENG".to_string()),
+            cycles: 0,
+            csv: false,
+        };
+        assert!(velocity_report(&client, options, OutputFormat::Human).is_err());
+    }
+}
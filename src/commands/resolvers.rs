@@ -1,17 +1,22 @@
-//! Team and state resolution with caching support.
+//! Team, state, label and user resolution with caching support.
 //!
-//! Resolves team keys to team IDs and state names to state IDs,
-//! using the config cache when available or querying the API.
+//! Resolves team keys to team IDs, state names to state IDs, and label and
+//! user names to their IDs, using the config cache when available or querying
+//! the API. A cache miss triggers one targeted re-sync before failing.
 
 use std::collections::HashMap;
 
 use crate::Result;
 use crate::api::GraphQLClient;
 use crate::api::queries;
+use crate::commands::cache::{self, CacheResource};
 use crate::commands::issue::is_uuid;
 use crate::config::{CachedTeam, Config};
 use crate::error::LinError;
-use crate::models::{IssueTeamResponse, TeamsResponse, WorkflowStatesResponse};
+use crate::models::{
+    IssueTeamResponse, Label, LabelResponse, LabelsResponse, Team, TeamsResponse, User,
+    UserResponse, UsersResponse, ViewerResponse, WorkflowState, WorkflowStatesResponse,
+};
 
 /// Resolve a team from an optional argument, falling back to the current team.
 ///
@@ -48,6 +53,30 @@ pub fn resolve_team_or_current(
     }
 }
 
+/// Resolve an optional team key, falling back to the current team.
+///
+/// Unlike [`resolve_team_or_current`], this returns the team key (uppercased)
+/// without looking up the team's UUID, for commands that filter by key.
+pub fn resolve_team_key_or_current(team_key: Option<&str>) -> Result<String> {
+    match team_key {
+        Some(team) => Ok(team.to_uppercase()),
+        None => team_key_or_current(None, &Config::load()?),
+    }
+}
+
+/// [`resolve_team_key_or_current`] against an already loaded config.
+fn team_key_or_current(team_key: Option<&str>, config: &Config) -> Result<String> {
+    if let Some(team) = team_key {
+        return Ok(team.to_uppercase());
+    }
+
+    config.get_current_team().ok_or_else(|| {
+        LinError::config(
+            "No team specified. Use --team or set a default team with 'lin team switch <key>'",
+        )
+    })
+}
+
 /// Resolve a team key or UUID to a team UUID.
 ///
 /// If `use_cache` is true, uses cached data from the config.
@@ -85,6 +114,7 @@ pub fn resolve_team_id(
                 let mut config = Config::load()?;
                 let team_id = cached_team.id.clone();
                 config.cache_team(team_key_or_id.to_uppercase(), cached_team)?;
+                config.save()?;
                 return Ok(team_id);
             }
             Err(_) => {
@@ -151,8 +181,9 @@ pub fn resolve_state_id(
             return Ok(id); // Cache hit
         }
 
-        // Cache miss - sync team's states and retry
-        sync_team_to_cache(client, team_key)?;
+        // Cache miss - the state may have been added or renamed, so
+        // re-sync the team's states and retry
+        refresh_team_cache(client, team_key)?;
         let config = Config::load()?;
         match config.get_state_id(team_key, &state_lower) {
             Some(id) => Ok(id),
@@ -184,6 +215,48 @@ pub fn resolve_state_id(
     }
 }
 
+/// Resolve the workflow state types for a team.
+///
+/// If `use_cache` is true, uses the cached state types, re-syncing the team
+/// when the cache predates state type tracking. Otherwise queries the API.
+///
+/// # Arguments
+///
+/// * `client` - GraphQL client for API queries
+/// * `team_key` - Team key (e.g., "ENG")
+/// * `use_cache` - Whether to use cached data
+///
+/// # Returns
+///
+/// A map of lowercase state names to state types (e.g., "in progress" → "started").
+pub fn resolve_state_types(
+    client: &GraphQLClient,
+    team_key: &str,
+    use_cache: bool,
+) -> Result<HashMap<String, String>> {
+    if use_cache {
+        let config = Config::load()?;
+        let state_types = config.get_state_types(team_key);
+        if !state_types.is_empty() {
+            return Ok(state_types); // Cache hit
+        }
+
+        // Cache miss - sync the team and store it for next time
+        let cached_team = sync_team_to_cache(client, team_key)?;
+        let state_types = cached_team.state_types.clone();
+        let mut config = Config::load()?;
+        config.cache_team(team_key.to_uppercase(), cached_team)?;
+        config.save()?;
+        return Ok(state_types);
+    }
+
+    let team_id = query_team_by_key(client, team_key)?;
+    Ok(query_workflow_states(client, &team_id)?
+        .into_iter()
+        .map(|s| (s.name.to_lowercase(), s.type_))
+        .collect())
+}
+
 /// Get the team ID for a given issue.
 ///
 /// Used when updating an issue's state - we need to know which team
@@ -222,8 +295,9 @@ pub fn get_team_key(client: &GraphQLClient, team_id: &str) -> Result<String> {
 
 /// Resolve an estimate name or numeric string to a numeric value.
 ///
-/// If `use_cache` is true, tries to resolve the estimate from cached team data.
-/// Otherwise, or if the value is already numeric, returns the parsed value.
+/// If `use_cache` is true and the team's estimate scale is cached, only the
+/// estimates the team allows are accepted, by name (e.g., "M") or value
+/// (e.g., "3"). Otherwise any numeric value is accepted.
 ///
 /// # Arguments
 ///
@@ -239,79 +313,101 @@ pub fn resolve_estimate_value(
     team_key: Option<&str>,
     use_cache: bool,
 ) -> Result<f64> {
-    // 1. Try to parse as a number first
-    if let Ok(value) = estimate_str.parse::<f64>() {
-        return Ok(value);
+    // 1. Check against the team's cached scale (if enabled and team provided)
+    if use_cache
+        && let Some(team) = team_key
+        && let Some(estimates) = Config::load()?.get_team_estimates(team)
+    {
+        if estimates.is_empty() {
+            return Err(LinError::config(format!(
+                "Team '{}' doesn't use estimates. Enable them in the team's settings in Linear.",
+                team
+            )));
+        }
+
+        let value = estimates
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(estimate_str))
+            .map(|(_, value)| *value)
+            .or_else(|| {
+                let value = estimate_str.parse::<f64>().ok()?;
+                estimates.iter().any(|(_, v)| *v == value).then_some(value)
+            });
+        return value.ok_or_else(|| {
+            LinError::config(format!(
+                "Invalid estimate '{}' for team '{}'. Valid estimates: {}",
+                estimate_str,
+                team,
+                format_estimates(&estimates)
+            ))
+        });
     }
 
-    // 2. If not a number, try to resolve from cache (if enabled and team provided)
-    if use_cache {
-        if let Some(team) = team_key {
-            let config = Config::load()?;
-            if let Some(value) = config.get_estimate_value(team, estimate_str) {
-                return Ok(value);
-            }
+    // 2. Scale unknown - accept any number
+    estimate_str.parse::<f64>().map_err(|_| {
+        LinError::parse(format!(
+            "Invalid estimate '{}': must be a numeric value",
+            estimate_str
+        ))
+    })
+}
 
-            // Cache miss - show helpful error
-            let available = config.get_all_estimates_for_team(team);
-            if available.is_empty() {
-                return Err(LinError::config(format!(
-                    "Estimate '{}' is not a number and no estimates are configured for team '{}'. \
-                    Configure estimates using the config file or provide a numeric value.",
-                    estimate_str, team
-                )));
+/// Format an estimate scale for messages and help text.
+///
+/// Named estimates show their value (e.g., "XS (1), S (2)"); numeric ones
+/// are shown as-is (e.g., "0, 1, 2, 3").
+///
+/// # Example
+///
+/// ```
+/// use lin::commands::resolvers::format_estimates;
+///
+/// let scale = vec![("s".to_string(), 2.0), ("m".to_string(), 3.0)];
+/// assert_eq!(format_estimates(&scale), "S (2), M (3)");
+/// assert_eq!(format_estimates(&[("8".to_string(), 8.0)]), "8");
+/// ```
+pub fn format_estimates(estimates: &[(String, f64)]) -> String {
+    estimates
+        .iter()
+        .map(|(name, value)| {
+            let value = if value.fract() == 0.0 {
+                format!("{}", *value as i64)
             } else {
-                return Err(LinError::config(format!(
-                    "Estimate '{}' not found for team '{}'. Available estimates: {}. \
-                    You can also use a numeric value directly.",
-                    estimate_str,
-                    team,
-                    available.join(", ")
-                )));
+                value.to_string()
+            };
+            if *name == value {
+                value
+            } else {
+                format!("{} ({})", name.to_uppercase(), value)
             }
-        }
-    }
-
-    // 3. Not a number and cache not available/enabled
-    Err(LinError::parse(format!(
-        "Invalid estimate '{}': must be a numeric value",
-        estimate_str
-    )))
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
 }
 
-/// Parse an estimate scale type string into a HashMap of name -> value mappings.
-///
-/// For t-shirt scales, maps ["xs","s","m","l","xl"] to [1,2,3,5,8].
-/// For numeric scales (linear, fibonacci, exponential), uses hardcoded default values.
-/// Returns an empty HashMap for "notUsed" or unknown types.
-fn parse_estimate_scale(estimate_type: &Option<String>) -> HashMap<String, f64> {
-    let Some(est_type) = estimate_type else {
-        return HashMap::new();
-    };
-
-    match est_type.as_str() {
-        "tShirt" => {
-            let names = ["xs", "s", "m", "l", "xl"];
-            let values = [1.0, 2.0, 3.0, 5.0, 8.0];
-            names
-                .iter()
-                .zip(values.iter())
-                .map(|(&name, &val)| (name.to_string(), val))
-                .collect()
-        }
-        "linear" => [1.0, 2.0, 3.0, 4.0, 5.0]
-            .iter()
-            .map(|&val| (format!("{}", val as i64), val))
-            .collect(),
-        "fibonacci" => [1.0, 2.0, 3.0, 5.0, 8.0, 13.0, 21.0]
-            .iter()
-            .map(|&val| (format!("{}", val as i64), val))
-            .collect(),
-        "exponential" => [1.0, 2.0, 4.0, 8.0, 16.0, 32.0, 64.0]
-            .iter()
-            .map(|&val| (format!("{}", val as i64), val))
+/// Build the cached form of a team from the team and its workflow states.
+pub(crate) fn cached_team(team: &Team, states: Vec<WorkflowState>) -> CachedTeam {
+    // Build state maps (lowercase keys for case-insensitive lookup)
+    let state_types: HashMap<String, String> = states
+        .iter()
+        .map(|s| (s.name.to_lowercase(), s.type_.clone()))
+        .collect();
+    let state_map: HashMap<String, String> = states
+        .into_iter()
+        .map(|s| (s.name.to_lowercase(), s.id))
+        .collect();
+
+    CachedTeam {
+        id: team.id.clone(),
+        name: team.name.clone(),
+        states: state_map,
+        estimates: team
+            .estimate_scale()
+            .into_iter()
+            .map(|(name, value)| (name.to_lowercase(), value))
             .collect(),
-        _ => HashMap::new(),
+        estimate_type: team.issue_estimate_type.clone(),
+        state_types,
     }
 }
 
@@ -337,19 +433,20 @@ fn sync_team_to_cache(client: &GraphQLClient, team_key: &str) -> Result<CachedTe
 
     // Query workflow states for this team
     let states = query_workflow_states(client, &team.id)?;
+    Ok(cached_team(team, states))
+}
 
-    // Build state map (lowercase keys for case-insensitive lookup)
-    let state_map: HashMap<String, String> = states
-        .into_iter()
-        .map(|s| (s.name.to_lowercase(), s.id))
-        .collect();
-
-    Ok(CachedTeam {
-        id: team.id.clone(),
-        name: team.name.clone(),
-        states: state_map,
-        estimates: parse_estimate_scale(&team.issue_estimate_type),
-    })
+/// Re-sync a team's cached states and estimates after its workflow changed.
+///
+/// # Arguments
+///
+/// * `client` - GraphQL client for API queries
+/// * `team_key` - Team key (e.g., "ENG")
+pub fn refresh_team_cache(client: &GraphQLClient, team_key: &str) -> Result<()> {
+    let cached_team = sync_team_to_cache(client, team_key)?;
+    let mut config = Config::load()?;
+    config.cache_team(team_key.to_uppercase(), cached_team)?;
+    config.save()
 }
 
 /// Query a team by its key.
@@ -451,12 +548,210 @@ pub fn resolve_milestone_id(
     }
 }
 
+/// Resolve a label name or UUID to a label UUID.
+///
+/// If `use_cache` is true, looks the name up in the cached labels, re-syncing
+/// them once on a miss. Ambiguous or unknown names fall back to
+/// [`resolve_label`] for its error message.
+///
+/// # Arguments
+///
+/// * `client` - GraphQL client for API queries
+/// * `label_name_or_id` - Label name (e.g., "Bug") or UUID
+/// * `use_cache` - Whether to use cached data
+pub fn resolve_label_id(
+    client: &GraphQLClient,
+    label_name_or_id: &str,
+    use_cache: bool,
+) -> Result<String> {
+    if is_uuid(label_name_or_id) {
+        return Ok(label_name_or_id.to_string());
+    }
+
+    if use_cache {
+        let mut config = Config::load()?;
+        let mut ids = config.get_label_ids(label_name_or_id);
+        if ids.is_empty() {
+            // Cache miss - the label may be new, so re-sync labels once
+            cache::refresh_cache(client, &mut config, Some(CacheResource::Labels))?;
+            ids = config.get_label_ids(label_name_or_id);
+        }
+        match ids.len() {
+            0 => {
+                return Err(LinError::api(format!(
+                    "Label '{}' not found",
+                    label_name_or_id
+                )));
+            }
+            1 => return Ok(ids.remove(0)),
+            _ => {}
+        }
+    }
+
+    resolve_label(client, label_name_or_id).map(|l| l.id)
+}
+
+/// Resolve a label name or UUID to the full label.
+///
+/// Name matching is case-insensitive across all workspace and team labels.
+/// A name shared by several labels is rejected so the caller can pass an ID.
+///
+/// # Arguments
+///
+/// * `client` - GraphQL client for API queries
+/// * `label_name_or_id` - Label name (e.g., "Bug") or UUID
+pub fn resolve_label(client: &GraphQLClient, label_name_or_id: &str) -> Result<Label> {
+    // 1. UUID lookup
+    if is_uuid(label_name_or_id) {
+        let response: LabelResponse = client.query(
+            queries::label::LABEL_QUERY,
+            serde_json::json!({ "id": label_name_or_id }),
+        )?;
+        return Ok(response.issue_label);
+    }
+
+    // 2. Find by name (case-insensitive)
+    let response: LabelsResponse = client.query(
+        queries::label::LABELS_QUERY,
+        serde_json::json!({ "first": 250 }),
+    )?;
+    let mut matches: Vec<Label> = response
+        .issue_labels
+        .nodes
+        .into_iter()
+        .filter(|l| l.name.eq_ignore_ascii_case(label_name_or_id))
+        .collect();
+    match matches.len() {
+        0 => Err(LinError::api(format!(
+            "Label '{}' not found",
+            label_name_or_id
+        ))),
+        1 => Ok(matches.remove(0)),
+        _ => {
+            let ids: Vec<_> = matches.iter().map(|l| l.id.as_str()).collect();
+            Err(LinError::api(format!(
+                "Label name '{}' is ambiguous. Use one of these IDs: {}",
+                label_name_or_id,
+                ids.join(", ")
+            )))
+        }
+    }
+}
+
+/// Resolve a user reference to a user UUID.
+///
+/// Accepts "me" (the authenticated user), a UUID, an email address, a full
+/// name, or a display name. Name and email matching is case-insensitive.
+///
+/// If `use_cache` is true, names and emails are looked up in the cached
+/// users, re-syncing them once on a miss.
+///
+/// # Arguments
+///
+/// * `client` - GraphQL client for API queries
+/// * `user` - User reference (e.g., "me", "alice", "alice@example.com")
+/// * `use_cache` - Whether to use cached data
+///
+/// # Returns
+///
+/// The user UUID.
+pub fn resolve_user_id(client: &GraphQLClient, user: &str, use_cache: bool) -> Result<String> {
+    // UUIDs pass through without an API call
+    if is_uuid(user) {
+        return Ok(user.to_string());
+    }
+
+    if use_cache && !user.eq_ignore_ascii_case("me") {
+        let mut config = Config::load()?;
+        if let Some(id) = config.get_user_id(user) {
+            return Ok(id); // Cache hit
+        }
+
+        // Cache miss - the user may be new, so re-sync users once
+        cache::refresh_cache(client, &mut config, Some(CacheResource::Users))?;
+        return config.get_user_id(user).ok_or_else(|| user_not_found(user));
+    }
+
+    resolve_user(client, user).map(|u| u.id)
+}
+
+fn user_not_found(user: &str) -> LinError {
+    LinError::api(format!(
+        "User '{}' not found. Use a name, email, or UUID (see 'lin user list')",
+        user
+    ))
+}
+
+/// Resolve a user reference to the full user record.
+///
+/// Accepts the same references as [`resolve_user_id`], but always queries the
+/// API so the user's name and email are available.
+///
+/// # Arguments
+///
+/// * `client` - GraphQL client for API queries
+/// * `user` - User reference (e.g., "me", "alice", "alice@example.com", UUID)
+pub fn resolve_user(client: &GraphQLClient, user: &str) -> Result<User> {
+    // 1. "me" resolves to the authenticated user
+    if user.eq_ignore_ascii_case("me") {
+        let response: ViewerResponse =
+            client.query(queries::user::VIEWER_QUERY, serde_json::json!({}))?;
+        return Ok(response.viewer);
+    }
+
+    // 2. UUID lookup
+    if is_uuid(user) {
+        let response: UserResponse =
+            client.query(queries::user::USER_QUERY, serde_json::json!({ "id": user }))?;
+        return Ok(response.user);
+    }
+
+    // 3. Query users and match by email, name, or display name
+    let response: UsersResponse = client.query(
+        queries::user::USERS_QUERY,
+        serde_json::json!({ "first": 250 }),
+    )?;
+
+    let user_lower = user.to_lowercase();
+    response
+        .users
+        .nodes
+        .into_iter()
+        .find(|u| {
+            u.email.to_lowercase() == user_lower
+                || u.name.to_lowercase() == user_lower
+                || u.display_name
+                    .as_deref()
+                    .is_some_and(|d| d.to_lowercase() == user_lower)
+        })
+        .ok_or_else(|| user_not_found(user))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     // These are basic unit tests. Full integration tests would require mockito.
 
+    #[test]
+    fn test_resolve_team_key_or_current_explicit() {
+        assert_eq!(resolve_team_key_or_current(Some("eng")).unwrap(), "ENG");
+    }
+
+    #[test]
+    fn test_team_key_or_current_falls_back_to_current_team() {
+        let mut config = Config::default();
+        let err = team_key_or_current(None, &config).unwrap_err();
+        assert!(err.to_string().contains("No team specified"));
+
+        config
+            .add_org("work".to_string(), "token".to_string())
+            .unwrap();
+        config.get_active_org_mut().unwrap().current_team = Some("ENG".to_string());
+        assert_eq!(team_key_or_current(None, &config).unwrap(), "ENG");
+        assert_eq!(team_key_or_current(Some("des"), &config).unwrap(), "DES");
+    }
+
     #[test]
     fn test_resolve_team_id_uuid_passthrough() {
         let client = GraphQLClient::new("test-token");
@@ -486,4 +781,113 @@ mod tests {
         assert!(!is_uuid("ENG"));
         assert!(!is_uuid("ENG-123"));
     }
+
+    #[test]
+    fn test_resolve_user_id_uuid_passthrough() {
+        let client = GraphQLClient::new("test-token");
+        let uuid = "550e8400-e29b-41d4-a716-446655440000";
+
+        // UUID should pass through without any API calls
+        let result = resolve_user_id(&client, uuid, true);
+        assert_eq!(result.unwrap(), uuid);
+    }
+
+    #[test]
+    fn test_resolve_user_id_by_name_and_email() {
+        let mut server = mockito::Server::new();
+
+        let mock = server
+            .mock("POST", "/")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{
+                    "data": {
+                        "users": {
+                            "nodes": [
+                                {
+                                    "id": "user-1",
+                                    "name": "Alice Smith",
+                                    "email": "alice@example.com",
+                                    "displayName": "alice",
+                                    "active": true
+                                }
+                            ]
+                        }
+                    }
+                }"#,
+            )
+            .expect(3)
+            .create();
+
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        assert_eq!(resolve_user_id(&client, "alice", false).unwrap(), "user-1");
+        assert_eq!(
+            resolve_user_id(&client, "ALICE@example.com", false).unwrap(),
+            "user-1"
+        );
+        let err = resolve_user_id(&client, "bob", false).unwrap_err();
+        assert!(err.to_string().contains("User 'bob' not found"));
+        mock.assert();
+    }
+
+    #[test]
+    fn test_resolve_label_by_name() {
+        let mut server = mockito::Server::new();
+
+        let mock = server
+            .mock("POST", "/")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r##"{
+                    "data": {
+                        "issueLabels": {
+                            "nodes": [
+                                {
+                                    "id": "label-1",
+                                    "name": "Bug",
+                                    "description": null,
+                                    "color": "#ff0000",
+                                    "isGroup": false,
+                                    "parent": null,
+                                    "createdAt": "2024-01-01T00:00:00.000Z",
+                                    "updatedAt": "2024-01-01T00:00:00.000Z"
+                                },
+                                {
+                                    "id": "label-2",
+                                    "name": "Backend",
+                                    "description": null,
+                                    "color": "#00ff00",
+                                    "isGroup": false,
+                                    "parent": null,
+                                    "createdAt": "2024-01-01T00:00:00.000Z",
+                                    "updatedAt": "2024-01-01T00:00:00.000Z"
+                                },
+                                {
+                                    "id": "label-3",
+                                    "name": "backend",
+                                    "description": null,
+                                    "color": "#0000ff",
+                                    "isGroup": false,
+                                    "parent": null,
+                                    "createdAt": "2024-01-01T00:00:00.000Z",
+                                    "updatedAt": "2024-01-01T00:00:00.000Z"
+                                }
+                            ]
+                        }
+                    }
+                }"##,
+            )
+            .expect(3)
+            .create();
+
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        assert_eq!(resolve_label(&client, "bug").unwrap().id, "label-1");
+        let err = resolve_label(&client, "Backend").unwrap_err();
+        assert!(err.to_string().contains("ambiguous"));
+        let err = resolve_label(&client, "Docs").unwrap_err();
+        assert!(err.to_string().contains("Label 'Docs' not found"));
+        mock.assert();
+    }
 }
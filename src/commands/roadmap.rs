@@ -0,0 +1,777 @@
+//! Project roadmap timeline.
+//!
+//! Draws projects as horizontal bars from start to target date, with
+//! milestone markers, progress shading and a "today" line. The same data can
+//! be exported as a Mermaid gantt chart.
+
+use chrono::{Datelike, Local, NaiveDate};
+use colored::Colorize;
+use serde::Serialize;
+
+use crate::Result;
+use crate::api::GraphQLClient;
+use crate::api::queries::project::ROADMAP_PROJECTS_QUERY;
+use crate::commands::resolvers;
+use crate::error::LinError;
+use crate::models::{RoadmapProject, RoadmapProjectsResponse};
+use crate::output::{HumanDisplay, OutputFormat, output};
+
+/// Maximum number of projects fetched for the roadmap.
+const ROADMAP_PROJECT_LIMIT: u32 = 250;
+
+/// Width of the project name column.
+const LABEL_WIDTH: usize = 24;
+
+/// Chart width used when the terminal size is unknown.
+const DEFAULT_TERMINAL_WIDTH: usize = 100;
+
+/// Narrowest chart that is still readable.
+const MIN_CHART_WIDTH: usize = 20;
+
+/// Options for the roadmap command.
+#[derive(Debug, Clone, Default)]
+pub struct RoadmapOptions {
+    /// Only show projects accessible to this team key (e.g., "ENG").
+    /// Uses the current team if not specified.
+    pub team: Option<String>,
+    /// Restrict the timeline to a quarter (e.g., "2026Q4").
+    pub quarter: Option<String>,
+    /// Print a Mermaid gantt chart instead of the terminal timeline.
+    pub mermaid: bool,
+}
+
+/// Parse a quarter like "2026Q4" or "2026-q4" into its first and last day.
+pub fn parse_quarter(value: &str) -> Result<(NaiveDate, NaiveDate)> {
+    let invalid = || {
+        LinError::parse(format!(
+            "Invalid quarter '{}'. Expected YYYYQ1-YYYYQ4 (e.g., 2026Q4)",
+            value
+        ))
+    };
+    let upper = value.trim().to_uppercase();
+    let (year, quarter) = upper.split_once('Q').ok_or_else(invalid)?;
+    let year: i32 = year.trim_end_matches('-').parse().map_err(|_| invalid())?;
+    let quarter: u32 = quarter.parse().map_err(|_| invalid())?;
+    if !(1..=4).contains(&quarter) {
+        return Err(invalid());
+    }
+
+    let start = NaiveDate::from_ymd_opt(year, (quarter - 1) * 3 + 1, 1).ok_or_else(invalid)?;
+    let end = if quarter == 4 {
+        NaiveDate::from_ymd_opt(year + 1, 1, 1)
+    } else {
+        NaiveDate::from_ymd_opt(year, quarter * 3 + 1, 1)
+    }
+    .ok_or_else(invalid)?
+    .pred_opt()
+    .ok_or_else(invalid)?;
+    Ok((start, end))
+}
+
+/// First and last day of the quarter containing `date`.
+fn quarter_of(date: NaiveDate) -> (NaiveDate, NaiveDate) {
+    let quarter = (date.month() - 1) / 3 + 1;
+    // Always valid: built from a real date's year and quarter
+    parse_quarter(&format!("{}Q{}", date.year(), quarter)).unwrap()
+}
+
+fn parse_date(value: Option<&str>) -> Option<NaiveDate> {
+    value.and_then(|v| NaiveDate::parse_from_str(v.get(..10).unwrap_or(v), "%Y-%m-%d").ok())
+}
+
+/// A milestone marker on a project bar.
+#[derive(Debug, Clone, Serialize)]
+pub struct RoadmapMarker {
+    /// Milestone name.
+    pub name: String,
+    /// Milestone target date.
+    pub date: NaiveDate,
+    /// Milestone status (done, next, overdue, unstarted).
+    pub status: String,
+}
+
+/// A project bar on the roadmap.
+#[derive(Debug, Clone, Serialize)]
+pub struct RoadmapBar {
+    /// Project name.
+    pub name: String,
+    /// Project state (planned, started, paused, completed).
+    pub state: String,
+    /// First day of the bar.
+    pub start: NaiveDate,
+    /// Last day of the bar.
+    pub end: NaiveDate,
+    /// Progress percentage (0-100).
+    pub progress: f64,
+    /// Milestones with a target date.
+    pub milestones: Vec<RoadmapMarker>,
+}
+
+/// A roadmap over a date window.
+#[derive(Debug, Clone, Serialize)]
+pub struct Roadmap {
+    /// Team key the roadmap is limited to, if any.
+    pub team: Option<String>,
+    /// First day of the timeline.
+    pub window_start: NaiveDate,
+    /// Last day of the timeline.
+    pub window_end: NaiveDate,
+    /// Today's date, drawn as a vertical line.
+    pub today: NaiveDate,
+    /// Scheduled projects, ordered by start date.
+    pub projects: Vec<RoadmapBar>,
+    /// Names of projects with neither a start nor a target date.
+    pub unscheduled: Vec<String>,
+}
+
+/// Build a roadmap from projects.
+///
+/// A project with only one of its dates becomes a one-day bar on that date.
+/// Canceled projects are left out. When `window` is given, only projects
+/// overlapping it are kept; otherwise the window spans all bars, or the
+/// current quarter if nothing is scheduled.
+pub fn build_roadmap(
+    team: Option<String>,
+    projects: &[RoadmapProject],
+    window: Option<(NaiveDate, NaiveDate)>,
+    today: NaiveDate,
+) -> Roadmap {
+    let mut bars = Vec::new();
+    let mut unscheduled = Vec::new();
+
+    for project in projects.iter().filter(|p| p.state != "canceled") {
+        let start = parse_date(project.start_date.as_deref());
+        let target = parse_date(project.target_date.as_deref());
+        let (start, end) = match (start, target) {
+            (Some(s), Some(t)) => (s.min(t), s.max(t)),
+            (Some(d), None) | (None, Some(d)) => (d, d),
+            (None, None) => {
+                unscheduled.push(project.name.clone());
+                continue;
+            }
+        };
+        let mut milestones: Vec<RoadmapMarker> = project
+            .project_milestones
+            .nodes
+            .iter()
+            .filter_map(|m| {
+                parse_date(m.target_date.as_deref()).map(|date| RoadmapMarker {
+                    name: m.name.clone(),
+                    date,
+                    status: m.status.clone(),
+                })
+            })
+            .collect();
+        milestones.sort_by_key(|m| m.date);
+        bars.push(RoadmapBar {
+            name: project.name.clone(),
+            state: project.state.clone(),
+            start,
+            end,
+            progress: project.progress.clamp(0.0, 100.0),
+            milestones,
+        });
+    }
+
+    let (window_start, window_end) = match window {
+        Some((start, end)) => {
+            bars.retain(|b| b.start <= end && b.end >= start);
+            (start, end)
+        }
+        None => match (
+            bars.iter().map(|b| b.start).min(),
+            bars.iter().map(|b| b.end).max(),
+        ) {
+            (Some(start), Some(end)) => (start, end),
+            _ => quarter_of(today),
+        },
+    };
+
+    bars.sort_by(|a, b| {
+        a.start
+            .cmp(&b.start)
+            .then(a.end.cmp(&b.end))
+            .then(a.name.cmp(&b.name))
+    });
+    unscheduled.sort();
+
+    Roadmap {
+        team,
+        window_start,
+        window_end,
+        today,
+        projects: bars,
+        unscheduled,
+    }
+}
+
+/// What a single chart cell shows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Empty,
+    Done,
+    Remaining,
+    Milestone,
+}
+
+/// Escape characters that Mermaid gantt task names cannot contain.
+fn mermaid_text(value: &str) -> String {
+    value.replace([':', ';', '#'], " ").trim().to_string()
+}
+
+fn truncate_label(name: &str, width: usize) -> String {
+    if name.chars().count() > width {
+        let cut: String = name.chars().take(width - 1).collect();
+        format!("{}…", cut)
+    } else {
+        name.to_string()
+    }
+}
+
+impl Roadmap {
+    fn days(&self) -> i64 {
+        (self.window_end - self.window_start).num_days() + 1
+    }
+
+    /// Column of a date in a chart `width` cells wide, clamped to the window.
+    fn column(&self, date: NaiveDate, width: usize) -> usize {
+        let offset = (date - self.window_start)
+            .num_days()
+            .clamp(0, self.days() - 1);
+        (offset as usize * width) / self.days() as usize
+    }
+
+    fn in_window(&self, date: NaiveDate) -> bool {
+        date >= self.window_start && date <= self.window_end
+    }
+
+    fn month_header(&self, width: usize) -> String {
+        let mut header = vec![' '; width];
+        let mut next_free = 0;
+        let mut month =
+            NaiveDate::from_ymd_opt(self.window_start.year(), self.window_start.month(), 1);
+        while let Some(first) = month {
+            if first > self.window_end {
+                break;
+            }
+            let date = first.max(self.window_start);
+            let label = if date == self.window_start || first.month() == 1 {
+                first.format("%b %Y").to_string()
+            } else {
+                first.format("%b").to_string()
+            };
+            let col = self.column(date, width);
+            if col >= next_free && col + label.len() <= width {
+                for (i, c) in label.chars().enumerate() {
+                    header[col + i] = c;
+                }
+                next_free = col + label.len() + 1;
+            }
+            month = first.checked_add_months(chrono::Months::new(1));
+        }
+        header.into_iter().collect()
+    }
+
+    fn bar_cells(&self, bar: &RoadmapBar, width: usize) -> Vec<Cell> {
+        let mut cells = vec![Cell::Empty; width];
+        let start = self.column(bar.start.max(self.window_start), width);
+        let end = self.column(bar.end.min(self.window_end), width);
+        // Progress is measured over the whole bar, not just the visible part
+        let full_start = (bar.start - self.window_start).num_days() as f64;
+        let full_len = ((bar.end - bar.start).num_days() + 1) as f64;
+        let done_until = self.window_start
+            + chrono::Duration::days((full_start + full_len * bar.progress / 100.0).round() as i64);
+        let done_col = if done_until <= self.window_start {
+            None
+        } else {
+            Some(self.column(done_until, width))
+        };
+
+        for (col, cell) in cells.iter_mut().enumerate().take(end + 1).skip(start) {
+            *cell = if bar.progress >= 100.0 || done_col.is_some_and(|d| col < d) {
+                Cell::Done
+            } else {
+                Cell::Remaining
+            };
+        }
+        for milestone in bar.milestones.iter().filter(|m| self.in_window(m.date)) {
+            cells[self.column(milestone.date, width)] = Cell::Milestone;
+        }
+        cells
+    }
+
+    /// Render the timeline with a chart area `width` cells wide.
+    pub fn render(&self, width: usize) -> String {
+        let today_col = self
+            .in_window(self.today)
+            .then(|| self.column(self.today, width));
+        let title = match &self.team {
+            Some(team) => format!("{} {}", "Roadmap for".bold(), team.bold().cyan()),
+            None => "Roadmap".bold().to_string(),
+        };
+        let mut parts = vec![
+            format!(
+                "{} {}",
+                title,
+                format!(
+                    "({} – {})",
+                    self.window_start.format("%b %-d, %Y"),
+                    self.window_end.format("%b %-d, %Y")
+                )
+                .dimmed()
+            ),
+            String::new(),
+        ];
+
+        if self.projects.is_empty() {
+            parts.push(
+                "  No scheduled projects in this window."
+                    .dimmed()
+                    .to_string(),
+            );
+        } else {
+            parts.push(format!(
+                "{:<label$} {}",
+                "",
+                self.month_header(width).dimmed(),
+                label = LABEL_WIDTH
+            ));
+            for bar in &self.projects {
+                let cells = self.bar_cells(bar, width);
+                let mut line = String::new();
+                for (col, cell) in cells.iter().enumerate() {
+                    let is_today = today_col == Some(col);
+                    let drawn = match cell {
+                        Cell::Empty if is_today => "│".yellow().to_string(),
+                        Cell::Empty => " ".to_string(),
+                        Cell::Done => "█".green().to_string(),
+                        Cell::Remaining => match bar.state.as_str() {
+                            "started" => "░".cyan().to_string(),
+                            "paused" => "░".yellow().to_string(),
+                            "completed" => "░".green().to_string(),
+                            _ => "░".dimmed().to_string(),
+                        },
+                        Cell::Milestone => "◆".magenta().to_string(),
+                    };
+                    line.push_str(&drawn);
+                }
+                parts.push(format!(
+                    "{:<label$} {} {:>4}",
+                    truncate_label(&bar.name, LABEL_WIDTH),
+                    line,
+                    format!("{:.0}%", bar.progress),
+                    label = LABEL_WIDTH
+                ));
+            }
+            parts.push(String::new());
+            parts.push(
+                format!(
+                    "  █ done  ░ remaining  ◆ milestone  │ today ({})",
+                    self.today.format("%Y-%m-%d")
+                )
+                .dimmed()
+                .to_string(),
+            );
+        }
+
+        if !self.unscheduled.is_empty() {
+            parts.push(format!(
+                "  {}: {}",
+                "Unscheduled".dimmed(),
+                self.unscheduled.join(", ")
+            ));
+        }
+
+        parts.join("\n")
+    }
+
+    /// Render the roadmap as a Mermaid gantt chart.
+    pub fn to_mermaid(&self) -> String {
+        let mut out = String::from("gantt\n");
+        match &self.team {
+            Some(team) => out.push_str(&format!("    title Roadmap for {}\n", mermaid_text(team))),
+            None => out.push_str("    title Roadmap\n"),
+        }
+        out.push_str("    dateFormat YYYY-MM-DD\n");
+        out.push_str("    axisFormat %b %d\n");
+
+        for (i, bar) in self.projects.iter().enumerate() {
+            let name = mermaid_text(&bar.name);
+            out.push_str(&format!("    section {}\n", name));
+            let tag = match bar.state.as_str() {
+                "completed" => "done, ",
+                "started" => "active, ",
+                _ => "",
+            };
+            // Mermaid end dates are exclusive
+            out.push_str(&format!(
+                "    {} ({:.0}%) :{}p{}, {}, {}\n",
+                name,
+                bar.progress,
+                tag,
+                i + 1,
+                bar.start.format("%Y-%m-%d"),
+                (bar.end + chrono::Duration::days(1)).format("%Y-%m-%d")
+            ));
+            for (j, milestone) in bar.milestones.iter().enumerate() {
+                let tag = match milestone.status.as_str() {
+                    "done" => "done, ",
+                    "overdue" => "crit, ",
+                    _ => "",
+                };
+                out.push_str(&format!(
+                    "    {} :{}milestone, p{}m{}, {}, 0d\n",
+                    mermaid_text(&milestone.name),
+                    tag,
+                    i + 1,
+                    j + 1,
+                    milestone.date.format("%Y-%m-%d")
+                ));
+            }
+        }
+        out
+    }
+}
+
+impl HumanDisplay for Roadmap {
+    fn human_fmt(&self) -> String {
+        let terminal_width = crossterm::terminal::size()
+            .map(|(cols, _)| cols as usize)
+            .unwrap_or(DEFAULT_TERMINAL_WIDTH);
+        let width = terminal_width
+            .saturating_sub(LABEL_WIDTH + 7)
+            .max(MIN_CHART_WIDTH);
+        self.render(width)
+    }
+}
+
+/// Show projects on a timeline.
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the API request
+/// * `options` - Team and quarter filters, and Mermaid export
+/// * `format` - The output format (Human or Json)
+///
+/// # Errors
+///
+/// Returns an error if no team is given or set as current, or if Mermaid
+/// output is combined with JSON output.
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use lin::api::GraphQLClient;
+/// use lin::commands::roadmap::{roadmap, RoadmapOptions};
+/// use lin::output::OutputFormat;
+///
+/// let client = GraphQLClient::new("lin_api_xxxxx");
+/// let options = RoadmapOptions {
+///     team: Some("ENG".to_string()),
+///     quarter: Some("2026Q4".to_string()),
+///     mermaid: false,
+/// };
+/// roadmap(&client, options, OutputFormat::Human)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn roadmap(
+    client: &GraphQLClient,
+    options: RoadmapOptions,
+    format: OutputFormat,
+) -> Result<()> {
+    if options.mermaid && format == OutputFormat::Json {
+        return Err(LinError::config(
+            "--mermaid prints a Mermaid chart and can't be combined with --json",
+        ));
+    }
+    let window = options.quarter.as_deref().map(parse_quarter).transpose()?;
+    let team = resolvers::resolve_team_key_or_current(options.team.as_deref())?;
+
+    let variables = serde_json::json!({
+        "first": ROADMAP_PROJECT_LIMIT,
+        "filter": {
+            "accessibleTeams": { "some": { "key": { "eq": team } } }
+        }
+    });
+    let response: RoadmapProjectsResponse = client.query(ROADMAP_PROJECTS_QUERY, variables)?;
+
+    let roadmap = build_roadmap(
+        Some(team),
+        &response.projects.nodes,
+        window,
+        Local::now().date_naive(),
+    );
+    if options.mermaid {
+        print!("{}", roadmap.to_mermaid());
+    } else {
+        output(&roadmap, format);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{RoadmapMilestone, RoadmapMilestoneConnection};
+
+    fn strip_ansi(s: &str) -> String {
+        let mut out = String::new();
+        let mut chars = s.chars();
+        while let Some(c) = chars.next() {
+            if c == '\u{1b}' {
+                for c in chars.by_ref() {
+                    if c == 'm' {
+                        break;
+                    }
+                }
+            } else {
+                out.push(c);
+            }
+        }
+        out
+    }
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    fn project(
+        name: &str,
+        state: &str,
+        start: Option<&str>,
+        target: Option<&str>,
+        progress: f64,
+    ) -> RoadmapProject {
+        RoadmapProject {
+            id: format!("id-{}", name),
+            name: name.to_string(),
+            state: state.to_string(),
+            start_date: start.map(String::from),
+            target_date: target.map(String::from),
+            progress,
+            project_milestones: RoadmapMilestoneConnection { nodes: vec![] },
+        }
+    }
+
+    #[test]
+    fn test_parse_quarter() {
+        assert_eq!(
+            parse_quarter("2026Q4").unwrap(),
+            (date("2026-10-01"), date("2026-12-31"))
+        );
+        assert_eq!(
+            parse_quarter("2026-q1").unwrap(),
+            (date("2026-01-01"), date("2026-03-31"))
+        );
+        assert_eq!(
+            parse_quarter("2024Q1").unwrap(),
+            (date("2024-01-01"), date("2024-03-31"))
+        );
+        assert!(parse_quarter("2026Q5").is_err());
+        assert!(parse_quarter("Q4").is_err());
+        assert!(parse_quarter("2026").is_err());
+    }
+
+    #[test]
+    fn test_build_roadmap_window_and_ordering() {
+        let projects = vec![
+            project(
+                "Later",
+                "planned",
+                Some("2026-11-01"),
+                Some("2026-12-15"),
+                0.0,
+            ),
+            project(
+                "Sooner",
+                "started",
+                Some("2026-10-01"),
+                Some("2026-11-15"),
+                40.0,
+            ),
+            project("Target only", "planned", None, Some("2026-12-01"), 0.0),
+            project("Someday", "planned", None, None, 0.0),
+            project(
+                "Dropped",
+                "canceled",
+                Some("2026-10-01"),
+                Some("2026-10-31"),
+                0.0,
+            ),
+        ];
+        let roadmap = build_roadmap(None, &projects, None, date("2026-10-18"));
+
+        let names: Vec<&str> = roadmap.projects.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["Sooner", "Later", "Target only"]);
+        assert_eq!(roadmap.window_start, date("2026-10-01"));
+        assert_eq!(roadmap.window_end, date("2026-12-15"));
+        assert_eq!(roadmap.unscheduled, vec!["Someday".to_string()]);
+        assert_eq!(roadmap.projects[2].start, roadmap.projects[2].end);
+    }
+
+    #[test]
+    fn test_build_roadmap_quarter_filters_projects() {
+        let projects = vec![
+            project(
+                "Q3",
+                "completed",
+                Some("2026-07-01"),
+                Some("2026-09-15"),
+                100.0,
+            ),
+            project(
+                "Spanning",
+                "started",
+                Some("2026-09-01"),
+                Some("2026-10-15"),
+                50.0,
+            ),
+            project("Q1", "planned", Some("2027-01-05"), Some("2027-02-01"), 0.0),
+        ];
+        let window = parse_quarter("2026Q4").unwrap();
+        let roadmap = build_roadmap(None, &projects, Some(window), date("2026-10-18"));
+
+        assert_eq!(roadmap.projects.len(), 1);
+        assert_eq!(roadmap.projects[0].name, "Spanning");
+        assert_eq!(roadmap.window_start, date("2026-10-01"));
+    }
+
+    #[test]
+    fn test_build_roadmap_defaults_to_current_quarter() {
+        let roadmap = build_roadmap(None, &[], None, date("2026-10-18"));
+        assert_eq!(roadmap.window_start, date("2026-10-01"));
+        assert_eq!(roadmap.window_end, date("2026-12-31"));
+    }
+
+    #[test]
+    fn test_render_bars_progress_milestones_and_today() {
+        let mut p = project(
+            "Launch",
+            "started",
+            Some("2026-10-01"),
+            Some("2026-10-10"),
+            50.0,
+        );
+        p.project_milestones.nodes.push(RoadmapMilestone {
+            id: "m1".to_string(),
+            name: "Beta".to_string(),
+            target_date: Some("2026-10-08".to_string()),
+            status: "next".to_string(),
+        });
+        let roadmap = build_roadmap(
+            None,
+            &[p],
+            Some((date("2026-10-01"), date("2026-10-20"))),
+            date("2026-10-15"),
+        );
+
+        // One cell per day
+        let rendered = strip_ansi(&roadmap.render(20));
+        let line = rendered.lines().find(|l| l.starts_with("Launch")).unwrap();
+        let chart: String = line.chars().skip(LABEL_WIDTH + 1).take(20).collect();
+        assert_eq!(chart, "█████░░◆░░    │     ");
+        assert!(line.ends_with(" 50%"));
+        assert!(rendered.contains("Oct 2026"));
+    }
+
+    #[test]
+    fn test_render_empty_window() {
+        let roadmap = build_roadmap(None, &[], None, date("2026-10-18"));
+        assert!(strip_ansi(&roadmap.render(40)).contains("No scheduled projects in this window."));
+    }
+
+    #[test]
+    fn test_to_mermaid() {
+        let mut p = project(
+            "Launch: v2",
+            "started",
+            Some("2026-10-01"),
+            Some("2026-10-10"),
+            50.0,
+        );
+        p.project_milestones.nodes.push(RoadmapMilestone {
+            id: "m1".to_string(),
+            name: "Beta".to_string(),
+            target_date: Some("2026-10-08".to_string()),
+            status: "done".to_string(),
+        });
+        let roadmap = build_roadmap(Some("ENG".to_string()), &[p], None, date("2026-10-15"));
+        let mermaid = roadmap.to_mermaid();
+
+        assert!(mermaid.starts_with("gantt\n    title Roadmap for ENG\n"));
+        assert!(mermaid.contains("    section Launch  v2\n"));
+        assert!(mermaid.contains("    Launch  v2 (50%) :active, p1, 2026-10-01, 2026-10-11\n"));
+        assert!(mermaid.contains("    Beta :done, milestone, p1m1, 2026-10-08, 0d\n"));
+    }
+
+    #[test]
+    fn test_roadmap_command_filters_by_team() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::Regex("query RoadmapProjects".to_string()),
+                mockito::Matcher::PartialJsonString(
+                    r#"{"variables": {"filter": {"accessibleTeams": {"some": {"key": {"eq": "ENG"}}}}}}"#
+                        .to_string(),
+                ),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{
+                    "data": {
+                        "projects": {
+                            "nodes": [
+                                {
+                                    "id": "p1",
+                                    "name": "Launch",
+                                    "state": "started",
+                                    "startDate": "2026-10-01",
+                                    "targetDate": "2026-12-01",
+                                    "progress": 25.0,
+                                    "projectMilestones": {"nodes": []}
+                                }
+                            ]
+                        }
+                    }
+                }"#,
+            )
+            .create();
+
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let options = RoadmapOptions {
+            team: Some("eng".to_string()),
+            quarter: None,
+            mermaid: false,
+        };
+        let result = roadmap(&client, options, OutputFormat::Json);
+
+        assert!(result.is_ok());
+        mock.assert();
+    }
+
+    #[test]
+    fn test_roadmap_rejects_invalid_quarter() {
+        let client = GraphQLClient::with_url("test-token", "http://localhost:1");
+        let options = RoadmapOptions {
+            quarter: Some("Q9".to_string()),
+            ..Default::default()
+        };
+        assert!(roadmap(&client, options, OutputFormat::Json).is_err());
+    }
+
+    #[test]
+    fn test_roadmap_rejects_mermaid_with_json() {
+        let client = GraphQLClient::with_url("test-token", "http://localhost:1");
+        let options = RoadmapOptions {
+            team: Some("ENG".to_string()),
+            mermaid: true,
+            ..Default::default()
+        };
+        let err = roadmap(&client, options, OutputFormat::Json).unwrap_err();
+        assert!(err.to_string().contains("--mermaid"));
+    }
+}
@@ -0,0 +1,457 @@
+//! Line editing and history for `lin shell`.
+//!
+//! On a terminal, lines are read in raw mode with Emacs-style key bindings,
+//! history navigation and Tab completion. Otherwise (e.g., a script piped to
+//! `lin shell`) lines are read from stdin as they are.
+
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::PathBuf;
+
+use colored::Colorize;
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::terminal::{self, ClearType};
+use crossterm::{cursor, queue, style::Print};
+
+/// Maximum number of lines kept in the history file.
+pub const HISTORY_LIMIT: usize = 1000;
+
+/// Completes the word before the cursor.
+///
+/// Given the text before the cursor, returns where the word being completed
+/// starts (in chars) and the candidates to replace it with.
+pub type Completer<'a> = dyn FnMut(&str) -> (usize, Vec<String>) + 'a;
+
+/// The line being edited.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct LineBuffer {
+    chars: Vec<char>,
+    cursor: usize,
+}
+
+impl LineBuffer {
+    fn text(&self) -> String {
+        self.chars.iter().collect()
+    }
+
+    fn before_cursor(&self) -> String {
+        self.chars[..self.cursor].iter().collect()
+    }
+
+    fn set(&mut self, text: &str) {
+        self.chars = text.chars().collect();
+        self.cursor = self.chars.len();
+    }
+
+    fn insert(&mut self, c: char) {
+        self.chars.insert(self.cursor, c);
+        self.cursor += 1;
+    }
+
+    fn backspace(&mut self) {
+        if self.cursor > 0 {
+            self.cursor -= 1;
+            self.chars.remove(self.cursor);
+        }
+    }
+
+    fn delete(&mut self) {
+        if self.cursor < self.chars.len() {
+            self.chars.remove(self.cursor);
+        }
+    }
+
+    fn left(&mut self) {
+        self.cursor = self.cursor.saturating_sub(1);
+    }
+
+    fn right(&mut self) {
+        self.cursor = (self.cursor + 1).min(self.chars.len());
+    }
+
+    fn home(&mut self) {
+        self.cursor = 0;
+    }
+
+    fn end(&mut self) {
+        self.cursor = self.chars.len();
+    }
+
+    /// Delete everything before the cursor (Ctrl-U).
+    fn kill_to_start(&mut self) {
+        self.chars.drain(..self.cursor);
+        self.cursor = 0;
+    }
+
+    /// Delete the word before the cursor (Ctrl-W).
+    fn kill_word_back(&mut self) {
+        let mut start = self.cursor;
+        while start > 0 && self.chars[start - 1].is_whitespace() {
+            start -= 1;
+        }
+        while start > 0 && !self.chars[start - 1].is_whitespace() {
+            start -= 1;
+        }
+        self.chars.drain(start..self.cursor);
+        self.cursor = start;
+    }
+
+    /// Replace the chars from `start` up to the cursor with `text`.
+    fn replace_before_cursor(&mut self, start: usize, text: &str) {
+        let start = start.min(self.cursor);
+        self.chars.splice(start..self.cursor, text.chars());
+        self.cursor = start + text.chars().count();
+    }
+}
+
+/// Longest prefix shared by all `candidates`.
+fn common_prefix(candidates: &[String]) -> String {
+    let Some(first) = candidates.first() else {
+        return String::new();
+    };
+    let mut prefix: Vec<char> = first.chars().collect();
+    for candidate in &candidates[1..] {
+        let shared = prefix
+            .iter()
+            .zip(candidate.chars())
+            .take_while(|(a, b)| *a == b)
+            .count();
+        prefix.truncate(shared);
+    }
+    prefix.into_iter().collect()
+}
+
+/// Text to replace the word `current` with on Tab, if Tab can make progress.
+///
+/// A single candidate is completed with a trailing space; several are
+/// completed as far as they agree.
+fn completion_for(current: &str, candidates: &[String]) -> Option<String> {
+    match candidates {
+        [] => None,
+        [only] => Some(format!("{} ", only)),
+        _ => {
+            let prefix = common_prefix(candidates);
+            (prefix.chars().count() > current.chars().count()).then_some(prefix)
+        }
+    }
+}
+
+/// Command history, oldest first, optionally saved to a file.
+#[derive(Debug, Default)]
+pub struct History {
+    entries: Vec<String>,
+    path: Option<PathBuf>,
+}
+
+impl History {
+    /// Load the history saved at `path`, and save new entries there.
+    ///
+    /// A missing or unreadable file starts an empty history.
+    pub fn load(path: PathBuf) -> Self {
+        let entries = fs::read_to_string(&path)
+            .map(|contents| contents.lines().map(str::to_string).collect())
+            .unwrap_or_default();
+        let mut history = Self {
+            entries,
+            path: Some(path),
+        };
+        history.truncate();
+        history
+    }
+
+    /// Lines in the history, oldest first.
+    pub fn entries(&self) -> &[String] {
+        &self.entries
+    }
+
+    /// Add a line, skipping blank lines and repeats of the previous line.
+    pub fn add(&mut self, line: &str) {
+        let line = line.trim();
+        if line.is_empty() || self.entries.last().is_some_and(|last| last == line) {
+            return;
+        }
+        self.entries.push(line.to_string());
+        self.truncate();
+        // History is a convenience; failing to save it shouldn't end the session
+        let _ = self.save();
+    }
+
+    fn truncate(&mut self) {
+        let excess = self.entries.len().saturating_sub(HISTORY_LIMIT);
+        self.entries.drain(..excess);
+    }
+
+    fn save(&self) -> io::Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut contents = self.entries.join("\n");
+        contents.push('\n');
+        fs::write(path, contents)
+    }
+}
+
+/// Leaves raw mode when dropped, so errors and panics restore the terminal.
+struct RawMode;
+
+impl RawMode {
+    fn enable() -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        Ok(Self)
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Reads command lines for the shell.
+pub struct LineEditor {
+    history: History,
+    interactive: bool,
+}
+
+impl LineEditor {
+    /// Create an editor. Editing is only enabled when stdin and stderr are terminals.
+    pub fn new(history: History) -> Self {
+        Self {
+            history,
+            interactive: atty::is(atty::Stream::Stdin) && atty::is(atty::Stream::Stderr),
+        }
+    }
+
+    /// Whether lines are read from a terminal with editing.
+    pub fn is_interactive(&self) -> bool {
+        self.interactive
+    }
+
+    /// Read one line. Returns `None` at end of input (Ctrl-D).
+    ///
+    /// Lines typed on a terminal are added to the history.
+    pub fn read_line(
+        &mut self,
+        prompt: &str,
+        complete: &mut Completer,
+    ) -> io::Result<Option<String>> {
+        if !self.interactive {
+            let mut line = String::new();
+            if io::stdin().lock().read_line(&mut line)? == 0 {
+                return Ok(None);
+            }
+            return Ok(Some(line.trim_end_matches(['\r', '\n']).to_string()));
+        }
+
+        let line = {
+            let _raw = RawMode::enable()?;
+            self.edit(prompt, complete)?
+        };
+        if let Some(line) = &line {
+            self.history.add(line);
+        }
+        Ok(line)
+    }
+
+    /// Edit a line in raw mode.
+    fn edit(&self, prompt: &str, complete: &mut Completer) -> io::Result<Option<String>> {
+        let mut out = io::stderr();
+        let entries = self.history.entries();
+        let mut line = LineBuffer::default();
+        // Position in the history while browsing; `entries.len()` is the new line
+        let mut browse = entries.len();
+        let mut draft = String::new();
+
+        render(&mut out, prompt, &line)?;
+        loop {
+            let key = match event::read()? {
+                Event::Key(key) if key.kind != KeyEventKind::Release => key,
+                Event::Resize(..) => {
+                    render(&mut out, prompt, &line)?;
+                    continue;
+                }
+                _ => continue,
+            };
+            let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+            match key.code {
+                KeyCode::Enter => {
+                    write!(out, "\r\n")?;
+                    return Ok(Some(line.text()));
+                }
+                KeyCode::Char('c') if ctrl => {
+                    write!(out, "^C\r\n")?;
+                    line = LineBuffer::default();
+                    browse = entries.len();
+                }
+                KeyCode::Char('d') if ctrl => {
+                    if line.chars.is_empty() {
+                        write!(out, "\r\n")?;
+                        return Ok(None);
+                    }
+                    line.delete();
+                }
+                KeyCode::Char('a') if ctrl => line.home(),
+                KeyCode::Char('e') if ctrl => line.end(),
+                KeyCode::Char('b') if ctrl => line.left(),
+                KeyCode::Char('f') if ctrl => line.right(),
+                KeyCode::Char('u') if ctrl => line.kill_to_start(),
+                KeyCode::Char('w') if ctrl => line.kill_word_back(),
+                KeyCode::Char('l') if ctrl => {
+                    queue!(out, terminal::Clear(ClearType::All), cursor::MoveTo(0, 0))?;
+                }
+                KeyCode::Char('p') if ctrl => {
+                    browse_back(entries, &mut browse, &mut draft, &mut line)
+                }
+                KeyCode::Char('n') if ctrl => {
+                    browse_forward(entries, &mut browse, &draft, &mut line)
+                }
+                KeyCode::Up => browse_back(entries, &mut browse, &mut draft, &mut line),
+                KeyCode::Down => browse_forward(entries, &mut browse, &draft, &mut line),
+                KeyCode::Home => line.home(),
+                KeyCode::End => line.end(),
+                KeyCode::Left => line.left(),
+                KeyCode::Right => line.right(),
+                KeyCode::Backspace => line.backspace(),
+                KeyCode::Delete => line.delete(),
+                KeyCode::Tab => {
+                    let before = line.before_cursor();
+                    let (start, candidates) = complete(&before);
+                    let current: String = before.chars().skip(start).collect();
+                    match completion_for(&current, &candidates) {
+                        Some(text) => line.replace_before_cursor(start, &text),
+                        None if candidates.len() > 1 => {
+                            write!(out, "\r\n{}\r\n", candidates.join("  "))?;
+                        }
+                        None => {}
+                    }
+                }
+                KeyCode::Char(c) if !ctrl => line.insert(c),
+                _ => {}
+            }
+            render(&mut out, prompt, &line)?;
+        }
+    }
+}
+
+fn browse_back(entries: &[String], browse: &mut usize, draft: &mut String, line: &mut LineBuffer) {
+    if *browse == 0 {
+        return;
+    }
+    if *browse == entries.len() {
+        *draft = line.text();
+    }
+    *browse -= 1;
+    line.set(&entries[*browse]);
+}
+
+fn browse_forward(entries: &[String], browse: &mut usize, draft: &str, line: &mut LineBuffer) {
+    if *browse >= entries.len() {
+        return;
+    }
+    *browse += 1;
+    line.set(entries.get(*browse).map_or(draft, String::as_str));
+}
+
+/// Redraw the prompt and line, and place the cursor.
+fn render(out: &mut impl Write, prompt: &str, line: &LineBuffer) -> io::Result<()> {
+    let column = prompt.chars().count() + line.cursor;
+    queue!(
+        out,
+        cursor::MoveToColumn(0),
+        terminal::Clear(ClearType::CurrentLine),
+        Print(prompt.cyan().bold()),
+        Print(line.text()),
+        cursor::MoveToColumn(u16::try_from(column).unwrap_or(u16::MAX)),
+    )?;
+    out.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn buffer(text: &str, cursor: usize) -> LineBuffer {
+        LineBuffer {
+            chars: text.chars().collect(),
+            cursor,
+        }
+    }
+
+    #[test]
+    fn test_line_buffer_editing() {
+        let mut line = buffer("get", 3);
+        line.insert('s');
+        line.left();
+        line.left();
+        line.backspace();
+        assert_eq!((line.text(), line.cursor), ("gts".to_string(), 1));
+        line.delete();
+        line.home();
+        line.insert('>');
+        line.end();
+        line.right();
+        assert_eq!((line.text(), line.cursor), (">gs".to_string(), 3));
+    }
+
+    #[test]
+    fn test_line_buffer_kills() {
+        let mut line = buffer("issue get  ENG-1", 16);
+        line.kill_word_back();
+        assert_eq!(line.text(), "issue get  ");
+        line.kill_word_back();
+        assert_eq!(line.text(), "issue ");
+
+        let mut line = buffer("state done", 6);
+        line.kill_to_start();
+        assert_eq!((line.text(), line.cursor), ("done".to_string(), 0));
+    }
+
+    #[test]
+    fn test_replace_before_cursor() {
+        let mut line = buffer("issue li --team ENG", 8);
+        line.replace_before_cursor(6, "list ");
+        assert_eq!(line.text(), "issue list  --team ENG");
+        assert_eq!(line.cursor, 11);
+    }
+
+    #[test]
+    fn test_completion_for() {
+        let candidates = |values: &[&str]| values.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            completion_for("ge", &candidates(&["get"])),
+            Some("get ".to_string())
+        );
+        assert_eq!(
+            completion_for("In", &candidates(&["In Progress", "In Review"])),
+            Some("In ".to_string())
+        );
+        assert_eq!(
+            completion_for("d", &candidates(&["done", "duplicate"])),
+            None
+        );
+        assert_eq!(completion_for("x", &[]), None);
+    }
+
+    #[test]
+    fn test_history_skips_repeats_and_caps_size() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("shell_history");
+        let mut history = History::load(path.clone());
+        history.add("issue list");
+        history.add("issue list");
+        history.add("   ");
+        history.add("get");
+        assert_eq!(history.entries(), ["issue list", "get"]);
+        assert_eq!(History::load(path.clone()).entries(), ["issue list", "get"]);
+
+        for i in 0..HISTORY_LIMIT {
+            history.add(&format!("issue get ENG-{}", i));
+        }
+        let reloaded = History::load(path);
+        assert_eq!(reloaded.entries().len(), HISTORY_LIMIT);
+        assert_eq!(reloaded.entries()[0], "issue get ENG-0");
+    }
+}
@@ -0,0 +1,898 @@
+//! Interactive shell (`lin shell`).
+//!
+//! The shell reads lin commands (without the leading `lin`) in a loop,
+//! reusing one API client and the loaded cache, so a triage session doesn't
+//! pay startup and config loading for every command. It keeps a session
+//! context:
+//!
+//! - the organization (`use org <name>` switches it for the session only),
+//! - a team, added as `--team` to commands that take it and don't have one,
+//! - a selected issue, which `get`, `comment`, `comments` and `state` act
+//!   on. Running `use <ID>`, or any command that prints a single issue,
+//!   selects it.
+//!
+//! `$_` in a line is replaced by the last result's identifier (or its `id`,
+//! `url`, `key` or `name`), and `$_.field` by one of its fields. For a list,
+//! `$_` is its first item and `$_.2` its third. Inside single quotes `$_` is
+//! left as is.
+
+mod editor;
+
+use clap::Command;
+use colored::Colorize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::Result;
+use crate::commands::completions::{self, ValueKind};
+use crate::config::Config;
+use crate::error::LinError;
+use crate::output::{self, OutputFormat, output, print_error};
+
+pub use editor::HISTORY_LIMIT;
+use editor::{History, LineEditor};
+
+/// Commands handled by the shell itself, with their help.
+const SHELL_COMMANDS: &[(&str, &str, &str)] = &[
+    ("use", "", "Show the session context"),
+    ("use", "issue <ID>", "Select an issue (also: use <ID>)"),
+    (
+        "use",
+        "team <KEY>",
+        "Use --team KEY for commands that take it",
+    ),
+    (
+        "use",
+        "org <NAME>",
+        "Use another organization in this session",
+    ),
+    ("get", "", "Show the selected issue"),
+    ("comment", "<TEXT>", "Comment on the selected issue"),
+    ("comments", "", "List comments on the selected issue"),
+    ("state", "<NAME>", "Move the selected issue to a state"),
+    (
+        "help",
+        "[COMMAND]",
+        "Show this help, or help for a lin command",
+    ),
+    ("exit", "", "Leave the shell (also: quit, Ctrl-D)"),
+];
+
+/// Fields used for `$_` when the last result is an object, in order.
+const DEFAULT_RESULT_FIELDS: &[&str] = &["identifier", "id", "url", "key", "name"];
+
+/// State kept between commands in the shell.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct Session {
+    /// Organization name
+    pub org: Option<String>,
+    /// Team key added as `--team` to commands that take one
+    pub team: Option<String>,
+    /// Identifier of the selected issue (e.g., "ENG-123")
+    pub issue: Option<String>,
+    /// The last result, available as `$_`
+    #[serde(skip)]
+    pub last_result: Option<Value>,
+}
+
+impl crate::output::HumanDisplay for Session {
+    fn human_fmt(&self) -> String {
+        let show = |value: &Option<String>| value.clone().unwrap_or_else(|| "-".to_string());
+        format!(
+            "org:   {}\nteam:  {}\nissue: {}",
+            show(&self.org),
+            show(&self.team),
+            show(&self.issue)
+        )
+    }
+}
+
+impl Session {
+    /// Start a session with the active organization and current team.
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            org: config.get_active_org_name().ok().map(str::to_string),
+            team: config.get_current_team(),
+            ..Self::default()
+        }
+    }
+
+    /// The prompt, e.g. `lin work/ENG ENG-123> `.
+    pub fn prompt(&self) -> String {
+        let mut context = self.org.clone().unwrap_or_default();
+        if let Some(team) = &self.team {
+            if !context.is_empty() {
+                context.push('/');
+            }
+            context.push_str(team);
+        }
+        if let Some(issue) = &self.issue {
+            if !context.is_empty() {
+                context.push(' ');
+            }
+            context.push_str(issue);
+        }
+        match context.is_empty() {
+            true => "lin> ".to_string(),
+            false => format!("lin {}> ", context),
+        }
+    }
+
+    /// Keep a command's result as `$_`; a single issue becomes the selected one.
+    ///
+    /// Commands that print nothing leave the previous result in place.
+    pub fn remember(&mut self, result: Option<Value>) {
+        let Some(result) = result else {
+            return;
+        };
+        if let Some(identifier) = result.get("identifier").and_then(Value::as_str) {
+            self.issue = Some(identifier.to_string());
+        }
+        self.last_result = Some(result);
+    }
+
+    /// Switch the session to another organization and its current team.
+    ///
+    /// Only the session changes; the active organization in the config stays.
+    pub fn use_org(&mut self, config: &Config, name: String) -> Result<()> {
+        let org = config.orgs.get(&name).ok_or_else(|| {
+            LinError::config(format!(
+                "Organization '{}' not found in configuration",
+                name
+            ))
+        })?;
+        self.team = match config.get_active_org_name() {
+            Ok(active) if active == name => config.get_current_team(),
+            _ => org.current_team.clone(),
+        };
+        self.org = Some(name);
+        self.issue = None;
+        Ok(())
+    }
+
+    fn selected_issue(&self) -> Result<&str> {
+        self.issue
+            .as_deref()
+            .ok_or_else(|| LinError::config("No issue selected. Select one with: use ENG-123"))
+    }
+}
+
+/// Which part of the session `use` changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum UseTarget {
+    Org,
+    Team,
+    Issue,
+}
+
+/// What a line asks the shell to do.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Action {
+    /// Leave the shell
+    Exit,
+    /// Print the shell's help
+    Help,
+    /// Print the session context
+    ShowContext,
+    /// Change (or with None, clear) part of the session
+    Use(UseTarget, Option<String>),
+    /// Run a lin command with these arguments (without the program name)
+    Run(Vec<String>),
+}
+
+/// Turn a line's words into an action, expanding the issue shortcuts.
+fn parse_words(mut words: Vec<String>, session: &Session) -> Result<Option<Action>> {
+    if words.first().is_some_and(|word| word == "lin") {
+        words.remove(0);
+    }
+    let Some(first) = words.first() else {
+        return Ok(None);
+    };
+    let rest = &words[1..];
+    let text = || {
+        let text = rest.join(" ");
+        match text.is_empty() {
+            true => {
+                let usage = SHELL_COMMANDS
+                    .iter()
+                    .find(|(name, _, _)| name == first)
+                    .map_or("", |(_, args, _)| args);
+                Err(LinError::config(format!("Usage: {} {}", first, usage)))
+            }
+            false => Ok(text),
+        }
+    };
+    let issue = || session.selected_issue().map(str::to_string);
+
+    let action = match first.as_str() {
+        "exit" | "quit" => Action::Exit,
+        "help" if rest.is_empty() => Action::Help,
+        "use" => match rest {
+            [] => Action::ShowContext,
+            [target, value @ ..] if value.len() <= 1 => {
+                let value = value.first().cloned();
+                match target.as_str() {
+                    "org" => Action::Use(UseTarget::Org, value),
+                    "team" => Action::Use(UseTarget::Team, value.map(|v| v.to_uppercase())),
+                    "issue" => Action::Use(UseTarget::Issue, value.map(|v| v.to_uppercase())),
+                    issue if value.is_none() => {
+                        Action::Use(UseTarget::Issue, Some(issue.to_uppercase()))
+                    }
+                    _ => return Err(LinError::config("Usage: use [org|team|issue] <VALUE>")),
+                }
+            }
+            _ => return Err(LinError::config("Usage: use [org|team|issue] <VALUE>")),
+        },
+        "get" => Action::Run(
+            ["issue", "get"]
+                .map(String::from)
+                .into_iter()
+                .chain([issue()?])
+                .chain(rest.iter().cloned())
+                .collect(),
+        ),
+        "comments" => Action::Run(
+            ["comment", "list"]
+                .map(String::from)
+                .into_iter()
+                .chain([issue()?])
+                .collect(),
+        ),
+        "comment" => Action::Run(vec![
+            "comment".to_string(),
+            "add".to_string(),
+            issue()?,
+            "--body".to_string(),
+            text()?,
+        ]),
+        "state" => Action::Run(vec![
+            "issue".to_string(),
+            "update".to_string(),
+            issue()?,
+            "--state".to_string(),
+            text()?,
+        ]),
+        _ => Action::Run(words),
+    };
+    Ok(Some(action))
+}
+
+/// The value `$_` (with optional `.field` path) refers to in `result`.
+///
+/// Lists stand for their first item unless the path indexes them.
+fn lookup_result<'a>(result: &'a Value, path: &[&str]) -> Result<&'a Value> {
+    let mut value = result;
+    for field in path {
+        value = match (value, field.parse::<usize>()) {
+            (Value::Array(items), Ok(index)) => items.get(index),
+            (Value::Array(items), Err(_)) => items.first().and_then(|item| item.get(field)),
+            (_, _) => value.get(field),
+        }
+        .ok_or_else(|| LinError::config(format!("The last result has no field '{}'", field)))?;
+    }
+    match value {
+        Value::Array(items) => items
+            .first()
+            .ok_or_else(|| LinError::config("The last result is an empty list")),
+        _ => Ok(value),
+    }
+}
+
+/// Text for a value substituted into a command line.
+fn result_text(value: &Value) -> Result<String> {
+    match value {
+        Value::String(text) => Ok(text.clone()),
+        Value::Number(_) | Value::Bool(_) => Ok(value.to_string()),
+        Value::Object(_) => DEFAULT_RESULT_FIELDS
+            .iter()
+            .find_map(|field| value.get(field).filter(|v| !v.is_null()))
+            .map(result_text)
+            .unwrap_or_else(|| {
+                Err(LinError::config(
+                    "The last result has no identifier; use $_.<field>",
+                ))
+            }),
+        Value::Null | Value::Array(_) => Err(LinError::config("The last result has no value here")),
+    }
+}
+
+/// Replace `$_` and `$_.field.subfield` in a line with the last result.
+///
+/// Works on the line before it is split into words so that `$_` inside
+/// single quotes, or escaped as `\$_`, is kept. Substituted text is quoted
+/// so it stays within its word.
+fn substitute_result(line: &str, last: Option<&Value>) -> Result<String> {
+    let mut out = String::with_capacity(line.len());
+    let mut quote = None;
+    let mut rest = line;
+    while let Some(c) = rest.chars().next() {
+        match (quote, c) {
+            (Some('\''), '\'') | (Some('"'), '"') => quote = None,
+            (None, '\'' | '"') => quote = Some(c),
+            (None | Some('"'), '\\') => {
+                // Keep the escape and the character it escapes
+                let escaped = rest[1..].chars().next().map_or(0, char::len_utf8);
+                out.push_str(&rest[..1 + escaped]);
+                rest = &rest[1 + escaped..];
+                continue;
+            }
+            (None | Some('"'), '$') if rest.starts_with("$_") => {
+                let last = last.ok_or_else(|| LinError::config("There is no result for $_ yet"))?;
+                rest = &rest[2..];
+                let mut path = Vec::new();
+                while let Some(after_dot) = rest.strip_prefix('.') {
+                    let len = after_dot
+                        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                        .unwrap_or(after_dot.len());
+                    if len == 0 {
+                        break;
+                    }
+                    path.push(&after_dot[..len]);
+                    rest = &after_dot[len..];
+                }
+                let text = result_text(lookup_result(last, &path)?)?;
+                match quote {
+                    Some(_) => {
+                        for c in text.chars() {
+                            if matches!(c, '"' | '\\' | '$' | '`') {
+                                out.push('\\');
+                            }
+                            out.push(c);
+                        }
+                    }
+                    None => out.push_str(
+                        &shlex::try_quote(&text)
+                            .map_err(|_| LinError::config("The last result can't be quoted"))?,
+                    ),
+                }
+                continue;
+            }
+            _ => {}
+        }
+        out.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+    Ok(out)
+}
+
+/// Add `--team <team>` when the command takes `--team` and none was given.
+///
+/// `standup` is left alone: `--team` changes what it reports, not which
+/// team it looks at.
+fn inject_team(cmd: &Command, args: &mut Vec<String>, team: &str) {
+    let mut command = cmd;
+    let mut path = Vec::new();
+    for word in args.iter() {
+        if word.starts_with('-') {
+            continue;
+        }
+        match command.find_subcommand(word) {
+            Some(sub) => {
+                command = sub;
+                path.push(word.as_str());
+            }
+            None if path.is_empty() => continue,
+            None => break,
+        }
+    }
+    let takes_team = command
+        .get_arguments()
+        .any(|arg| arg.get_long() == Some("team"));
+    let has_team = args
+        .iter()
+        .any(|arg| arg == "--team" || arg.starts_with("--team="));
+    if takes_team && !has_team && path.first() != Some(&"standup") {
+        args.extend(["--team".to_string(), team.to_string()]);
+    }
+}
+
+/// Split the text before the cursor into words, keeping quotes.
+///
+/// Returns each word with its start (in chars). A trailing empty word is
+/// added when the text ends between words.
+fn split_words(line: &str) -> Vec<(usize, String)> {
+    let mut words: Vec<(usize, String)> = Vec::new();
+    let mut current: Option<(usize, String)> = None;
+    let mut quote = None;
+    for (i, c) in line.chars().enumerate() {
+        match (quote, c) {
+            (None, c) if c.is_whitespace() => {
+                words.extend(current.take());
+                continue;
+            }
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), c) if c == q => quote = None,
+            _ => {}
+        }
+        current.get_or_insert_with(|| (i, String::new())).1.push(c);
+    }
+    words.push(current.unwrap_or_else(|| (line.chars().count(), String::new())));
+    words
+}
+
+/// Remove quotes from a complete word.
+fn unquote(word: &str) -> String {
+    shlex::split(word)
+        .and_then(|words| words.into_iter().next())
+        .unwrap_or_else(|| word.to_string())
+}
+
+/// Quote a completion so it stays one word, reusing the quote already typed.
+fn quote_candidate(value: &str, quote: Option<char>) -> String {
+    match quote {
+        Some(q) => format!("{q}{value}{q}"),
+        None if value.contains(char::is_whitespace) => format!("\"{}\"", value),
+        None => value.to_string(),
+    }
+}
+
+/// Complete the word being typed at the end of `line`.
+fn complete(cmd: &mut Command, session: &Session, line: &str) -> (usize, Vec<String>) {
+    let mut words = split_words(line);
+    let (start, raw) = words.pop().unwrap_or_default();
+    let quote = raw.chars().next().filter(|c| *c == '"' || *c == '\'');
+    let current = match quote {
+        Some(_) => raw[1..].to_string(),
+        None => raw.clone(),
+    };
+    let mut words: Vec<String> = words.iter().map(|(_, word)| unquote(word)).collect();
+    if words.first().is_some_and(|word| word == "lin") {
+        words.remove(0);
+    }
+
+    let mut values: Vec<String> = match words.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        [] => {
+            let mut names: Vec<String> = SHELL_COMMANDS
+                .iter()
+                .map(|(name, _, _)| name.to_string())
+                .chain(["quit".to_string()])
+                .filter(|name| name.starts_with(&current))
+                .collect();
+            names.extend(engine_values(cmd, session, &words, &current));
+            names.sort();
+            names.dedup();
+            names
+        }
+        ["use"] => ["org", "team", "issue"]
+            .iter()
+            .filter(|target| target.starts_with(&current))
+            .map(|target| target.to_string())
+            .collect(),
+        ["use", "org"] => Config::load()
+            .map(|config| {
+                config
+                    .list_orgs()
+                    .into_iter()
+                    .filter(|org| org.starts_with(&current))
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default(),
+        ["use", "team"] => cached_values(ValueKind::Team, &current, None),
+        ["use", "issue"] => cached_values(ValueKind::Issue, &current, None),
+        ["state"] => cached_values(ValueKind::State, &current, session.team.as_deref()),
+        ["use", ..] | ["state", ..] | ["get", ..] | ["comment", ..] | ["comments", ..] => {
+            Vec::new()
+        }
+        _ => engine_values(cmd, session, &words, &current),
+    };
+    values.dedup();
+    let candidates = values
+        .iter()
+        .map(|value| quote_candidate(value, quote))
+        .collect();
+    (start, candidates)
+}
+
+fn cached_values(kind: ValueKind, current: &str, team: Option<&str>) -> Vec<String> {
+    completions::complete_cached(kind, current, team)
+        .iter()
+        .map(|candidate| candidate.get_value().to_string_lossy().into_owned())
+        .collect()
+}
+
+/// Complete with the same engine the shell completion scripts use.
+fn engine_values(
+    cmd: &mut Command,
+    session: &Session,
+    words: &[String],
+    current: &str,
+) -> Vec<String> {
+    let args: Vec<_> = ["lin"]
+        .iter()
+        .map(|word| word.to_string())
+        .chain(words.iter().cloned())
+        .chain([current.to_string()])
+        .map(std::ffi::OsString::from)
+        .collect();
+    let index = args.len() - 1;
+    completions::complete_args(cmd, args, index, session.team.as_deref())
+        .iter()
+        .filter(|candidate| !candidate.is_hide_set())
+        .map(|candidate| candidate.get_value().to_string_lossy().into_owned())
+        .collect()
+}
+
+/// Print the shell's help.
+fn print_help() {
+    let width = SHELL_COMMANDS
+        .iter()
+        .map(|(name, args, _)| name.len() + args.len() + 1)
+        .max()
+        .unwrap_or(0);
+    println!("Shell commands:");
+    for (name, args, help) in SHELL_COMMANDS {
+        let usage = format!("{} {}", name, args);
+        println!("  {:width$}  {}", usage.trim_end(), help, width = width);
+    }
+    println!();
+    println!("Any other line runs a lin command without 'lin', e.g.: issue list --assignee me");
+    println!("$_ is the last result's identifier and $_.<field> one of its fields.");
+    println!("Getting or creating an issue selects it.");
+}
+
+/// Print an error without leaving the shell.
+fn report_error(err: &LinError, format: OutputFormat) {
+    match format {
+        OutputFormat::Human => eprintln!("{}: {}", "Error".red().bold(), err),
+        OutputFormat::Json => print_error(err),
+    }
+}
+
+/// Apply `use` to the session.
+fn apply_use(session: &mut Session, target: UseTarget, value: Option<String>) -> Result<()> {
+    match target {
+        UseTarget::Issue => session.issue = value,
+        UseTarget::Team => session.team = value,
+        UseTarget::Org => {
+            let name = value.ok_or_else(|| LinError::config("Usage: use org <NAME>"))?;
+            session.use_org(&Config::load()?, name)?;
+        }
+    }
+    Ok(())
+}
+
+/// Where the shell's history is saved, next to the config file.
+pub fn history_path() -> std::path::PathBuf {
+    Config::config_path().with_file_name("shell_history")
+}
+
+/// Run the interactive shell until `exit` or end of input.
+///
+/// Each command line is handed to `run` as lin arguments (without the
+/// program name), after shortcuts, `$_` and the session team are applied.
+/// Results that `run` prints with [`output`](crate::output::output) become
+/// `$_`. Errors are printed and the shell carries on.
+///
+/// # Arguments
+///
+/// * `cmd` - The lin command definition, for completion and `--team` detection
+/// * `session` - Starting session context
+/// * `format` - Output format for the shell's own messages
+/// * `run` - Runs one lin command line with the current session
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use lin::commands::shell::{Session, shell};
+/// use lin::config::Config;
+/// use lin::output::OutputFormat;
+///
+/// let session = Session::from_config(&Config::load()?);
+/// let cmd = clap::Command::new("lin");
+/// shell(cmd, session, OutputFormat::Human, |args, _session| {
+///     println!("would run: lin {}", args.join(" "));
+///     Ok(())
+/// })?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn shell<F>(
+    mut cmd: Command,
+    mut session: Session,
+    format: OutputFormat,
+    mut run: F,
+) -> Result<()>
+where
+    F: FnMut(Vec<String>, &Session) -> Result<()>,
+{
+    let mut editor = LineEditor::new(History::load(history_path()));
+    if editor.is_interactive() {
+        eprintln!("lin shell. Type 'help' for commands, 'exit' or Ctrl-D to leave.");
+    }
+
+    loop {
+        let prompt = session.prompt();
+        let line = {
+            let mut completer = |line: &str| complete(&mut cmd, &session, line);
+            editor.read_line(&prompt, &mut completer)?
+        };
+        let Some(line) = line else {
+            return Ok(());
+        };
+
+        let action = substitute_result(&line, session.last_result.as_ref())
+            .and_then(|line| {
+                shlex::split(&line).ok_or_else(|| LinError::config("Unbalanced quotes"))
+            })
+            .and_then(|words| parse_words(words, &session));
+        let result = match action {
+            Ok(None) => Ok(()),
+            Ok(Some(Action::Exit)) => return Ok(()),
+            Ok(Some(Action::Help)) => {
+                print_help();
+                Ok(())
+            }
+            Ok(Some(Action::ShowContext)) => {
+                output(&session, format);
+                Ok(())
+            }
+            Ok(Some(Action::Use(target, value))) => apply_use(&mut session, target, value),
+            Ok(Some(Action::Run(mut args))) => {
+                if let Some(team) = &session.team {
+                    inject_team(&cmd, &mut args, team);
+                }
+                output::record_results(true);
+                let result = run(args, &session);
+                output::record_results(false);
+                let last = output::take_last_result();
+                if result.is_ok() {
+                    session.remember(last);
+                }
+                result
+            }
+            Err(err) => Err(err),
+        };
+        if let Err(err) = result {
+            report_error(&err, format);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Arg;
+    use serde_json::json;
+
+    fn words(line: &str) -> Vec<String> {
+        shlex::split(line).unwrap()
+    }
+
+    fn selected(issue: &str) -> Session {
+        Session {
+            issue: Some(issue.to_string()),
+            ..Session::default()
+        }
+    }
+
+    fn run_args(line: &str, session: &Session) -> Vec<String> {
+        match parse_words(words(line), session).unwrap() {
+            Some(Action::Run(args)) => args,
+            other => panic!("expected a command, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_prompt() {
+        assert_eq!(Session::default().prompt(), "lin> ");
+        let session = Session {
+            org: Some("work".to_string()),
+            team: Some("ENG".to_string()),
+            issue: Some("ENG-123".to_string()),
+            last_result: None,
+        };
+        assert_eq!(session.prompt(), "lin work/ENG ENG-123> ");
+        assert_eq!(selected("ENG-1").prompt(), "lin ENG-1> ");
+    }
+
+    #[test]
+    fn test_shortcuts_act_on_selected_issue() {
+        let session = selected("ENG-123");
+        assert_eq!(run_args("get", &session), ["issue", "get", "ENG-123"]);
+        assert_eq!(
+            run_args("comment \"Looks good\" to me", &session),
+            ["comment", "add", "ENG-123", "--body", "Looks good to me"]
+        );
+        assert_eq!(
+            run_args("state in progress", &session),
+            ["issue", "update", "ENG-123", "--state", "in progress"]
+        );
+        assert_eq!(
+            run_args("comments", &session),
+            ["comment", "list", "ENG-123"]
+        );
+        assert_eq!(
+            run_args("lin issue list --assignee me", &session),
+            ["issue", "list", "--assignee", "me"]
+        );
+
+        let err = parse_words(words("get"), &Session::default()).unwrap_err();
+        assert!(err.to_string().contains("No issue selected"));
+        let err = parse_words(words("state"), &session).unwrap_err();
+        assert!(err.to_string().contains("Usage: state"));
+    }
+
+    #[test]
+    fn test_parse_use_and_builtins() {
+        let session = Session::default();
+        let parse = |line: &str| parse_words(words(line), &session).unwrap();
+        assert_eq!(parse(""), None);
+        assert_eq!(parse("quit"), Some(Action::Exit));
+        assert_eq!(parse("help"), Some(Action::Help));
+        assert_eq!(parse("help issue"), Some(Action::Run(words("help issue"))));
+        assert_eq!(parse("use"), Some(Action::ShowContext));
+        assert_eq!(
+            parse("use eng-42"),
+            Some(Action::Use(UseTarget::Issue, Some("ENG-42".to_string())))
+        );
+        assert_eq!(
+            parse("use team des"),
+            Some(Action::Use(UseTarget::Team, Some("DES".to_string())))
+        );
+        assert_eq!(
+            parse("use issue"),
+            Some(Action::Use(UseTarget::Issue, None))
+        );
+        assert_eq!(
+            parse("use org Work"),
+            Some(Action::Use(UseTarget::Org, Some("Work".to_string())))
+        );
+        assert!(parse_words(words("use team a b"), &session).is_err());
+    }
+
+    #[test]
+    fn test_substitute_result() {
+        let issue = json!({"id": "uuid-1", "identifier": "ENG-7", "state": {"name": "Todo"}});
+        let substitute =
+            |line: &str, last: &Value| substitute_result(line, Some(last)).map(|line| words(&line));
+        assert_eq!(
+            substitute("issue get $_", &issue).unwrap(),
+            ["issue", "get", "ENG-7"]
+        );
+        assert_eq!(
+            substitute("comment \"was $_.state.name\" $_.id.", &issue).unwrap(),
+            ["comment", "was Todo", "uuid-1."]
+        );
+
+        let list = json!([{"identifier": "ENG-1"}, {"identifier": "ENG-2"}]);
+        assert_eq!(substitute("use $_", &list).unwrap(), ["use", "ENG-1"]);
+        assert_eq!(substitute("use $_.1", &list).unwrap(), ["use", "ENG-2"]);
+        assert_eq!(
+            substitute("x $_.identifier", &list).unwrap(),
+            ["x", "ENG-1"]
+        );
+
+        assert!(substitute("x $_.missing", &issue).is_err());
+        assert!(substitute("x $_", &json!({"title": "t"})).is_err());
+        assert!(substitute_result("get $_", None).is_err());
+        assert_eq!(substitute_result("issue list", None).unwrap(), "issue list");
+    }
+
+    #[test]
+    fn test_substitute_result_respects_quotes() {
+        let issue = json!({"identifier": "ENG-7", "state": {"name": "In \"Review\""}});
+        let substitute =
+            |line: &str| substitute_result(line, Some(&issue)).map(|line| words(&line));
+        assert_eq!(
+            substitute("comment 'costs $_ to fix'").unwrap(),
+            ["comment", "costs $_ to fix"]
+        );
+        assert_eq!(substitute(r"x \$_").unwrap(), ["x", "$_"]);
+        assert_eq!(
+            substitute("x $_.state.name").unwrap(),
+            ["x", "In \"Review\""]
+        );
+        assert_eq!(
+            substitute("x \"now $_.state.name\"").unwrap(),
+            ["x", "now In \"Review\""]
+        );
+        assert_eq!(substitute_result("echo '$_'", None).unwrap(), "echo '$_'");
+    }
+
+    #[test]
+    fn test_remember_selects_single_issue() {
+        let mut session = Session::default();
+        session.remember(Some(json!([{"identifier": "ENG-1"}])));
+        assert_eq!(session.issue, None);
+        session.remember(Some(json!({"identifier": "ENG-2", "title": "t"})));
+        assert_eq!(session.issue.as_deref(), Some("ENG-2"));
+        session.remember(None);
+        assert_eq!(
+            session.last_result,
+            Some(json!({"identifier": "ENG-2", "title": "t"}))
+        );
+    }
+
+    #[test]
+    fn test_use_org_only_changes_session() {
+        let mut config = Config::default();
+        config
+            .add_org("work".to_string(), "t1".to_string())
+            .unwrap();
+        config
+            .add_org("side".to_string(), "t2".to_string())
+            .unwrap();
+        config.switch_org("work").unwrap();
+        config.orgs.get_mut("side").unwrap().current_team = Some("OPS".to_string());
+
+        let mut session = selected("ENG-1");
+        session.use_org(&config, "side".to_string()).unwrap();
+        assert_eq!(session.org.as_deref(), Some("side"));
+        assert_eq!(session.team.as_deref(), Some("OPS"));
+        assert_eq!(session.issue, None);
+        assert_eq!(config.active_org.as_deref(), Some("work"));
+
+        let err = session.use_org(&config, "nope".to_string()).unwrap_err();
+        assert!(err.to_string().contains("not found"));
+    }
+
+    #[test]
+    fn test_inject_team() {
+        let team = || Arg::new("team").long("team");
+        let cmd = Command::new("lin")
+            .arg(Arg::new("json").long("json").num_args(0))
+            .subcommand(
+                Command::new("issue")
+                    .subcommand(Command::new("list").arg(team()))
+                    .subcommand(Command::new("get").arg(Arg::new("identifier"))),
+            )
+            .subcommand(Command::new("standup").arg(team()));
+        let inject = |line: &str| {
+            let mut args = words(line);
+            inject_team(&cmd, &mut args, "ENG");
+            args
+        };
+        assert_eq!(
+            inject("--json issue list"),
+            ["--json", "issue", "list", "--team", "ENG"]
+        );
+        assert_eq!(
+            inject("issue list --team=DES"),
+            ["issue", "list", "--team=DES"]
+        );
+        assert_eq!(inject("issue get ENG-1"), ["issue", "get", "ENG-1"]);
+        assert_eq!(inject("standup"), ["standup"]);
+    }
+
+    #[test]
+    fn test_split_words_keeps_quotes() {
+        assert_eq!(
+            split_words("state \"In Pr"),
+            vec![(0, "state".to_string()), (6, "\"In Pr".to_string())]
+        );
+        assert_eq!(
+            split_words("issue  "),
+            vec![(0, "issue".to_string()), (7, String::new())]
+        );
+        assert_eq!(split_words(""), vec![(0, String::new())]);
+    }
+
+    #[test]
+    fn test_complete_shell_and_lin_commands() {
+        let mut cmd = Command::new("lin")
+            .subcommand(Command::new("issue").subcommand(Command::new("list")))
+            .subcommand(Command::new("user"));
+        let session = Session::default();
+        assert_eq!(
+            complete(&mut cmd, &session, "u"),
+            (0, vec!["use".to_string(), "user".to_string()])
+        );
+        assert_eq!(
+            complete(&mut cmd, &session, "lin issue l"),
+            (10, vec!["list".to_string()])
+        );
+        assert_eq!(
+            complete(&mut cmd, &session, "use t"),
+            (4, vec!["team".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_quote_candidate() {
+        assert_eq!(quote_candidate("Done", None), "Done");
+        assert_eq!(quote_candidate("In Progress", None), "\"In Progress\"");
+        assert_eq!(quote_candidate("In Progress", Some('\'')), "'In Progress'");
+    }
+}
@@ -0,0 +1,765 @@
+//! Daily standup summary generator.
+//!
+//! Collects what a person (or a whole team) completed, moved, commented on
+//! and created since a point in time, plus what is currently in progress and
+//! blocked, and renders it as ready-to-paste Markdown.
+
+use std::collections::BTreeSet;
+
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, Utc, Weekday};
+use serde::Serialize;
+
+use crate::Result;
+use crate::api::GraphQLClient;
+use crate::api::queries::report::{OPEN_ISSUES_WITH_BLOCKERS_QUERY, STANDUP_ACTIVITY_QUERY};
+use crate::commands::issue::IssueListOptions;
+use crate::commands::issue::list::build_issue_filter;
+use crate::commands::resolvers;
+use crate::error::LinError;
+use crate::models::{OpenIssue, OpenIssuesResponse, StandupIssue, StandupIssuesResponse, User};
+use crate::output::{HumanDisplay, OutputFormat, output};
+
+/// Maximum number of recently updated issues to inspect.
+const ACTIVITY_ISSUE_LIMIT: u32 = 100;
+
+/// Number of history entries and comments fetched per issue.
+const ACTIVITY_PER_ISSUE: u32 = 50;
+
+/// Maximum number of open issues to check for in-progress and blocked work.
+const OPEN_ISSUE_LIMIT: u32 = 100;
+
+/// Whose activity to summarize.
+#[derive(Debug, Clone)]
+pub enum StandupScope {
+    /// A single user ("me", name, email, or UUID).
+    User(String),
+    /// Everyone on a team (team key).
+    Team(String),
+}
+
+/// Generate a standup summary.
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the API request
+/// * `scope` - Whose activity to summarize (a user or a whole team)
+/// * `since` - Start of the window: "yesterday", "today", YYYY-MM-DD, or a duration like 24h or 3d
+/// * `format` - The output format (Human prints Markdown, Json prints structured data)
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use lin::api::GraphQLClient;
+/// use lin::commands::standup::{standup, StandupScope};
+/// use lin::output::OutputFormat;
+///
+/// let client = GraphQLClient::new("lin_api_xxxxx");
+/// standup(&client, StandupScope::User("me".to_string()), "yesterday", OutputFormat::Human)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn standup(
+    client: &GraphQLClient,
+    scope: StandupScope,
+    since: &str,
+    format: OutputFormat,
+) -> Result<()> {
+    let since = parse_since(since, Local::now())?;
+
+    let (subject, user) = match &scope {
+        StandupScope::User(reference) => {
+            let user = resolvers::resolve_user(client, reference)?;
+            (user.name.clone(), Some(user))
+        }
+        StandupScope::Team(key) => (key.to_uppercase(), None),
+    };
+    let team = match &scope {
+        StandupScope::Team(key) => Some(key.to_uppercase()),
+        StandupScope::User(_) => None,
+    };
+    let user_id = user.as_ref().map(|u| u.id.as_str());
+
+    // Activity: issues touched since the window start that involve the subject
+    let mut activity_filter = build_issue_filter(
+        client,
+        user_id,
+        &IssueListOptions {
+            team: team.clone(),
+            updated_after: Some(since.to_rfc3339()),
+            ..Default::default()
+        },
+    )?;
+    if let Some(user_id) = user_id {
+        activity_filter.insert(
+            "or".to_string(),
+            serde_json::json!([
+                { "assignee": { "id": { "eq": user_id } } },
+                { "creator": { "id": { "eq": user_id } } },
+                { "subscribers": { "id": { "eq": user_id } } }
+            ]),
+        );
+    }
+    let variables = serde_json::json!({
+        "first": ACTIVITY_ISSUE_LIMIT,
+        "activityFirst": ACTIVITY_PER_ISSUE,
+        "filter": activity_filter
+    });
+    let activity: StandupIssuesResponse = client.query(STANDUP_ACTIVITY_QUERY, variables)?;
+
+    // Open work: reuse the list filter for assignee/team, then restrict to open states
+    let mut open_filter = build_issue_filter(
+        client,
+        user_id,
+        &IssueListOptions {
+            team,
+            assignee: user_id.map(String::from),
+            ..Default::default()
+        },
+    )?;
+    open_filter.insert(
+        "state".to_string(),
+        serde_json::json!({ "type": { "in": ["unstarted", "started"] } }),
+    );
+    let variables = serde_json::json!({
+        "first": OPEN_ISSUE_LIMIT,
+        "filter": open_filter
+    });
+    let open: OpenIssuesResponse = client.query(OPEN_ISSUES_WITH_BLOCKERS_QUERY, variables)?;
+
+    let report = build_standup_report(
+        subject,
+        user.as_ref(),
+        since,
+        &activity.issues.nodes,
+        &open.issues.nodes,
+    );
+    output(&report, format);
+    Ok(())
+}
+
+/// Parse the start of a standup window.
+///
+/// Accepts:
+/// - `yesterday`: midnight of the previous working day (Friday on a Monday)
+/// - `today`: midnight today
+/// - `YYYY-MM-DD`: midnight of that day in local time
+/// - a duration ago: `12h`, `3d`, `1w`
+pub fn parse_since(s: &str, now: DateTime<Local>) -> Result<DateTime<Utc>> {
+    let s = s.trim();
+    let local_midnight = |date: NaiveDate| {
+        date.and_hms_opt(0, 0, 0)
+            .and_then(|dt| dt.and_local_timezone(Local).earliest())
+            .map(|dt| dt.with_timezone(&Utc))
+            .ok_or_else(|| LinError::parse(format!("Invalid --since date '{}'", s)))
+    };
+
+    match s.to_lowercase().as_str() {
+        "today" => return local_midnight(now.date_naive()),
+        "yesterday" => {
+            let today = now.date_naive();
+            let back = match today.weekday() {
+                Weekday::Mon => 3,
+                Weekday::Sun => 2,
+                _ => 1,
+            };
+            return local_midnight(today - Duration::days(back));
+        }
+        _ => {}
+    }
+
+    if let Ok(date) = NaiveDate::parse_from_str(s, "%Y-%m-%d") {
+        return local_midnight(date);
+    }
+
+    if s.len() >= 2 {
+        let (amount, unit) = s.split_at(s.len() - 1);
+        if let Ok(amount) = amount.parse::<i64>() {
+            let delta = match unit {
+                "h" => Some(Duration::hours(amount)),
+                "d" => Some(Duration::days(amount)),
+                "w" => Some(Duration::weeks(amount)),
+                _ => None,
+            };
+            if let Some(delta) = delta.filter(|_| amount > 0) {
+                return Ok((now - delta).with_timezone(&Utc));
+            }
+        }
+    }
+
+    Err(LinError::parse(format!(
+        "Invalid --since value '{}': expected yesterday, today, YYYY-MM-DD, or a duration like 24h, 3d, 1w",
+        s
+    )))
+}
+
+/// A single line in a standup section.
+#[derive(Debug, Clone, Serialize)]
+pub struct StandupItem {
+    /// Human-readable identifier (e.g., "ENG-123").
+    pub identifier: String,
+    /// Issue title.
+    pub title: String,
+    /// Extra context (e.g., "Todo → In Progress", "blocked by ENG-9").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    /// People involved (team standups only).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub people: Vec<String>,
+}
+
+/// A standup summary.
+#[derive(Debug, Clone, Serialize)]
+pub struct StandupReport {
+    /// User name or team key the summary is for.
+    pub subject: String,
+    /// ISO 8601 start of the window.
+    pub since: String,
+    /// Issues completed in the window.
+    pub completed: Vec<StandupItem>,
+    /// Issues moved between states in the window (excluding completions).
+    pub moved: Vec<StandupItem>,
+    /// Issues commented on in the window.
+    pub commented: Vec<StandupItem>,
+    /// Issues created in the window.
+    pub created: Vec<StandupItem>,
+    /// Issues currently in progress.
+    pub in_progress: Vec<StandupItem>,
+    /// Open issues blocked by unfinished issues.
+    pub blocked: Vec<StandupItem>,
+}
+
+fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .ok()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+fn in_window(timestamp: &str, since: DateTime<Utc>) -> bool {
+    parse_timestamp(timestamp).is_some_and(|t| t >= since)
+}
+
+fn is_done(state_type: &str) -> bool {
+    matches!(state_type, "completed" | "canceled")
+}
+
+/// Collects items for one section, tracking the people involved.
+struct ItemBuilder<'a> {
+    issue: &'a StandupIssue,
+    people: BTreeSet<String>,
+    detail: Option<String>,
+}
+
+impl<'a> ItemBuilder<'a> {
+    fn new(issue: &'a StandupIssue) -> Self {
+        Self {
+            issue,
+            people: BTreeSet::new(),
+            detail: None,
+        }
+    }
+
+    fn build(self, team_mode: bool) -> StandupItem {
+        StandupItem {
+            identifier: self.issue.identifier.clone(),
+            title: self.issue.title.clone(),
+            detail: self.detail,
+            people: if team_mode {
+                self.people.into_iter().collect()
+            } else {
+                Vec::new()
+            },
+        }
+    }
+}
+
+/// Build a standup report from fetched issues.
+///
+/// When `user` is set, only that user's actions count (plus completions of
+/// issues assigned to them, which are often made by integrations). Otherwise
+/// every actor's activity counts and items list the people involved.
+pub fn build_standup_report(
+    subject: String,
+    user: Option<&User>,
+    since: DateTime<Utc>,
+    activity: &[StandupIssue],
+    open: &[OpenIssue],
+) -> StandupReport {
+    let team_mode = user.is_none();
+    let matches = |person: Option<&User>| match (user, person) {
+        (Some(user), Some(person)) => person.id == user.id,
+        (Some(_), None) => false,
+        (None, _) => true,
+    };
+    let name = |person: Option<&User>| person.map(|p| p.name.clone());
+
+    let mut completed = Vec::new();
+    let mut moved = Vec::new();
+    let mut commented = Vec::new();
+    let mut created = Vec::new();
+
+    for issue in activity {
+        // State changes in the window, oldest first
+        let mut transitions: Vec<_> = issue
+            .history
+            .nodes
+            .iter()
+            .filter(|h| h.to_state.is_some() && in_window(&h.created_at, since))
+            .filter(|h| matches(h.actor.as_ref()))
+            .collect();
+        transitions.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+
+        // Completed: completed in the window by the subject, or an assigned issue completed
+        let completed_in_window = issue
+            .completed_at
+            .as_deref()
+            .is_some_and(|c| in_window(c, since))
+            && issue.state.type_ == "completed";
+        let completed_by_subject = transitions
+            .iter()
+            .any(|h| h.to_state.as_ref().is_some_and(|s| s.type_ == "completed"));
+        if completed_in_window && (completed_by_subject || matches(issue.assignee.as_ref())) {
+            let mut item = ItemBuilder::new(issue);
+            item.people.extend(name(issue.assignee.as_ref()));
+            completed.push(item.build(team_mode));
+        } else if let (Some(first), Some(last)) = (transitions.first(), transitions.last()) {
+            let from = first.from_state.as_ref().map(|s| s.name.as_str());
+            let to = last.to_state.as_ref().map(|s| s.name.as_str());
+            let mut item = ItemBuilder::new(issue);
+            item.detail = match (from, to) {
+                (Some(from), Some(to)) if from != to => Some(format!("{} → {}", from, to)),
+                (None, Some(to)) => Some(format!("→ {}", to)),
+                _ => None,
+            };
+            for h in &transitions {
+                item.people.extend(name(h.actor.as_ref()));
+            }
+            moved.push(item.build(team_mode));
+        }
+
+        let comments: Vec<_> = issue
+            .comments
+            .nodes
+            .iter()
+            .filter(|c| in_window(&c.created_at, since) && matches(c.user.as_ref()))
+            .collect();
+        if !comments.is_empty() {
+            let mut item = ItemBuilder::new(issue);
+            if comments.len() > 1 {
+                item.detail = Some(format!("{} comments", comments.len()));
+            }
+            for c in &comments {
+                item.people.extend(name(c.user.as_ref()));
+            }
+            commented.push(item.build(team_mode));
+        }
+
+        if in_window(&issue.created_at, since) && matches(issue.creator.as_ref()) {
+            let mut item = ItemBuilder::new(issue);
+            item.people.extend(name(issue.creator.as_ref()));
+            created.push(item.build(team_mode));
+        }
+    }
+
+    let mut in_progress = Vec::new();
+    let mut blocked = Vec::new();
+    for issue in open {
+        let people: Vec<String> = match (&issue.assignee, team_mode) {
+            (Some(assignee), true) => vec![assignee.name.clone()],
+            _ => Vec::new(),
+        };
+
+        if issue.state.type_ == "started" {
+            in_progress.push(StandupItem {
+                identifier: issue.identifier.clone(),
+                title: issue.title.clone(),
+                detail: None,
+                people: people.clone(),
+            });
+        }
+
+        // Inverse "blocks" relations point from the blocker to this issue
+        let blockers: Vec<&str> = issue
+            .inverse_relations
+            .nodes
+            .iter()
+            .filter(|r| r.type_ == "blocks")
+            .filter_map(|r| r.issue.as_ref())
+            .filter(|b| !b.state.as_ref().is_some_and(|s| is_done(&s.type_)))
+            .map(|b| b.identifier.as_str())
+            .collect();
+        if !blockers.is_empty() {
+            blocked.push(StandupItem {
+                identifier: issue.identifier.clone(),
+                title: issue.title.clone(),
+                detail: Some(format!("blocked by {}", blockers.join(", "))),
+                people,
+            });
+        }
+    }
+
+    StandupReport {
+        subject,
+        since: since.to_rfc3339(),
+        completed,
+        moved,
+        commented,
+        created,
+        in_progress,
+        blocked,
+    }
+}
+
+fn markdown_item(item: &StandupItem) -> String {
+    let mut line = format!("- {} {}", item.identifier, item.title);
+    if let Some(detail) = &item.detail {
+        line.push_str(&format!(" ({})", detail));
+    }
+    if !item.people.is_empty() {
+        line.push_str(&format!(" — {}", item.people.join(", ")));
+    }
+    line
+}
+
+impl HumanDisplay for StandupReport {
+    // Plain Markdown without colours so it can be pasted as-is
+    fn human_fmt(&self) -> String {
+        let since = parse_timestamp(&self.since)
+            .map(|t| t.with_timezone(&Local).format("%Y-%m-%d %H:%M").to_string())
+            .unwrap_or_else(|| self.since.clone());
+        let mut parts = vec![
+            format!("## Standup: {}", self.subject),
+            format!("_Since {}_", since),
+        ];
+
+        let sections = [
+            ("Completed", &self.completed),
+            ("Moved", &self.moved),
+            ("Commented", &self.commented),
+            ("Created", &self.created),
+            ("In progress", &self.in_progress),
+            ("Blocked", &self.blocked),
+        ];
+        let mut any = false;
+        for (title, items) in sections {
+            if items.is_empty() {
+                continue;
+            }
+            any = true;
+            parts.push(String::new());
+            parts.push(format!("### {}", title));
+            parts.extend(items.iter().map(markdown_item));
+        }
+        if !any {
+            parts.push(String::new());
+            parts.push("_No activity._".to_string());
+        }
+
+        parts.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn since() -> DateTime<Utc> {
+        parse_timestamp("2024-01-02T00:00:00Z").unwrap()
+    }
+
+    fn user_json(id: &str, name: &str) -> String {
+        format!(
+            r#"{{ "id": "{}", "name": "{}", "email": "{}@example.com", "displayName": null, "active": true }}"#,
+            id, name, id
+        )
+    }
+
+    fn state_json(name: &str, type_: &str) -> String {
+        format!(
+            r##"{{ "id": "{}", "name": "{}", "color": "#000", "type": "{}" }}"##,
+            name, name, type_
+        )
+    }
+
+    fn activity() -> Vec<StandupIssue> {
+        let jane = user_json("jane", "Jane Doe");
+        let bob = user_json("bob", "Bob Roe");
+        let json = format!(
+            r#"[
+                {{
+                    "id": "i1", "identifier": "ENG-1", "title": "Fix login",
+                    "createdAt": "2023-12-01T00:00:00Z", "completedAt": "2024-01-02T10:00:00Z",
+                    "state": {done}, "assignee": {jane}, "creator": {bob},
+                    "history": {{ "nodes": [
+                        {{ "id": "h1", "createdAt": "2024-01-02T10:00:00Z", "actor": null,
+                           "fromState": {doing}, "toState": {done} }}
+                    ] }},
+                    "comments": {{ "nodes": [] }}
+                }},
+                {{
+                    "id": "i2", "identifier": "ENG-2", "title": "Add OAuth",
+                    "createdAt": "2023-12-01T00:00:00Z", "completedAt": null,
+                    "state": {doing}, "assignee": {jane}, "creator": {jane},
+                    "history": {{ "nodes": [
+                        {{ "id": "h2", "createdAt": "2024-01-02T09:00:00Z", "actor": {jane},
+                           "fromState": {todo}, "toState": {doing} }},
+                        {{ "id": "h3", "createdAt": "2024-01-01T09:00:00Z", "actor": {jane},
+                           "fromState": {backlog}, "toState": {todo} }}
+                    ] }},
+                    "comments": {{ "nodes": [
+                        {{ "id": "c1", "body": "Started", "createdAt": "2024-01-02T09:05:00Z",
+                           "updatedAt": "2024-01-02T09:05:00Z", "user": {jane} }},
+                        {{ "id": "c2", "body": "Review?", "createdAt": "2024-01-02T11:00:00Z",
+                           "updatedAt": "2024-01-02T11:00:00Z", "user": {bob} }}
+                    ] }}
+                }},
+                {{
+                    "id": "i3", "identifier": "ENG-3", "title": "Write docs",
+                    "createdAt": "2024-01-02T12:00:00Z", "completedAt": null,
+                    "state": {todo}, "assignee": null, "creator": {bob},
+                    "history": {{ "nodes": [] }},
+                    "comments": {{ "nodes": [] }}
+                }}
+            ]"#,
+            jane = jane,
+            bob = bob,
+            backlog = state_json("Backlog", "backlog"),
+            todo = state_json("Todo", "unstarted"),
+            doing = state_json("In Progress", "started"),
+            done = state_json("Done", "completed"),
+        );
+        serde_json::from_str(&json).unwrap()
+    }
+
+    fn open() -> Vec<OpenIssue> {
+        let json = format!(
+            r#"[
+                {{
+                    "id": "i2", "identifier": "ENG-2", "title": "Add OAuth",
+                    "state": {doing}, "assignee": {jane},
+                    "inverseRelations": {{ "nodes": [
+                        {{ "id": "r1", "type": "blocks",
+                           "issue": {{ "id": "i9", "identifier": "ENG-9", "title": "Client IDs", "state": {todo} }} }},
+                        {{ "id": "r2", "type": "blocks",
+                           "issue": {{ "id": "i8", "identifier": "ENG-8", "title": "Old", "state": {done} }} }},
+                        {{ "id": "r3", "type": "related",
+                           "issue": {{ "id": "i7", "identifier": "ENG-7", "title": "Other", "state": {todo} }} }}
+                    ] }}
+                }},
+                {{
+                    "id": "i4", "identifier": "ENG-4", "title": "Plan Q1",
+                    "state": {todo}, "assignee": {jane},
+                    "inverseRelations": {{ "nodes": [] }}
+                }}
+            ]"#,
+            jane = user_json("jane", "Jane Doe"),
+            todo = state_json("Todo", "unstarted"),
+            doing = state_json("In Progress", "started"),
+            done = state_json("Done", "completed"),
+        );
+        serde_json::from_str(&json).unwrap()
+    }
+
+    fn jane() -> User {
+        serde_json::from_str(&user_json("jane", "Jane Doe")).unwrap()
+    }
+
+    fn identifiers(items: &[StandupItem]) -> Vec<&str> {
+        items.iter().map(|i| i.identifier.as_str()).collect()
+    }
+
+    #[test]
+    fn test_build_standup_report_for_user() {
+        let user = jane();
+        let report = build_standup_report(
+            "Jane Doe".into(),
+            Some(&user),
+            since(),
+            &activity(),
+            &open(),
+        );
+
+        // ENG-1 was completed by an integration but is assigned to Jane
+        assert_eq!(identifiers(&report.completed), vec!["ENG-1"]);
+        assert_eq!(identifiers(&report.moved), vec!["ENG-2"]);
+        assert_eq!(
+            report.moved[0].detail.as_deref(),
+            Some("Todo → In Progress")
+        );
+        // Bob's comment doesn't count for Jane
+        assert_eq!(identifiers(&report.commented), vec!["ENG-2"]);
+        assert!(report.commented[0].detail.is_none());
+        assert!(report.created.is_empty());
+        assert_eq!(identifiers(&report.in_progress), vec!["ENG-2"]);
+        assert_eq!(identifiers(&report.blocked), vec!["ENG-2"]);
+        assert_eq!(
+            report.blocked[0].detail.as_deref(),
+            Some("blocked by ENG-9")
+        );
+        assert!(report.completed[0].people.is_empty());
+    }
+
+    #[test]
+    fn test_build_standup_report_for_team() {
+        let report = build_standup_report("ENG".into(), None, since(), &activity(), &open());
+
+        assert_eq!(identifiers(&report.completed), vec!["ENG-1"]);
+        assert_eq!(report.completed[0].people, vec!["Jane Doe"]);
+        assert_eq!(report.commented[0].detail.as_deref(), Some("2 comments"));
+        assert_eq!(report.commented[0].people, vec!["Bob Roe", "Jane Doe"]);
+        assert_eq!(identifiers(&report.created), vec!["ENG-3"]);
+        assert_eq!(report.created[0].people, vec!["Bob Roe"]);
+    }
+
+    #[test]
+    fn test_standup_markdown() {
+        let user = jane();
+        let report = build_standup_report(
+            "Jane Doe".into(),
+            Some(&user),
+            since(),
+            &activity(),
+            &open(),
+        );
+        let output = report.human_fmt();
+        assert!(output.starts_with("## Standup: Jane Doe"));
+        assert!(output.contains("### Completed\n- ENG-1 Fix login"));
+        assert!(output.contains("- ENG-2 Add OAuth (Todo → In Progress)"));
+        assert!(output.contains("### Blocked\n- ENG-2 Add OAuth (blocked by ENG-9)"));
+        assert!(!output.contains("### Created"));
+        assert!(!output.contains('\u{1b}'));
+    }
+
+    #[test]
+    fn test_standup_markdown_empty() {
+        let report = build_standup_report("ENG".into(), None, since(), &[], &[]);
+        assert!(report.human_fmt().contains("_No activity._"));
+    }
+
+    #[test]
+    fn test_parse_since_durations() {
+        let now = Local::now();
+        let since = parse_since("24h", now).unwrap();
+        assert_eq!(since, (now - Duration::hours(24)).with_timezone(&Utc));
+        let since = parse_since("1w", now).unwrap();
+        assert_eq!(since, (now - Duration::weeks(1)).with_timezone(&Utc));
+    }
+
+    #[test]
+    fn test_parse_since_keywords() {
+        let now = Local::now();
+        let today = parse_since("today", now).unwrap();
+        assert!(today <= now.with_timezone(&Utc));
+        assert_eq!(today.with_timezone(&Local).date_naive(), now.date_naive());
+
+        let yesterday = parse_since("yesterday", now).unwrap();
+        assert!(yesterday < today);
+        let weekday = yesterday.with_timezone(&Local).weekday();
+        assert!(!matches!(weekday, Weekday::Sat | Weekday::Sun));
+    }
+
+    #[test]
+    fn test_parse_since_date_and_invalid() {
+        let now = Local::now();
+        let since = parse_since("2024-01-02", now).unwrap();
+        assert_eq!(
+            since.with_timezone(&Local).date_naive(),
+            NaiveDate::from_ymd_opt(2024, 1, 2).unwrap()
+        );
+        assert!(parse_since("last week", now).is_err());
+        assert!(parse_since("0d", now).is_err());
+    }
+
+    #[test]
+    fn test_standup_team_api() {
+        let mut server = mockito::Server::new();
+
+        let activity_mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::Regex("query StandupActivity".to_string()),
+                mockito::Matcher::PartialJsonString(
+                    r#"{"variables": {"filter": {"team": {"key": {"eq": "ENG"}}}}}"#.to_string(),
+                ),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"data": {"issues": {"nodes": []}}}"#)
+            .create();
+        let open_mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::Regex("query OpenIssuesWithBlockers".to_string()),
+                mockito::Matcher::PartialJsonString(
+                    r#"{"variables": {"filter": {"state": {"type": {"in": ["unstarted", "started"]}}}}}"#
+                        .to_string(),
+                ),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"data": {"issues": {"nodes": []}}}"#)
+            .create();
+
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let result = standup(
+            &client,
+            StandupScope::Team("eng".to_string()),
+            "3d",
+            OutputFormat::Json,
+        );
+        assert!(result.is_ok());
+        activity_mock.assert();
+        open_mock.assert();
+    }
+
+    #[test]
+    fn test_standup_user_api() {
+        let mut server = mockito::Server::new();
+
+        let viewer_mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::Regex("query Viewer".to_string()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(format!(
+                r#"{{"data": {{"viewer": {}}}}}"#,
+                user_json("jane", "Jane Doe")
+            ))
+            .create();
+        let activity_mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::Regex("query StandupActivity".to_string()),
+                mockito::Matcher::Regex(r#""creator":\{"id":\{"eq":"jane"\}\}"#.to_string()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"data": {"issues": {"nodes": []}}}"#)
+            .create();
+        let open_mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::Regex("query OpenIssuesWithBlockers".to_string()),
+                mockito::Matcher::PartialJsonString(
+                    r#"{"variables": {"filter": {"assignee": {"id": {"eq": "jane"}}}}}"#
+                        .to_string(),
+                ),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"data": {"issues": {"nodes": []}}}"#)
+            .create();
+
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let result = standup(
+            &client,
+            StandupScope::User("me".to_string()),
+            "yesterday",
+            OutputFormat::Human,
+        );
+        assert!(result.is_ok());
+        viewer_mock.assert();
+        activity_mock.assert();
+        open_mock.assert();
+    }
+}
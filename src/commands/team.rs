@@ -1,15 +1,42 @@
 //! Team management commands.
 //!
-//! Commands for listing and viewing team information from Linear.
+//! Commands for listing and viewing team information from Linear, managing
+//! team membership and editing team settings.
 
 use crate::Result;
 use crate::api::GraphQLClient;
-use crate::api::queries::team::{TEAM_QUERY, TEAMS_QUERY};
+use crate::api::queries::team::{
+    TEAM_MEMBERSHIP_CREATE_MUTATION, TEAM_MEMBERSHIP_DELETE_MUTATION, TEAM_MEMBERSHIPS_QUERY,
+    TEAM_QUERY, TEAM_SETTINGS_QUERY, TEAM_SETTINGS_UPDATE_MUTATION, TEAMS_QUERY,
+};
+use crate::commands::issue::is_uuid;
+use crate::commands::resolvers;
 use crate::config::Config;
-use crate::models::{TeamResponse, TeamsResponse};
+use crate::error::LinError;
+use crate::models::{
+    TeamMembership, TeamMembershipCreateResponse, TeamMembershipDeleteResponse,
+    TeamMembershipsResponse, TeamResponse, TeamSettingsResponse, TeamSettingsUpdateResponse,
+    TeamsResponse,
+};
 use crate::output::{OutputFormat, output};
 use serde::Serialize;
 
+/// Issue estimation types accepted by Linear.
+pub const ESTIMATION_TYPES: [&str; 6] = [
+    "notUsed",
+    "exactly",
+    "fibonacci",
+    "linear",
+    "exponential",
+    "tShirt",
+];
+
+/// Auto-archive periods (in months) accepted by Linear.
+const AUTO_ARCHIVE_PERIODS: [f64; 5] = [1.0, 3.0, 6.0, 9.0, 12.0];
+
+/// Auto-close periods (in months) accepted by Linear. Zero disables auto-close.
+const AUTO_CLOSE_PERIODS: [f64; 6] = [0.0, 1.0, 3.0, 6.0, 9.0, 12.0];
+
 /// List all teams in the organization.
 ///
 /// Fetches teams from the Linear API and outputs them.
@@ -124,6 +151,336 @@ pub fn switch_team(team_key: Option<String>, format: OutputFormat) -> Result<()>
     Ok(())
 }
 
+/// Fetch a team's memberships, owners first and then by name.
+fn fetch_memberships(client: &GraphQLClient, team: &str) -> Result<(String, Vec<TeamMembership>)> {
+    let response: TeamMembershipsResponse = client.query(
+        TEAM_MEMBERSHIPS_QUERY,
+        serde_json::json!({ "id": team, "first": 250 }),
+    )?;
+    let mut members = response.team.memberships.nodes;
+    members.sort_by(|a, b| {
+        b.owner
+            .cmp(&a.owner)
+            .then_with(|| a.user.name.to_lowercase().cmp(&b.user.name.to_lowercase()))
+    });
+    Ok((response.team.id, members))
+}
+
+/// List the members of a team with their roles.
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the API request
+/// * `team` - Team key or UUID
+/// * `format` - The output format (Human or Json)
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use lin::api::GraphQLClient;
+/// use lin::commands::team::list_team_members;
+/// use lin::output::OutputFormat;
+///
+/// let client = GraphQLClient::new("lin_api_xxxxx");
+/// list_team_members(&client, "ENG", OutputFormat::Human)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn list_team_members(client: &GraphQLClient, team: &str, format: OutputFormat) -> Result<()> {
+    let (_, members) = fetch_memberships(client, team)?;
+    output(&members, format);
+    Ok(())
+}
+
+/// Add a user to a team.
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the API request
+/// * `team` - Team key or UUID
+/// * `user` - User reference ("me", name, email or UUID)
+/// * `owner` - Whether to make the user a team owner
+/// * `use_cache` - Whether to use cached team and user data
+/// * `format` - The output format (Human or Json)
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use lin::api::GraphQLClient;
+/// use lin::commands::team::add_team_member;
+/// use lin::output::OutputFormat;
+///
+/// let client = GraphQLClient::new("lin_api_xxxxx");
+/// add_team_member(&client, "ENG", "alice@example.com", false, true, OutputFormat::Human)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn add_team_member(
+    client: &GraphQLClient,
+    team: &str,
+    user: &str,
+    owner: bool,
+    use_cache: bool,
+    format: OutputFormat,
+) -> Result<()> {
+    let team_id = resolvers::resolve_team_id(client, team, use_cache)?;
+    let user_id = resolvers::resolve_user_id(client, user, use_cache)?;
+
+    let variables = serde_json::json!({
+        "input": {
+            "teamId": team_id,
+            "userId": user_id,
+            "owner": owner
+        }
+    });
+    let response: TeamMembershipCreateResponse =
+        client.query(TEAM_MEMBERSHIP_CREATE_MUTATION, variables)?;
+
+    if !response.team_membership_create.success {
+        return Err(LinError::api("Failed to add team member"));
+    }
+    let membership = response
+        .team_membership_create
+        .team_membership
+        .ok_or_else(|| LinError::api("No team membership returned in response"))?;
+
+    output(&membership, format);
+    Ok(())
+}
+
+/// Remove a user from a team.
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the API requests
+/// * `team` - Team key or UUID
+/// * `user` - User reference ("me", name, email or UUID)
+/// * `use_cache` - Whether to use cached user data
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use lin::api::GraphQLClient;
+/// use lin::commands::team::remove_team_member;
+///
+/// let client = GraphQLClient::new("lin_api_xxxxx");
+/// remove_team_member(&client, "ENG", "alice@example.com", true)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn remove_team_member(
+    client: &GraphQLClient,
+    team: &str,
+    user: &str,
+    use_cache: bool,
+) -> Result<()> {
+    let user_id = resolvers::resolve_user_id(client, user, use_cache)?;
+    let (_, members) = fetch_memberships(client, team)?;
+
+    let membership = members
+        .iter()
+        .find(|m| m.user.id == user_id)
+        .ok_or_else(|| {
+            LinError::api(format!(
+                "User '{}' is not a member of team '{}'",
+                user, team
+            ))
+        })?;
+
+    let response: TeamMembershipDeleteResponse = client.query(
+        TEAM_MEMBERSHIP_DELETE_MUTATION,
+        serde_json::json!({ "id": membership.id }),
+    )?;
+    if !response.team_membership_delete.success {
+        return Err(LinError::api("Failed to remove team member"));
+    }
+
+    println!(
+        "Removed {} from team '{}'",
+        membership.user.name,
+        team.to_uppercase()
+    );
+    Ok(())
+}
+
+/// Validate an estimation type, accepting any casing.
+pub fn parse_estimation_type(value: &str) -> Result<String> {
+    ESTIMATION_TYPES
+        .iter()
+        .find(|t| t.eq_ignore_ascii_case(value))
+        .map(|t| t.to_string())
+        .ok_or_else(|| {
+            LinError::config(format!(
+                "Invalid estimation type '{}'. Valid types: {}",
+                value,
+                ESTIMATION_TYPES.join(", ")
+            ))
+        })
+}
+
+fn validate_period(value: f64, allowed: &[f64], setting: &str) -> Result<()> {
+    if allowed.contains(&value) {
+        Ok(())
+    } else {
+        let allowed: Vec<String> = allowed.iter().map(|v| v.to_string()).collect();
+        Err(LinError::config(format!(
+            "Invalid {} period '{}'. Valid months: {}",
+            setting,
+            value,
+            allowed.join(", ")
+        )))
+    }
+}
+
+/// Changes to apply to a team's settings.
+#[derive(Debug, Clone, Default)]
+pub struct TeamSettingsOptions {
+    /// Cycle duration in weeks.
+    pub cycle_duration: Option<u32>,
+    /// Cooldown between cycles in weeks.
+    pub cycle_cooldown: Option<u32>,
+    /// Issue estimation type (see [`ESTIMATION_TYPES`]).
+    pub estimation: Option<String>,
+    /// Whether the triage workflow is enabled.
+    pub triage: Option<bool>,
+    /// Months after which completed issues are archived.
+    pub auto_archive: Option<f64>,
+    /// Months after which stale issues are closed (0 disables).
+    pub auto_close: Option<f64>,
+    /// Name or UUID of the state assigned to new issues.
+    pub default_state: Option<String>,
+}
+
+impl TeamSettingsOptions {
+    /// Whether any setting should be changed.
+    pub fn has_changes(&self) -> bool {
+        self.cycle_duration.is_some()
+            || self.cycle_cooldown.is_some()
+            || self.estimation.is_some()
+            || self.triage.is_some()
+            || self.auto_archive.is_some()
+            || self.auto_close.is_some()
+            || self.default_state.is_some()
+    }
+}
+
+/// Show a team's settings, or update them when any change is given.
+///
+/// After an update the team's cached states and estimates are refreshed, since
+/// the estimation scale may have changed.
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the API requests
+/// * `team` - Team key or UUID
+/// * `options` - Settings to change (none to only show the settings)
+/// * `use_cache` - Whether to use (and refresh) cached team data
+/// * `format` - The output format (Human or Json)
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use lin::api::GraphQLClient;
+/// use lin::commands::team::{team_settings, TeamSettingsOptions};
+/// use lin::output::OutputFormat;
+///
+/// let client = GraphQLClient::new("lin_api_xxxxx");
+/// let options = TeamSettingsOptions {
+///     cycle_duration: Some(2),
+///     triage: Some(true),
+///     ..Default::default()
+/// };
+/// team_settings(&client, "ENG", options, true, OutputFormat::Human)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn team_settings(
+    client: &GraphQLClient,
+    team: &str,
+    options: TeamSettingsOptions,
+    use_cache: bool,
+    format: OutputFormat,
+) -> Result<()> {
+    if !options.has_changes() {
+        let response: TeamSettingsResponse =
+            client.query(TEAM_SETTINGS_QUERY, serde_json::json!({ "id": team }))?;
+        output(&response.team, format);
+        return Ok(());
+    }
+
+    let mut input = serde_json::Map::new();
+    if let Some(weeks) = options.cycle_duration {
+        input.insert("cycleDuration".to_string(), serde_json::json!(weeks));
+    }
+    if let Some(weeks) = options.cycle_cooldown {
+        input.insert("cycleCooldownTime".to_string(), serde_json::json!(weeks));
+    }
+    if let Some(estimation) = &options.estimation {
+        input.insert(
+            "issueEstimationType".to_string(),
+            serde_json::json!(parse_estimation_type(estimation)?),
+        );
+    }
+    if let Some(triage) = options.triage {
+        input.insert("triageEnabled".to_string(), serde_json::json!(triage));
+    }
+    if let Some(months) = options.auto_archive {
+        validate_period(months, &AUTO_ARCHIVE_PERIODS, "auto-archive")?;
+        input.insert("autoArchivePeriod".to_string(), serde_json::json!(months));
+    }
+    if let Some(months) = options.auto_close {
+        validate_period(months, &AUTO_CLOSE_PERIODS, "auto-close")?;
+        // Zero disables auto-close
+        let value = if months == 0.0 {
+            serde_json::Value::Null
+        } else {
+            serde_json::json!(months)
+        };
+        input.insert("autoClosePeriod".to_string(), value);
+    }
+
+    let team_key = if is_uuid(team) {
+        resolvers::get_team_key(client, team)?
+    } else {
+        team.to_uppercase()
+    };
+    if let Some(state) = &options.default_state {
+        let state_id = resolvers::resolve_state_id(client, &team_key, state, use_cache)?;
+        input.insert(
+            "defaultIssueStateId".to_string(),
+            serde_json::json!(state_id),
+        );
+    }
+
+    let team_id = resolvers::resolve_team_id(client, team, use_cache)?;
+    let response: TeamSettingsUpdateResponse = client.query(
+        TEAM_SETTINGS_UPDATE_MUTATION,
+        serde_json::json!({ "id": team_id, "input": input }),
+    )?;
+
+    if !response.team_update.success {
+        return Err(LinError::api("Failed to update team settings"));
+    }
+    let settings = response
+        .team_update
+        .team
+        .ok_or_else(|| LinError::api("No team returned in response"))?;
+
+    // The settings are already changed, so a failed refresh is only a warning
+    if use_cache && let Err(e) = resolvers::refresh_team_cache(client, &team_key) {
+        eprintln!(
+            "Warning: could not refresh cached settings for {}: {}. Run 'lin cache refresh' to retry.",
+            team_key, e
+        );
+    }
+    output(&settings, format);
+    Ok(())
+}
+
 // Response types for JSON output
 
 #[derive(Debug, Serialize)]
@@ -568,4 +925,207 @@ mod tests {
 
         mock.assert();
     }
+
+    const TEAM_UUID: &str = "11111111-1111-1111-1111-111111111111";
+    const USER_UUID: &str = "22222222-2222-2222-2222-222222222222";
+
+    fn memberships_body() -> String {
+        format!(
+            r#"{{"data": {{"team": {{"id": "{}", "key": "ENG", "name": "Engineering", "memberships": {{"nodes": [
+                {{"id": "membership-1", "owner": false, "user": {{"id": "{}", "name": "Zed", "email": "zed@example.com", "displayName": null, "active": true}}}},
+                {{"id": "membership-2", "owner": true, "user": {{"id": "user-2", "name": "Alice", "email": "alice@example.com", "displayName": null, "active": true}}}}
+            ]}}}}}}}}"#,
+            TEAM_UUID, USER_UUID
+        )
+    }
+
+    #[test]
+    fn test_fetch_memberships_sorts_owners_first() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::Regex("TeamMemberships".to_string()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(memberships_body())
+            .create();
+
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let (team_id, members) = fetch_memberships(&client, "ENG").unwrap();
+
+        assert_eq!(team_id, TEAM_UUID);
+        assert_eq!(members[0].user.name, "Alice");
+        assert!(members[0].owner);
+        assert_eq!(members[1].user.name, "Zed");
+        mock.assert();
+    }
+
+    #[test]
+    fn test_add_team_member_success() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::PartialJsonString(format!(
+                r#"{{"variables": {{"input": {{"teamId": "{}", "userId": "{}", "owner": true}}}}}}"#,
+                TEAM_UUID, USER_UUID
+            )))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(format!(
+                r#"{{"data": {{"teamMembershipCreate": {{"success": true, "teamMembership": {{"id": "membership-3", "owner": true, "user": {{"id": "{}", "name": "Zed", "email": "zed@example.com", "displayName": null, "active": true}}}}}}}}}}"#,
+                USER_UUID
+            ))
+            .create();
+
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let result = add_team_member(
+            &client,
+            TEAM_UUID,
+            USER_UUID,
+            true,
+            false,
+            OutputFormat::Json,
+        );
+
+        assert!(result.is_ok(), "{:?}", result);
+        mock.assert();
+    }
+
+    #[test]
+    fn test_remove_team_member_deletes_membership() {
+        let mut server = mockito::Server::new();
+        let _memberships_mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::Regex("TeamMemberships".to_string()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(memberships_body())
+            .create();
+        let delete_mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::PartialJsonString(
+                r#"{"variables": {"id": "membership-1"}}"#.to_string(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"data": {"teamMembershipDelete": {"success": true}}}"#)
+            .create();
+
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let result = remove_team_member(&client, "ENG", USER_UUID, false);
+
+        assert!(result.is_ok(), "{:?}", result);
+        delete_mock.assert();
+    }
+
+    #[test]
+    fn test_remove_team_member_not_a_member() {
+        let mut server = mockito::Server::new();
+        let _memberships_mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::Regex("TeamMemberships".to_string()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(memberships_body())
+            .create();
+
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let err = remove_team_member(
+            &client,
+            "ENG",
+            "33333333-3333-3333-3333-333333333333",
+            false,
+        )
+        .unwrap_err();
+
+        assert!(err.to_string().contains("is not a member of team 'ENG'"));
+    }
+
+    #[test]
+    fn test_parse_estimation_type() {
+        assert_eq!(parse_estimation_type("tshirt").unwrap(), "tShirt");
+        assert_eq!(parse_estimation_type("Fibonacci").unwrap(), "fibonacci");
+        assert!(parse_estimation_type("powers").is_err());
+    }
+
+    #[test]
+    fn test_team_settings_show_without_changes() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::Regex("query TeamSettings".to_string()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"data": {"team": {"id": "team-1", "key": "ENG", "name": "Engineering", "cyclesEnabled": false, "triageEnabled": true, "issueEstimationType": "linear"}}}"#,
+            )
+            .create();
+
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let result = team_settings(
+            &client,
+            "ENG",
+            TeamSettingsOptions::default(),
+            false,
+            OutputFormat::Human,
+        );
+
+        assert!(result.is_ok(), "{:?}", result);
+        mock.assert();
+    }
+
+    #[test]
+    fn test_team_settings_update() {
+        let mut server = mockito::Server::new();
+        let _team_mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::Regex(r"query Team\(".to_string()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(format!(
+                r#"{{"data": {{"team": {{"id": "{}", "key": "ENG", "name": "Engineering"}}}}}}"#,
+                TEAM_UUID
+            ))
+            .create();
+        let update_mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::Regex("teamUpdate".to_string()),
+                mockito::Matcher::PartialJsonString(format!(
+                    r#"{{"variables": {{"id": "{}", "input": {{"cycleDuration": 2, "issueEstimationType": "tShirt", "triageEnabled": true, "autoClosePeriod": null}}}}}}"#,
+                    TEAM_UUID
+                )),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(format!(
+                r#"{{"data": {{"teamUpdate": {{"success": true, "team": {{"id": "{}", "key": "ENG", "name": "Engineering", "cyclesEnabled": true, "cycleDuration": 2, "issueEstimationType": "tShirt", "triageEnabled": true, "autoClosePeriod": null}}}}}}}}"#,
+                TEAM_UUID
+            ))
+            .create();
+
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let options = TeamSettingsOptions {
+            cycle_duration: Some(2),
+            estimation: Some("tshirt".to_string()),
+            triage: Some(true),
+            auto_close: Some(0.0),
+            ..Default::default()
+        };
+        let result = team_settings(&client, TEAM_UUID, options, false, OutputFormat::Json);
+
+        assert!(result.is_ok(), "{:?}", result);
+        update_mock.assert();
+    }
+
+    #[test]
+    fn test_team_settings_rejects_invalid_period() {
+        let client = GraphQLClient::with_url("test-token", "http://127.0.0.1:9");
+        let options = TeamSettingsOptions {
+            auto_archive: Some(2.0),
+            ..Default::default()
+        };
+        let err = team_settings(&client, "ENG", options, false, OutputFormat::Human).unwrap_err();
+        assert!(err.to_string().contains("Invalid auto-archive period '2'"));
+    }
 }
@@ -0,0 +1,864 @@
+//! Triage queue workflow.
+//!
+//! Steps through a team's triage issues one at a time, prompting to accept,
+//! decline, mark as duplicate, snooze or skip each one. Every choice is
+//! applied through the regular issue, comment and relation mutations.
+
+use std::io::{BufRead, Write};
+
+use chrono::{Duration, Utc};
+use colored::Colorize;
+use serde::Serialize;
+
+use crate::Result;
+use crate::api::GraphQLClient;
+use crate::api::queries::comment::COMMENT_CREATE_MUTATION;
+use crate::api::queries::issue::{
+    ISSUE_RELATION_CREATE_MUTATION, ISSUE_UPDATE_MUTATION, TRIAGE_ISSUES_QUERY,
+};
+use crate::api::queries::workflow::WORKFLOW_STATES_QUERY;
+use crate::commands::issue::{PriorityFilter, is_uuid, resolve_issue_id};
+use crate::commands::resolvers;
+use crate::error::LinError;
+use crate::models::{
+    CommentCreateResponse, IssueRelationCreateResponse, IssueUpdateResponse, TriageIssue,
+    TriageIssuesResponse, WorkflowState, WorkflowStatesResponse,
+};
+use crate::output::{HumanDisplay, OutputFormat, output};
+
+/// Options for the triage command.
+#[derive(Debug, Clone, Default)]
+pub struct TriageOptions {
+    /// Team key or UUID. Uses the current team if not specified.
+    pub team: Option<String>,
+    /// Accept every triage issue created by this user without prompting.
+    pub accept_all_from: Option<String>,
+    /// State to move accepted issues to with `accept_all_from`.
+    pub state: Option<String>,
+    /// Maximum number of triage issues to fetch.
+    pub limit: u32,
+}
+
+/// Outcome of a triage session.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct TriageSummary {
+    /// Team key.
+    pub team: String,
+    /// Accepted issue identifiers.
+    pub accepted: Vec<String>,
+    /// Declined issue identifiers.
+    pub declined: Vec<String>,
+    /// Issues marked as duplicates.
+    pub duplicates: Vec<String>,
+    /// Snoozed issue identifiers.
+    pub snoozed: Vec<String>,
+    /// Issues left in triage.
+    pub skipped: Vec<String>,
+}
+
+impl HumanDisplay for TriageSummary {
+    fn human_fmt(&self) -> String {
+        let handled =
+            self.accepted.len() + self.declined.len() + self.duplicates.len() + self.snoozed.len();
+        let mut lines = vec![format!(
+            "{} {}: {} handled, {} left in triage",
+            "Triage for".bold(),
+            self.team.bold().cyan(),
+            handled,
+            self.skipped.len()
+        )];
+        for (title, ids) in [
+            ("Accepted", &self.accepted),
+            ("Declined", &self.declined),
+            ("Duplicates", &self.duplicates),
+            ("Snoozed", &self.snoozed),
+        ] {
+            if !ids.is_empty() {
+                lines.push(format!("  {}: {}", title, ids.join(", ")));
+            }
+        }
+        lines.join("\n")
+    }
+}
+
+/// Team context needed to apply triage decisions.
+#[derive(Debug, Clone)]
+pub struct TriageContext {
+    /// Team key (e.g., "ENG").
+    pub team_key: String,
+    /// The team's workflow states.
+    pub states: Vec<WorkflowState>,
+    /// Whether to use cached team data when resolving values.
+    pub use_cache: bool,
+}
+
+impl TriageContext {
+    /// Find a state by name (case-insensitive) or UUID.
+    pub fn find_state(&self, name_or_id: &str) -> Option<&WorkflowState> {
+        self.states
+            .iter()
+            .find(|s| s.id == name_or_id || s.name.eq_ignore_ascii_case(name_or_id))
+    }
+
+    fn first_of_type(&self, state_type: &str) -> Option<&WorkflowState> {
+        self.states.iter().find(|s| s.type_ == state_type)
+    }
+
+    /// State accepted issues move to by default: the first backlog state,
+    /// falling back to the first unstarted one.
+    pub fn accept_state(&self) -> Result<&WorkflowState> {
+        self.first_of_type("backlog")
+            .or_else(|| self.first_of_type("unstarted"))
+            .ok_or_else(|| LinError::api("Team has no backlog or unstarted state to accept into"))
+    }
+
+    /// State declined issues move to: the first canceled state.
+    pub fn decline_state(&self) -> Result<&WorkflowState> {
+        self.first_of_type("canceled")
+            .ok_or_else(|| LinError::api("Team has no canceled state to decline into"))
+    }
+
+    /// State duplicates move to: a canceled state named "Duplicate" if the
+    /// team has one, otherwise the decline state.
+    pub fn duplicate_state(&self) -> Result<&WorkflowState> {
+        match self
+            .states
+            .iter()
+            .find(|s| s.type_ == "canceled" && s.name.eq_ignore_ascii_case("duplicate"))
+        {
+            Some(state) => Ok(state),
+            None => self.decline_state(),
+        }
+    }
+}
+
+/// Fields to set when accepting an issue.
+#[derive(Debug, Clone, Default)]
+pub struct AcceptChoices {
+    /// Target state UUID.
+    pub state_id: String,
+    /// Assignee UUID.
+    pub assignee_id: Option<String>,
+    /// Priority (0-4).
+    pub priority: Option<i32>,
+    /// Estimate value.
+    pub estimate: Option<f64>,
+    /// Label UUIDs.
+    pub label_ids: Vec<String>,
+}
+
+fn update_issue(
+    client: &GraphQLClient,
+    issue: &TriageIssue,
+    input: serde_json::Value,
+    action: &str,
+) -> Result<()> {
+    let response: IssueUpdateResponse = client.query(
+        ISSUE_UPDATE_MUTATION,
+        serde_json::json!({ "id": issue.id, "input": input }),
+    )?;
+    if !response.issue_update.success {
+        return Err(LinError::api(format!(
+            "Failed to {} {}",
+            action, issue.identifier
+        )));
+    }
+    Ok(())
+}
+
+/// Accept a triage issue, moving it out of triage with the given fields.
+pub fn accept_issue(
+    client: &GraphQLClient,
+    issue: &TriageIssue,
+    choices: &AcceptChoices,
+) -> Result<()> {
+    let mut input = serde_json::Map::new();
+    input.insert("stateId".to_string(), serde_json::json!(choices.state_id));
+    if let Some(assignee_id) = &choices.assignee_id {
+        input.insert("assigneeId".to_string(), serde_json::json!(assignee_id));
+    }
+    if let Some(priority) = choices.priority {
+        input.insert("priority".to_string(), serde_json::json!(priority));
+    }
+    if let Some(estimate) = choices.estimate {
+        input.insert("estimate".to_string(), serde_json::json!(estimate));
+    }
+    if !choices.label_ids.is_empty() {
+        input.insert("labelIds".to_string(), serde_json::json!(choices.label_ids));
+    }
+    update_issue(client, issue, serde_json::Value::Object(input), "accept")
+}
+
+/// Decline a triage issue: comment with the reason and cancel it.
+pub fn decline_issue(
+    client: &GraphQLClient,
+    issue: &TriageIssue,
+    reason: &str,
+    state_id: &str,
+) -> Result<()> {
+    let response: CommentCreateResponse = client.query(
+        COMMENT_CREATE_MUTATION,
+        serde_json::json!({ "input": { "issueId": issue.id, "body": reason } }),
+    )?;
+    if !response.comment_create.success {
+        return Err(LinError::api(format!(
+            "Failed to comment on {}",
+            issue.identifier
+        )));
+    }
+    update_issue(
+        client,
+        issue,
+        serde_json::json!({ "stateId": state_id }),
+        "decline",
+    )
+}
+
+/// Mark a triage issue as a duplicate of another issue and close it.
+pub fn mark_duplicate(
+    client: &GraphQLClient,
+    issue: &TriageIssue,
+    duplicate_of_id: &str,
+    state_id: &str,
+) -> Result<()> {
+    let response: IssueRelationCreateResponse = client.query(
+        ISSUE_RELATION_CREATE_MUTATION,
+        serde_json::json!({
+            "input": {
+                "issueId": issue.id,
+                "relatedIssueId": duplicate_of_id,
+                "type": "duplicate"
+            }
+        }),
+    )?;
+    if !response.issue_relation_create.success {
+        return Err(LinError::api(format!(
+            "Failed to mark {} as duplicate",
+            issue.identifier
+        )));
+    }
+    update_issue(
+        client,
+        issue,
+        serde_json::json!({ "stateId": state_id }),
+        "close",
+    )
+}
+
+/// Snooze a triage issue for a number of days.
+pub fn snooze_issue(client: &GraphQLClient, issue: &TriageIssue, days: i64) -> Result<()> {
+    let until = (Utc::now() + Duration::days(days)).to_rfc3339();
+    update_issue(
+        client,
+        issue,
+        serde_json::json!({ "snoozedUntilAt": until }),
+        "snooze",
+    )
+}
+
+/// Print a prompt and read one trimmed line. Returns `None` at end of input.
+fn prompt<R: BufRead, W: Write>(input: &mut R, out: &mut W, label: &str) -> Result<Option<String>> {
+    write!(out, "{}", label)?;
+    out.flush()?;
+    let mut line = String::new();
+    if input.read_line(&mut line)? == 0 {
+        return Ok(None);
+    }
+    Ok(Some(line.trim().to_string()))
+}
+
+/// Prompt until `parse` accepts the answer. Blank answers yield `Ok(None)`
+/// without calling `parse`.
+fn prompt_with<R, W, T, F>(
+    input: &mut R,
+    out: &mut W,
+    label: &str,
+    mut parse: F,
+) -> Result<Option<T>>
+where
+    R: BufRead,
+    W: Write,
+    F: FnMut(&str) -> Result<T>,
+{
+    loop {
+        let Some(answer) = prompt(input, out, label)? else {
+            return Err(LinError::config("Triage aborted: unexpected end of input"));
+        };
+        if answer.is_empty() {
+            return Ok(None);
+        }
+        match parse(&answer) {
+            Ok(value) => return Ok(Some(value)),
+            Err(err) => writeln!(out, "  {}", err.to_string().red())?,
+        }
+    }
+}
+
+fn describe_issue<W: Write>(
+    out: &mut W,
+    issue: &TriageIssue,
+    position: usize,
+    total: usize,
+) -> Result<()> {
+    writeln!(out)?;
+    writeln!(
+        out,
+        "{} {} {}",
+        format!("[{}/{}]", position, total).dimmed(),
+        issue.identifier.cyan().bold(),
+        issue.title.bold()
+    )?;
+    let creator = issue
+        .creator
+        .as_ref()
+        .map(|c| c.name.as_str())
+        .unwrap_or("unknown");
+    let created = issue.created_at.get(..10).unwrap_or(&issue.created_at);
+    writeln!(
+        out,
+        "  {}",
+        format!("Created by {} on {}", creator, created).dimmed()
+    )?;
+    if let Some(description) = issue
+        .description
+        .as_deref()
+        .filter(|d| !d.trim().is_empty())
+    {
+        const MAX_LINES: usize = 12;
+        let lines: Vec<&str> = description.lines().collect();
+        for line in lines.iter().take(MAX_LINES) {
+            writeln!(out, "  {}", line)?;
+        }
+        if lines.len() > MAX_LINES {
+            writeln!(out, "  {}", "…".dimmed())?;
+        }
+    }
+    Ok(())
+}
+
+/// Prompt for the fields to set when accepting an issue.
+fn prompt_accept<R: BufRead, W: Write>(
+    client: &GraphQLClient,
+    context: &TriageContext,
+    input: &mut R,
+    out: &mut W,
+) -> Result<AcceptChoices> {
+    let default_state = context.accept_state()?;
+    let state_id = prompt_with(
+        input,
+        out,
+        &format!("  State [{}]: ", default_state.name),
+        |answer| {
+            context
+                .find_state(answer)
+                .map(|s| s.id.clone())
+                .ok_or_else(|| LinError::config(format!("Unknown state '{}'", answer)))
+        },
+    )?
+    .unwrap_or_else(|| default_state.id.clone());
+
+    let assignee_id = prompt_with(input, out, "  Assignee (blank to skip): ", |answer| {
+        resolvers::resolve_user_id(client, answer, context.use_cache)
+    })?;
+    let priority = prompt_with(
+        input,
+        out,
+        "  Priority (none/urgent/high/normal/low, blank to skip): ",
+        |answer| {
+            PriorityFilter::parse(answer)
+                .map(|p| p.to_value())
+                .ok_or_else(|| LinError::config(format!("Invalid priority '{}'", answer)))
+        },
+    )?;
+    let estimate = prompt_with(input, out, "  Estimate (blank to skip): ", |answer| {
+        resolvers::resolve_estimate_value(answer, Some(&context.team_key), context.use_cache)
+    })?;
+    let label_ids = prompt_with(
+        input,
+        out,
+        "  Labels (comma-separated, blank to skip): ",
+        |answer| {
+            answer
+                .split(',')
+                .map(str::trim)
+                .filter(|name| !name.is_empty())
+                .map(|name| resolvers::resolve_label_id(client, name, context.use_cache))
+                .collect::<Result<Vec<_>>>()
+        },
+    )?
+    .unwrap_or_default();
+
+    Ok(AcceptChoices {
+        state_id,
+        assignee_id,
+        priority,
+        estimate,
+        label_ids,
+    })
+}
+
+/// Run an interactive triage session over `issues`.
+///
+/// Reads answers from `input` and writes prompts to `out`, so the session
+/// can be driven by a terminal or by tests.
+pub fn run_session<R: BufRead, W: Write>(
+    client: &GraphQLClient,
+    context: &TriageContext,
+    issues: &[TriageIssue],
+    input: &mut R,
+    out: &mut W,
+) -> Result<TriageSummary> {
+    let mut summary = TriageSummary {
+        team: context.team_key.clone(),
+        ..Default::default()
+    };
+
+    let mut remaining = issues.iter().enumerate();
+    while let Some((i, issue)) = remaining.next() {
+        describe_issue(out, issue, i + 1, issues.len())?;
+        loop {
+            let answer = prompt(
+                input,
+                out,
+                "  (a)ccept, (d)ecline, du(p)licate, (s)nooze, s(k)ip, (q)uit: ",
+            )?;
+            let Some(answer) = answer else {
+                // End of input: leave the rest untouched
+                summary.skipped.push(issue.identifier.clone());
+                summary
+                    .skipped
+                    .extend(remaining.map(|(_, i)| i.identifier.clone()));
+                return Ok(summary);
+            };
+            match answer.to_lowercase().as_str() {
+                "a" | "accept" => {
+                    let choices = prompt_accept(client, context, input, out)?;
+                    accept_issue(client, issue, &choices)?;
+                    summary.accepted.push(issue.identifier.clone());
+                }
+                "d" | "decline" => {
+                    let state = context.decline_state()?;
+                    let reason =
+                        prompt_with(input, out, "  Reason: ", |answer| Ok(answer.to_string()))?;
+                    let Some(reason) = reason else {
+                        writeln!(out, "  {}", "A reason is required to decline".red())?;
+                        continue;
+                    };
+                    decline_issue(client, issue, &reason, &state.id)?;
+                    summary.declined.push(issue.identifier.clone());
+                }
+                "p" | "duplicate" => {
+                    let state = context.duplicate_state()?;
+                    let original = prompt_with(
+                        input,
+                        out,
+                        "  Duplicate of (e.g., ENG-123, blank to cancel): ",
+                        |answer| resolve_issue_id(client, answer),
+                    )?;
+                    let Some(original) = original else {
+                        continue;
+                    };
+                    mark_duplicate(client, issue, &original, &state.id)?;
+                    summary.duplicates.push(issue.identifier.clone());
+                }
+                "s" | "snooze" => {
+                    let days = prompt_with(input, out, "  Snooze for days [1]: ", |answer| {
+                        answer
+                            .parse::<i64>()
+                            .ok()
+                            .filter(|d| *d > 0)
+                            .ok_or_else(|| {
+                                LinError::config(format!("Invalid number of days '{}'", answer))
+                            })
+                    })?
+                    .unwrap_or(1);
+                    snooze_issue(client, issue, days)?;
+                    summary.snoozed.push(issue.identifier.clone());
+                }
+                "k" | "skip" => summary.skipped.push(issue.identifier.clone()),
+                "q" | "quit" => {
+                    summary.skipped.push(issue.identifier.clone());
+                    summary
+                        .skipped
+                        .extend(remaining.map(|(_, i)| i.identifier.clone()));
+                    return Ok(summary);
+                }
+                _ => {
+                    writeln!(out, "  {}", format!("Unknown choice '{}'", answer).red())?;
+                    continue;
+                }
+            }
+            break;
+        }
+    }
+
+    Ok(summary)
+}
+
+/// Accept every triage issue without prompting.
+pub fn accept_all(
+    client: &GraphQLClient,
+    context: &TriageContext,
+    issues: &[TriageIssue],
+    state: Option<&str>,
+) -> Result<TriageSummary> {
+    let state = match state {
+        Some(name) => context
+            .find_state(name)
+            .ok_or_else(|| LinError::config(format!("Unknown state '{}'", name)))?,
+        None => context.accept_state()?,
+    };
+    let choices = AcceptChoices {
+        state_id: state.id.clone(),
+        ..Default::default()
+    };
+
+    let mut summary = TriageSummary {
+        team: context.team_key.clone(),
+        ..Default::default()
+    };
+    for issue in issues {
+        accept_issue(client, issue, &choices)?;
+        summary.accepted.push(issue.identifier.clone());
+    }
+    Ok(summary)
+}
+
+/// Fetch a team's triage issues, oldest first, optionally only those
+/// created by one user.
+pub fn fetch_triage_issues(
+    client: &GraphQLClient,
+    team_key: &str,
+    creator_id: Option<&str>,
+    limit: u32,
+) -> Result<Vec<TriageIssue>> {
+    let mut filter = serde_json::json!({
+        "team": { "key": { "eq": team_key } },
+        "state": { "type": { "eq": "triage" } }
+    });
+    if let Some(creator_id) = creator_id {
+        filter["creator"] = serde_json::json!({ "id": { "eq": creator_id } });
+    }
+    let response: TriageIssuesResponse = client.query(
+        TRIAGE_ISSUES_QUERY,
+        serde_json::json!({ "first": limit, "filter": filter }),
+    )?;
+    let mut issues = response.issues.nodes;
+    issues.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+    Ok(issues)
+}
+
+/// Work through a team's triage queue.
+///
+/// Interactive by default. With `accept_all_from`, accepts every triage
+/// issue created by that user without prompting.
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the API requests
+/// * `options` - Team, accept-all user, target state and limit
+/// * `use_cache` - Whether to use cached team data
+/// * `format` - The output format for the final summary (Human or Json)
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use lin::api::GraphQLClient;
+/// use lin::commands::triage::{triage, TriageOptions};
+/// use lin::output::OutputFormat;
+///
+/// let client = GraphQLClient::new("lin_api_xxxxx");
+/// let options = TriageOptions {
+///     team: Some("ENG".to_string()),
+///     accept_all_from: Some("support-bot@example.com".to_string()),
+///     limit: 50,
+///     ..Default::default()
+/// };
+/// triage(&client, options, true, OutputFormat::Human)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn triage(
+    client: &GraphQLClient,
+    options: TriageOptions,
+    use_cache: bool,
+    format: OutputFormat,
+) -> Result<()> {
+    let team_key = match options.team.as_deref() {
+        Some(id) if is_uuid(id) => resolvers::get_team_key(client, id)?,
+        other => resolvers::resolve_team_key_or_current(other)?,
+    };
+    let team_id = resolvers::resolve_team_id(client, &team_key, use_cache)?;
+    let states: WorkflowStatesResponse =
+        client.query(WORKFLOW_STATES_QUERY, serde_json::json!({ "id": team_id }))?;
+    let context = TriageContext {
+        team_key: team_key.clone(),
+        states: states.team.states.nodes,
+        use_cache,
+    };
+
+    let summary = if let Some(user) = &options.accept_all_from {
+        let creator_id = resolvers::resolve_user_id(client, user, use_cache)?;
+        let issues = fetch_triage_issues(client, &team_key, Some(&creator_id), options.limit)?;
+        accept_all(client, &context, &issues, options.state.as_deref())?
+    } else {
+        if !atty::is(atty::Stream::Stdin) {
+            return Err(LinError::config(
+                "Interactive triage needs a terminal. Use --accept-all-from to triage non-interactively",
+            ));
+        }
+        let issues = fetch_triage_issues(client, &team_key, None, options.limit)?;
+        if issues.is_empty() {
+            output(
+                &TriageSummary {
+                    team: team_key,
+                    ..Default::default()
+                },
+                format,
+            );
+            return Ok(());
+        }
+        let stdin = std::io::stdin();
+        let mut input = stdin.lock();
+        let mut out = std::io::stderr();
+        run_session(client, &context, &issues, &mut input, &mut out)?
+    };
+
+    output(&summary, format);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    const ISSUE_UUID: &str = "11111111-1111-1111-1111-111111111111";
+
+    fn state(id: &str, name: &str, type_: &str) -> WorkflowState {
+        WorkflowState {
+            id: id.to_string(),
+            name: name.to_string(),
+            color: "#000000".to_string(),
+            type_: type_.to_string(),
+        }
+    }
+
+    fn context() -> TriageContext {
+        TriageContext {
+            team_key: "ENG".to_string(),
+            states: vec![
+                state("state-triage", "Triage", "triage"),
+                state("state-todo", "Todo", "unstarted"),
+                state("state-backlog", "Backlog", "backlog"),
+                state("state-canceled", "Canceled", "canceled"),
+                state("state-duplicate", "Duplicate", "canceled"),
+            ],
+            use_cache: false,
+        }
+    }
+
+    fn issue(identifier: &str) -> TriageIssue {
+        TriageIssue {
+            id: format!("id-{}", identifier),
+            identifier: identifier.to_string(),
+            title: "Login button broken".to_string(),
+            description: Some("Steps to reproduce".to_string()),
+            priority: 0,
+            estimate: None,
+            created_at: "2026-10-17T09:00:00.000Z".to_string(),
+            creator: None,
+        }
+    }
+
+    fn update_mock(server: &mut mockito::ServerGuard, body: String) -> mockito::Mock {
+        server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::Regex("issueUpdate".to_string()),
+                mockito::Matcher::PartialJsonString(body),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"data": {"issueUpdate": {"success": true, "issue": null}}}"#)
+            .create()
+    }
+
+    #[test]
+    fn test_context_state_selection() {
+        let context = context();
+        assert_eq!(context.accept_state().unwrap().name, "Backlog");
+        assert_eq!(context.decline_state().unwrap().name, "Canceled");
+        assert_eq!(context.duplicate_state().unwrap().name, "Duplicate");
+        assert_eq!(context.find_state("todo").unwrap().id, "state-todo");
+    }
+
+    #[test]
+    fn test_session_accept_with_defaults_and_priority() {
+        let mut server = mockito::Server::new();
+        let mock = update_mock(
+            &mut server,
+            r#"{"variables": {"id": "id-ENG-1", "input": {"stateId": "state-todo", "priority": 2}}}"#
+                .to_string(),
+        );
+        let client = GraphQLClient::with_url("test-token", &server.url());
+
+        // accept, state Todo, no assignee, bad then valid priority, no estimate, no labels
+        let mut input = Cursor::new("a\nTodo\n\nsoon\nhigh\n\n\n");
+        let mut out = Vec::new();
+        let summary =
+            run_session(&client, &context(), &[issue("ENG-1")], &mut input, &mut out).unwrap();
+
+        assert_eq!(summary.accepted, vec!["ENG-1"]);
+        assert!(
+            String::from_utf8(out)
+                .unwrap()
+                .contains("Invalid priority 'soon'")
+        );
+        mock.assert();
+    }
+
+    #[test]
+    fn test_session_decline_comments_and_cancels() {
+        let mut server = mockito::Server::new();
+        let comment_mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::PartialJsonString(
+                r#"{"variables": {"input": {"issueId": "id-ENG-1", "body": "Not a bug"}}}"#
+                    .to_string(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"data": {"commentCreate": {"success": true, "comment": null}}}"#)
+            .create();
+        let update = update_mock(
+            &mut server,
+            r#"{"variables": {"id": "id-ENG-1", "input": {"stateId": "state-canceled"}}}"#
+                .to_string(),
+        );
+        let client = GraphQLClient::with_url("test-token", &server.url());
+
+        let mut input = Cursor::new("d\nNot a bug\n");
+        let mut out = Vec::new();
+        let summary =
+            run_session(&client, &context(), &[issue("ENG-1")], &mut input, &mut out).unwrap();
+
+        assert_eq!(summary.declined, vec!["ENG-1"]);
+        comment_mock.assert();
+        update.assert();
+    }
+
+    #[test]
+    fn test_session_duplicate_links_and_closes() {
+        let mut server = mockito::Server::new();
+        let relation_mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::PartialJsonString(format!(
+                r#"{{"variables": {{"input": {{"issueId": "id-ENG-1", "relatedIssueId": "{}", "type": "duplicate"}}}}}}"#,
+                ISSUE_UUID
+            )))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"data": {"issueRelationCreate": {"success": true, "issueRelation": null}}}"#,
+            )
+            .create();
+        let update = update_mock(
+            &mut server,
+            r#"{"variables": {"id": "id-ENG-1", "input": {"stateId": "state-duplicate"}}}"#
+                .to_string(),
+        );
+        let client = GraphQLClient::with_url("test-token", &server.url());
+
+        let mut input = Cursor::new(format!("p\n{}\n", ISSUE_UUID));
+        let mut out = Vec::new();
+        let summary =
+            run_session(&client, &context(), &[issue("ENG-1")], &mut input, &mut out).unwrap();
+
+        assert_eq!(summary.duplicates, vec!["ENG-1"]);
+        relation_mock.assert();
+        update.assert();
+    }
+
+    #[test]
+    fn test_session_snooze_skip_and_quit() {
+        let mut server = mockito::Server::new();
+        let snooze_mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::Regex("snoozedUntilAt".to_string()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"data": {"issueUpdate": {"success": true, "issue": null}}}"#)
+            .create();
+        let client = GraphQLClient::with_url("test-token", &server.url());
+
+        let issues = [
+            issue("ENG-1"),
+            issue("ENG-2"),
+            issue("ENG-3"),
+            issue("ENG-4"),
+        ];
+        let mut input = Cursor::new("s\n3\nk\nwhat\nq\n");
+        let mut out = Vec::new();
+        let summary = run_session(&client, &context(), &issues, &mut input, &mut out).unwrap();
+
+        assert_eq!(summary.snoozed, vec!["ENG-1"]);
+        assert_eq!(summary.skipped, vec!["ENG-2", "ENG-3", "ENG-4"]);
+        assert!(
+            String::from_utf8(out)
+                .unwrap()
+                .contains("Unknown choice 'what'")
+        );
+        snooze_mock.assert();
+    }
+
+    #[test]
+    fn test_accept_all_uses_given_state() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::PartialJsonString(
+                r#"{"variables": {"input": {"stateId": "state-todo"}}}"#.to_string(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"data": {"issueUpdate": {"success": true, "issue": null}}}"#)
+            .expect(2)
+            .create();
+        let client = GraphQLClient::with_url("test-token", &server.url());
+
+        let issues = [issue("ENG-1"), issue("ENG-2")];
+        let summary = accept_all(&client, &context(), &issues, Some("todo")).unwrap();
+
+        assert_eq!(summary.accepted, vec!["ENG-1", "ENG-2"]);
+        mock.assert();
+    }
+
+    #[test]
+    fn test_fetch_triage_issues_filters_by_creator_and_sorts_oldest_first() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::PartialJsonString(
+                r#"{"variables": {"filter": {"team": {"key": {"eq": "ENG"}}, "state": {"type": {"eq": "triage"}}, "creator": {"id": {"eq": "user-1"}}}}}"#
+                    .to_string(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"data": {"issues": {"nodes": [
+                    {"id": "i-2", "identifier": "ENG-2", "title": "Newer", "description": null, "priority": 0, "estimate": null, "createdAt": "2026-10-17T00:00:00.000Z", "creator": null},
+                    {"id": "i-1", "identifier": "ENG-1", "title": "Older", "description": null, "priority": 0, "estimate": null, "createdAt": "2026-10-01T00:00:00.000Z", "creator": null}
+                ]}}}"#,
+            )
+            .create();
+        let client = GraphQLClient::with_url("test-token", &server.url());
+
+        let issues = fetch_triage_issues(&client, "ENG", Some("user-1"), 50).unwrap();
+
+        assert_eq!(issues[0].identifier, "ENG-1");
+        assert_eq!(issues[1].identifier, "ENG-2");
+        mock.assert();
+    }
+}
@@ -1,13 +1,26 @@
 //! User management commands.
 //!
-//! Commands for getting user information from Linear.
+//! Commands for getting user information from Linear, listing a user's
+//! issues and summarizing a team's workload.
+
+use std::collections::HashMap;
+
+use chrono::{Local, NaiveDate};
+use colored::Colorize;
+use serde::Serialize;
 
 use crate::Result;
 use crate::api::GraphQLClient;
 use crate::api::queries::organization::VIEWER_QUERY;
-use crate::api::queries::user::USERS_QUERY;
-use crate::models::{UsersResponse, ViewerResponse};
-use crate::output::{OutputFormat, output};
+use crate::api::queries::user::{USER_WORKLOAD_QUERY, USERS_QUERY};
+use crate::commands::issue::stale::OPEN_STATE_TYPES;
+use crate::commands::issue::{IssueListOptions, is_uuid, list::list_issues};
+use crate::commands::resolvers;
+use crate::models::{UserWorkloadResponse, UsersResponse, ViewerResponse};
+use crate::output::{HumanDisplay, OutputFormat, output};
+
+/// Default cycle capacity (estimate points per member) for workload warnings.
+pub const DEFAULT_CAPACITY: f64 = 10.0;
 
 /// Get the current authenticated user's information.
 ///
@@ -66,6 +79,340 @@ pub fn list_users(client: &GraphQLClient, format: OutputFormat) -> Result<()> {
     Ok(())
 }
 
+/// Get a user by name, display name, email, UUID or "me".
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the API request
+/// * `user` - User reference (e.g., "me", "alice", "alice@example.com", UUID)
+/// * `format` - The output format (Human or Json)
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use lin::api::GraphQLClient;
+/// use lin::commands::user::get_user;
+/// use lin::output::OutputFormat;
+///
+/// let client = GraphQLClient::new("lin_api_xxxxx");
+/// get_user(&client, "alice@example.com", OutputFormat::Human)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn get_user(client: &GraphQLClient, user: &str, format: OutputFormat) -> Result<()> {
+    let user = resolvers::resolve_user(client, user)?;
+    output(&user, format);
+    Ok(())
+}
+
+/// List the issues assigned to a user.
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the API request
+/// * `user` - User reference (e.g., "me", "alice", "alice@example.com", UUID)
+/// * `state` - Optional state name to filter by
+/// * `limit` - Maximum number of issues to return
+/// * `use_cache` - Whether to use cached users when resolving `user`
+/// * `format` - The output format (Human or Json)
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use lin::api::GraphQLClient;
+/// use lin::commands::user::user_issues;
+/// use lin::output::OutputFormat;
+///
+/// let client = GraphQLClient::new("lin_api_xxxxx");
+/// user_issues(&client, "alice", Some("In Progress".to_string()), 50, true, OutputFormat::Human)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn user_issues(
+    client: &GraphQLClient,
+    user: &str,
+    state: Option<String>,
+    limit: u32,
+    use_cache: bool,
+    format: OutputFormat,
+) -> Result<()> {
+    let user_id = resolvers::resolve_user_id(client, user, use_cache)?;
+    let options = IssueListOptions {
+        assignee: Some(user_id),
+        state,
+        limit: Some(limit as i32),
+        ..Default::default()
+    };
+    list_issues(client, None, options, format)
+}
+
+/// One member's share of a team's open work.
+#[derive(Debug, Clone, Serialize)]
+pub struct MemberWorkload {
+    /// The member's name.
+    pub name: String,
+    /// The member's email.
+    pub email: String,
+    /// Open issues assigned to the member.
+    pub open_issues: usize,
+    /// Estimate points assigned to the member in the active cycle.
+    pub cycle_points: f64,
+    /// Open issues past their due date.
+    pub overdue: usize,
+    /// Whether the member's cycle points exceed the capacity.
+    pub over_capacity: bool,
+}
+
+/// Workload summary for a team.
+#[derive(Debug, Clone, Serialize)]
+pub struct WorkloadReport {
+    /// Team key.
+    pub team: String,
+    /// Number of the active cycle, if the team has one.
+    pub cycle: Option<i32>,
+    /// Cycle points per member above which a warning is shown.
+    pub capacity: f64,
+    /// Per-member workload, heaviest first.
+    pub members: Vec<MemberWorkload>,
+    /// Open issues without an assignee.
+    pub unassigned: usize,
+}
+
+/// Summarize open issues per member.
+///
+/// Every team member appears, even with no assigned work. Assignees outside
+/// the team are included too, since their issues still count against the team.
+pub fn build_workload(
+    response: UserWorkloadResponse,
+    capacity: f64,
+    today: NaiveDate,
+) -> WorkloadReport {
+    let active_cycle = response.team.active_cycle;
+    let mut members: Vec<MemberWorkload> = Vec::new();
+    let mut index: HashMap<String, usize> = HashMap::new();
+
+    let mut member_slot = |user: &crate::models::User| -> usize {
+        *index.entry(user.id.clone()).or_insert_with(|| {
+            members.push(MemberWorkload {
+                name: user.name.clone(),
+                email: user.email.clone(),
+                open_issues: 0,
+                cycle_points: 0.0,
+                overdue: 0,
+                over_capacity: false,
+            });
+            members.len() - 1
+        })
+    };
+
+    for membership in &response.team.memberships.nodes {
+        member_slot(&membership.user);
+    }
+
+    let mut unassigned = 0;
+    let mut tallies: Vec<(usize, &crate::models::WorkloadIssue)> = Vec::new();
+    for issue in &response.issues.nodes {
+        match &issue.assignee {
+            Some(user) => tallies.push((member_slot(user), issue)),
+            None => unassigned += 1,
+        }
+    }
+
+    for (slot, issue) in tallies {
+        let member = &mut members[slot];
+        member.open_issues += 1;
+        let in_active_cycle = match (&active_cycle, &issue.cycle) {
+            (Some(active), Some(cycle)) => active.id == cycle.id,
+            _ => false,
+        };
+        if in_active_cycle {
+            member.cycle_points += issue.estimate.unwrap_or(0.0);
+        }
+        let overdue = issue
+            .due_date
+            .as_deref()
+            .and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+            .is_some_and(|due| due < today);
+        if overdue {
+            member.overdue += 1;
+        }
+    }
+
+    for member in &mut members {
+        member.over_capacity = active_cycle.is_some() && member.cycle_points > capacity;
+    }
+    members.sort_by(|a, b| {
+        b.cycle_points
+            .total_cmp(&a.cycle_points)
+            .then_with(|| b.open_issues.cmp(&a.open_issues))
+            .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+    });
+
+    WorkloadReport {
+        team: response.team.key,
+        cycle: active_cycle.map(|c| c.number),
+        capacity,
+        members,
+        unassigned,
+    }
+}
+
+/// Format points without a trailing ".0".
+fn format_points(points: f64) -> String {
+    if points.fract() == 0.0 {
+        format!("{}", points as i64)
+    } else {
+        format!("{:.1}", points)
+    }
+}
+
+impl HumanDisplay for WorkloadReport {
+    fn human_fmt(&self) -> String {
+        let cycle = match self.cycle {
+            Some(number) => format!("cycle {}", number),
+            None => "no active cycle".to_string(),
+        };
+        let mut lines = vec![format!(
+            "{} {} ({}, capacity {} pts)",
+            "Workload for".bold(),
+            self.team.bold().cyan(),
+            cycle,
+            format_points(self.capacity)
+        )];
+
+        if self.members.is_empty() {
+            lines.push("  No members.".dimmed().to_string());
+            return lines.join("\n");
+        }
+
+        let name_width = self
+            .members
+            .iter()
+            .map(|m| m.name.chars().count())
+            .max()
+            .unwrap_or(0)
+            .max("Member".len());
+        lines.push(
+            format!(
+                "  {:<width$}  {:>5}  {:>10}  {:>7}",
+                "Member",
+                "Open",
+                "Cycle pts",
+                "Overdue",
+                width = name_width
+            )
+            .dimmed()
+            .to_string(),
+        );
+        for member in &self.members {
+            let points = format!("{:>10}", format_points(member.cycle_points));
+            let points = if member.over_capacity {
+                points.red().bold().to_string()
+            } else {
+                points
+            };
+            let overdue = format!("{:>7}", member.overdue);
+            let overdue = if member.overdue > 0 {
+                overdue.yellow().to_string()
+            } else {
+                overdue
+            };
+            lines.push(format!(
+                "  {:<width$}  {:>5}  {}  {}",
+                member.name,
+                member.open_issues,
+                points,
+                overdue,
+                width = name_width
+            ));
+        }
+
+        if self.unassigned > 0 {
+            lines.push(
+                format!("  {} unassigned open issues", self.unassigned)
+                    .dimmed()
+                    .to_string(),
+            );
+        }
+
+        let over: Vec<&str> = self
+            .members
+            .iter()
+            .filter(|m| m.over_capacity)
+            .map(|m| m.name.as_str())
+            .collect();
+        if !over.is_empty() {
+            lines.push(String::new());
+            lines.push(
+                format!(
+                    "Warning: over capacity ({} pts): {}",
+                    format_points(self.capacity),
+                    over.join(", ")
+                )
+                .yellow()
+                .to_string(),
+            );
+        }
+        lines.join("\n")
+    }
+}
+
+/// Show how open work is spread across a team's members.
+///
+/// Tabulates each member's open issues, estimate points in the active cycle
+/// and overdue issues, warning about members above `capacity` points.
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the API request
+/// * `team` - Team key or UUID. Uses the current team if not specified.
+/// * `capacity` - Cycle points per member above which a warning is shown
+/// * `format` - The output format (Human or Json)
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use lin::api::GraphQLClient;
+/// use lin::commands::user::user_workload;
+/// use lin::output::OutputFormat;
+///
+/// let client = GraphQLClient::new("lin_api_xxxxx");
+/// user_workload(&client, Some("ENG"), 13.0, OutputFormat::Human)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn user_workload(
+    client: &GraphQLClient,
+    team: Option<&str>,
+    capacity: f64,
+    format: OutputFormat,
+) -> Result<()> {
+    let (team, team_filter) = match team {
+        Some(id) if is_uuid(id) => (id.to_string(), serde_json::json!({ "id": { "eq": id } })),
+        other => {
+            let key = resolvers::resolve_team_key_or_current(other)?;
+            let filter = serde_json::json!({ "key": { "eq": key } });
+            (key, filter)
+        }
+    };
+    let variables = serde_json::json!({
+        "teamId": team,
+        "first": 250,
+        "filter": {
+            "team": team_filter,
+            "state": { "type": { "in": OPEN_STATE_TYPES } }
+        }
+    });
+    let response: UserWorkloadResponse = client.query(USER_WORKLOAD_QUERY, variables)?;
+
+    let report = build_workload(response, capacity, Local::now().date_naive());
+    output(&report, format);
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -592,4 +939,134 @@ mod tests {
         // Verify mock was called
         mock.assert();
     }
+
+    // =============================================================================
+    // workload tests
+    // =============================================================================
+
+    fn workload_response() -> UserWorkloadResponse {
+        serde_json::from_str(
+            r#"{
+                "team": {
+                    "id": "team-1",
+                    "key": "ENG",
+                    "name": "Engineering",
+                    "activeCycle": { "id": "cycle-7", "number": 7, "name": null },
+                    "memberships": { "nodes": [
+                        { "id": "m-1", "owner": false, "user": { "id": "user-1", "name": "Alice", "email": "alice@example.com", "displayName": null, "active": true } },
+                        { "id": "m-2", "owner": false, "user": { "id": "user-2", "name": "Bob", "email": "bob@example.com", "displayName": null, "active": true } },
+                        { "id": "m-3", "owner": false, "user": { "id": "user-3", "name": "Cara", "email": "cara@example.com", "displayName": null, "active": true } }
+                    ] }
+                },
+                "issues": { "nodes": [
+                    { "id": "i-1", "identifier": "ENG-1", "estimate": 8, "dueDate": "2026-01-01", "assignee": { "id": "user-2", "name": "Bob", "email": "bob@example.com", "displayName": null, "active": true }, "cycle": { "id": "cycle-7", "number": 7, "name": null } },
+                    { "id": "i-2", "identifier": "ENG-2", "estimate": 5, "dueDate": null, "assignee": { "id": "user-2", "name": "Bob", "email": "bob@example.com", "displayName": null, "active": true }, "cycle": { "id": "cycle-7", "number": 7, "name": null } },
+                    { "id": "i-3", "identifier": "ENG-3", "estimate": 13, "dueDate": "2026-12-01", "assignee": { "id": "user-1", "name": "Alice", "email": "alice@example.com", "displayName": null, "active": true }, "cycle": { "id": "cycle-6", "number": 6, "name": null } },
+                    { "id": "i-4", "identifier": "ENG-4", "estimate": null, "dueDate": null, "assignee": { "id": "user-9", "name": "Dev", "email": "dev@example.com", "displayName": null, "active": true }, "cycle": null },
+                    { "id": "i-5", "identifier": "ENG-5", "estimate": 3, "dueDate": null, "assignee": null, "cycle": null }
+                ] }
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_build_workload_tallies_members() {
+        let today = NaiveDate::from_ymd_opt(2026, 6, 1).unwrap();
+        let report = build_workload(workload_response(), 10.0, today);
+
+        assert_eq!(report.team, "ENG");
+        assert_eq!(report.cycle, Some(7));
+        assert_eq!(report.unassigned, 1);
+
+        let names: Vec<&str> = report.members.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(names, vec!["Bob", "Alice", "Dev", "Cara"]);
+
+        let bob = &report.members[0];
+        assert_eq!(bob.open_issues, 2);
+        assert_eq!(bob.cycle_points, 13.0);
+        assert_eq!(bob.overdue, 1);
+        assert!(bob.over_capacity);
+
+        // Alice's issue is in an older cycle, so it doesn't count toward cycle points
+        let alice = &report.members[1];
+        assert_eq!(alice.open_issues, 1);
+        assert_eq!(alice.cycle_points, 0.0);
+        assert_eq!(alice.overdue, 0);
+        assert!(!alice.over_capacity);
+
+        let cara = &report.members[3];
+        assert_eq!(cara.open_issues, 0);
+    }
+
+    #[test]
+    fn test_build_workload_without_active_cycle_skips_warnings() {
+        let mut response = workload_response();
+        response.team.active_cycle = None;
+        let today = NaiveDate::from_ymd_opt(2026, 6, 1).unwrap();
+        let report = build_workload(response, 1.0, today);
+
+        assert_eq!(report.cycle, None);
+        assert!(report.members.iter().all(|m| m.cycle_points == 0.0));
+        assert!(report.members.iter().all(|m| !m.over_capacity));
+    }
+
+    #[test]
+    fn test_workload_report_human_display_warns_over_capacity() {
+        let today = NaiveDate::from_ymd_opt(2026, 6, 1).unwrap();
+        let report = build_workload(workload_response(), 10.0, today);
+        let output = report.human_fmt();
+
+        assert!(output.contains("cycle 7"));
+        assert!(output.contains("capacity 10 pts"));
+        assert!(output.contains("1 unassigned open issues"));
+        assert!(output.contains("over capacity (10 pts): Bob"));
+    }
+
+    #[test]
+    fn test_user_workload_filters_open_team_issues() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::PartialJsonString(
+                r#"{"variables": {"teamId": "ENG", "filter": {"team": {"key": {"eq": "ENG"}}, "state": {"type": {"in": ["backlog", "unstarted", "started"]}}}}}"#
+                    .to_string(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"data": {"team": {"id": "team-1", "key": "ENG", "name": "Engineering", "activeCycle": null, "memberships": {"nodes": []}}, "issues": {"nodes": []}}}"#,
+            )
+            .create();
+
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let result = user_workload(&client, Some("eng"), DEFAULT_CAPACITY, OutputFormat::Json);
+
+        assert!(result.is_ok(), "{:?}", result);
+        mock.assert();
+    }
+
+    #[test]
+    fn test_get_user_by_uuid() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::Regex("query User\\(".to_string()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"data": {"user": {"id": "11111111-1111-1111-1111-111111111111", "name": "Alice", "email": "alice@example.com", "displayName": null, "active": true}}}"#,
+            )
+            .create();
+
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let result = get_user(
+            &client,
+            "11111111-1111-1111-1111-111111111111",
+            OutputFormat::Human,
+        );
+
+        assert!(result.is_ok(), "{:?}", result);
+        mock.assert();
+    }
 }
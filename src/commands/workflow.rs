@@ -1,13 +1,37 @@
 //! Workflow state management commands.
 //!
-//! Commands for listing workflow states from Linear teams.
+//! Commands for listing, creating, updating, archiving and reordering
+//! workflow states of Linear teams.
+//!
+//! Every change re-syncs the team's cached states so state names keep
+//! resolving without a manual `lin auth sync`.
 
 use crate::Result;
 use crate::api::GraphQLClient;
-use crate::api::queries::workflow::WORKFLOW_STATES_QUERY;
-use crate::models::WorkflowStatesResponse;
+use crate::api::queries::workflow::{
+    WORKFLOW_STATE_ARCHIVE_MUTATION, WORKFLOW_STATE_CREATE_MUTATION,
+    WORKFLOW_STATE_POSITIONS_QUERY, WORKFLOW_STATE_UPDATE_MUTATION, WORKFLOW_STATES_QUERY,
+};
+use crate::commands::issue::is_uuid;
+use crate::commands::resolvers;
+use crate::error::LinError;
+use crate::models::{
+    WorkflowState, WorkflowStateArchiveResponse, WorkflowStateCreateResponse,
+    WorkflowStatePosition, WorkflowStatePositionsResponse, WorkflowStateUpdateResponse,
+    WorkflowStatesResponse,
+};
 use crate::output::{OutputFormat, output};
 
+/// Workflow state types accepted by Linear.
+pub const STATE_TYPES: [&str; 6] = [
+    "triage",
+    "backlog",
+    "unstarted",
+    "started",
+    "completed",
+    "canceled",
+];
+
 /// List all workflow states for a team.
 ///
 /// Fetches workflow states from the Linear API and outputs them.
@@ -44,6 +68,379 @@ pub fn list_workflow_states(
     Ok(())
 }
 
+/// Default color for a new state of the given type.
+fn default_state_color(state_type: &str) -> &'static str {
+    match state_type {
+        "triage" => "#fc7840",
+        "backlog" => "#bec2c8",
+        "unstarted" => "#e2e2e2",
+        "started" => "#f2c94c",
+        "completed" => "#5e6ad2",
+        _ => "#95a2b3",
+    }
+}
+
+/// Validate a workflow state type.
+pub fn parse_state_type(state_type: &str) -> Result<String> {
+    let lower = state_type.to_lowercase();
+    if STATE_TYPES.contains(&lower.as_str()) {
+        Ok(lower)
+    } else {
+        Err(LinError::config(format!(
+            "Invalid state type '{}'. Valid types: {}",
+            state_type,
+            STATE_TYPES.join(", ")
+        )))
+    }
+}
+
+/// Resolve an optional team key or UUID to `(key, id)`, using the current
+/// team when none is given.
+fn resolve_team(
+    client: &GraphQLClient,
+    team: Option<&str>,
+    use_cache: bool,
+) -> Result<(String, String)> {
+    if let Some(team_id) = team.filter(|team| is_uuid(team)) {
+        let team_key = resolvers::get_team_key(client, team_id)?;
+        return Ok((team_key, team_id.to_string()));
+    }
+    let team_key = resolvers::resolve_team_key_or_current(team)?;
+    let team_id = resolvers::resolve_team_id(client, &team_key, use_cache)?;
+    Ok((team_key, team_id))
+}
+
+/// Refresh the team's cached states when running with a config file.
+///
+/// Runs after the change was made, so a failure is only a warning.
+fn refresh_cache(client: &GraphQLClient, team_key: &str, use_cache: bool) {
+    if use_cache && let Err(e) = resolvers::refresh_team_cache(client, team_key) {
+        eprintln!(
+            "Warning: could not refresh cached states for {}: {}. Run 'lin cache refresh' to retry.",
+            team_key, e
+        );
+    }
+}
+
+fn update_state(
+    client: &GraphQLClient,
+    state_id: &str,
+    input: serde_json::Map<String, serde_json::Value>,
+) -> Result<WorkflowState> {
+    let variables = serde_json::json!({
+        "id": state_id,
+        "input": input
+    });
+    let response: WorkflowStateUpdateResponse =
+        client.query(WORKFLOW_STATE_UPDATE_MUTATION, variables)?;
+
+    if !response.workflow_state_update.success {
+        return Err(LinError::api("Failed to update workflow state"));
+    }
+    response
+        .workflow_state_update
+        .workflow_state
+        .ok_or_else(|| LinError::api("No workflow state returned in response"))
+}
+
+/// Options for creating a workflow state.
+#[derive(Debug, Clone, Default)]
+pub struct WorkflowStateCreateOptions {
+    /// Team key or UUID. Uses the current team if not specified.
+    pub team: Option<String>,
+    /// State name.
+    pub name: String,
+    /// State type (triage, backlog, unstarted, started, completed, canceled).
+    pub state_type: String,
+    /// Hex color code. Defaults to a color matching the type.
+    pub color: Option<String>,
+    /// Position within the workflow.
+    pub position: Option<f64>,
+    /// State description.
+    pub description: Option<String>,
+}
+
+/// Create a workflow state for a team.
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the API request
+/// * `options` - The state's team, name, type, color and position
+/// * `use_cache` - Whether to use (and refresh) cached team data
+/// * `format` - The output format (Human or Json)
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use lin::api::GraphQLClient;
+/// use lin::commands::workflow::{create_workflow_state, WorkflowStateCreateOptions};
+/// use lin::output::OutputFormat;
+///
+/// let client = GraphQLClient::new("lin_api_xxxxx");
+/// let options = WorkflowStateCreateOptions {
+///     team: Some("ENG".to_string()),
+///     name: "In Review".to_string(),
+///     state_type: "started".to_string(),
+///     ..Default::default()
+/// };
+/// create_workflow_state(&client, options, true, OutputFormat::Human)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn create_workflow_state(
+    client: &GraphQLClient,
+    options: WorkflowStateCreateOptions,
+    use_cache: bool,
+    format: OutputFormat,
+) -> Result<()> {
+    let state_type = parse_state_type(&options.state_type)?;
+    let color = match &options.color {
+        Some(color) => crate::commands::label::normalize_color(color)?,
+        None => default_state_color(&state_type).to_string(),
+    };
+    let (team_key, team_id) = resolve_team(client, options.team.as_deref(), use_cache)?;
+
+    let mut input = serde_json::Map::new();
+    input.insert("teamId".to_string(), serde_json::json!(team_id));
+    input.insert("name".to_string(), serde_json::json!(options.name));
+    input.insert("type".to_string(), serde_json::json!(state_type));
+    input.insert("color".to_string(), serde_json::json!(color));
+    if let Some(position) = options.position {
+        input.insert("position".to_string(), serde_json::json!(position));
+    }
+    if let Some(description) = options.description {
+        input.insert("description".to_string(), serde_json::json!(description));
+    }
+
+    let variables = serde_json::json!({ "input": input });
+    let response: WorkflowStateCreateResponse =
+        client.query(WORKFLOW_STATE_CREATE_MUTATION, variables)?;
+
+    if !response.workflow_state_create.success {
+        return Err(LinError::api("Failed to create workflow state"));
+    }
+    let state = response
+        .workflow_state_create
+        .workflow_state
+        .ok_or_else(|| LinError::api("No workflow state returned in response"))?;
+
+    refresh_cache(client, &team_key, use_cache);
+    output(&state, format);
+    Ok(())
+}
+
+/// Options for updating a workflow state.
+#[derive(Debug, Clone, Default)]
+pub struct WorkflowStateUpdateOptions {
+    /// Team key or UUID used to resolve the state name.
+    pub team: Option<String>,
+    /// New state name.
+    pub name: Option<String>,
+    /// New hex color code.
+    pub color: Option<String>,
+    /// New position within the workflow.
+    pub position: Option<f64>,
+    /// New description.
+    pub description: Option<String>,
+}
+
+/// Update a workflow state.
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the API request
+/// * `state` - The state's name (within the team) or UUID
+/// * `options` - Team and fields to change
+/// * `use_cache` - Whether to use (and refresh) cached team data
+/// * `format` - The output format (Human or Json)
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use lin::api::GraphQLClient;
+/// use lin::commands::workflow::{update_workflow_state, WorkflowStateUpdateOptions};
+/// use lin::output::OutputFormat;
+///
+/// let client = GraphQLClient::new("lin_api_xxxxx");
+/// let options = WorkflowStateUpdateOptions {
+///     team: Some("ENG".to_string()),
+///     name: Some("Code Review".to_string()),
+///     ..Default::default()
+/// };
+/// update_workflow_state(&client, "In Review", options, true, OutputFormat::Human)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn update_workflow_state(
+    client: &GraphQLClient,
+    state: &str,
+    options: WorkflowStateUpdateOptions,
+    use_cache: bool,
+    format: OutputFormat,
+) -> Result<()> {
+    let mut input = serde_json::Map::new();
+    if let Some(name) = options.name {
+        input.insert("name".to_string(), serde_json::json!(name));
+    }
+    if let Some(color) = &options.color {
+        input.insert(
+            "color".to_string(),
+            serde_json::json!(crate::commands::label::normalize_color(color)?),
+        );
+    }
+    if let Some(position) = options.position {
+        input.insert("position".to_string(), serde_json::json!(position));
+    }
+    if let Some(description) = options.description {
+        input.insert("description".to_string(), serde_json::json!(description));
+    }
+    if input.is_empty() {
+        return Err(LinError::config(
+            "Nothing to update. Pass at least one of --name, --color, --position or --description",
+        ));
+    }
+
+    let (team_key, _) = resolve_team(client, options.team.as_deref(), use_cache)?;
+    let state_id = resolvers::resolve_state_id(client, &team_key, state, use_cache)?;
+    let updated = update_state(client, &state_id, input)?;
+
+    refresh_cache(client, &team_key, use_cache);
+    output(&updated, format);
+    Ok(())
+}
+
+/// Archive a workflow state.
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the API request
+/// * `team` - Team key or UUID used to resolve the state name
+/// * `state` - The state's name (within the team) or UUID
+/// * `use_cache` - Whether to use (and refresh) cached team data
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use lin::api::GraphQLClient;
+/// use lin::commands::workflow::archive_workflow_state;
+///
+/// let client = GraphQLClient::new("lin_api_xxxxx");
+/// archive_workflow_state(&client, Some("ENG"), "Duplicate", true)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn archive_workflow_state(
+    client: &GraphQLClient,
+    team: Option<&str>,
+    state: &str,
+    use_cache: bool,
+) -> Result<()> {
+    let (team_key, _) = resolve_team(client, team, use_cache)?;
+    let state_id = resolvers::resolve_state_id(client, &team_key, state, use_cache)?;
+
+    let response: WorkflowStateArchiveResponse = client.query(
+        WORKFLOW_STATE_ARCHIVE_MUTATION,
+        serde_json::json!({ "id": state_id }),
+    )?;
+    if !response.workflow_state_archive.success {
+        return Err(LinError::api("Failed to archive workflow state"));
+    }
+
+    refresh_cache(client, &team_key, use_cache);
+    println!("Workflow state '{}' archived successfully", state);
+    Ok(())
+}
+
+/// Assign the listed states' current positions to them in the listed order.
+///
+/// Reusing the positions the listed states already occupy keeps every
+/// unlisted state where it was relative to them.
+fn reorder_positions(
+    state_ids: &[String],
+    current: &[WorkflowStatePosition],
+) -> Result<Vec<(String, f64)>> {
+    let mut positions = Vec::with_capacity(state_ids.len());
+    for (i, state_id) in state_ids.iter().enumerate() {
+        if state_ids[..i].contains(state_id) {
+            return Err(LinError::config(format!(
+                "State '{}' is listed more than once",
+                state_id
+            )));
+        }
+        let state = current.iter().find(|s| &s.id == state_id).ok_or_else(|| {
+            LinError::config(format!("State '{}' doesn't belong to this team", state_id))
+        })?;
+        positions.push(state.position);
+    }
+    positions.sort_by(f64::total_cmp);
+    Ok(state_ids.iter().cloned().zip(positions).collect())
+}
+
+/// Reorder workflow states.
+///
+/// The listed states swap positions among themselves so they appear in the
+/// given order. States that are not listed keep their current positions.
+///
+/// # Arguments
+///
+/// * `client` - The GraphQL client to use for the API requests
+/// * `team` - Team key or UUID used to resolve state names
+/// * `states` - State names or UUIDs in the desired order
+/// * `use_cache` - Whether to use (and refresh) cached team data
+/// * `format` - The output format (Human or Json)
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use lin::api::GraphQLClient;
+/// use lin::commands::workflow::reorder_workflow_states;
+/// use lin::output::OutputFormat;
+///
+/// let client = GraphQLClient::new("lin_api_xxxxx");
+/// let states = vec!["In Progress".to_string(), "In Review".to_string()];
+/// reorder_workflow_states(&client, Some("ENG"), &states, true, OutputFormat::Human)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn reorder_workflow_states(
+    client: &GraphQLClient,
+    team: Option<&str>,
+    states: &[String],
+    use_cache: bool,
+    format: OutputFormat,
+) -> Result<()> {
+    if states.len() < 2 {
+        return Err(LinError::config("List at least two states to reorder"));
+    }
+    let (team_key, team_id) = resolve_team(client, team, use_cache)?;
+
+    // Resolve everything before changing anything
+    let state_ids = states
+        .iter()
+        .map(|state| resolvers::resolve_state_id(client, &team_key, state, use_cache))
+        .collect::<Result<Vec<_>>>()?;
+    let response: WorkflowStatePositionsResponse = client.query(
+        WORKFLOW_STATE_POSITIONS_QUERY,
+        serde_json::json!({ "id": team_id }),
+    )?;
+    let positions = reorder_positions(&state_ids, &response.team.states.nodes)?;
+
+    let mut updated = Vec::with_capacity(positions.len());
+    for (state_id, position) in positions {
+        let mut input = serde_json::Map::new();
+        input.insert("position".to_string(), serde_json::json!(position));
+        updated.push(update_state(client, &state_id, input)?);
+    }
+
+    refresh_cache(client, &team_key, use_cache);
+    output(&updated, format);
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -300,4 +697,270 @@ mod tests {
         // Verify mock was called
         mock.assert();
     }
+
+    const TEAM_UUID: &str = "11111111-1111-1111-1111-111111111111";
+    const STATE_UUID: &str = "22222222-2222-2222-2222-222222222222";
+
+    fn mock_team(server: &mut mockito::ServerGuard) -> mockito::Mock {
+        server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::Regex(r"query Team\(".to_string()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(format!(
+                r#"{{"data": {{"team": {{"id": "{}", "key": "ENG", "name": "Engineering"}}}}}}"#,
+                TEAM_UUID
+            ))
+            .create()
+    }
+
+    fn state_payload(field: &str, name: &str, position: f64) -> String {
+        format!(
+            r##"{{"data": {{"{}": {{"success": true, "workflowState": {{"id": "{}", "name": "{}", "color": "#f2c94c", "type": "started", "position": {}}}}}}}}}"##,
+            field, STATE_UUID, name, position
+        )
+    }
+
+    #[test]
+    fn test_parse_state_type() {
+        assert_eq!(parse_state_type("Started").unwrap(), "started");
+        assert!(parse_state_type("doing").is_err());
+    }
+
+    #[test]
+    fn test_create_workflow_state_uses_type_default_color() {
+        let mut server = mockito::Server::new();
+        let team_mock = mock_team(&mut server);
+        let create_mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::Regex("workflowStateCreate".to_string()),
+                mockito::Matcher::PartialJsonString(format!(
+                    r##"{{"variables": {{"input": {{"teamId": "{}", "name": "In Review", "type": "started", "color": "#f2c94c", "position": 3.0}}}}}}"##,
+                    TEAM_UUID
+                )),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(state_payload("workflowStateCreate", "In Review", 3.0))
+            .create();
+
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let options = WorkflowStateCreateOptions {
+            team: Some(TEAM_UUID.to_string()),
+            name: "In Review".to_string(),
+            state_type: "started".to_string(),
+            position: Some(3.0),
+            ..Default::default()
+        };
+        let result = create_workflow_state(&client, options, false, OutputFormat::Json);
+
+        assert!(result.is_ok(), "{:?}", result);
+        team_mock.assert();
+        create_mock.assert();
+    }
+
+    #[test]
+    fn test_create_workflow_state_rejects_invalid_type() {
+        let client = GraphQLClient::with_url("test-token", "http://127.0.0.1:9");
+        let options = WorkflowStateCreateOptions {
+            team: Some(TEAM_UUID.to_string()),
+            name: "Doing".to_string(),
+            state_type: "doing".to_string(),
+            ..Default::default()
+        };
+        let err = create_workflow_state(&client, options, false, OutputFormat::Human).unwrap_err();
+        assert!(err.to_string().contains("Invalid state type 'doing'"));
+    }
+
+    #[test]
+    fn test_update_workflow_state_requires_changes() {
+        let client = GraphQLClient::with_url("test-token", "http://127.0.0.1:9");
+        let options = WorkflowStateUpdateOptions {
+            team: Some(TEAM_UUID.to_string()),
+            ..Default::default()
+        };
+        let err = update_workflow_state(&client, STATE_UUID, options, false, OutputFormat::Human)
+            .unwrap_err();
+        assert!(err.to_string().contains("Nothing to update"));
+    }
+
+    #[test]
+    fn test_update_workflow_state_by_name() {
+        let mut server = mockito::Server::new();
+        let team_mock = mock_team(&mut server);
+        let _team_by_key_mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::Regex("TeamByKey".to_string()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(format!(
+                r#"{{"data": {{"teams": {{"nodes": [{{"id": "{}", "key": "ENG", "name": "Engineering"}}]}}}}}}"#,
+                TEAM_UUID
+            ))
+            .create();
+        let states_mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::Regex("states".to_string()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(format!(
+                r##"{{"data": {{"team": {{"id": "{}", "states": {{"nodes": [{{"id": "{}", "name": "In Review", "color": "#f2c94c", "type": "started"}}]}}}}}}}}"##,
+                TEAM_UUID, STATE_UUID
+            ))
+            .create();
+        let update_mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::Regex("workflowStateUpdate".to_string()),
+                mockito::Matcher::PartialJsonString(format!(
+                    r##"{{"variables": {{"id": "{}", "input": {{"name": "Code Review", "color": "#aabbcc"}}}}}}"##,
+                    STATE_UUID
+                )),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(state_payload("workflowStateUpdate", "Code Review", 3.0))
+            .create();
+
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let options = WorkflowStateUpdateOptions {
+            team: Some(TEAM_UUID.to_string()),
+            name: Some("Code Review".to_string()),
+            color: Some("aabbcc".to_string()),
+            ..Default::default()
+        };
+        let result =
+            update_workflow_state(&client, "in review", options, false, OutputFormat::Json);
+
+        assert!(result.is_ok(), "{:?}", result);
+        team_mock.assert();
+        states_mock.assert();
+        update_mock.assert();
+    }
+
+    #[test]
+    fn test_archive_workflow_state_failure() {
+        let mut server = mockito::Server::new();
+        let _team_mock = mock_team(&mut server);
+        let archive_mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::Regex("workflowStateArchive".to_string()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"data": {"workflowStateArchive": {"success": false}}}"#)
+            .create();
+
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let err = archive_workflow_state(&client, Some(TEAM_UUID), STATE_UUID, false).unwrap_err();
+
+        assert!(
+            err.to_string().contains("Failed to archive workflow state"),
+            "{}",
+            err
+        );
+        archive_mock.assert();
+    }
+
+    #[test]
+    fn test_reorder_workflow_states_reuses_listed_positions() {
+        let mut server = mockito::Server::new();
+        let _team_mock = mock_team(&mut server);
+        let positions_mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::Regex(
+                "query WorkflowStatePositions".to_string(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(format!(
+                r#"{{"data": {{"team": {{"states": {{"nodes": [
+                    {{"id": "{}", "position": 2.0}},
+                    {{"id": "44444444-4444-4444-4444-444444444444", "position": 3.0}},
+                    {{"id": "33333333-3333-3333-3333-333333333333", "position": 5.0}}
+                ]}}}}}}}}"#,
+                STATE_UUID
+            ))
+            .create();
+        let first_mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::Regex("workflowStateUpdate".to_string()),
+                mockito::Matcher::PartialJsonString(
+                    r#"{"variables": {"id": "33333333-3333-3333-3333-333333333333", "input": {"position": 2.0}}}"#
+                        .to_string(),
+                ),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(state_payload("workflowStateUpdate", "In Progress", 2.0))
+            .create();
+        let second_mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::Regex("workflowStateUpdate".to_string()),
+                mockito::Matcher::PartialJsonString(format!(
+                    r#"{{"variables": {{"id": "{}", "input": {{"position": 5.0}}}}}}"#,
+                    STATE_UUID
+                )),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(state_payload("workflowStateUpdate", "In Review", 5.0))
+            .create();
+
+        let client = GraphQLClient::with_url("test-token", &server.url());
+        let states = vec![
+            "33333333-3333-3333-3333-333333333333".to_string(),
+            STATE_UUID.to_string(),
+        ];
+        let result =
+            reorder_workflow_states(&client, Some(TEAM_UUID), &states, false, OutputFormat::Json);
+
+        assert!(result.is_ok(), "{:?}", result);
+        positions_mock.assert();
+        first_mock.assert();
+        second_mock.assert();
+    }
+
+    #[test]
+    fn test_reorder_positions_rejects_unknown_and_duplicate_states() {
+        let current = vec![
+            WorkflowStatePosition {
+                id: "a".to_string(),
+                position: 1.0,
+            },
+            WorkflowStatePosition {
+                id: "b".to_string(),
+                position: 4.0,
+            },
+        ];
+
+        let err = reorder_positions(&["a".to_string(), "c".to_string()], &current).unwrap_err();
+        assert!(err.to_string().contains("doesn't belong to this team"));
+
+        let err = reorder_positions(&["a".to_string(), "a".to_string()], &current).unwrap_err();
+        assert!(err.to_string().contains("listed more than once"));
+
+        let positions = reorder_positions(&["b".to_string(), "a".to_string()], &current).unwrap();
+        assert_eq!(
+            positions,
+            vec![("b".to_string(), 1.0), ("a".to_string(), 4.0)]
+        );
+    }
+
+    #[test]
+    fn test_reorder_workflow_states_requires_two_states() {
+        let client = GraphQLClient::with_url("test-token", "http://127.0.0.1:9");
+        let states = vec![STATE_UUID.to_string()];
+        let err = reorder_workflow_states(
+            &client,
+            Some(TEAM_UUID),
+            &states,
+            false,
+            OutputFormat::Human,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("at least two states"));
+    }
 }
@@ -1,9 +1,10 @@
 //! Configuration management for the lin CLI.
 //!
 //! Handles multi-organization authentication with embedded cache for teams and workflow states.
-//! Config is stored in `~/.config/lin/config.json`.
+//! Config is stored in `~/.config/lin/config.json`. Tokens are stored there too unless a
+//! [`SecretBackend`] is configured, in which case they are resolved through it on demand.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::io::Write;
 use std::path::PathBuf;
@@ -15,28 +16,231 @@ use std::os::unix::fs::PermissionsExt;
 
 use crate::Result;
 use crate::error::LinError;
+use crate::repo_config::{RepoConfig, RepoConfigFile};
+use crate::secrets::{self, SecretBackend, SecretStore};
+
+/// Current on-disk config format version.
+pub const CONFIG_VERSION: u32 = MIGRATIONS.len() as u32;
+
+/// Config format migrations. `MIGRATIONS[n]` upgrades a version `n` file to `n + 1`.
+const MIGRATIONS: &[fn(&mut serde_json::Value)] = &[migrate_v0_to_v1];
+
+/// Version 0 files predate the `version` field; the layout is otherwise unchanged.
+fn migrate_v0_to_v1(_config: &mut serde_json::Value) {}
+
+/// Upgrade a config file's JSON to [`CONFIG_VERSION`].
+///
+/// # Errors
+///
+/// Returns an error if the file was written by a newer version of lin.
+pub fn migrate(config: &mut serde_json::Value) -> Result<()> {
+    let version = config.get("version").and_then(|v| v.as_u64()).unwrap_or(0) as u32;
+    if version > CONFIG_VERSION {
+        return Err(LinError::config(format!(
+            "Config file version {} is newer than this lin supports ({}). Run: lin update",
+            version, CONFIG_VERSION
+        )));
+    }
+
+    for migration in &MIGRATIONS[version as usize..] {
+        migration(config);
+    }
+    if let Some(object) = config.as_object_mut() {
+        object.insert("version".to_string(), serde_json::json!(CONFIG_VERSION));
+    }
+    Ok(())
+}
+
+/// Keys readable with `lin config get`, and whether `lin config set` may change them.
+///
+/// `<org>` matches any configured organization name and `<team>` any team key.
+/// Tokens are deliberately absent; they are managed with `lin auth`.
+pub const CONFIG_KEYS: &[(&str, bool)] = &[
+    ("version", false),
+    ("active_org", true),
+    ("secret_backend", false),
+    ("defaults.issue.priority", true),
+    ("defaults.issue.state", true),
+    ("cache.ttl_hours", true),
+    ("aliases.<name>", true),
+    ("orgs.<org>.current_team", true),
+    ("orgs.<org>.tshirt_scales.<team>", true),
+    ("orgs.<org>.oauth.client_id", false),
+    ("orgs.<org>.oauth.expires_at", false),
+    ("orgs.<org>.cache.last_sync", false),
+];
 
 /// Configuration for the lin CLI with multi-org support.
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
+    /// On-disk format version (see [`migrate`])
+    #[serde(default)]
+    pub version: u32,
     /// The active organization name (used when no env var override)
     pub active_org: Option<String>,
     /// Map of organization names to their configuration
     #[serde(default)]
     pub orgs: HashMap<String, OrgConfig>,
+    /// Where tokens are stored (defaults to this file)
+    #[serde(default, skip_serializing_if = "SecretBackend::is_plaintext")]
+    pub secret_backend: SecretBackend,
+    /// Defaults for command options
+    #[serde(default, skip_serializing_if = "Defaults::is_empty")]
+    pub defaults: Defaults,
+    /// Cache settings
+    #[serde(default, skip_serializing_if = "CacheSettings::is_empty")]
+    pub cache: CacheSettings,
+    /// Command aliases (name -> expansion, see [`crate::alias`])
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub aliases: BTreeMap<String, String>,
+    /// The repository's `.lin.toml`, if one was found (never saved)
+    #[serde(skip)]
+    pub repo: Option<RepoConfigFile>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            version: CONFIG_VERSION,
+            active_org: None,
+            orgs: HashMap::new(),
+            secret_backend: SecretBackend::default(),
+            defaults: Defaults::default(),
+            cache: CacheSettings::default(),
+            aliases: BTreeMap::new(),
+            repo: None,
+        }
+    }
+}
+
+/// Defaults for command options, used when the option isn't given.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Defaults {
+    /// Defaults for `lin issue create`
+    #[serde(default)]
+    pub issue: IssueDefaults,
+}
+
+impl Defaults {
+    fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Defaults for new issues.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct IssueDefaults {
+    /// Priority (0-4: 0=none, 1=urgent, 2=high, 3=normal, 4=low)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<u8>,
+    /// Workflow state name (e.g., "Todo")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+}
+
+/// Expand the `<...>` wildcards in `pattern` using the keys present in `json`.
+fn expand_key(json: &serde_json::Value, prefix: &str, pattern: &str, keys: &mut Vec<String>) {
+    let (head, rest) = match pattern.split_once('.') {
+        Some((head, rest)) => (head, Some(rest)),
+        None => (pattern, None),
+    };
+    let names: Vec<String> = if head.starts_with('<') {
+        let mut names: Vec<String> = json
+            .as_object()
+            .map(|object| object.keys().cloned().collect())
+            .unwrap_or_default();
+        names.sort_unstable();
+        names
+    } else {
+        vec![head.to_string()]
+    };
+
+    for name in names {
+        let key = if prefix.is_empty() {
+            name.clone()
+        } else {
+            format!("{}.{}", prefix, name)
+        };
+        match rest {
+            Some(rest) => {
+                if let Some(child) = json.get(&name) {
+                    expand_key(child, &key, rest, keys);
+                }
+            }
+            None => keys.push(key),
+        }
+    }
+}
+
+/// Convert a dotted key to a JSON pointer.
+fn json_pointer(key: &str) -> String {
+    key.split('.')
+        .map(|part| format!("/{}", part.replace('~', "~0").replace('/', "~1")))
+        .collect()
+}
+
+/// Number of recently viewed issues remembered for completions.
+pub const RECENT_ISSUES_LIMIT: usize = 20;
+
+/// Cache TTL used when `cache.ttl_hours` isn't set.
+pub const DEFAULT_CACHE_TTL_HOURS: u64 = 24;
+
+/// How long cached teams, states, labels, users and projects stay fresh.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CacheSettings {
+    /// Hours before the cache is refreshed (0 = never refresh automatically)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ttl_hours: Option<u64>,
+}
+
+impl CacheSettings {
+    fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// The cache TTL, or `None` if the cache never expires.
+    pub fn ttl(&self) -> Option<chrono::Duration> {
+        match self.ttl_hours.unwrap_or(DEFAULT_CACHE_TTL_HOURS) {
+            0 => None,
+            hours => Some(chrono::Duration::hours(
+                hours.min(i64::MAX as u64 / 3600) as i64
+            )),
+        }
+    }
 }
 
 /// Configuration for a single organization.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrgConfig {
-    /// Linear API token for this organization
+    /// Linear API token for this organization (the access token for OAuth logins).
+    /// Empty when the token lives in a secret backend.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub token: String,
+    /// OAuth credentials, present when the org was added with `lin auth login`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oauth: Option<OAuthCredentials>,
     /// Cached data for this organization
     #[serde(default)]
     pub cache: OrgCache,
     /// Current/default team key for this organization
     #[serde(default)]
     pub current_team: Option<String>,
+    /// Custom t-shirt size mappings by team key (e.g., "ENG" → {"S": 1, "M": 3})
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub tshirt_scales: HashMap<String, HashMap<String, f64>>,
+}
+
+/// OAuth credentials used to refresh an organization's access token.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OAuthCredentials {
+    /// OAuth application client ID the tokens were issued to
+    pub client_id: String,
+    /// Refresh token (absent if the server didn't issue one or it lives in a secret backend)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<String>,
+    /// Access token expiry (ISO 8601 timestamp)
+    #[serde(default)]
+    pub expires_at: Option<String>,
 }
 
 /// Cached data for an organization.
@@ -48,10 +252,63 @@ pub struct OrgCache {
     /// Map of project slugs to project UUIDs
     #[serde(default)]
     pub projects: HashMap<String, String>,
+    /// Workspace and team labels
+    #[serde(default)]
+    pub labels: Vec<CachedLabel>,
+    /// Users in the organization
+    #[serde(default)]
+    pub users: Vec<CachedUser>,
+    /// Identifiers of recently viewed issues, most recent first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub recent_issues: Vec<String>,
     /// Last time the cache was synced (ISO 8601 timestamp)
     pub last_sync: Option<String>,
 }
 
+impl OrgCache {
+    /// Whether the cache is older than `ttl` at `now`.
+    ///
+    /// A cache that was never synced is always stale; with no TTL, a synced
+    /// cache never is.
+    pub fn is_stale(
+        &self,
+        ttl: Option<chrono::Duration>,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> bool {
+        let Some(last_sync) = self
+            .last_sync
+            .as_deref()
+            .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok())
+        else {
+            return true;
+        };
+        ttl.is_some_and(|ttl| now.signed_duration_since(last_sync) > ttl)
+    }
+}
+
+/// Cached label data.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CachedLabel {
+    /// Label UUID
+    pub id: String,
+    /// Label name (e.g., "Bug")
+    pub name: String,
+}
+
+/// Cached user data.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CachedUser {
+    /// User UUID
+    pub id: String,
+    /// Full name
+    pub name: String,
+    /// Email address
+    pub email: String,
+    /// Display name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+}
+
 /// Cached data for a team.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CachedTeam {
@@ -61,9 +318,18 @@ pub struct CachedTeam {
     pub name: String,
     /// Map of state names (lowercase) to state UUIDs
     pub states: HashMap<String, String>,
-    /// Map of estimate names (lowercase) to numeric values
+    /// Map of estimate names (lowercase) to numeric values, exactly the
+    /// estimates the team allows
     #[serde(default)]
     pub estimates: HashMap<String, f64>,
+    /// Estimation type (e.g., "fibonacci", "tShirt", "notUsed"); absent in
+    /// caches synced before estimate scales were tracked
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estimate_type: Option<String>,
+    /// Map of state names (lowercase) to workflow state types
+    /// (backlog, unstarted, started, completed, canceled, triage)
+    #[serde(default)]
+    pub state_types: HashMap<String, String>,
 }
 
 impl Config {
@@ -94,19 +360,32 @@ impl Config {
     ///
     /// If the config file doesn't exist, returns a default empty configuration.
     /// On macOS, automatically migrates from legacy location if found.
+    /// The nearest `.lin.toml` is attached as [`Config::repo`] and takes
+    /// precedence over the global org and team.
     ///
     /// # Errors
     ///
-    /// Returns an error if the config file exists but cannot be read or parsed.
+    /// Returns an error if the config file or `.lin.toml` exists but cannot be read or parsed.
     pub fn load() -> Result<Self> {
+        let mut config = Self::load_global()?;
+        config.repo = RepoConfig::discover_from_cwd()?;
+        Ok(config)
+    }
+
+    /// Load only the global config file, ignoring any `.lin.toml`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the config file exists but cannot be read or parsed.
+    pub fn load_global() -> Result<Self> {
         let path = Self::config_path();
 
         // Check if config exists at new location
         if path.exists() {
             let contents = fs::read_to_string(&path)?;
-            let config: Config = serde_json::from_str(&contents)
-                .map_err(|e| LinError::parse(format!("Failed to parse config file: {}", e)))?;
-            return Ok(config);
+            // Migrations only apply in memory; the upgraded format is
+            // written the next time a command saves the config.
+            return Self::parse(&contents);
         }
 
         // On macOS, check legacy location and migrate if found
@@ -116,8 +395,7 @@ impl Config {
             if legacy_path.exists() {
                 // Read from legacy location
                 let contents = fs::read_to_string(&legacy_path)?;
-                let config: Config = serde_json::from_str(&contents)
-                    .map_err(|e| LinError::parse(format!("Failed to parse config file: {}", e)))?;
+                let config = Self::parse(&contents)?;
 
                 // Save to new location (this will set proper permissions)
                 config.save()?;
@@ -132,6 +410,208 @@ impl Config {
         Ok(Config::default())
     }
 
+    /// Parse config file contents, migrating older formats.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the contents are not a valid config.
+    pub fn parse(contents: &str) -> Result<Self> {
+        let mut value: serde_json::Value = serde_json::from_str(contents)
+            .map_err(|e| LinError::parse(format!("Failed to parse config file: {}", e)))?;
+        migrate(&mut value)?;
+        let config: Config = serde_json::from_value(value)
+            .map_err(|e| LinError::parse(format!("Failed to parse config file: {}", e)))?;
+        Ok(config)
+    }
+
+    /// Check values that serde can't (references between fields, ranges).
+    ///
+    /// # Errors
+    ///
+    /// Returns a config error describing the first invalid value.
+    pub fn validate(&self) -> Result<()> {
+        if let Some(active) = &self.active_org
+            && !self.orgs.contains_key(active)
+        {
+            return Err(LinError::config(format!(
+                "active_org: organization '{}' is not configured",
+                active
+            )));
+        }
+
+        for (name, org) in &self.orgs {
+            if let Some(team) = &org.current_team
+                && !org.cache.teams.is_empty()
+                && !org.cache.teams.contains_key(team)
+            {
+                return Err(LinError::config(format!(
+                    "orgs.{}.current_team: team '{}' not found in cache. Run 'lin auth sync' to sync teams.",
+                    name, team
+                )));
+            }
+        }
+
+        for (name, org) in &self.orgs {
+            for (team, sizes) in &org.tshirt_scales {
+                if let Some((size, value)) = sizes.iter().find(|(size, value)| {
+                    size.trim().is_empty() || !value.is_finite() || **value < 0.0
+                }) {
+                    return Err(LinError::config(format!(
+                        "orgs.{}.tshirt_scales.{}: invalid size '{}' = {}",
+                        name, team, size, value
+                    )));
+                }
+            }
+        }
+
+        for (name, expansion) in &self.aliases {
+            crate::alias::validate(name, expansion)?;
+        }
+
+        if let Some(priority) = self.defaults.issue.priority
+            && priority > 4
+        {
+            return Err(LinError::config(format!(
+                "defaults.issue.priority: {} is out of range (0-4: 0=none, 1=urgent, 2=high, 3=normal, 4=low)",
+                priority
+            )));
+        }
+        Ok(())
+    }
+
+    /// Look up a dotted key in [`CONFIG_KEYS`], returning whether it's settable.
+    fn config_key(&self, key: &str) -> Result<bool> {
+        let parts: Vec<&str> = key.split('.').collect();
+        let settable = CONFIG_KEYS.iter().find_map(|(pattern, settable)| {
+            let pattern: Vec<&str> = pattern.split('.').collect();
+            (pattern.len() == parts.len()
+                && pattern
+                    .iter()
+                    .zip(&parts)
+                    .all(|(p, k)| p.starts_with('<') || p == k))
+            .then_some(*settable)
+        });
+
+        let Some(settable) = settable else {
+            let valid: Vec<&str> = CONFIG_KEYS.iter().map(|(pattern, _)| *pattern).collect();
+            return Err(LinError::config(format!(
+                "Unknown config key '{}'. Valid keys: {}",
+                key,
+                valid.join(", ")
+            )));
+        };
+        if parts[0] == "orgs" && !self.orgs.contains_key(parts[1]) {
+            return Err(LinError::config(format!(
+                "Organization '{}' not found. Available: {}",
+                parts[1],
+                self.list_orgs().join(", ")
+            )));
+        }
+        Ok(settable)
+    }
+
+    /// Get the value of a dotted key (e.g., `orgs.work.current_team`).
+    ///
+    /// Returns `Value::Null` for unset keys.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for keys not in [`CONFIG_KEYS`].
+    pub fn get_key(&self, key: &str) -> Result<serde_json::Value> {
+        self.config_key(key)?;
+        if key == "secret_backend" {
+            return Ok(serde_json::json!(self.secret_backend.name()));
+        }
+        let value = self.to_json()?;
+        Ok(value
+            .pointer(&json_pointer(key))
+            .cloned()
+            .unwrap_or(serde_json::Value::Null))
+    }
+
+    /// Set a dotted key from its command-line form.
+    ///
+    /// The value is parsed as JSON when possible (so `3` is a number) and
+    /// otherwise taken as a string, then type-checked against the config
+    /// structs and validated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for unknown or read-only keys, values of the wrong
+    /// type, or values that fail [`Config::validate`].
+    pub fn set_key(&mut self, key: &str, raw: &str) -> Result<()> {
+        let string = serde_json::Value::String(raw.to_string());
+        match serde_json::from_str::<serde_json::Value>(raw) {
+            Ok(parsed) if parsed != string => self
+                .replace_key(key, parsed)
+                .or_else(|e| self.replace_key(key, string).map_err(|_| e)),
+            _ => self.replace_key(key, string),
+        }
+    }
+
+    /// Remove a dotted key, restoring its default.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for unknown or read-only keys.
+    pub fn unset_key(&mut self, key: &str) -> Result<()> {
+        self.replace_key(key, serde_json::Value::Null)
+    }
+
+    /// All keys with a value, in [`CONFIG_KEYS`] order with wildcards expanded.
+    pub fn list_keys(&self) -> Result<Vec<(String, serde_json::Value)>> {
+        let json = self.to_json()?;
+        let mut keys = Vec::new();
+        for (pattern, _) in CONFIG_KEYS {
+            expand_key(&json, "", pattern, &mut keys);
+        }
+
+        let mut values = Vec::new();
+        for key in keys {
+            let value = self.get_key(&key)?;
+            if !value.is_null() {
+                values.push((key, value));
+            }
+        }
+        Ok(values)
+    }
+
+    fn replace_key(&mut self, key: &str, new_value: serde_json::Value) -> Result<()> {
+        if !self.config_key(key)? {
+            return Err(LinError::config(format!("'{}' is read-only", key)));
+        }
+
+        let mut value = self.to_json()?;
+        let (parent, field) = key.rsplit_once('.').unwrap_or(("", key));
+        let mut node = &mut value;
+        for part in parent.split('.').filter(|p| !p.is_empty()) {
+            node = node
+                .as_object_mut()
+                .map(|object| object.entry(part).or_insert(serde_json::json!({})))
+                .ok_or_else(|| LinError::config(format!("'{}' is not a table", part)))?;
+        }
+        if let Some(object) = node.as_object_mut() {
+            // Removing rather than nulling lets map entries (aliases, scales) be unset
+            if new_value.is_null() {
+                object.remove(field);
+            } else {
+                object.insert(field.to_string(), new_value);
+            }
+        }
+
+        let mut updated: Config = serde_json::from_value(value)
+            .map_err(|e| LinError::config(format!("Invalid value for {}: {}", key, e)))?;
+        updated.validate()?;
+        updated.repo = self.repo.take();
+        *self = updated;
+        Ok(())
+    }
+
+    fn to_json(&self) -> Result<serde_json::Value> {
+        serde_json::to_value(self)
+            .map_err(|e| LinError::parse(format!("Failed to serialize config: {}", e)))
+    }
+
     /// Save the configuration to the config file.
     ///
     /// Creates the config directory and any parent directories if they don't exist.
@@ -149,7 +629,14 @@ impl Config {
             fs::create_dir_all(parent)?;
         }
 
-        let contents = serde_json::to_string_pretty(self)
+        // Move tokens out of the file when a secret backend is configured
+        let mut on_disk = self.clone();
+        on_disk.version = CONFIG_VERSION;
+        if let Some(store) = self.secret_backend.open() {
+            on_disk.stash_secrets(store.as_ref())?;
+        }
+
+        let contents = serde_json::to_string_pretty(&on_disk)
             .map_err(|e| LinError::parse(format!("Failed to serialize config: {}", e)))?;
 
         // Use atomic write: write to temp file in same directory, then rename
@@ -178,6 +665,34 @@ impl Config {
         Ok(())
     }
 
+    /// Move in-memory tokens into `store`, clearing them from this config.
+    ///
+    /// Tokens read through the backend are never held in the config, so only
+    /// newly added or refreshed tokens are written.
+    pub fn stash_secrets(&mut self, store: &dyn SecretStore) -> Result<()> {
+        for (name, org) in &mut self.orgs {
+            if !org.token.is_empty() {
+                store.set(&secrets::token_key(name), &org.token)?;
+                org.token.clear();
+            }
+            if let Some(refresh_token) = org.oauth.as_mut().and_then(|c| c.refresh_token.take()) {
+                store.set(&secrets::refresh_token_key(name), &refresh_token)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Remove an organization's tokens from the secret backend.
+    ///
+    /// Does nothing for the plaintext backend.
+    pub fn delete_org_secrets(&self, name: &str) -> Result<()> {
+        if let Some(store) = self.secret_backend.open() {
+            store.delete(&secrets::token_key(name))?;
+            store.delete(&secrets::refresh_token_key(name))?;
+        }
+        Ok(())
+    }
+
     /// Add or update an organization with the given token.
     ///
     /// If this is the first organization, it will be set as active.
@@ -185,8 +700,10 @@ impl Config {
     pub fn add_org(&mut self, name: String, token: String) -> Result<()> {
         let org_config = OrgConfig {
             token,
+            oauth: None,
             cache: OrgCache::default(),
             current_team: None,
+            tshirt_scales: HashMap::new(),
         };
 
         self.orgs.insert(name.clone(), org_config);
@@ -199,6 +716,23 @@ impl Config {
         Ok(())
     }
 
+    /// Add or update an organization authenticated through OAuth.
+    ///
+    /// Behaves like [`Config::add_org`], storing the access token as the org's
+    /// token alongside the credentials needed to refresh it.
+    pub fn add_oauth_org(
+        &mut self,
+        name: String,
+        access_token: String,
+        credentials: OAuthCredentials,
+    ) -> Result<()> {
+        self.add_org(name.clone(), access_token)?;
+        if let Some(org) = self.orgs.get_mut(&name) {
+            org.oauth = Some(credentials);
+        }
+        Ok(())
+    }
+
     /// Remove an organization from the configuration.
     ///
     /// If the removed organization was active, the active org will be cleared.
@@ -246,44 +780,53 @@ impl Config {
     ///
     /// Returns an error if no active organization is set or if it doesn't exist.
     pub fn get_active_org(&self) -> Result<&OrgConfig> {
-        let org_name = self.active_org.as_deref().ok_or_else(|| {
-            LinError::config("No active organization. Run: lin auth <name> <token>")
-        })?;
-
-        self.orgs.get(org_name).ok_or_else(|| {
-            LinError::config(format!(
-                "Active organization '{}' not found in configuration",
-                org_name
-            ))
-        })
+        let org_name = self.get_active_org_name()?;
+        self.orgs
+            .get(org_name)
+            .ok_or_else(|| self.missing_active_org(org_name))
     }
 
     /// Get the active organization configuration mutably.
     pub fn get_active_org_mut(&mut self) -> Result<&mut OrgConfig> {
-        let org_name = self
-            .active_org
-            .as_deref()
-            .ok_or_else(|| {
-                LinError::config("No active organization. Run: lin auth <name> <token>")
-            })?
-            .to_string();
-
-        self.orgs.get_mut(&org_name).ok_or_else(|| {
-            LinError::config(format!(
+        let org_name = self.get_active_org_name()?.to_string();
+        let missing = self.missing_active_org(&org_name);
+        self.orgs.get_mut(&org_name).ok_or(missing)
+    }
+
+    /// Error for an active organization that isn't configured.
+    fn missing_active_org(&self, org_name: &str) -> LinError {
+        match self.repo_pin(|repo| repo.org.is_some()) {
+            Some(path) => LinError::config(format!(
+                "Organization '{}' (from {}) not found. Run: lin auth add {} <token>",
+                org_name,
+                path.display(),
+                org_name
+            )),
+            None => LinError::config(format!(
                 "Active organization '{}' not found in configuration",
                 org_name
-            ))
-        })
+            )),
+        }
+    }
+
+    /// Path of the `.lin.toml` if `is_set` reports that it sets a value.
+    pub fn repo_pin(&self, is_set: impl Fn(&RepoConfig) -> bool) -> Option<&std::path::Path> {
+        let repo = self.repo.as_ref()?;
+        is_set(&repo.config).then_some(repo.path.as_path())
     }
 
     /// Get the active organization name.
     ///
+    /// An `org` pinned in `.lin.toml` takes precedence over the global active org.
+    ///
     /// # Errors
     ///
     /// Returns an error if no active organization is set.
     pub fn get_active_org_name(&self) -> Result<&str> {
-        self.active_org
-            .as_deref()
+        self.repo
+            .as_ref()
+            .and_then(|repo| repo.config.org.as_deref())
+            .or(self.active_org.as_deref())
             .ok_or_else(|| LinError::config("No active organization. Run: lin auth <name> <token>"))
     }
 
@@ -297,15 +840,68 @@ impl Config {
     ///
     /// Returns an error if no organization is found or no active organization is set.
     pub fn get_token(&self, org_name: Option<&str>) -> Result<String> {
-        let org = if let Some(name) = org_name {
-            self.orgs
-                .get(name)
-                .ok_or_else(|| LinError::config(format!("Organization '{}' not found", name)))?
-        } else {
-            self.get_active_org()?
+        let (name, org) = self.resolve_org(org_name)?;
+        if !org.token.is_empty() {
+            return Ok(org.token.clone());
+        }
+
+        self.lookup_secret(&secrets::token_key(name))?
+            .ok_or_else(|| {
+                LinError::config(format!(
+                    "No token stored for '{}' in the {} secret backend. Run: lin auth add {} <token>",
+                    name,
+                    self.secret_backend.name(),
+                    name
+                ))
+            })
+    }
+
+    /// Get the OAuth refresh token for an organization, or the active organization if None.
+    ///
+    /// Returns None if the organization has no refresh token.
+    pub fn get_refresh_token(&self, org_name: Option<&str>) -> Result<Option<String>> {
+        let (name, org) = self.resolve_org(org_name)?;
+        let Some(creds) = &org.oauth else {
+            return Ok(None);
         };
+        if creds.refresh_token.is_some() {
+            return Ok(creds.refresh_token.clone());
+        }
+        self.lookup_secret(&secrets::refresh_token_key(name))
+    }
 
-        Ok(org.token.clone())
+    /// Get the Authorization header value for an organization.
+    ///
+    /// Personal API keys are sent as-is; OAuth access tokens use the Bearer scheme.
+    pub fn get_authorization(&self, org_name: Option<&str>) -> Result<String> {
+        let (_, org) = self.resolve_org(org_name)?;
+        let token = self.get_token(org_name)?;
+        if org.oauth.is_some() {
+            Ok(format!("Bearer {}", token))
+        } else {
+            Ok(token)
+        }
+    }
+
+    /// Look up an organization by name, or the active organization if None.
+    fn resolve_org<'a>(&'a self, org_name: Option<&'a str>) -> Result<(&'a str, &'a OrgConfig)> {
+        let name = match org_name {
+            Some(name) => name,
+            None => self.get_active_org_name()?,
+        };
+        let org = self
+            .orgs
+            .get(name)
+            .ok_or_else(|| LinError::config(format!("Organization '{}' not found", name)))?;
+        Ok((name, org))
+    }
+
+    /// Read a secret from the configured backend.
+    fn lookup_secret(&self, key: &str) -> Result<Option<String>> {
+        match self.secret_backend.open() {
+            Some(store) => store.get(key),
+            None => Ok(None),
+        }
     }
 
     /// Get a team ID from the cache by team key.
@@ -354,23 +950,73 @@ impl Config {
             .unwrap_or_default()
     }
 
+    /// Get the workflow state types for a team from the cache.
+    ///
+    /// Returns a map of lowercase state names to state types, which is empty
+    /// if the team is not cached or was cached before state types were tracked.
+    pub fn get_state_types(&self, team_key: &str) -> HashMap<String, String> {
+        self.get_active_org()
+            .ok()
+            .and_then(|org| org.cache.teams.get(team_key))
+            .map(|team| team.state_types.clone())
+            .unwrap_or_default()
+    }
+
+    /// Get the estimates a team allows, as (name, value) pairs in ascending order.
+    ///
+    /// A custom t-shirt mapping from `tshirt_scales` replaces the synced sizes
+    /// for t-shirt teams. Returns None if the team isn't cached or was cached
+    /// before estimate scales were tracked; an empty list means the team
+    /// doesn't use estimates.
+    pub fn get_team_estimates(&self, team_key: &str) -> Option<Vec<(String, f64)>> {
+        let org = self.get_active_org().ok()?;
+        let team = org.cache.teams.get(team_key)?;
+        if team.estimate_type.is_none() && team.estimates.is_empty() {
+            return None;
+        }
+
+        let custom = org
+            .tshirt_scales
+            .get(team_key)
+            .filter(|_| team.estimate_type.as_deref() == Some("tShirt"));
+        let mut estimates: Vec<(String, f64)> = match custom {
+            Some(sizes) => sizes
+                .iter()
+                .map(|(name, value)| (name.to_lowercase(), *value))
+                .chain(
+                    team.estimates
+                        .get_key_value("0")
+                        .map(|(k, v)| (k.clone(), *v)),
+                )
+                .collect(),
+            None => team
+                .estimates
+                .iter()
+                .map(|(name, value)| (name.clone(), *value))
+                .collect(),
+        };
+        estimates.sort_by(|a, b| a.1.total_cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+        Some(estimates)
+    }
+
     /// Get an estimate value from the cache by team key and estimate name.
     ///
     /// Estimate name lookup is case-insensitive.
     /// Returns None if the team or estimate is not in the cache.
     pub fn get_estimate_value(&self, team_key: &str, estimate_name: &str) -> Option<f64> {
-        let org = self.get_active_org().ok()?;
-        let team = org.cache.teams.get(team_key)?;
-        team.estimates.get(&estimate_name.to_lowercase()).copied()
+        self.get_team_estimates(team_key)?
+            .into_iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(estimate_name))
+            .map(|(_, value)| value)
     }
 
-    /// Get all estimate names for a team from the active organization's cache.
+    /// Get all estimate names for a team, in ascending order of value.
     pub fn get_all_estimates_for_team(&self, team_key: &str) -> Vec<String> {
-        self.get_active_org()
-            .ok()
-            .and_then(|org| org.cache.teams.get(team_key))
-            .map(|team| team.estimates.keys().cloned().collect())
+        self.get_team_estimates(team_key)
             .unwrap_or_default()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect()
     }
 
     /// Set estimates for a team in the active organization's cache.
@@ -409,6 +1055,90 @@ impl Config {
         Ok(())
     }
 
+    /// Remember an issue as recently viewed, for completing issue identifiers.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no active organization is set.
+    pub fn record_recent_issue(&mut self, identifier: &str) -> Result<()> {
+        let identifier = identifier.to_uppercase();
+        let recent = &mut self.get_active_org_mut()?.cache.recent_issues;
+        recent.retain(|i| *i != identifier);
+        recent.insert(0, identifier);
+        recent.truncate(RECENT_ISSUES_LIMIT);
+        Ok(())
+    }
+
+    /// Whether the active organization's cache has outlived the configured TTL.
+    pub fn cache_is_stale(&self) -> bool {
+        self.get_active_org()
+            .is_ok_and(|org| org.cache.is_stale(self.cache.ttl(), chrono::Utc::now()))
+    }
+
+    /// Replace the active organization's cached labels.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no active organization is set.
+    pub fn cache_labels(&mut self, labels: Vec<CachedLabel>) -> Result<()> {
+        self.get_active_org_mut()?.cache.labels = labels;
+        Ok(())
+    }
+
+    /// Replace the active organization's cached users.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no active organization is set.
+    pub fn cache_users(&mut self, users: Vec<CachedUser>) -> Result<()> {
+        self.get_active_org_mut()?.cache.users = users;
+        Ok(())
+    }
+
+    /// Get the IDs of cached labels with the given name (case-insensitive).
+    ///
+    /// More than one ID means the name is ambiguous.
+    pub fn get_label_ids(&self, name: &str) -> Vec<String> {
+        self.get_active_org()
+            .map(|org| {
+                org.cache
+                    .labels
+                    .iter()
+                    .filter(|label| label.name.eq_ignore_ascii_case(name))
+                    .map(|label| label.id.clone())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Get a cached user's ID by email, name or display name (case-insensitive).
+    pub fn get_user_id(&self, user: &str) -> Option<String> {
+        let user = user.to_lowercase();
+        self.get_active_org()
+            .ok()?
+            .cache
+            .users
+            .iter()
+            .find(|u| {
+                u.email.to_lowercase() == user
+                    || u.name.to_lowercase() == user
+                    || u.display_name
+                        .as_deref()
+                        .is_some_and(|d| d.to_lowercase() == user)
+            })
+            .map(|u| u.id.clone())
+    }
+
+    /// Remove all cached data for the active organization.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no active organization is set.
+    pub fn clear_cache(&mut self) -> Result<()> {
+        self.get_active_org_mut()?.cache = OrgCache::default();
+        Ok(())
+    }
+
     /// List all configured organization names.
     pub fn list_orgs(&self) -> Vec<&str> {
         self.orgs.keys().map(|s| s.as_str()).collect()
@@ -436,8 +1166,16 @@ impl Config {
 
     /// Get the current team for the active organization.
     ///
+    /// A `team` pinned in `.lin.toml` takes precedence over the org's current team.
     /// Returns None if no current team is set.
     pub fn get_current_team(&self) -> Option<String> {
+        if let Some(team) = self
+            .repo
+            .as_ref()
+            .and_then(|repo| repo.config.team.as_ref())
+        {
+            return Some(team.to_uppercase());
+        }
         self.get_active_org().ok()?.current_team.clone()
     }
 
@@ -754,6 +1492,8 @@ mod tests {
             name: "Engineering".to_string(),
             states: HashMap::new(),
             estimates: HashMap::new(),
+            state_types: HashMap::new(),
+            estimate_type: None,
         };
 
         config.cache_team("ENG".to_string(), team).unwrap();
@@ -789,6 +1529,8 @@ mod tests {
             name: "Engineering".to_string(),
             states,
             estimates: HashMap::new(),
+            state_types: HashMap::new(),
+            estimate_type: None,
         };
 
         config.cache_team("ENG".to_string(), team).unwrap();
@@ -808,6 +1550,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_get_state_types() {
+        let mut config = Config::default();
+        config
+            .add_org("org".to_string(), "token".to_string())
+            .unwrap();
+
+        let mut state_types = HashMap::new();
+        state_types.insert("todo".to_string(), "unstarted".to_string());
+        state_types.insert("in progress".to_string(), "started".to_string());
+
+        let team = CachedTeam {
+            id: "team-123".to_string(),
+            name: "Engineering".to_string(),
+            states: HashMap::new(),
+            estimates: HashMap::new(),
+            state_types,
+            estimate_type: None,
+        };
+
+        config.cache_team("ENG".to_string(), team).unwrap();
+
+        let types = config.get_state_types("ENG");
+        assert_eq!(
+            types.get("in progress").map(String::as_str),
+            Some("started")
+        );
+        assert!(config.get_state_types("OPS").is_empty());
+    }
+
+    #[test]
+    fn test_cached_team_without_state_types_deserializes() {
+        // Caches written before state types were tracked must still load
+        let json = r#"{"id": "team-1", "name": "Eng", "states": {"todo": "s1"}}"#;
+        let team: CachedTeam = serde_json::from_str(json).unwrap();
+        assert!(team.state_types.is_empty());
+        assert!(team.estimates.is_empty());
+    }
+
     #[test]
     fn test_get_state_id_not_cached() {
         let mut config = Config::default();
@@ -831,12 +1612,16 @@ mod tests {
             name: "Engineering".to_string(),
             states: HashMap::new(),
             estimates: HashMap::new(),
+            state_types: HashMap::new(),
+            estimate_type: None,
         };
         let team2 = CachedTeam {
             id: "team-2".to_string(),
             name: "Design".to_string(),
             states: HashMap::new(),
             estimates: HashMap::new(),
+            state_types: HashMap::new(),
+            estimate_type: None,
         };
 
         config.cache_team("ENG".to_string(), team1).unwrap();
@@ -864,6 +1649,8 @@ mod tests {
             name: "Engineering".to_string(),
             states,
             estimates: HashMap::new(),
+            state_types: HashMap::new(),
+            estimate_type: None,
         };
 
         config.cache_team("ENG".to_string(), team).unwrap();
@@ -920,6 +1707,8 @@ mod tests {
             name: "Engineering".to_string(),
             states: HashMap::new(),
             estimates,
+            state_types: HashMap::new(),
+            estimate_type: None,
         };
 
         config.cache_team("ENG".to_string(), team).unwrap();
@@ -960,6 +1749,8 @@ mod tests {
             name: "Engineering".to_string(),
             states: HashMap::new(),
             estimates,
+            state_types: HashMap::new(),
+            estimate_type: None,
         };
 
         config.cache_team("ENG".to_string(), team).unwrap();
@@ -983,6 +1774,8 @@ mod tests {
             name: "Engineering".to_string(),
             states: HashMap::new(),
             estimates: HashMap::new(),
+            state_types: HashMap::new(),
+            estimate_type: None,
         };
 
         config.cache_team("ENG".to_string(), team).unwrap();
@@ -1181,4 +1974,391 @@ mod tests {
         assert!(slugs.contains(&"project-a".to_string()));
         assert!(slugs.contains(&"project-b".to_string()));
     }
+
+    #[test]
+    fn test_add_oauth_org_uses_bearer_authorization() {
+        let mut config = Config::default();
+        config
+            .add_oauth_org(
+                "oauth-org".to_string(),
+                "access-token".to_string(),
+                OAuthCredentials {
+                    client_id: "client-123".to_string(),
+                    refresh_token: Some("refresh-token".to_string()),
+                    expires_at: None,
+                },
+            )
+            .unwrap();
+
+        let org = config.get_active_org().unwrap();
+        assert_eq!(org.token, "access-token");
+        assert_eq!(
+            config.get_authorization(None).unwrap(),
+            "Bearer access-token"
+        );
+        assert_eq!(
+            org.oauth.as_ref().unwrap().refresh_token.as_deref(),
+            Some("refresh-token")
+        );
+    }
+
+    #[test]
+    fn test_api_key_org_omits_oauth_when_serialized() {
+        let mut config = Config::default();
+        config
+            .add_org("org".to_string(), "lin_api_xxxxx".to_string())
+            .unwrap();
+
+        assert_eq!(config.get_authorization(None).unwrap(), "lin_api_xxxxx");
+
+        let json = serde_json::to_string(&config).unwrap();
+        assert!(!json.contains("oauth"));
+        let parsed: Config = serde_json::from_str(&json).unwrap();
+        assert!(parsed.orgs.get("org").unwrap().oauth.is_none());
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_secrets_resolve_through_backend() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("secrets.enc");
+        let store = crate::secrets::EncryptedFileStore::new(path, Some("pass".to_string()));
+
+        let mut config = Config::default();
+        config
+            .add_oauth_org(
+                "work".to_string(),
+                "access-token".to_string(),
+                OAuthCredentials {
+                    client_id: "client-123".to_string(),
+                    refresh_token: Some("refresh-token".to_string()),
+                    expires_at: None,
+                },
+            )
+            .unwrap();
+        config.stash_secrets(&store).unwrap();
+
+        let json = serde_json::to_string(&config).unwrap();
+        assert!(!json.contains("access-token"));
+        assert!(!json.contains("refresh-token"));
+        assert_eq!(
+            store.get("work/token").unwrap().as_deref(),
+            Some("access-token")
+        );
+        assert_eq!(
+            store.get("work/refresh_token").unwrap().as_deref(),
+            Some("refresh-token")
+        );
+
+        // With the plaintext backend, a stashed token can't be found
+        assert!(config.get_token(None).is_err());
+
+        config.secret_backend = SecretBackend::EncryptedFile {
+            path: Some(dir.path().join("secrets.enc")),
+        };
+        unsafe { std::env::set_var(crate::secrets::LIN_SECRETS_PASSPHRASE_ENV, "pass") };
+        assert_eq!(config.get_token(None).unwrap(), "access-token");
+        assert_eq!(
+            config.get_authorization(Some("work")).unwrap(),
+            "Bearer access-token"
+        );
+        assert_eq!(
+            config.get_refresh_token(None).unwrap().as_deref(),
+            Some("refresh-token")
+        );
+        unsafe { std::env::remove_var(crate::secrets::LIN_SECRETS_PASSPHRASE_ENV) };
+    }
+
+    #[test]
+    fn test_parse_migrates_unversioned_config() {
+        let config =
+            Config::parse(r#"{"active_org": "work", "orgs": {"work": {"token": "t"}}}"#).unwrap();
+        assert_eq!(config.version, CONFIG_VERSION);
+        assert_eq!(config.active_org.as_deref(), Some("work"));
+
+        let current = format!(r#"{{"version": {}, "orgs": {{}}}}"#, CONFIG_VERSION);
+        assert_eq!(Config::parse(&current).unwrap().version, CONFIG_VERSION);
+    }
+
+    #[test]
+    fn test_parse_rejects_newer_version() {
+        let newer = format!(r#"{{"version": {}, "orgs": {{}}}}"#, CONFIG_VERSION + 1);
+        let err = Config::parse(&newer).unwrap_err();
+        assert!(err.to_string().contains("newer"));
+    }
+
+    #[test]
+    fn test_get_and_set_keys() {
+        let mut config = Config::default();
+        config
+            .add_org("work".to_string(), "token".to_string())
+            .unwrap();
+
+        config.set_key("defaults.issue.priority", "2").unwrap();
+        assert_eq!(config.defaults.issue.priority, Some(2));
+        assert_eq!(
+            config.get_key("defaults.issue.priority").unwrap(),
+            serde_json::json!(2)
+        );
+
+        // Numeric-looking strings are kept for string fields
+        config.set_key("defaults.issue.state", "123").unwrap();
+        assert_eq!(config.defaults.issue.state.as_deref(), Some("123"));
+
+        config.set_key("orgs.work.current_team", "ENG").unwrap();
+        assert_eq!(config.orgs["work"].current_team.as_deref(), Some("ENG"));
+
+        config.unset_key("defaults.issue.priority").unwrap();
+        assert_eq!(config.defaults.issue.priority, None);
+        assert_eq!(
+            config.get_key("defaults.issue.priority").unwrap(),
+            serde_json::Value::Null
+        );
+    }
+
+    #[test]
+    fn test_set_key_type_checks_and_validates() {
+        let mut config = Config::default();
+        config
+            .add_org("work".to_string(), "token".to_string())
+            .unwrap();
+
+        let err = config
+            .set_key("defaults.issue.priority", "high")
+            .unwrap_err();
+        assert!(err.to_string().contains("defaults.issue.priority"));
+
+        let err = config.set_key("defaults.issue.priority", "7").unwrap_err();
+        assert!(err.to_string().contains("out of range"));
+
+        let err = config.set_key("active_org", "missing").unwrap_err();
+        assert!(err.to_string().contains("not configured"));
+
+        let err = config
+            .set_key("orgs.other.current_team", "ENG")
+            .unwrap_err();
+        assert!(err.to_string().contains("'other' not found"));
+
+        let err = config.set_key("version", "3").unwrap_err();
+        assert!(err.to_string().contains("read-only"));
+
+        let err = config.get_key("orgs.work.token").unwrap_err();
+        assert!(err.to_string().contains("Unknown config key"));
+
+        // Failed sets leave the config untouched
+        assert_eq!(config.defaults, Defaults::default());
+        assert_eq!(config.active_org.as_deref(), Some("work"));
+    }
+
+    #[test]
+    fn test_list_keys_expands_orgs() {
+        let mut config = Config::default();
+        config
+            .add_org("work".to_string(), "token".to_string())
+            .unwrap();
+        config
+            .add_org("personal".to_string(), "token".to_string())
+            .unwrap();
+        config.set_key("orgs.personal.current_team", "OPS").unwrap();
+
+        let keys: Vec<String> = config
+            .list_keys()
+            .unwrap()
+            .into_iter()
+            .map(|(k, _)| k)
+            .collect();
+        assert_eq!(
+            keys,
+            vec![
+                "version",
+                "active_org",
+                "secret_backend",
+                "orgs.personal.current_team"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_org_cache_is_stale() {
+        let now = chrono::Utc::now();
+        let mut cache = OrgCache::default();
+        assert!(cache.is_stale(Some(chrono::Duration::hours(24)), now));
+
+        cache.last_sync = Some((now - chrono::Duration::hours(2)).to_rfc3339());
+        assert!(!cache.is_stale(Some(chrono::Duration::hours(24)), now));
+        assert!(cache.is_stale(Some(chrono::Duration::hours(1)), now));
+        assert!(!cache.is_stale(None, now));
+    }
+
+    #[test]
+    fn test_cache_ttl_setting() {
+        let mut config = Config::default();
+        assert_eq!(
+            config.cache.ttl(),
+            Some(chrono::Duration::hours(DEFAULT_CACHE_TTL_HOURS as i64))
+        );
+
+        config.set_key("cache.ttl_hours", "0").unwrap();
+        assert_eq!(config.cache.ttl(), None);
+
+        let err = config.set_key("cache.ttl_hours", "-1").unwrap_err();
+        assert!(err.to_string().contains("cache.ttl_hours"));
+    }
+
+    #[test]
+    fn test_cached_label_and_user_lookup() {
+        let mut config = Config::default();
+        config
+            .add_org("work".to_string(), "token".to_string())
+            .unwrap();
+        config
+            .cache_labels(vec![
+                CachedLabel {
+                    id: "label-1".to_string(),
+                    name: "Bug".to_string(),
+                },
+                CachedLabel {
+                    id: "label-2".to_string(),
+                    name: "backend".to_string(),
+                },
+                CachedLabel {
+                    id: "label-3".to_string(),
+                    name: "Backend".to_string(),
+                },
+            ])
+            .unwrap();
+        config
+            .cache_users(vec![CachedUser {
+                id: "user-1".to_string(),
+                name: "Alice Smith".to_string(),
+                email: "alice@example.com".to_string(),
+                display_name: Some("alice".to_string()),
+            }])
+            .unwrap();
+
+        assert_eq!(config.get_label_ids("BUG"), vec!["label-1"]);
+        assert_eq!(config.get_label_ids("backend").len(), 2);
+        assert!(config.get_label_ids("docs").is_empty());
+
+        assert_eq!(config.get_user_id("Alice").as_deref(), Some("user-1"));
+        assert_eq!(
+            config.get_user_id("ALICE@example.com").as_deref(),
+            Some("user-1")
+        );
+        assert_eq!(config.get_user_id("bob"), None);
+
+        config.clear_cache().unwrap();
+        assert!(config.get_label_ids("bug").is_empty());
+        assert!(config.get_active_org().unwrap().cache.last_sync.is_none());
+    }
+
+    #[test]
+    fn test_get_team_estimates_with_custom_tshirt_scale() {
+        let mut config = Config::default();
+        config
+            .add_org("work".to_string(), "token".to_string())
+            .unwrap();
+        let mut estimates = HashMap::new();
+        estimates.insert("0".to_string(), 0.0);
+        estimates.insert("s".to_string(), 2.0);
+        estimates.insert("m".to_string(), 3.0);
+        config
+            .cache_team(
+                "ENG".to_string(),
+                CachedTeam {
+                    id: "team-1".to_string(),
+                    name: "Engineering".to_string(),
+                    states: HashMap::new(),
+                    estimates,
+                    estimate_type: Some("tShirt".to_string()),
+                    state_types: HashMap::new(),
+                },
+            )
+            .unwrap();
+
+        assert_eq!(
+            config.get_all_estimates_for_team("ENG"),
+            vec!["0", "s", "m"]
+        );
+
+        config
+            .set_key("orgs.work.tshirt_scales.ENG", r#"{"S": 1, "M": 2, "L": 4}"#)
+            .unwrap();
+        assert_eq!(
+            config.get_team_estimates("ENG").unwrap(),
+            vec![
+                ("0".to_string(), 0.0),
+                ("s".to_string(), 1.0),
+                ("m".to_string(), 2.0),
+                ("l".to_string(), 4.0)
+            ]
+        );
+        assert_eq!(config.get_estimate_value("ENG", "L"), Some(4.0));
+        assert!(
+            config
+                .list_keys()
+                .unwrap()
+                .iter()
+                .any(|(key, _)| key == "orgs.work.tshirt_scales.ENG")
+        );
+
+        let err = config
+            .set_key("orgs.work.tshirt_scales.ENG", r#"{"S": -1}"#)
+            .unwrap_err();
+        assert!(err.to_string().contains("invalid size"));
+    }
+
+    #[test]
+    fn test_get_team_estimates_unknown_scale() {
+        let mut config = Config::default();
+        config
+            .add_org("work".to_string(), "token".to_string())
+            .unwrap();
+        config
+            .cache_team(
+                "ENG".to_string(),
+                CachedTeam {
+                    id: "team-1".to_string(),
+                    name: "Engineering".to_string(),
+                    states: HashMap::new(),
+                    estimates: HashMap::new(),
+                    estimate_type: None,
+                    state_types: HashMap::new(),
+                },
+            )
+            .unwrap();
+        assert_eq!(config.get_team_estimates("ENG"), None);
+        assert_eq!(config.get_team_estimates("OPS"), None);
+
+        config
+            .get_active_org_mut()
+            .unwrap()
+            .cache
+            .teams
+            .get_mut("ENG")
+            .unwrap()
+            .estimate_type = Some("notUsed".to_string());
+        assert_eq!(config.get_team_estimates("ENG"), Some(Vec::new()));
+    }
+
+    #[test]
+    fn test_record_recent_issue() {
+        let mut config = Config::default();
+        config
+            .add_org("work".to_string(), "token".to_string())
+            .unwrap();
+
+        config.record_recent_issue("eng-1").unwrap();
+        config.record_recent_issue("ENG-2").unwrap();
+        config.record_recent_issue("ENG-1").unwrap();
+        let recent = &config.get_active_org().unwrap().cache.recent_issues;
+        assert_eq!(recent, &vec!["ENG-1".to_string(), "ENG-2".to_string()]);
+
+        for n in 0..RECENT_ISSUES_LIMIT + 5 {
+            config.record_recent_issue(&format!("ENG-{}", n)).unwrap();
+        }
+        let recent = &config.get_active_org().unwrap().cache.recent_issues;
+        assert_eq!(recent.len(), RECENT_ISSUES_LIMIT);
+        assert_eq!(recent[0], format!("ENG-{}", RECENT_ISSUES_LIMIT + 4));
+    }
 }
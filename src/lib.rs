@@ -16,10 +16,15 @@
 //! - [`output`] - JSON output utilities
 //! - [`config`] - Configuration management
 //! - [`auth`] - Authentication and token resolution
+//! - [`secrets`] - Pluggable token storage backends
+//! - [`repo_config`] - Per-repository `.lin.toml` configuration
+//! - [`alias`] - Command alias expansion
+//! - [`plugin`] - External `lin-<name>` subcommands on `PATH`
 //! - [`commands`] - Command implementations
 //! - [`api`] - GraphQL client and queries for Linear API
 //! - [`models`] - Domain models for Linear entities
 
+pub mod alias;
 pub mod api;
 pub mod auth;
 pub mod commands;
@@ -27,6 +32,9 @@ pub mod config;
 pub mod error;
 pub mod models;
 pub mod output;
+pub mod plugin;
+pub mod repo_config;
+pub mod secrets;
 
 // Re-export commonly used types
 pub use error::LinError;
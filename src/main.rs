@@ -4,16 +4,20 @@
 
 use clap::{CommandFactory, FromArgMatches, Parser, Subcommand};
 use clap_complete::Shell;
+use lin::alias::{self, Expansion};
 use lin::api::GraphQLClient;
 use lin::auth;
 use lin::commands::{
-    attachment, comment, completions, cycle, git, issue, label, milestone, project, relation,
-    resolvers, search, self_update, team, user, workflow,
+    alias as alias_cmd, attachment, cache, comment, completions, config, cycle, git, inbox, issue,
+    label, milestone, plugin, project, relation, report, resolvers, roadmap, search, self_update,
+    shell, standup, team, triage, user, workflow,
 };
 use lin::config::Config;
 use lin::error::LinError;
 use lin::output::{OutputFormat, init_colors, output_error_with_format};
+use std::collections::BTreeMap;
 use std::env;
+use std::ffi::OsString;
 
 /// lin - A command-line interface for Linear
 #[derive(Parser, Debug)]
@@ -71,6 +75,39 @@ enum Commands {
         #[command(subcommand)]
         command: AuthCommands,
     },
+    /// Inspect and change configuration (global config and the repository's .lin.toml)
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommands,
+    },
+    /// Inspect, clear or refresh the cached teams, states, labels, users and projects
+    Cache {
+        #[command(subcommand)]
+        command: CacheCommands,
+    },
+    /// Define shortcuts for lin or shell commands
+    #[command(after_help = "EXAMPLES:\n  \
+    lin alias set mine 'issue list --assignee me --state started'\n  \
+    lin alias set bug 'issue create --labels bug --priority high --title'\n  \
+    lin alias set mv 'issue update $1 --state \"$2\"'\n  \
+    lin alias set web '!open \"https://linear.app/issue/$1\"'\n  \
+    lin alias list")]
+    Alias {
+        #[command(subcommand)]
+        command: AliasCommands,
+    },
+    /// List external lin-<name> plugins found on PATH
+    #[command(
+        after_help = "Any executable named lin-<name> on PATH runs as `lin <name>`, with\n\
+    LINEAR_API_TOKEN, LIN_ORG, LIN_TEAM, LIN_OUTPUT and LIN_BIN set.\n\n\
+    EXAMPLES:\n  \
+    lin plugin list\n  \
+    lin deploy-notes v1.2.0    # runs lin-deploy-notes v1.2.0"
+    )]
+    Plugin {
+        #[command(subcommand)]
+        command: PluginCommands,
+    },
     /// Manage workflow states
     Workflow {
         #[command(subcommand)]
@@ -96,6 +133,93 @@ enum Commands {
         #[command(subcommand)]
         command: MilestoneCommands,
     },
+    /// Show unread notifications (mentions, assignments, comments)
+    #[command(after_help = "EXAMPLES:\n  \
+    lin inbox\n  \
+    lin inbox --include-read --limit 20\n  \
+    lin inbox --watch --interval 60\n  \
+    lin inbox read <notification-id>\n  \
+    lin inbox read --all\n  \
+    lin inbox snooze <notification-id> --until 2024-06-30")]
+    Inbox {
+        #[command(subcommand)]
+        command: Option<InboxCommands>,
+        /// Include notifications that have already been read
+        #[arg(long)]
+        include_read: bool,
+        /// Keep running and print new notifications as they arrive
+        #[arg(long)]
+        watch: bool,
+        /// Seconds between polls in watch mode
+        #[arg(long, default_value = "30", requires = "watch")]
+        interval: u64,
+        /// Maximum number of notifications to show
+        #[arg(long, default_value = "50")]
+        limit: u32,
+    },
+    /// Team-level reports (flow analytics, velocity)
+    #[command(after_help = "EXAMPLES:\n  \
+    lin report flow --team ENG --since 2026-07-01\n  \
+    lin report flow --since 2026-07-01 --until 2026-09-30 --group-by label\n  \
+    lin report velocity --team ENG --cycles 6\n  \
+    lin report velocity --team ENG --csv > velocity.csv")]
+    Report {
+        #[command(subcommand)]
+        command: ReportCommands,
+    },
+    /// Generate a Markdown standup summary
+    #[command(after_help = "EXAMPLES:\n  \
+    lin standup\n  \
+    lin standup --since today\n  \
+    lin standup --user alice@example.com --since 2026-10-16\n  \
+    lin standup --team ENG --since 24h | pbcopy")]
+    Standup {
+        /// Start of the window: yesterday, today, YYYY-MM-DD, or a duration (e.g., 24h, 3d)
+        #[arg(long, default_value = "yesterday")]
+        since: String,
+        /// User to summarize (name, email, UUID, or "me")
+        #[arg(long, conflicts_with = "team")]
+        user: Option<String>,
+        /// Summarize everyone on a team instead of one user (e.g., "ENG")
+        #[arg(long)]
+        team: Option<String>,
+    },
+    /// Show projects and milestones on a timeline
+    #[command(after_help = "EXAMPLES:\n  \
+    lin roadmap\n  \
+    lin roadmap --team ENG --quarter 2026Q4\n  \
+    lin roadmap --team ENG --mermaid > roadmap.mmd")]
+    Roadmap {
+        /// Only show projects for this team (e.g., "ENG"). Uses the current team if not specified
+        #[arg(long)]
+        team: Option<String>,
+        /// Restrict the timeline to a quarter (e.g., "2026Q4")
+        #[arg(long)]
+        quarter: Option<String>,
+        /// Print a Mermaid gantt chart instead of the terminal timeline
+        #[arg(long)]
+        mermaid: bool,
+    },
+    /// Work through the triage queue one issue at a time
+    #[command(after_help = "EXAMPLES:\n  \
+    lin triage\n  \
+    lin triage --team ENG\n  \
+    lin triage --team ENG --accept-all-from support-bot@example.com\n  \
+    lin triage --team ENG --accept-all-from alice --state Todo")]
+    Triage {
+        /// Team key or UUID (e.g., "ENG"). Uses current team if not specified.
+        #[arg(long)]
+        team: Option<String>,
+        /// Accept every triage issue created by this user without prompting
+        #[arg(long)]
+        accept_all_from: Option<String>,
+        /// State to move issues accepted with --accept-all-from to (defaults to Backlog)
+        #[arg(long, requires = "accept_all_from")]
+        state: Option<String>,
+        /// Maximum number of triage issues to fetch
+        #[arg(long, default_value = "50")]
+        limit: u32,
+    },
     /// Search for issues
     #[command(after_help = "EXAMPLES:\n  \
     lin search \"authentication bug\"\n  \
@@ -118,15 +242,34 @@ enum Commands {
         limit: u32,
     },
     /// Generate shell completion scripts
-    #[command(after_help = "EXAMPLES:\n  \
+    #[command(
+        after_help = "Scripts complete team keys, states, projects, labels, users and recently\n\
+    viewed issues from the cache. Use --static for a script that only completes commands.\n\n\
+    EXAMPLES:\n  \
     lin completions bash > ~/.local/share/bash-completion/completions/lin\n  \
     lin completions zsh > ~/.zfunc/_lin\n  \
-    lin completions fish > ~/.config/fish/completions/lin.fish")]
+    lin completions fish > ~/.config/fish/completions/lin.fish"
+    )]
     Completions {
         /// The shell to generate completions for
         #[arg(value_enum)]
         shell: Shell,
+        /// Generate a static script (commands and options only, no cached values)
+        #[arg(long = "static")]
+        static_script: bool,
     },
+    /// Start an interactive shell that keeps the client, cache and context warm
+    #[command(
+        after_help = "Inside the shell, type lin commands without 'lin'. 'use ENG-123' selects an\n\
+    issue for 'get', 'comment <text>' and 'state <name>'; '$_' is the last result.\n\n\
+    EXAMPLES:\n  \
+    lin shell\n  \
+    lin> issue list --assignee me --state todo\n  \
+    lin> use $_\n  \
+    lin> comment \"Taking this one\"\n  \
+    lin> state in progress"
+    )]
+    Shell,
     /// Update lin to the latest version
     #[command(after_help = "EXAMPLES:\n  \
     lin update\n  \
@@ -136,6 +279,9 @@ enum Commands {
         #[arg(long)]
         check: bool,
     },
+    /// Any other command runs the lin-<name> plugin from PATH
+    #[command(external_subcommand)]
+    External(Vec<OsString>),
 }
 
 /// Issue-related subcommands.
@@ -154,7 +300,8 @@ enum IssueCommands {
     lin issue list --updated-before 2024-12-31\n  \
     lin issue list --sort priority --order asc\n  \
     lin issue list --sort updated --order desc\n  \
-    lin issue list --team ENG --assignee me --priority high")]
+    lin issue list --team ENG --assignee me --priority high\n  \
+    lin issue list --subscribed")]
     List {
         /// Filter by team key or UUID (e.g., "ENG")
         #[arg(long)]
@@ -171,7 +318,7 @@ enum IssueCommands {
         /// Filter by cycle ID
         #[arg(long)]
         cycle: Option<String>,
-        /// Filter by label ID
+        /// Filter by label name or ID
         #[arg(long)]
         label: Option<String>,
         /// Filter by milestone name or UUID (requires --project if name)
@@ -180,6 +327,9 @@ enum IssueCommands {
         /// Filter by priority (0-4 or: none, urgent, high, normal, low)
         #[arg(long)]
         priority: Option<String>,
+        /// Only show issues you are subscribed to
+        #[arg(long)]
+        subscribed: bool,
         /// Maximum number of issues to return
         #[arg(long, default_value = "50")]
         limit: u32,
@@ -205,14 +355,60 @@ enum IssueCommands {
     /// Get details of a specific issue
     #[command(after_help = "EXAMPLES:\n  \
     lin issue get ENG-123\n  \
-    lin issue get ENG-123 --with-comments")]
+    lin issue get ENG-123 --with-comments\n  \
+    lin issue get   # issue from the current git branch (see issue_patterns in .lin.toml)")]
     Get {
-        /// Issue identifier (e.g., "ENG-123")
-        identifier: String,
+        /// Issue identifier (e.g., "ENG-123"). Defaults to the issue in the current git branch's name
+        identifier: Option<String>,
         /// Include comments in the output
         #[arg(long)]
         with_comments: bool,
     },
+    /// Show the activity timeline of an issue (changes and comments)
+    #[command(after_help = "EXAMPLES:\n  \
+    lin issue history ENG-123\n  \
+    lin issue history ENG-123 --limit 250\n  \
+    lin --json issue history ENG-123 | jq '.data.events[] | select(.kind == \"change\")'")]
+    History {
+        /// Issue identifier (e.g., "ENG-123") or UUID
+        identifier: String,
+        /// Maximum number of history entries and comments to fetch
+        #[arg(long, default_value = "100")]
+        limit: u32,
+    },
+    /// Find stale and neglected open issues
+    #[command(after_help = "EXAMPLES:\n  \
+    lin issue stale --team ENG\n  \
+    lin issue stale --team ENG --days 60 --in-progress-days 14\n  \
+    lin issue stale --team ENG --reason overdue\n  \
+    lin issue stale --team ENG --reason in-progress --ping\n  \
+    lin issue stale --team ENG --days 90 --reason no-activity --move-to-triage")]
+    Stale {
+        /// Team key (e.g., "ENG"). Uses the current team if not specified
+        #[arg(long)]
+        team: Option<String>,
+        /// Days without updates before an open issue counts as stale
+        #[arg(long, default_value = "30")]
+        days: u32,
+        /// Days without updates before an in-progress issue counts as stale (defaults to --days)
+        #[arg(long)]
+        in_progress_days: Option<u32>,
+        /// Only show one kind of problem (no-activity, in-progress, unassigned, overdue)
+        #[arg(long)]
+        reason: Option<String>,
+        /// Maximum number of issues to fetch per check
+        #[arg(long, default_value = "100")]
+        limit: u32,
+        /// Comment on every flagged issue
+        #[arg(long)]
+        ping: bool,
+        /// Comment text used with --ping
+        #[arg(long, requires = "ping")]
+        message: Option<String>,
+        /// Move every flagged issue to the team's triage state
+        #[arg(long)]
+        move_to_triage: bool,
+    },
     /// Create a new issue
     #[command(after_help = "EXAMPLES:\n  \
     lin issue create --team <team-id> --title \"Fix bug\" --priority 2\n  \
@@ -234,13 +430,13 @@ enum IssueCommands {
         /// State name or UUID (e.g., "Todo")
         #[arg(long)]
         state: Option<String>,
-        /// Priority (0-4: 0=none, 1=urgent, 2=high, 3=normal, 4=low)
-        #[arg(long)]
+        /// Priority (0-4 or: none, urgent, high, normal, low)
+        #[arg(long, value_parser = parse_priority)]
         priority: Option<u8>,
-        /// Estimate (numeric value or team-configured name like "XS", "S", "M", "L", "XL")
+        /// Estimate (a value or name from the team's scale, e.g., "3" or "M")
         #[arg(long)]
         estimate: Option<String>,
-        /// Label IDs to add to the issue (can be specified multiple times)
+        /// Label names or IDs to add to the issue (can be specified multiple times)
         #[arg(long)]
         labels: Option<Vec<String>>,
         /// Project slug or UUID (see 'lin project list')
@@ -271,13 +467,13 @@ enum IssueCommands {
         /// State name or UUID (e.g., "In Progress")
         #[arg(long)]
         state: Option<String>,
-        /// New priority (0-4: 0=none, 1=urgent, 2=high, 3=normal, 4=low)
-        #[arg(long)]
+        /// New priority (0-4 or: none, urgent, high, normal, low)
+        #[arg(long, value_parser = parse_priority)]
         priority: Option<u8>,
-        /// Estimate (numeric value or team-configured name like "XS", "S", "M", "L", "XL")
+        /// Estimate (a value or name from the team's scale, e.g., "3" or "M")
         #[arg(long)]
         estimate: Option<String>,
-        /// Label IDs to set on the issue (replaces existing labels, can be specified multiple times)
+        /// Label names or IDs to set on the issue (replaces existing labels, can be specified multiple times)
         #[arg(long)]
         labels: Option<Vec<String>>,
         /// Project slug or UUID (see 'lin project list')
@@ -287,6 +483,29 @@ enum IssueCommands {
         #[arg(long)]
         milestone: Option<String>,
     },
+    /// Subscribe to an issue's notifications
+    #[command(after_help = "EXAMPLES:\n  \
+    lin issue subscribe ENG-123\n  \
+    lin issue subscribe ENG-123 --user alice\n  \
+    lin issue subscribe ENG-123 --user alice@example.com")]
+    Subscribe {
+        /// Issue identifier (e.g., "ENG-123") or UUID
+        identifier: String,
+        /// User to subscribe (name, email, UUID or "me"). Defaults to you.
+        #[arg(long)]
+        user: Option<String>,
+    },
+    /// Unsubscribe from an issue's notifications
+    #[command(after_help = "EXAMPLES:\n  \
+    lin issue unsubscribe ENG-123\n  \
+    lin issue unsubscribe ENG-123 --user alice")]
+    Unsubscribe {
+        /// Issue identifier (e.g., "ENG-123") or UUID
+        identifier: String,
+        /// User to unsubscribe (name, email, UUID or "me"). Defaults to you.
+        #[arg(long)]
+        user: Option<String>,
+    },
     /// Delete an issue
     #[command(after_help = "EXAMPLES:\n  \
     lin issue delete ENG-123")]
@@ -311,12 +530,13 @@ enum IssueCommands {
     /// Link a git branch to an issue
     #[command(after_help = "EXAMPLES:\n  \
     lin issue link-branch ENG-123 feature/my-feature\n  \
-    lin issue link-branch ENG-123 feature/my-feature --repo https://github.com/org/repo")]
+    lin issue link-branch ENG-123 feature/my-feature --repo https://github.com/org/repo\n  \
+    lin issue link-branch ENG-123   # name from .lin.toml branch_template")]
     LinkBranch {
         /// Issue identifier (e.g., "ENG-123") or UUID
         identifier: String,
-        /// Name of the branch to link
-        branch: String,
+        /// Name of the branch to link (defaults to the branch template applied to the issue)
+        branch: Option<String>,
         /// Repository URL (optional, for constructing the branch URL)
         #[arg(long)]
         repo: Option<String>,
@@ -344,6 +564,25 @@ enum IssueCommands {
         /// Issue identifier (e.g., "ENG-123") or UUID
         identifier: String,
     },
+    /// Export the relation graph around an issue (or a whole project) as DOT or Mermaid
+    #[command(after_help = "EXAMPLES:\n  \
+    lin issue graph ENG-123 | dot -Tsvg > deps.svg\n  \
+    lin issue graph ENG-123 --depth 1 --format mermaid\n  \
+    lin issue graph --project q1-backend --format mermaid")]
+    Graph {
+        /// Issue identifier (e.g., "ENG-123") or UUID to start from
+        #[arg(required_unless_present = "project")]
+        identifier: Option<String>,
+        /// Number of relation hops to follow from the issue
+        #[arg(long, default_value = "3", value_parser = clap::value_parser!(u32).range(1..))]
+        depth: u32,
+        /// Output syntax: dot or mermaid
+        #[arg(long, default_value = "dot")]
+        format: String,
+        /// Render every issue in a project instead (slug or ID)
+        #[arg(long, conflicts_with = "identifier")]
+        project: Option<String>,
+    },
     /// Add a relation between two issues
     #[command(after_help = "EXAMPLES:\n  \
     lin issue add-relation ENG-123 ENG-456 --type blocks\n  \
@@ -441,6 +680,67 @@ enum TeamCommands {
         /// Team key to switch to (if not provided, shows current team)
         team: Option<String>,
     },
+    /// List team members and their roles
+    #[command(after_help = "EXAMPLES:\n  \
+    lin team members ENG\n  \
+    lin --json team members ENG")]
+    Members {
+        /// Team key or UUID
+        team: String,
+    },
+    /// Add a user to a team
+    #[command(after_help = "EXAMPLES:\n  \
+    lin team add-member ENG alice@example.com\n  \
+    lin team add-member ENG \"Alice Smith\" --owner")]
+    AddMember {
+        /// Team key or UUID
+        team: String,
+        /// User name, email, UUID or "me"
+        user: String,
+        /// Make the user a team owner
+        #[arg(long)]
+        owner: bool,
+    },
+    /// Remove a user from a team
+    #[command(after_help = "EXAMPLES:\n  \
+    lin team remove-member ENG alice@example.com")]
+    RemoveMember {
+        /// Team key or UUID
+        team: String,
+        /// User name, email, UUID or "me"
+        user: String,
+    },
+    /// Show or edit team settings
+    #[command(after_help = "EXAMPLES:\n  \
+    lin team settings ENG\n  \
+    lin team settings ENG --cycle-duration 2 --cycle-cooldown 1\n  \
+    lin team settings ENG --estimation fibonacci --triage true\n  \
+    lin team settings ENG --auto-archive 6 --auto-close 0 --default-state Backlog")]
+    Settings {
+        /// Team key or UUID
+        team: String,
+        /// Cycle duration in weeks
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..=8))]
+        cycle_duration: Option<u32>,
+        /// Cooldown between cycles in weeks
+        #[arg(long)]
+        cycle_cooldown: Option<u32>,
+        /// Estimation scale (notUsed, exactly, fibonacci, linear, exponential, tShirt)
+        #[arg(long)]
+        estimation: Option<String>,
+        /// Enable or disable triage (true/false)
+        #[arg(long)]
+        triage: Option<bool>,
+        /// Months after which completed issues are archived (1, 3, 6, 9, 12)
+        #[arg(long)]
+        auto_archive: Option<f64>,
+        /// Months after which stale issues are closed (0 disables; 1, 3, 6, 9, 12)
+        #[arg(long)]
+        auto_close: Option<f64>,
+        /// State name or UUID assigned to new issues
+        #[arg(long)]
+        default_state: Option<String>,
+    },
 }
 
 /// Workflow state-related subcommands.
@@ -456,6 +756,75 @@ enum WorkflowCommands {
         #[arg(long)]
         team: Option<String>,
     },
+    /// Create a workflow state
+    #[command(after_help = "EXAMPLES:\n  \
+    lin workflow create --team ENG --name \"In Review\" --type started\n  \
+    lin workflow create --name Triage --type triage --color \"#fc7840\" --position 0")]
+    Create {
+        /// Team key or UUID (e.g., "ENG"). Uses current team if not specified.
+        #[arg(long)]
+        team: Option<String>,
+        /// State name
+        #[arg(long)]
+        name: String,
+        /// State type (triage, backlog, unstarted, started, completed, canceled)
+        #[arg(long = "type")]
+        state_type: String,
+        /// Hex color code (defaults to a color matching the type)
+        #[arg(long)]
+        color: Option<String>,
+        /// Position within the workflow
+        #[arg(long)]
+        position: Option<f64>,
+        /// State description
+        #[arg(long)]
+        description: Option<String>,
+    },
+    /// Update a workflow state
+    #[command(after_help = "EXAMPLES:\n  \
+    lin workflow update \"In Review\" --team ENG --name \"Code Review\"\n  \
+    lin workflow update <state-id> --color \"#f2c94c\" --position 4")]
+    Update {
+        /// State name or UUID
+        state: String,
+        /// Team key or UUID (e.g., "ENG"). Uses current team if not specified.
+        #[arg(long)]
+        team: Option<String>,
+        /// New state name
+        #[arg(long)]
+        name: Option<String>,
+        /// New hex color code
+        #[arg(long)]
+        color: Option<String>,
+        /// New position within the workflow
+        #[arg(long)]
+        position: Option<f64>,
+        /// New description
+        #[arg(long)]
+        description: Option<String>,
+    },
+    /// Archive a workflow state
+    #[command(after_help = "EXAMPLES:\n  \
+    lin workflow archive Duplicate --team ENG\n  \
+    lin workflow archive <state-id>")]
+    Archive {
+        /// State name or UUID
+        state: String,
+        /// Team key or UUID (e.g., "ENG"). Uses current team if not specified.
+        #[arg(long)]
+        team: Option<String>,
+    },
+    /// Reorder workflow states (listed states swap positions into the given order)
+    #[command(after_help = "EXAMPLES:\n  \
+    lin workflow reorder --team ENG Todo \"In Progress\" \"In Review\" Done")]
+    Reorder {
+        /// Team key or UUID (e.g., "ENG"). Uses current team if not specified.
+        #[arg(long)]
+        team: Option<String>,
+        /// State names or UUIDs in the desired order
+        #[arg(required = true, num_args = 2..)]
+        states: Vec<String>,
+    },
 }
 
 /// Project-related subcommands.
@@ -472,6 +841,14 @@ enum ProjectCommands {
         /// Project ID
         id: String,
     },
+    /// Show the longest blocking chain, top blockers, and circular blocking in a project
+    #[command(after_help = "EXAMPLES:\n  \
+    lin project critical-path q1-backend\n  \
+    lin --json project critical-path q1-backend | jq '.data.critical_path[].identifier'")]
+    CriticalPath {
+        /// Project slug or ID
+        slug: String,
+    },
 }
 
 /// Cycle (sprint) related subcommands.
@@ -501,7 +878,8 @@ enum LabelCommands {
     /// List all labels in the workspace
     #[command(after_help = "EXAMPLES:\n  \
     lin label list\n  \
-    lin label list --team <team-id>")]
+    lin label list --team <team-id>\n  \
+    lin --json label list | jq '.data[] | select(.isGroup)'")]
     List {
         /// Filter by team ID to show only team-specific labels (optional)
         #[arg(long)]
@@ -514,6 +892,68 @@ enum LabelCommands {
         /// Label ID
         id: String,
     },
+    /// Create a new label
+    #[command(after_help = "EXAMPLES:\n  \
+    lin label create --name Bug --color \"#eb5757\"\n  \
+    lin label create --name Area --group\n  \
+    lin label create --name Backend --team ENG --parent Area")]
+    Create {
+        /// Label name
+        #[arg(long)]
+        name: String,
+        /// Hex color code (e.g., "#eb5757")
+        #[arg(long)]
+        color: Option<String>,
+        /// Label description
+        #[arg(long)]
+        description: Option<String>,
+        /// Team key or UUID (creates a workspace label if omitted)
+        #[arg(long)]
+        team: Option<String>,
+        /// Label group name or ID to put the label in
+        #[arg(long, conflicts_with = "group")]
+        parent: Option<String>,
+        /// Create a label group instead of a label
+        #[arg(long)]
+        group: bool,
+    },
+    /// Update a label
+    #[command(after_help = "EXAMPLES:\n  \
+    lin label update Bug --color \"#ff0000\"\n  \
+    lin label update <label-id> --name Defect --parent Type")]
+    Update {
+        /// Label name or ID
+        label: String,
+        /// New label name
+        #[arg(long)]
+        name: Option<String>,
+        /// New hex color code
+        #[arg(long)]
+        color: Option<String>,
+        /// New description
+        #[arg(long)]
+        description: Option<String>,
+        /// Label group name or ID to move the label into
+        #[arg(long)]
+        parent: Option<String>,
+    },
+    /// Delete a label
+    #[command(after_help = "EXAMPLES:\n  \
+    lin label delete Obsolete\n  \
+    lin label delete <label-id>")]
+    Delete {
+        /// Label name or ID
+        label: String,
+    },
+    /// Move every issue from one label to another, then delete the first
+    #[command(after_help = "EXAMPLES:\n  \
+    lin label merge bugfix Bug")]
+    Merge {
+        /// Label to merge away (name or ID)
+        from: String,
+        /// Label to keep (name or ID)
+        into: String,
+    },
 }
 
 /// Project milestone-related subcommands.
@@ -585,6 +1025,79 @@ enum MilestoneCommands {
     },
 }
 
+/// Inbox notification subcommands.
+#[derive(Subcommand, Debug)]
+enum InboxCommands {
+    /// Mark notifications as read
+    #[command(after_help = "EXAMPLES:\n  \
+    lin inbox read <notification-id>\n  \
+    lin inbox read --all")]
+    Read {
+        /// Notification ID (see 'lin inbox')
+        #[arg(required_unless_present = "all", conflicts_with = "all")]
+        id: Option<String>,
+        /// Mark every unread notification as read
+        #[arg(long)]
+        all: bool,
+    },
+    /// Snooze a notification until a later time
+    #[command(after_help = "EXAMPLES:\n  \
+    lin inbox snooze <notification-id> --until 2024-06-30\n  \
+    lin inbox snooze <notification-id> --until 3d\n  \
+    lin inbox snooze <notification-id> --until 2024-06-30T09:00:00Z")]
+    Snooze {
+        /// Notification ID (see 'lin inbox')
+        id: String,
+        /// YYYY-MM-DD, RFC 3339 timestamp, or duration (e.g., 2h, 3d, 1w)
+        #[arg(long)]
+        until: String,
+    },
+}
+
+/// Report subcommands.
+#[derive(Subcommand, Debug)]
+enum ReportCommands {
+    /// Lead time, cycle time and time-in-state for completed issues
+    #[command(after_help = "EXAMPLES:\n  \
+    lin report flow --team ENG --since 2026-07-01\n  \
+    lin report flow --since 2026-07-01 --until 2026-09-30\n  \
+    lin report flow --team ENG --since 2026-07-01 --group-by estimate\n  \
+    lin --json report flow --team ENG --since 2026-07-01")]
+    Flow {
+        /// Team key (e.g., "ENG"). Uses the current team if not specified
+        #[arg(long)]
+        team: Option<String>,
+        /// Include issues completed on or after this date (YYYY-MM-DD)
+        #[arg(long)]
+        since: String,
+        /// Include issues completed on or before this date (YYYY-MM-DD)
+        #[arg(long)]
+        until: Option<String>,
+        /// Group statistics by field (label, estimate)
+        #[arg(long)]
+        group_by: Option<String>,
+        /// Maximum number of completed issues to analyze
+        #[arg(long, default_value = "250")]
+        limit: u32,
+    },
+    /// Completed points, carry-over and scope change per cycle
+    #[command(after_help = "EXAMPLES:\n  \
+    lin report velocity --team ENG\n  \
+    lin report velocity --team ENG --cycles 12\n  \
+    lin report velocity --team ENG --csv > velocity.csv")]
+    Velocity {
+        /// Team key (e.g., "ENG"). Uses the current team if not specified
+        #[arg(long)]
+        team: Option<String>,
+        /// Number of most recent cycles to include
+        #[arg(long, default_value = "6")]
+        cycles: u32,
+        /// Print the report as CSV
+        #[arg(long)]
+        csv: bool,
+    },
+}
+
 /// User-related subcommands.
 #[derive(Subcommand, Debug)]
 enum UserCommands {
@@ -596,6 +1109,41 @@ enum UserCommands {
     #[command(after_help = "EXAMPLES:\n  \
     lin user list")]
     List,
+    /// Get a user by name, email or ID
+    #[command(after_help = "EXAMPLES:\n  \
+    lin user get alice@example.com\n  \
+    lin user get \"Alice Smith\"\n  \
+    lin user get <user-id>")]
+    Get {
+        /// User name, email, UUID or "me"
+        user: String,
+    },
+    /// List issues assigned to a user
+    #[command(after_help = "EXAMPLES:\n  \
+    lin user issues alice\n  \
+    lin user issues alice@example.com --state \"In Progress\"")]
+    Issues {
+        /// User name, email, UUID or "me"
+        user: String,
+        /// Filter by state name
+        #[arg(long)]
+        state: Option<String>,
+        /// Maximum number of issues to return
+        #[arg(long, default_value = "50")]
+        limit: u32,
+    },
+    /// Show open issues, cycle points and overdue items per team member
+    #[command(after_help = "EXAMPLES:\n  \
+    lin user workload --team ENG\n  \
+    lin user workload --team ENG --capacity 13")]
+    Workload {
+        /// Team key or UUID (e.g., "ENG"). Uses current team if not specified.
+        #[arg(long)]
+        team: Option<String>,
+        /// Cycle points per member above which a warning is shown
+        #[arg(long, default_value_t = user::DEFAULT_CAPACITY)]
+        capacity: f64,
+    },
 }
 
 /// Authentication-related subcommands.
@@ -611,6 +1159,20 @@ enum AuthCommands {
         /// Linear API token
         token: String,
     },
+    /// Log in to a Linear organization through the browser (OAuth2)
+    #[command(after_help = "EXAMPLES:\n  \
+    lin auth login work --client-id abc123\n  \
+    LIN_OAUTH_CLIENT_ID=abc123 lin auth login work --port 8123")]
+    Login {
+        /// Organization name (e.g., "work", "personal")
+        name: String,
+        /// OAuth application client ID (defaults to LIN_OAUTH_CLIENT_ID)
+        #[arg(long)]
+        client_id: Option<String>,
+        /// Loopback port for the redirect (0 picks a free port)
+        #[arg(long, default_value_t = 0)]
+        port: u16,
+    },
     /// Switch to a different organization
     #[command(after_help = "EXAMPLES:\n  \
     lin auth switch work")]
@@ -637,12 +1199,166 @@ enum AuthCommands {
     #[command(after_help = "EXAMPLES:\n  \
     lin auth sync")]
     Sync,
+    /// Move stored tokens to a different secret backend
+    #[command(after_help = "EXAMPLES:\n  \
+    lin auth migrate-secrets secret-service\n  \
+    lin auth migrate-secrets helper --helper 'pass-lin'\n  \
+    LIN_SECRETS_PASSPHRASE=... lin auth migrate-secrets encrypted-file\n  \
+    lin auth migrate-secrets plaintext")]
+    MigrateSecrets {
+        /// Target backend: plaintext, secret-service, helper, encrypted-file
+        backend: String,
+        /// Credential-helper command (for the helper backend)
+        #[arg(long)]
+        helper: Option<String>,
+        /// Secrets file location (for the encrypted-file backend)
+        #[arg(long)]
+        path: Option<std::path::PathBuf>,
+    },
+}
+
+/// Configuration subcommands.
+#[derive(Subcommand, Debug)]
+enum ConfigCommands {
+    /// Show the effective configuration
+    #[command(after_help = "EXAMPLES:\n  \
+    lin config show\n  \
+    lin config show --origin")]
+    Show {
+        /// Show where each value came from (.lin.toml, global config, env, default)
+        #[arg(long)]
+        origin: bool,
+    },
+    /// Get a value from the global config file
+    #[command(after_help = "EXAMPLES:\n  \
+    lin config get active_org\n  \
+    lin config get orgs.work.current_team")]
+    Get {
+        /// Dotted key (e.g., defaults.issue.priority)
+        key: String,
+    },
+    /// Set a value in the global config file
+    #[command(after_help = "KEYS:\n  \
+    active_org                 Organization used by default\n  \
+    orgs.<org>.current_team    Default team for an organization\n  \
+    defaults.issue.priority    Priority for new issues (0-4)\n  \
+    defaults.issue.state       State for new issues (e.g., Todo)\n\n\
+    EXAMPLES:\n  \
+    lin config set defaults.issue.priority 3\n  \
+    lin config set orgs.work.current_team ENG")]
+    Set {
+        /// Dotted key (e.g., defaults.issue.priority)
+        key: String,
+        /// New value
+        value: String,
+    },
+    /// Remove a value from the global config file
+    #[command(after_help = "EXAMPLES:\n  \
+    lin config unset defaults.issue.state")]
+    Unset {
+        /// Dotted key (e.g., defaults.issue.state)
+        key: String,
+    },
+    /// List all values set in the global config file
+    #[command(after_help = "EXAMPLES:\n  \
+    lin config list\n  \
+    lin config list --json")]
+    List,
+    /// Open the global config file in $EDITOR and validate it on save
+    #[command(after_help = "EXAMPLES:\n  \
+    lin config edit\n  \
+    EDITOR=nano lin config edit")]
+    Edit,
+}
+
+/// Cache subcommands.
+#[derive(Subcommand, Debug)]
+enum CacheCommands {
+    /// Show what is cached for the active organization and whether it is stale
+    #[command(after_help = "EXAMPLES:\n  \
+    lin cache status\n  \
+    lin config set cache.ttl_hours 4    # refresh every 4 hours (0 = never)")]
+    Status,
+    /// Remove all cached data for the active organization
+    #[command(after_help = "EXAMPLES:\n  \
+    lin cache clear")]
+    Clear,
+    /// Re-sync the cache now
+    #[command(after_help = "EXAMPLES:\n  \
+    lin cache refresh\n  \
+    lin cache refresh --only labels")]
+    Refresh {
+        /// Refresh only one resource
+        #[arg(long, value_parser = clap::builder::PossibleValuesParser::new(cache::CacheResource::NAMES))]
+        only: Option<String>,
+    },
+}
+
+/// Alias subcommands.
+#[derive(Subcommand, Debug)]
+enum AliasCommands {
+    /// Define or replace an alias
+    #[command(after_help = "EXPANSIONS:\n  \
+    $1 ... $9 are replaced by the alias's arguments; the rest are appended.\n  \
+    A leading '!' runs the expansion with sh, arguments as $1, $2, ...\n\n\
+    EXAMPLES:\n  \
+    lin alias set mine 'issue list --assignee me --state started'\n  \
+    lin alias set bug 'issue create --labels bug --priority high --title'\n  \
+    lin bug \"Crash on login\"")]
+    Set {
+        /// Alias name, used as `lin <name>`
+        name: String,
+        /// lin arguments, or a shell command prefixed with '!'
+        expansion: String,
+    },
+    /// Delete an alias
+    #[command(after_help = "EXAMPLES:\n  \
+    lin alias delete mine")]
+    Delete {
+        /// Alias name
+        name: String,
+    },
+    /// List all aliases
+    #[command(after_help = "EXAMPLES:\n  \
+    lin alias list\n  \
+    lin alias list --json")]
+    List,
+}
+
+/// Plugin subcommands.
+#[derive(Subcommand, Debug)]
+enum PluginCommands {
+    /// List plugins found on PATH
+    #[command(after_help = "EXAMPLES:\n  \
+    lin plugin list\n  \
+    lin plugin list --json")]
+    List,
+}
+
+/// Parse a `--priority` value given as a number (0-4) or a name.
+fn parse_priority(value: &str) -> Result<u8, String> {
+    issue::PriorityFilter::parse(value)
+        .map(|priority| priority.to_value() as u8)
+        .ok_or_else(|| "valid values: 0-4 or none, urgent, high, normal, low".to_string())
 }
 
 fn main() {
-    let mut cmd = Cli::command();
+    let aliases = Config::load_global()
+        .map(|config| config.aliases)
+        .unwrap_or_default();
+
+    // Answer dynamic completion requests from the scripts `lin completions` writes
+    clap_complete::CompleteEnv::with_factory(|| {
+        completions::add_dynamic_completers(add_alias_subcommands(Cli::command(), &aliases))
+    })
+    .var(completions::COMPLETE_ENV)
+    .complete();
+
+    let args = expand_aliases(env::args_os().collect(), &aliases);
+
+    let mut cmd = add_alias_subcommands(Cli::command(), &aliases);
     cmd = enrich_help_with_cached_data(cmd);
-    let matches = cmd.get_matches();
+    let matches = cmd.get_matches_from(args);
     let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
 
     let format = OutputFormat::from_json_flag(cli.json);
@@ -655,6 +1371,63 @@ fn main() {
     }
 }
 
+/// Names of the built-in top-level commands, which aliases can't shadow.
+fn builtin_commands(cmd: &clap::Command) -> Vec<&str> {
+    cmd.get_subcommands()
+        .map(|sub| sub.get_name())
+        .chain(["help"])
+        .collect()
+}
+
+/// Expand an alias in the command line before clap sees it.
+///
+/// Shell aliases are run here and lin exits with their status.
+fn expand_aliases(args: Vec<OsString>, aliases: &BTreeMap<String, String>) -> Vec<OsString> {
+    if aliases.is_empty() {
+        return args;
+    }
+    let cmd = Cli::command();
+    let format = OutputFormat::from_json_flag(args.iter().any(|arg| arg == "--json"));
+    match alias::expand(&args, aliases, &builtin_commands(&cmd)) {
+        Ok(Some(Expansion::Args(expanded))) => expanded,
+        Ok(Some(Expansion::Shell {
+            name,
+            command,
+            args,
+        })) => match alias::run_shell(&name, &command, &args) {
+            Ok(code) => std::process::exit(code),
+            Err(err) => output_error_with_format(&err, format),
+        },
+        Ok(None) => args,
+        Err(err) => output_error_with_format(&err, format),
+    }
+}
+
+/// Add each alias as a subcommand so it shows up in help and completions.
+///
+/// Aliases are expanded before parsing, so these are never matched.
+fn add_alias_subcommands(
+    mut cmd: clap::Command,
+    aliases: &BTreeMap<String, String>,
+) -> clap::Command {
+    for (name, expansion) in aliases {
+        if cmd.find_subcommand(name).is_some() {
+            continue;
+        }
+        cmd = cmd.subcommand(
+            clap::Command::new(name.clone())
+                .about(format!("Alias for '{}'", expansion))
+                .arg(
+                    clap::Arg::new("args")
+                        .num_args(0..)
+                        .trailing_var_arg(true)
+                        .allow_hyphen_values(true),
+                ),
+        );
+    }
+    cmd
+}
+
 /// Convert a lowercase string to title case (capitalize first letter of each word).
 fn title_case(s: &str) -> String {
     s.split_whitespace()
@@ -707,27 +1480,17 @@ fn enrich_help_with_cached_data(cmd: clap::Command) -> clap::Command {
         String::new()
     };
 
-    // Build estimate choices string
-    let estimate_suffix = if !cached_team.estimates.is_empty() {
-        let mut estimates: Vec<(String, f64)> = cached_team
-            .estimates
-            .iter()
-            .map(|(k, v)| (k.to_uppercase(), *v))
-            .collect();
-        estimates.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
-        let choices: Vec<String> = estimates
-            .iter()
-            .map(|(name, val)| {
-                if *val == val.floor() {
-                    format!("{} ({})", name, *val as i64)
-                } else {
-                    format!("{} ({})", name, val)
-                }
-            })
-            .collect();
-        format!(" [{}: {}]", current_team, choices.join(", "))
-    } else {
-        String::new()
+    // Build estimate choices string from the team's real scale
+    let estimate_suffix = match config.get_team_estimates(&current_team) {
+        Some(estimates) if estimates.is_empty() => {
+            format!(" [{}: estimates not used]", current_team)
+        }
+        Some(estimates) => format!(
+            " [{}: {}]",
+            current_team,
+            resolvers::format_estimates(&estimates)
+        ),
+        None => String::new(),
     };
 
     // Build help strings for different contexts
@@ -763,12 +1526,70 @@ fn run(cli: Cli, format: OutputFormat) -> lin::Result<()> {
     match &cli.command {
         // Auth commands don't require being authenticated yet
         Commands::Auth { command } => handle_auth_command(command, format),
+        // Config commands don't require an API token
+        Commands::Config { command } => match command {
+            ConfigCommands::Show { origin } => config::config_show(*origin, format),
+            ConfigCommands::Get { key } => config::config_get(key, format),
+            ConfigCommands::Set { key, value } => config::config_set(key, value, format),
+            ConfigCommands::Unset { key } => config::config_unset(key, format),
+            ConfigCommands::List => config::config_list(format),
+            ConfigCommands::Edit => config::config_edit(format),
+        },
+        // Cache commands always manage the active organization's cache
+        Commands::Cache { command } => match command {
+            CacheCommands::Status => cache::cache_status(format),
+            CacheCommands::Clear => cache::cache_clear(format),
+            CacheCommands::Refresh { only } => {
+                let only = only
+                    .as_deref()
+                    .map(cache::CacheResource::from_name)
+                    .transpose()?;
+                let client = auth::client_for_org(&Config::load()?, None)?;
+                cache::cache_refresh(&client, only, format)
+            }
+        },
+        // Aliases are stored in the global config
+        Commands::Alias { command } => match command {
+            AliasCommands::Set { name, expansion } => {
+                let cmd = Cli::command();
+                alias_cmd::alias_set(name, expansion, &builtin_commands(&cmd), format)
+            }
+            AliasCommands::Delete { name } => alias_cmd::alias_delete(name, format),
+            AliasCommands::List => alias_cmd::alias_list(format),
+        },
+        // Plugins resolve their own context and may not need a token
+        Commands::Plugin { command } => match command {
+            PluginCommands::List => plugin::plugin_list(format),
+        },
+        Commands::External(args) => {
+            let (name, args) = args
+                .split_first()
+                .ok_or_else(|| LinError::config("Missing command"))?;
+            let builtins = Cli::command();
+            let code = plugin::run_plugin(
+                &name.to_string_lossy(),
+                args,
+                &builtin_commands(&builtins),
+                cli.org.as_deref(),
+                format,
+            )?;
+            std::process::exit(code)
+        }
         // Completions command doesn't require an API token
-        Commands::Completions { shell } => {
-            let mut cmd = Cli::command();
+        Commands::Completions {
+            shell,
+            static_script,
+        } => {
+            if !*static_script {
+                return completions::generate_dynamic_completions(*shell, &mut std::io::stdout());
+            }
+            let aliases = Config::load_global()?.aliases;
+            let mut cmd = add_alias_subcommands(Cli::command(), &aliases);
             completions::generate_completions(*shell, &mut cmd);
             Ok(())
         }
+        // The shell creates its client once and reuses it for every command
+        Commands::Shell => run_shell(format),
         // Update command doesn't require an API token
         Commands::Update { check } => {
             if *check {
@@ -780,39 +1601,184 @@ fn run(cli: Cli, format: OutputFormat) -> lin::Result<()> {
         // All other commands require an API token
         _ => {
             let (client, use_cache) = get_client_and_mode()?;
+            run_with_client(cli.command, &client, use_cache, format)
+        }
+    }
+}
 
-            match cli.command {
-                Commands::Issue { command } => {
-                    handle_issue_command(*command, client, use_cache, format)
-                }
-                Commands::Comment { command } => handle_comment_command(command, client, format),
-                Commands::Attachment { command } => {
-                    handle_attachment_command(command, client, format)
-                }
-                Commands::Team { command } => handle_team_command(command, client, format),
-                Commands::User { command } => handle_user_command(command, client, format),
-                Commands::Workflow { command } => {
-                    handle_workflow_command(command, client, use_cache, format)
-                }
-                Commands::Project { command } => handle_project_command(command, client, format),
-                Commands::Cycle { command } => handle_cycle_command(command, client, format),
-                Commands::Label { command } => handle_label_command(command, client, format),
-                Commands::Milestone { command } => {
-                    handle_milestone_command(command, client, format)
-                }
-                Commands::Search {
-                    query,
-                    team,
-                    assignee,
-                    state,
-                    limit,
-                } => handle_search_command(client, &query, team, assignee, state, limit, format),
-                Commands::Auth { .. } | Commands::Completions { .. } | Commands::Update { .. } => {
-                    unreachable!()
+/// Run a command that talks to the API with an existing client.
+fn run_with_client(
+    command: Commands,
+    client: &GraphQLClient,
+    use_cache: bool,
+    format: OutputFormat,
+) -> lin::Result<()> {
+    match command {
+        Commands::Issue { command } => handle_issue_command(*command, client, use_cache, format),
+        Commands::Comment { command } => handle_comment_command(command, client, format),
+        Commands::Attachment { command } => handle_attachment_command(command, client, format),
+        Commands::Team { command } => handle_team_command(command, client, use_cache, format),
+        Commands::User { command } => handle_user_command(command, client, use_cache, format),
+        Commands::Workflow { command } => {
+            handle_workflow_command(command, client, use_cache, format)
+        }
+        Commands::Project { command } => handle_project_command(command, client, format),
+        Commands::Cycle { command } => handle_cycle_command(command, client, format),
+        Commands::Label { command } => handle_label_command(command, client, use_cache, format),
+        Commands::Milestone { command } => handle_milestone_command(command, client, format),
+        Commands::Inbox {
+            command,
+            include_read,
+            watch,
+            interval,
+            limit,
+        } => handle_inbox_command(
+            command,
+            client,
+            include_read,
+            watch,
+            interval,
+            limit,
+            format,
+        ),
+        Commands::Report { command } => handle_report_command(command, client, use_cache, format),
+        Commands::Standup { since, user, team } => {
+            let scope = match team {
+                Some(team) => standup::StandupScope::Team(team),
+                None => standup::StandupScope::User(user.unwrap_or_else(|| "me".to_string())),
+            };
+            standup::standup(client, scope, &since, format)
+        }
+        Commands::Roadmap {
+            team,
+            quarter,
+            mermaid,
+        } => {
+            let options = roadmap::RoadmapOptions {
+                team,
+                quarter,
+                mermaid,
+            };
+            roadmap::roadmap(client, options, format)
+        }
+        Commands::Triage {
+            team,
+            accept_all_from,
+            state,
+            limit,
+        } => {
+            let options = triage::TriageOptions {
+                team,
+                accept_all_from,
+                state,
+                limit,
+            };
+            triage::triage(client, options, use_cache, format)
+        }
+        Commands::Search {
+            query,
+            team,
+            assignee,
+            state,
+            limit,
+        } => handle_search_command(client, &query, team, assignee, state, limit, format),
+        Commands::Auth { .. }
+        | Commands::Config { .. }
+        | Commands::Cache { .. }
+        | Commands::Alias { .. }
+        | Commands::Plugin { .. }
+        | Commands::External(_)
+        | Commands::Completions { .. }
+        | Commands::Shell
+        | Commands::Update { .. } => {
+            unreachable!()
+        }
+    }
+}
+
+/// Whether a command talks to the API through [`run_with_client`].
+fn uses_client(command: &Commands) -> bool {
+    !matches!(
+        command,
+        Commands::Auth { .. }
+            | Commands::Config { .. }
+            | Commands::Cache { .. }
+            | Commands::Alias { .. }
+            | Commands::Plugin { .. }
+            | Commands::External(_)
+            | Commands::Completions { .. }
+            | Commands::Shell
+            | Commands::Update { .. }
+    )
+}
+
+/// Run `lin shell`, dispatching each line like a `lin` invocation.
+///
+/// The client is created (and a stale cache refreshed) once, and recreated
+/// only when `use org` switches organizations. The cache belongs to the
+/// active organization, so names in another one are resolved through the API.
+fn run_shell(format: OutputFormat) -> lin::Result<()> {
+    let config = Config::load()?;
+    let aliases = config.aliases.clone();
+    let session = shell::Session::from_config(&config);
+    let (mut client, use_cache) = get_client_and_mode()?;
+    let active_org = session.org.clone();
+    let mut client_org = session.org.clone();
+
+    let cmd = completions::add_dynamic_completers(add_alias_subcommands(Cli::command(), &aliases));
+    shell::shell(cmd, session, format, |args, session| {
+        let mut args: Vec<OsString> = std::iter::once(OsString::from("lin"))
+            .chain(args.into_iter().map(OsString::from))
+            .collect();
+        let builtins = Cli::command();
+        match alias::expand(&args, &aliases, &builtin_commands(&builtins))? {
+            Some(Expansion::Args(expanded)) => args = expanded,
+            Some(Expansion::Shell {
+                name,
+                command,
+                args,
+            }) => {
+                alias::run_shell(&name, &command, &args)?;
+                return Ok(());
+            }
+            None => {}
+        }
+
+        let cli = match Cli::try_parse_from(args) {
+            Ok(cli) => cli,
+            // Usage errors, --help and --version print like they would outside the shell
+            Err(err) => {
+                let _ = err.print();
+                return Ok(());
+            }
+        };
+        let format = OutputFormat::from_json_flag(cli.json || format == OutputFormat::Json);
+        match cli.command {
+            Commands::Shell => Err(LinError::config("Already in lin shell")),
+            Commands::External(args) => {
+                let (name, args) = args
+                    .split_first()
+                    .ok_or_else(|| LinError::config("Missing command"))?;
+                plugin::run_plugin(
+                    &name.to_string_lossy(),
+                    args,
+                    &builtin_commands(&builtins),
+                    cli.org.as_deref().or(session.org.as_deref()),
+                    format,
+                )?;
+                Ok(())
+            }
+            ref command if !uses_client(command) => run(cli, format),
+            command => {
+                if use_cache && session.org != client_org {
+                    client = auth::client_for_org(&Config::load()?, session.org.as_deref())?;
+                    client_org = session.org.clone();
                 }
+                let use_cache = use_cache && session.org == active_org;
+                run_with_client(command, &client, use_cache, format)
             }
         }
-    }
+    })
 }
 
 /// Get a GraphQL client and determine whether to use cache.
@@ -821,6 +1787,8 @@ fn run(cli: Cli, format: OutputFormat) -> lin::Result<()> {
 /// - client: GraphQL client initialized with the API token
 /// - use_cache: true if using config-based auth (cache available), false if using env var
 ///
+/// With config-based auth, a cache older than `cache.ttl_hours` is refreshed first.
+///
 /// # Errors
 ///
 /// Returns an error if no authentication is available.
@@ -833,9 +1801,13 @@ fn get_client_and_mode() -> lin::Result<(GraphQLClient, bool)> {
     }
 
     // Try to load config and get active org token
-    let config = Config::load()?;
-    match config.get_token(None) {
-        Ok(token) => Ok((GraphQLClient::new(&token), true)),
+    let mut config = Config::load()?;
+    match auth::client_for_org(&config, None) {
+        Ok(client) => {
+            // Refresh the cache lazily once it outlives the TTL
+            cache::refresh_if_stale(&client, &mut config);
+            Ok((client, true))
+        }
         Err(_) => Err(LinError::config(
             "Not authenticated. Run: lin auth add <name> <token>".to_string(),
         )),
@@ -847,17 +1819,31 @@ fn handle_auth_command(command: &AuthCommands, format: OutputFormat) -> lin::Res
         AuthCommands::Add { name, token } => {
             lin::commands::auth::auth_add(name.clone(), token.clone(), format)
         }
+        AuthCommands::Login {
+            name,
+            client_id,
+            port,
+        } => lin::commands::auth::auth_login(name.clone(), client_id.clone(), *port, format),
         AuthCommands::Switch { name } => lin::commands::auth::auth_switch(name.clone(), format),
         AuthCommands::List => lin::commands::auth::auth_list(format),
         AuthCommands::Remove { name } => lin::commands::auth::auth_remove(name.clone(), format),
         AuthCommands::Status => lin::commands::auth::auth_status(format),
         AuthCommands::Sync => lin::commands::auth::auth_sync(format),
+        AuthCommands::MigrateSecrets {
+            backend,
+            helper,
+            path,
+        } => {
+            let backend =
+                lin::secrets::SecretBackend::from_name(backend, helper.clone(), path.clone())?;
+            lin::commands::auth::auth_migrate_secrets(backend, format)
+        }
     }
 }
 
 fn handle_issue_command(
     command: IssueCommands,
-    client: GraphQLClient,
+    client: &GraphQLClient,
     use_cache: bool,
     format: OutputFormat,
 ) -> lin::Result<()> {
@@ -871,6 +1857,7 @@ fn handle_issue_command(
             label,
             milestone,
             priority,
+            subscribed,
             limit,
             created_after,
             created_before,
@@ -887,8 +1874,8 @@ fn handle_issue_command(
                 let config = Config::load()?;
                 config.get_current_team()
             };
-            // If assignee is "me", we need to fetch the viewer ID first
-            let viewer_id = if assignee.as_deref() == Some("me") {
+            // If assignee is "me" or filtering by subscription, we need the viewer ID first
+            let viewer_id = if assignee.as_deref() == Some("me") || subscribed {
                 let response: lin::models::ViewerResponse = client.query(
                     lin::api::queries::organization::VIEWER_QUERY,
                     serde_json::json!({}),
@@ -937,6 +1924,10 @@ fn handle_issue_command(
                 None
             };
 
+            let label = label
+                .map(|label| resolvers::resolve_label_id(client, &label, use_cache))
+                .transpose()?;
+
             let options = issue::IssueListOptions {
                 team: resolved_team,
                 assignee,
@@ -946,6 +1937,7 @@ fn handle_issue_command(
                 label,
                 milestone,
                 priority: priority_filter,
+                subscribed,
                 limit: Some(limit as i32),
                 created_after,
                 created_before,
@@ -954,12 +1946,64 @@ fn handle_issue_command(
                 sort_by,
                 sort_order,
             };
-            issue::list::list_issues(&client, viewer_id.as_deref(), options, format)
+            issue::list::list_issues(client, viewer_id.as_deref(), options, format)
         }
         IssueCommands::Get {
             identifier,
             with_comments,
-        } => issue::get::get_issue_with_comments(&client, &identifier, with_comments, format),
+        } => {
+            let identifier = match identifier {
+                Some(identifier) => identifier,
+                None => git::issue_from_current_branch()?,
+            };
+            issue::get::get_issue_with_comments(client, &identifier, with_comments, format)?;
+            // Remember the issue for completing identifiers
+            if use_cache
+                && !issue::is_uuid(&identifier)
+                && let Ok(mut config) = Config::load()
+                && config.record_recent_issue(&identifier).is_ok()
+            {
+                let _ = config.save();
+            }
+            Ok(())
+        }
+        IssueCommands::History { identifier, limit } => {
+            issue::history::issue_history(client, &identifier, limit, format)
+        }
+        IssueCommands::Stale {
+            team,
+            days,
+            in_progress_days,
+            reason,
+            limit,
+            ping,
+            message,
+            move_to_triage,
+        } => {
+            let reason = if let Some(reason_str) = &reason {
+                let parsed = issue::stale::StaleReason::parse(reason_str).ok_or_else(|| {
+                    lin::error::LinError::config(format!(
+                        "Invalid reason '{}'. Valid reasons: no-activity, in-progress, unassigned, overdue",
+                        reason_str
+                    ))
+                })?;
+                Some(parsed)
+            } else {
+                None
+            };
+            let options = issue::stale::StaleIssueOptions {
+                team,
+                days,
+                in_progress_days: in_progress_days.unwrap_or(days),
+                reason,
+                limit,
+                ping: ping.then(|| {
+                    message.unwrap_or_else(|| issue::stale::DEFAULT_PING_MESSAGE.to_string())
+                }),
+                move_to_triage,
+            };
+            issue::stale::stale_issues(client, options, use_cache, format)
+        }
         IssueCommands::Create {
             title,
             team,
@@ -972,21 +2016,42 @@ fn handle_issue_command(
             project,
             milestone,
         } => {
+            // Fall back to configured defaults for state and priority, and to
+            // the repository's .lin.toml for project and labels
+            let config = Config::load()?;
+            let state = state.or(config.defaults.issue.state);
+            let priority = priority.or(config.defaults.issue.priority);
+            let repo_config = config.repo.map(|repo| repo.config);
+            let project = project.or_else(|| repo_config.as_ref()?.project.clone());
+            let labels = labels
+                .or_else(|| {
+                    repo_config
+                        .map(|repo| repo.labels)
+                        .filter(|l| !l.is_empty())
+                })
+                .map(|labels| {
+                    labels
+                        .iter()
+                        .map(|name| resolvers::resolve_label_id(client, name, use_cache))
+                        .collect::<lin::Result<Vec<_>>>()
+                })
+                .transpose()?;
+
             // Resolve team key to team ID (using current team if not specified)
-            let team_id = resolvers::resolve_team_or_current(&client, team.as_deref(), use_cache)?;
+            let team_id = resolvers::resolve_team_or_current(client, team.as_deref(), use_cache)?;
 
             // Get the actual team key for state/estimate resolution
             let team_key = if let Some(ref t) = team {
                 t.clone()
             } else {
                 // We need the team key for state/estimate resolution
-                resolvers::get_team_key(&client, &team_id)?
+                resolvers::get_team_key(client, &team_id)?
             };
 
             // Resolve state name to state ID if provided
             let state_id = if let Some(state_name) = state {
                 Some(resolvers::resolve_state_id(
-                    &client,
+                    client,
                     &team_key,
                     &state_name,
                     use_cache,
@@ -1026,7 +2091,7 @@ fn handle_issue_command(
                         .unwrap_or_else(|| project_slug_or_id.clone());
 
                     // Resolve milestone name to ID
-                    Some(resolvers::resolve_milestone_id(&client, &ms, &project_id)?)
+                    Some(resolvers::resolve_milestone_id(client, &ms, &project_id)?)
                 }
             } else {
                 None
@@ -1044,7 +2109,7 @@ fn handle_issue_command(
                 project_id: project,
                 project_milestone_id: milestone_id,
             };
-            issue::create::create_issue(&client, options, format)
+            issue::create::create_issue(client, options, format)
         }
         IssueCommands::Update {
             identifier,
@@ -1058,6 +2123,15 @@ fn handle_issue_command(
             project,
             milestone,
         } => {
+            let labels = labels
+                .map(|labels| {
+                    labels
+                        .iter()
+                        .map(|name| resolvers::resolve_label_id(client, name, use_cache))
+                        .collect::<lin::Result<Vec<_>>>()
+                })
+                .transpose()?;
+
             // We may need team context for state or estimate resolution
             let team_key_opt = if state.is_some() || estimate.is_some() {
                 // Resolve identifier to UUID if needed to get team context
@@ -1083,8 +2157,8 @@ fn handle_issue_command(
                 };
 
                 // Get team context
-                let team_id = resolvers::get_issue_team_id(&client, &issue_id)?;
-                Some(resolvers::get_team_key(&client, &team_id)?)
+                let team_id = resolvers::get_issue_team_id(client, &issue_id)?;
+                Some(resolvers::get_team_key(client, &team_id)?)
             } else {
                 None
             };
@@ -1096,7 +2170,7 @@ fn handle_issue_command(
                 } else {
                     let team_key = team_key_opt.as_ref().unwrap();
                     Some(resolvers::resolve_state_id(
-                        &client,
+                        client,
                         team_key,
                         &state_name,
                         use_cache,
@@ -1139,7 +2213,7 @@ fn handle_issue_command(
                         .unwrap_or_else(|| project_slug_or_id.clone());
 
                     // Resolve milestone name to ID
-                    let milestone_id = resolvers::resolve_milestone_id(&client, &ms, &project_id)?;
+                    let milestone_id = resolvers::resolve_milestone_id(client, &ms, &project_id)?;
                     Some(issue::MilestoneAction::Set(milestone_id))
                 }
             } else {
@@ -1157,28 +2231,69 @@ fn handle_issue_command(
                 project_id: project,
                 project_milestone_id: milestone_action,
             };
-            issue::update::update_issue(&client, &identifier, options, format)
+            issue::update::update_issue(client, &identifier, options, format)
         }
+        IssueCommands::Subscribe { identifier, user } => issue::subscribe::subscribe_issue(
+            client,
+            &identifier,
+            user.as_deref(),
+            use_cache,
+            format,
+        ),
+        IssueCommands::Unsubscribe { identifier, user } => issue::subscribe::unsubscribe_issue(
+            client,
+            &identifier,
+            user.as_deref(),
+            use_cache,
+            format,
+        ),
         IssueCommands::Delete { identifier } => {
-            issue::delete::delete_issue(&client, &identifier, format)
+            issue::delete::delete_issue(client, &identifier, format)
         }
         IssueCommands::Archive { identifier } => {
-            issue::delete::archive_issue(&client, &identifier, format)
+            issue::delete::archive_issue(client, &identifier, format)
         }
         IssueCommands::Unarchive { identifier } => {
-            issue::delete::unarchive_issue(&client, &identifier, format)
+            issue::delete::unarchive_issue(client, &identifier, format)
         }
         IssueCommands::LinkBranch {
             identifier,
             branch,
             repo,
-        } => git::link_branch(&client, &identifier, &branch, repo.as_deref(), format),
+        } => {
+            let branch = match branch {
+                Some(branch) => branch,
+                None => git::default_branch_name(client, &identifier)?,
+            };
+            git::link_branch(client, &identifier, &branch, repo.as_deref(), format)
+        }
         IssueCommands::LinkPr { identifier, url } => {
-            git::link_pr(&client, &identifier, &url, format)
+            git::link_pr(client, &identifier, &url, format)
         }
-        IssueCommands::Links { identifier } => git::list_links(&client, &identifier, format),
+        IssueCommands::Links { identifier } => git::list_links(client, &identifier, format),
         IssueCommands::Relations { identifier } => {
-            relation::list_relations(&client, &identifier, format)
+            relation::list_relations(client, &identifier, format)
+        }
+        IssueCommands::Graph {
+            identifier,
+            depth,
+            format: graph_format,
+            project,
+        } => {
+            let graph_format =
+                issue::graph::GraphFormat::parse(&graph_format).ok_or_else(|| {
+                    lin::error::LinError::config(format!(
+                        "Invalid graph format '{}'. Valid formats: dot, mermaid",
+                        graph_format
+                    ))
+                })?;
+            let options = issue::graph::IssueGraphOptions {
+                identifier,
+                project,
+                depth: depth as usize,
+                graph_format,
+            };
+            issue::graph::issue_graph(client, options, format)
         }
         IssueCommands::AddRelation {
             issue,
@@ -1191,128 +2306,243 @@ fn handle_issue_command(
                     relation_type
                 ))
             })?;
-            relation::add_relation(&client, &issue, &related_issue, rel_type, format)
+            relation::add_relation(client, &issue, &related_issue, rel_type, format)
         }
         IssueCommands::RemoveRelation { relation_id } => {
-            relation::remove_relation(&client, &relation_id, format)
+            relation::remove_relation(client, &relation_id, format)
         }
     }
 }
 
 fn handle_comment_command(
     command: CommentCommands,
-    client: GraphQLClient,
+    client: &GraphQLClient,
     format: OutputFormat,
 ) -> lin::Result<()> {
     match command {
-        CommentCommands::List { issue } => comment::list_comments(&client, &issue, format),
+        CommentCommands::List { issue } => comment::list_comments(client, &issue, format),
         CommentCommands::Add { issue, body } => {
-            comment::create_comment(&client, &issue, &body, format)
+            comment::create_comment(client, &issue, &body, format)
         }
     }
 }
 
 fn handle_attachment_command(
     command: AttachmentCommands,
-    client: GraphQLClient,
+    client: &GraphQLClient,
     format: OutputFormat,
 ) -> lin::Result<()> {
     match command {
-        AttachmentCommands::List { issue } => attachment::list_attachments(&client, &issue, format),
+        AttachmentCommands::List { issue } => attachment::list_attachments(client, &issue, format),
         AttachmentCommands::Upload { issue, file_path } => {
-            attachment::upload_attachment(&client, &issue, &file_path, format)
+            attachment::upload_attachment(client, &issue, &file_path, format)
         }
-        AttachmentCommands::Get { id } => attachment::get_attachment(&client, &id, format),
+        AttachmentCommands::Get { id } => attachment::get_attachment(client, &id, format),
     }
 }
 
 fn handle_team_command(
     command: TeamCommands,
-    client: GraphQLClient,
+    client: &GraphQLClient,
+    use_cache: bool,
     format: OutputFormat,
 ) -> lin::Result<()> {
     match command {
-        TeamCommands::List => team::list_teams(&client, format),
-        TeamCommands::Get { identifier } => team::get_team(&client, &identifier, format),
+        TeamCommands::List => team::list_teams(client, format),
+        TeamCommands::Get { identifier } => team::get_team(client, &identifier, format),
         TeamCommands::Switch { team } => team::switch_team(team, format),
+        TeamCommands::Members { team } => team::list_team_members(client, &team, format),
+        TeamCommands::AddMember { team, user, owner } => {
+            team::add_team_member(client, &team, &user, owner, use_cache, format)
+        }
+        TeamCommands::RemoveMember { team, user } => {
+            team::remove_team_member(client, &team, &user, use_cache)
+        }
+        TeamCommands::Settings {
+            team,
+            cycle_duration,
+            cycle_cooldown,
+            estimation,
+            triage,
+            auto_archive,
+            auto_close,
+            default_state,
+        } => {
+            let options = team::TeamSettingsOptions {
+                cycle_duration,
+                cycle_cooldown,
+                estimation,
+                triage,
+                auto_archive,
+                auto_close,
+                default_state,
+            };
+            team::team_settings(client, &team, options, use_cache, format)
+        }
     }
 }
 
 fn handle_user_command(
     command: UserCommands,
-    client: GraphQLClient,
+    client: &GraphQLClient,
+    use_cache: bool,
     format: OutputFormat,
 ) -> lin::Result<()> {
     match command {
-        UserCommands::Me => user::me(&client, format),
-        UserCommands::List => user::list_users(&client, format),
+        UserCommands::Me => user::me(client, format),
+        UserCommands::List => user::list_users(client, format),
+        UserCommands::Get { user } => user::get_user(client, &user, format),
+        UserCommands::Issues { user, state, limit } => {
+            user::user_issues(client, &user, state, limit, use_cache, format)
+        }
+        UserCommands::Workload { team, capacity } => {
+            user::user_workload(client, team.as_deref(), capacity, format)
+        }
     }
 }
 
 fn handle_workflow_command(
     command: WorkflowCommands,
-    client: GraphQLClient,
+    client: &GraphQLClient,
     use_cache: bool,
     format: OutputFormat,
 ) -> lin::Result<()> {
     match command {
         WorkflowCommands::List { team } => {
             // Resolve team key to team ID (using current team if not specified)
-            let team_id = resolvers::resolve_team_or_current(&client, team.as_deref(), use_cache)?;
-            workflow::list_workflow_states(&client, &team_id, format)
+            let team_id = resolvers::resolve_team_or_current(client, team.as_deref(), use_cache)?;
+            workflow::list_workflow_states(client, &team_id, format)
+        }
+        WorkflowCommands::Create {
+            team,
+            name,
+            state_type,
+            color,
+            position,
+            description,
+        } => {
+            let options = workflow::WorkflowStateCreateOptions {
+                team,
+                name,
+                state_type,
+                color,
+                position,
+                description,
+            };
+            workflow::create_workflow_state(client, options, use_cache, format)
+        }
+        WorkflowCommands::Update {
+            state,
+            team,
+            name,
+            color,
+            position,
+            description,
+        } => {
+            let options = workflow::WorkflowStateUpdateOptions {
+                team,
+                name,
+                color,
+                position,
+                description,
+            };
+            workflow::update_workflow_state(client, &state, options, use_cache, format)
+        }
+        WorkflowCommands::Archive { state, team } => {
+            workflow::archive_workflow_state(client, team.as_deref(), &state, use_cache)
+        }
+        WorkflowCommands::Reorder { team, states } => {
+            workflow::reorder_workflow_states(client, team.as_deref(), &states, use_cache, format)
         }
     }
 }
 
 fn handle_project_command(
     command: ProjectCommands,
-    client: GraphQLClient,
+    client: &GraphQLClient,
     format: OutputFormat,
 ) -> lin::Result<()> {
     match command {
         ProjectCommands::List => {
             let options = project::ProjectListOptions::default();
-            project::list_projects(&client, options, format)
+            project::list_projects(client, options, format)
+        }
+        ProjectCommands::Get { id } => project::get_project(client, &id, format),
+        ProjectCommands::CriticalPath { slug } => {
+            project::critical_path::critical_path(client, &slug, format)
         }
-        ProjectCommands::Get { id } => project::get_project(&client, &id, format),
     }
 }
 
 fn handle_cycle_command(
     command: CycleCommands,
-    client: GraphQLClient,
+    client: &GraphQLClient,
     format: OutputFormat,
 ) -> lin::Result<()> {
     match command {
-        CycleCommands::List { team } => cycle::list_cycles(&client, &team, format),
-        CycleCommands::Get { id } => cycle::get_cycle(&client, &id, format),
+        CycleCommands::List { team } => cycle::list_cycles(client, &team, format),
+        CycleCommands::Get { id } => cycle::get_cycle(client, &id, format),
     }
 }
 
 fn handle_label_command(
     command: LabelCommands,
-    client: GraphQLClient,
+    client: &GraphQLClient,
+    use_cache: bool,
     format: OutputFormat,
 ) -> lin::Result<()> {
     match command {
         LabelCommands::List { team } => {
             let options = label::LabelListOptions { team_id: team };
-            label::list_labels(&client, options, format)
+            label::list_labels(client, options, format)
         }
-        LabelCommands::Get { id } => label::get_label(&client, &id, format),
+        LabelCommands::Get { id } => label::get_label(client, &id, format),
+        LabelCommands::Create {
+            name,
+            color,
+            description,
+            team,
+            parent,
+            group,
+        } => {
+            let options = label::LabelCreateOptions {
+                name,
+                color,
+                description,
+                team,
+                parent,
+                is_group: group,
+            };
+            label::create_label(client, options, use_cache, format)
+        }
+        LabelCommands::Update {
+            label: label_ref,
+            name,
+            color,
+            description,
+            parent,
+        } => {
+            let options = label::LabelUpdateOptions {
+                name,
+                color,
+                description,
+                parent,
+            };
+            label::update_label(client, &label_ref, options, format)
+        }
+        LabelCommands::Delete { label: label_ref } => label::delete_label(client, &label_ref),
+        LabelCommands::Merge { from, into } => label::merge_labels(client, &from, &into, format),
     }
 }
 
 fn handle_milestone_command(
     command: MilestoneCommands,
-    client: GraphQLClient,
+    client: &GraphQLClient,
     format: OutputFormat,
 ) -> lin::Result<()> {
     match command {
-        MilestoneCommands::List { project } => {
-            milestone::list_milestones(&client, &project, format)
-        }
-        MilestoneCommands::Get { id } => milestone::get_milestone(&client, &id, format),
+        MilestoneCommands::List { project } => milestone::list_milestones(client, &project, format),
+        MilestoneCommands::Get { id } => milestone::get_milestone(client, &id, format),
         MilestoneCommands::Create {
             project,
             name,
@@ -1327,7 +2557,7 @@ fn handle_milestone_command(
                 target_date,
                 sort_order,
             };
-            milestone::create_milestone(&client, options, format)
+            milestone::create_milestone(client, options, format)
         }
         MilestoneCommands::Update {
             id,
@@ -1343,14 +2573,95 @@ fn handle_milestone_command(
                 target_date,
                 sort_order,
             };
-            milestone::update_milestone(&client, options, format)
+            milestone::update_milestone(client, options, format)
+        }
+        MilestoneCommands::Delete { id } => milestone::delete_milestone(client, &id),
+    }
+}
+
+fn handle_inbox_command(
+    command: Option<InboxCommands>,
+    client: &GraphQLClient,
+    include_read: bool,
+    watch: bool,
+    interval: u64,
+    limit: u32,
+    format: OutputFormat,
+) -> lin::Result<()> {
+    match command {
+        Some(InboxCommands::Read { id, all }) => {
+            if all {
+                inbox::mark_all_read(client, format)
+            } else {
+                // clap guarantees an ID when --all is absent
+                inbox::mark_read(client, id.as_deref().unwrap_or_default(), format)
+            }
+        }
+        Some(InboxCommands::Snooze { id, until }) => {
+            inbox::snooze_notification(client, &id, &until, format)
+        }
+        None => {
+            let options = inbox::InboxListOptions {
+                include_read,
+                limit: Some(limit as i32),
+            };
+            if watch {
+                inbox::watch_notifications(
+                    client,
+                    options,
+                    std::time::Duration::from_secs(interval.max(1)),
+                    format,
+                )
+            } else {
+                inbox::list_notifications(client, options, format)
+            }
+        }
+    }
+}
+
+fn handle_report_command(
+    command: ReportCommands,
+    client: &GraphQLClient,
+    use_cache: bool,
+    format: OutputFormat,
+) -> lin::Result<()> {
+    match command {
+        ReportCommands::Flow {
+            team,
+            since,
+            until,
+            group_by,
+            limit,
+        } => {
+            let group_by = if let Some(group_str) = &group_by {
+                let field = report::flow::FlowGroupBy::parse(group_str).ok_or_else(|| {
+                    lin::error::LinError::config(format!(
+                        "Invalid group-by field '{}'. Valid fields: label, estimate",
+                        group_str
+                    ))
+                })?;
+                Some(field)
+            } else {
+                None
+            };
+            let options = report::flow::FlowReportOptions {
+                team,
+                since,
+                until,
+                group_by,
+                limit,
+            };
+            report::flow::flow_report(client, options, use_cache, format)
+        }
+        ReportCommands::Velocity { team, cycles, csv } => {
+            let options = report::velocity::VelocityReportOptions { team, cycles, csv };
+            report::velocity::velocity_report(client, options, format)
         }
-        MilestoneCommands::Delete { id } => milestone::delete_milestone(&client, &id),
     }
 }
 
 fn handle_search_command(
-    client: GraphQLClient,
+    client: &GraphQLClient,
     query: &str,
     team: Option<String>,
     assignee: Option<String>,
@@ -1385,5 +2696,51 @@ fn handle_search_command(
         limit: Some(limit as i32),
     };
 
-    search::search_issues(&client, query, viewer_id.as_deref(), options, format)
+    search::search_issues(client, query, viewer_id.as_deref(), options, format)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_alias(alias: &str, args: &[&str]) -> Cli {
+        let aliases = BTreeMap::from([("bug".to_string(), alias.to_string())]);
+        let args: Vec<OsString> = args.iter().map(OsString::from).collect();
+        let builtins = Cli::command();
+        let Some(Expansion::Args(args)) =
+            alias::expand(&args, &aliases, &builtin_commands(&builtins)).unwrap()
+        else {
+            panic!("expected the alias to expand to lin arguments");
+        };
+        Cli::try_parse_from(args).unwrap()
+    }
+
+    #[test]
+    fn test_bug_alias_parses_named_priority() {
+        let cli = parse_alias(
+            "issue create --labels bug --priority high --title",
+            &["lin", "bug", "Crash on login"],
+        );
+        let Commands::Issue { command } = cli.command else {
+            panic!("expected an issue command");
+        };
+        let IssueCommands::Create {
+            title, priority, ..
+        } = *command
+        else {
+            panic!("expected issue create");
+        };
+        assert_eq!(title, "Crash on login");
+        assert_eq!(priority, Some(2));
+    }
+
+    #[test]
+    fn test_parse_priority() {
+        assert_eq!(parse_priority("urgent"), Ok(1));
+        assert_eq!(parse_priority("4"), Ok(4));
+        assert!(parse_priority("5").is_err());
+        assert!(
+            Cli::try_parse_from(["lin", "issue", "update", "ENG-1", "--priority", "soon"]).is_err()
+        );
+    }
 }
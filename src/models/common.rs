@@ -3,5 +3,14 @@
 //! This module contains shared types like pagination helpers and enums
 //! that are used by multiple domain-specific modules.
 
-// Currently no shared types needed - PageInfo and similar pagination types
-// are not yet used in this codebase. This module is reserved for future use.
+use serde::{Deserialize, Serialize};
+
+/// Cursor information for a paginated connection.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PageInfo {
+    /// Whether more items follow this page.
+    pub has_next_page: bool,
+    /// Cursor to pass as `after` to fetch the next page.
+    pub end_cursor: Option<String>,
+}
@@ -0,0 +1,508 @@
+//! Issue history types for the Linear API.
+//!
+//! This module contains types for representing an issue's activity history
+//! (state, assignee, priority, estimate, label, project and cycle changes)
+//! and the combined timeline of history entries and comments.
+
+use serde::{Deserialize, Serialize};
+
+use super::comment::CommentConnection;
+use super::user::User;
+use super::workflow::WorkflowState;
+
+/// A label referenced by a history entry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryLabel {
+    /// Unique identifier for the label.
+    pub id: String,
+    /// The label's name.
+    pub name: String,
+}
+
+/// A project referenced by a history entry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryProject {
+    /// Unique identifier for the project.
+    pub id: String,
+    /// The project's name.
+    pub name: String,
+}
+
+/// A cycle referenced by a history entry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryCycle {
+    /// Unique identifier for the cycle.
+    pub id: String,
+    /// The cycle's number within the team.
+    pub number: i32,
+    /// The cycle's name (optional).
+    pub name: Option<String>,
+}
+
+impl HistoryCycle {
+    /// Display name for the cycle, falling back to "Cycle N".
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("Cycle {}", self.number))
+    }
+}
+
+/// A single entry in an issue's history.
+///
+/// Each entry records one update to the issue. Only the `from*`/`to*` pairs
+/// relevant to that update are populated; the rest are null.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueHistoryEntry {
+    /// Unique identifier for the history entry.
+    pub id: String,
+    /// ISO 8601 timestamp of when the change happened.
+    pub created_at: String,
+    /// The user who made the change (null for automations and integrations).
+    pub actor: Option<User>,
+    /// Previous workflow state.
+    pub from_state: Option<WorkflowState>,
+    /// New workflow state.
+    pub to_state: Option<WorkflowState>,
+    /// Previous assignee.
+    pub from_assignee: Option<User>,
+    /// New assignee.
+    pub to_assignee: Option<User>,
+    /// Previous priority (0-4).
+    pub from_priority: Option<f64>,
+    /// New priority (0-4).
+    pub to_priority: Option<f64>,
+    /// Previous estimate.
+    pub from_estimate: Option<f64>,
+    /// New estimate.
+    pub to_estimate: Option<f64>,
+    /// Labels added in this change.
+    #[serde(default)]
+    pub added_labels: Option<Vec<HistoryLabel>>,
+    /// Labels removed in this change.
+    #[serde(default)]
+    pub removed_labels: Option<Vec<HistoryLabel>>,
+    /// Previous project.
+    pub from_project: Option<HistoryProject>,
+    /// New project.
+    pub to_project: Option<HistoryProject>,
+    /// Previous cycle.
+    pub from_cycle: Option<HistoryCycle>,
+    /// New cycle.
+    pub to_cycle: Option<HistoryCycle>,
+}
+
+/// A single field change extracted from a history entry.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryChange {
+    /// The changed field (state, assignee, priority, estimate, label, project, cycle).
+    pub field: String,
+    /// Previous value, if any.
+    pub from: Option<String>,
+    /// New value, if any.
+    pub to: Option<String>,
+}
+
+impl HistoryChange {
+    fn new(field: &str, from: Option<String>, to: Option<String>) -> Self {
+        Self {
+            field: field.to_string(),
+            from,
+            to,
+        }
+    }
+}
+
+/// Convert a numeric priority into its Linear name.
+pub fn priority_name(priority: f64) -> String {
+    match priority as i64 {
+        0 => "No priority".to_string(),
+        1 => "Urgent".to_string(),
+        2 => "High".to_string(),
+        3 => "Normal".to_string(),
+        4 => "Low".to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// Format a float without a trailing ".0" for whole numbers.
+fn format_number(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{}", value as i64)
+    } else {
+        format!("{}", value)
+    }
+}
+
+impl IssueHistoryEntry {
+    /// Extract the tracked field changes from this entry.
+    ///
+    /// Returns an empty list for entries that only touch untracked fields
+    /// (e.g., description edits).
+    pub fn changes(&self) -> Vec<HistoryChange> {
+        let mut changes = Vec::new();
+
+        if self.from_state.is_some() || self.to_state.is_some() {
+            changes.push(HistoryChange::new(
+                "state",
+                self.from_state.as_ref().map(|s| s.name.clone()),
+                self.to_state.as_ref().map(|s| s.name.clone()),
+            ));
+        }
+
+        if self.from_assignee.is_some() || self.to_assignee.is_some() {
+            changes.push(HistoryChange::new(
+                "assignee",
+                self.from_assignee.as_ref().map(|u| u.name.clone()),
+                self.to_assignee.as_ref().map(|u| u.name.clone()),
+            ));
+        }
+
+        if self.from_priority != self.to_priority {
+            changes.push(HistoryChange::new(
+                "priority",
+                self.from_priority.map(priority_name),
+                self.to_priority.map(priority_name),
+            ));
+        }
+
+        if self.from_estimate != self.to_estimate {
+            changes.push(HistoryChange::new(
+                "estimate",
+                self.from_estimate.map(format_number),
+                self.to_estimate.map(format_number),
+            ));
+        }
+
+        for label in self.added_labels.iter().flatten() {
+            changes.push(HistoryChange::new("label", None, Some(label.name.clone())));
+        }
+        for label in self.removed_labels.iter().flatten() {
+            changes.push(HistoryChange::new("label", Some(label.name.clone()), None));
+        }
+
+        if self.from_project.is_some() || self.to_project.is_some() {
+            changes.push(HistoryChange::new(
+                "project",
+                self.from_project.as_ref().map(|p| p.name.clone()),
+                self.to_project.as_ref().map(|p| p.name.clone()),
+            ));
+        }
+
+        if self.from_cycle.is_some() || self.to_cycle.is_some() {
+            changes.push(HistoryChange::new(
+                "cycle",
+                self.from_cycle.as_ref().map(|c| c.display_name()),
+                self.to_cycle.as_ref().map(|c| c.display_name()),
+            ));
+        }
+
+        changes
+    }
+}
+
+/// A paginated list of issue history entries.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueHistoryConnection {
+    /// List of history entries.
+    pub nodes: Vec<IssueHistoryEntry>,
+}
+
+/// An issue with its history and comments.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueWithHistory {
+    /// Unique identifier for the issue.
+    pub id: String,
+    /// Human-readable identifier (e.g., "ENG-123").
+    pub identifier: String,
+    /// Issue title.
+    pub title: String,
+    /// ISO 8601 timestamp of when the issue was created.
+    pub created_at: String,
+    /// The user who created the issue.
+    pub creator: Option<User>,
+    /// The issue's history entries.
+    pub history: IssueHistoryConnection,
+    /// Comments on the issue.
+    pub comments: CommentConnection,
+}
+
+/// Response wrapper for the issue history query.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueHistoryResponse {
+    /// The issue with history and comments.
+    pub issue: IssueWithHistory,
+}
+
+/// The kind of event in an issue timeline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TimelineEventKind {
+    /// The issue was created.
+    Created,
+    /// One or more tracked fields changed.
+    Change,
+    /// A comment was posted.
+    Comment,
+}
+
+/// A single event in an issue's timeline.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimelineEvent {
+    /// What kind of event this is.
+    pub kind: TimelineEventKind,
+    /// ISO 8601 timestamp of the event.
+    pub created_at: String,
+    /// Name of the user responsible (null for automations).
+    pub actor: Option<String>,
+    /// Field changes (only for `change` events).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub changes: Vec<HistoryChange>,
+    /// Comment body (only for `comment` events).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+}
+
+/// Chronological timeline of an issue's changes and comments.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueTimeline {
+    /// Unique identifier for the issue.
+    pub id: String,
+    /// Human-readable identifier (e.g., "ENG-123").
+    pub identifier: String,
+    /// Issue title.
+    pub title: String,
+    /// Events in chronological order (oldest first).
+    pub events: Vec<TimelineEvent>,
+}
+
+impl From<IssueWithHistory> for IssueTimeline {
+    fn from(issue: IssueWithHistory) -> Self {
+        let mut events = vec![TimelineEvent {
+            kind: TimelineEventKind::Created,
+            created_at: issue.created_at.clone(),
+            actor: issue.creator.as_ref().map(|u| u.name.clone()),
+            changes: Vec::new(),
+            body: None,
+        }];
+
+        for entry in &issue.history.nodes {
+            let changes = entry.changes();
+            if changes.is_empty() {
+                continue;
+            }
+            events.push(TimelineEvent {
+                kind: TimelineEventKind::Change,
+                created_at: entry.created_at.clone(),
+                actor: entry.actor.as_ref().map(|u| u.name.clone()),
+                changes,
+                body: None,
+            });
+        }
+
+        for comment in issue.comments.nodes {
+            events.push(TimelineEvent {
+                kind: TimelineEventKind::Comment,
+                created_at: comment.created_at,
+                actor: comment.user.map(|u| u.name),
+                changes: Vec::new(),
+                body: Some(comment.body),
+            });
+        }
+
+        // ISO 8601 timestamps in the same timezone sort lexicographically
+        events.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+
+        Self {
+            id: issue.id,
+            identifier: issue.identifier,
+            title: issue.title,
+            events,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn history_json() -> &'static str {
+        r##"{
+            "issue": {
+                "id": "issue-1",
+                "identifier": "ENG-123",
+                "title": "Fix login",
+                "createdAt": "2024-01-01T09:00:00.000Z",
+                "creator": {
+                    "id": "user-1",
+                    "name": "Jane Doe",
+                    "email": "jane@example.com",
+                    "displayName": null,
+                    "active": true
+                },
+                "history": {
+                    "nodes": [
+                        {
+                            "id": "hist-2",
+                            "createdAt": "2024-01-03T12:00:00.000Z",
+                            "actor": null,
+                            "fromState": { "id": "s1", "name": "Todo", "color": "#ccc", "type": "unstarted" },
+                            "toState": { "id": "s2", "name": "In Progress", "color": "#ff0", "type": "started" },
+                            "fromAssignee": null,
+                            "toAssignee": {
+                                "id": "user-2",
+                                "name": "John Roe",
+                                "email": "john@example.com",
+                                "displayName": null,
+                                "active": true
+                            },
+                            "fromPriority": null,
+                            "toPriority": null,
+                            "fromEstimate": null,
+                            "toEstimate": null,
+                            "addedLabels": null,
+                            "removedLabels": null,
+                            "fromProject": null,
+                            "toProject": null,
+                            "fromCycle": null,
+                            "toCycle": null
+                        },
+                        {
+                            "id": "hist-1",
+                            "createdAt": "2024-01-02T12:00:00.000Z",
+                            "actor": {
+                                "id": "user-1",
+                                "name": "Jane Doe",
+                                "email": "jane@example.com",
+                                "displayName": null,
+                                "active": true
+                            },
+                            "fromState": null,
+                            "toState": null,
+                            "fromAssignee": null,
+                            "toAssignee": null,
+                            "fromPriority": 0,
+                            "toPriority": 2,
+                            "fromEstimate": null,
+                            "toEstimate": 3,
+                            "addedLabels": [{ "id": "l1", "name": "bug" }],
+                            "removedLabels": [],
+                            "fromProject": null,
+                            "toProject": { "id": "p1", "name": "Auth" },
+                            "fromCycle": null,
+                            "toCycle": { "id": "c1", "number": 7, "name": null }
+                        },
+                        {
+                            "id": "hist-0",
+                            "createdAt": "2024-01-01T10:00:00.000Z",
+                            "actor": null,
+                            "fromState": null,
+                            "toState": null,
+                            "fromAssignee": null,
+                            "toAssignee": null,
+                            "fromPriority": null,
+                            "toPriority": null,
+                            "fromEstimate": null,
+                            "toEstimate": null,
+                            "fromProject": null,
+                            "toProject": null,
+                            "fromCycle": null,
+                            "toCycle": null
+                        }
+                    ]
+                },
+                "comments": {
+                    "nodes": [
+                        {
+                            "id": "comment-1",
+                            "body": "On it",
+                            "user": {
+                                "id": "user-2",
+                                "name": "John Roe",
+                                "email": "john@example.com",
+                                "displayName": null,
+                                "active": true
+                            },
+                            "createdAt": "2024-01-03T13:00:00.000Z",
+                            "updatedAt": "2024-01-03T13:00:00.000Z"
+                        }
+                    ]
+                }
+            }
+        }"##
+    }
+
+    #[test]
+    fn test_issue_history_response_deserialization() {
+        let response: IssueHistoryResponse = serde_json::from_str(history_json()).unwrap();
+        assert_eq!(response.issue.identifier, "ENG-123");
+        assert_eq!(response.issue.history.nodes.len(), 3);
+        assert_eq!(response.issue.comments.nodes.len(), 1);
+    }
+
+    #[test]
+    fn test_history_entry_changes() {
+        let response: IssueHistoryResponse = serde_json::from_str(history_json()).unwrap();
+
+        let changes = response.issue.history.nodes[1].changes();
+        assert_eq!(
+            changes,
+            vec![
+                HistoryChange::new("priority", Some("No priority".into()), Some("High".into())),
+                HistoryChange::new("estimate", None, Some("3".into())),
+                HistoryChange::new("label", None, Some("bug".into())),
+                HistoryChange::new("project", None, Some("Auth".into())),
+                HistoryChange::new("cycle", None, Some("Cycle 7".into())),
+            ]
+        );
+
+        // Entries without tracked fields produce no changes
+        assert!(response.issue.history.nodes[2].changes().is_empty());
+    }
+
+    #[test]
+    fn test_timeline_is_chronological() {
+        let response: IssueHistoryResponse = serde_json::from_str(history_json()).unwrap();
+        let timeline = IssueTimeline::from(response.issue);
+
+        let kinds: Vec<TimelineEventKind> = timeline.events.iter().map(|e| e.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                TimelineEventKind::Created,
+                TimelineEventKind::Change,
+                TimelineEventKind::Change,
+                TimelineEventKind::Comment,
+            ]
+        );
+        assert_eq!(timeline.events[0].actor.as_deref(), Some("Jane Doe"));
+        assert_eq!(timeline.events[2].actor, None);
+        assert_eq!(timeline.events[2].changes[0].field, "state");
+        assert_eq!(timeline.events[3].body.as_deref(), Some("On it"));
+    }
+
+    #[test]
+    fn test_timeline_serialization_skips_empty_fields() {
+        let event = TimelineEvent {
+            kind: TimelineEventKind::Created,
+            created_at: "2024-01-01T00:00:00.000Z".to_string(),
+            actor: None,
+            changes: Vec::new(),
+            body: None,
+        };
+        let json = serde_json::to_string(&event).unwrap();
+        assert!(json.contains("\"kind\":\"created\""));
+        assert!(!json.contains("changes"));
+        assert!(!json.contains("body"));
+    }
+}
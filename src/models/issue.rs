@@ -9,7 +9,7 @@ use super::attachment::AttachmentConnection;
 use super::comment::CommentConnection;
 use super::milestone::ProjectMilestone;
 use super::team::Team;
-use super::user::User;
+use super::user::{User, UserConnection};
 use super::workflow::WorkflowState;
 
 /// A Linear issue.
@@ -36,6 +36,11 @@ pub struct Issue {
     pub assignee: Option<User>,
     /// Project milestone the issue belongs to.
     pub project_milestone: Option<ProjectMilestone>,
+    /// Users subscribed to the issue (only fetched for single-issue queries).
+    pub subscribers: Option<UserConnection>,
+    /// Due date in YYYY-MM-DD format (only fetched by some queries).
+    #[serde(default)]
+    pub due_date: Option<String>,
     /// ISO 8601 timestamp of when the issue was created.
     pub created_at: String,
     /// ISO 8601 timestamp of when the issue was last updated.
@@ -74,6 +79,8 @@ pub struct IssueWithComments {
     pub assignee: Option<User>,
     /// Project milestone the issue belongs to.
     pub project_milestone: Option<ProjectMilestone>,
+    /// Users subscribed to the issue (only fetched for single-issue queries).
+    pub subscribers: Option<UserConnection>,
     /// ISO 8601 timestamp of when the issue was created.
     pub created_at: String,
     /// ISO 8601 timestamp of when the issue was last updated.
@@ -114,6 +121,20 @@ pub struct IssueWithAttachments {
     pub attachments: AttachmentConnection,
 }
 
+/// Issue with only its subscribers (returned by subscription mutations).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueWithSubscribers {
+    /// Unique identifier for the issue.
+    pub id: String,
+    /// Human-readable identifier (e.g., "ENG-123").
+    pub identifier: String,
+    /// Issue title.
+    pub title: String,
+    /// Users subscribed to the issue.
+    pub subscribers: UserConnection,
+}
+
 // =============================================================================
 // Query Response Types
 // =============================================================================
@@ -280,6 +301,71 @@ pub struct IssueUnarchiveResponse {
     pub issue_unarchive: IssueUnarchivePayload,
 }
 
+/// Response for issue subscribe/unsubscribe mutations.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueSubscriptionPayload {
+    /// Whether the mutation was successful.
+    pub success: bool,
+    /// The issue with its updated subscribers.
+    pub issue: Option<IssueWithSubscribers>,
+}
+
+/// Response wrapper for issue subscribe.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueSubscribeResponse {
+    /// The mutation payload.
+    pub issue_subscribe: IssueSubscriptionPayload,
+}
+
+/// Response wrapper for issue unsubscribe.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueUnsubscribeResponse {
+    /// The mutation payload.
+    pub issue_unsubscribe: IssueSubscriptionPayload,
+}
+
+/// An issue waiting in a team's triage queue.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TriageIssue {
+    /// Unique identifier for the issue.
+    pub id: String,
+    /// Human-readable identifier (e.g., "ENG-123").
+    pub identifier: String,
+    /// The issue's title.
+    pub title: String,
+    /// Optional description of the issue.
+    pub description: Option<String>,
+    /// Priority level (0 = no priority, 1 = urgent, 2 = high, 3 = normal, 4 = low).
+    #[serde(default)]
+    pub priority: i32,
+    /// Estimate points, if estimated.
+    pub estimate: Option<f64>,
+    /// When the issue was created (ISO 8601 timestamp).
+    pub created_at: String,
+    /// The user who created the issue.
+    pub creator: Option<User>,
+}
+
+/// A paginated list of triage issues.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TriageIssueConnection {
+    /// List of issues.
+    pub nodes: Vec<TriageIssue>,
+}
+
+/// Response wrapper for the triage issues query.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TriageIssuesResponse {
+    /// Paginated list of triage issues.
+    pub issues: TriageIssueConnection,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -509,4 +595,49 @@ mod tests {
         assert_eq!(response.issue.team.id, "team-456");
         assert_eq!(response.issue.team.key, "ENG");
     }
+
+    #[test]
+    fn test_issue_with_subscribers_deserialization() {
+        let json = r#"{
+            "id": "issue-1",
+            "identifier": "ENG-1",
+            "title": "Test",
+            "priority": 0,
+            "createdAt": "2024-01-01T00:00:00.000Z",
+            "updatedAt": "2024-01-01T00:00:00.000Z",
+            "subscribers": {
+                "nodes": [
+                    {
+                        "id": "user-1",
+                        "name": "Jane Doe",
+                        "email": "jane@example.com",
+                        "displayName": null,
+                        "active": true
+                    }
+                ]
+            }
+        }"#;
+        let issue: Issue = serde_json::from_str(json).unwrap();
+        let subscribers = issue.subscribers.unwrap();
+        assert_eq!(subscribers.nodes.len(), 1);
+        assert_eq!(subscribers.nodes[0].name, "Jane Doe");
+    }
+
+    #[test]
+    fn test_issue_subscribe_response_deserialization() {
+        let json = r#"{
+            "issueSubscribe": {
+                "success": true,
+                "issue": {
+                    "id": "issue-1",
+                    "identifier": "ENG-1",
+                    "title": "Test",
+                    "subscribers": { "nodes": [] }
+                }
+            }
+        }"#;
+        let response: IssueSubscribeResponse = serde_json::from_str(json).unwrap();
+        assert!(response.issue_subscribe.success);
+        assert_eq!(response.issue_subscribe.issue.unwrap().identifier, "ENG-1");
+    }
 }
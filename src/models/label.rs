@@ -5,6 +5,16 @@
 
 use serde::{Deserialize, Serialize};
 
+/// A minimal reference to a label.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LabelRef {
+    /// Unique identifier for the label.
+    pub id: String,
+    /// The label's name.
+    pub name: String,
+}
+
 /// A Linear label.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -19,6 +29,9 @@ pub struct Label {
     pub color: String,
     /// Whether this is a group label (parent label).
     pub is_group: bool,
+    /// The group this label belongs to, if any.
+    #[serde(default)]
+    pub parent: Option<LabelRef>,
     /// ISO 8601 timestamp of when the label was created.
     pub created_at: String,
     /// ISO 8601 timestamp of when the label was last updated.
@@ -67,6 +80,92 @@ pub struct LabelResponse {
     pub issue_label: Label,
 }
 
+/// Payload for label create and update mutations.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LabelPayload {
+    /// Whether the mutation was successful.
+    pub success: bool,
+    /// The created or updated label.
+    pub issue_label: Option<Label>,
+}
+
+/// Response wrapper for label creation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LabelCreateResponse {
+    /// The mutation payload.
+    pub issue_label_create: LabelPayload,
+}
+
+/// Response wrapper for label update.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LabelUpdateResponse {
+    /// The mutation payload.
+    pub issue_label_update: LabelPayload,
+}
+
+/// Payload for label deletion.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LabelDeletePayload {
+    /// Whether the mutation was successful.
+    pub success: bool,
+}
+
+/// Response wrapper for label deletion.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LabelDeleteResponse {
+    /// The mutation payload.
+    pub issue_label_delete: LabelDeletePayload,
+}
+
+/// A label ID on an issue.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LabelId {
+    /// Unique identifier for the label.
+    pub id: String,
+}
+
+/// A list of label IDs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LabelIdConnection {
+    /// List of label IDs.
+    pub nodes: Vec<LabelId>,
+}
+
+/// An issue with the IDs of its labels.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LabeledIssue {
+    /// Unique identifier for the issue.
+    pub id: String,
+    /// Human-readable identifier (e.g., "ENG-123").
+    pub identifier: String,
+    /// The issue's labels.
+    pub labels: LabelIdConnection,
+}
+
+/// A list of labeled issues.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LabeledIssueConnection {
+    /// List of issues.
+    pub nodes: Vec<LabeledIssue>,
+}
+
+/// Response wrapper for the labeled issues query.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LabeledIssuesResponse {
+    /// Issues carrying the label.
+    pub issues: LabeledIssueConnection,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
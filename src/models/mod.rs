@@ -7,11 +7,14 @@ pub mod attachment;
 pub mod comment;
 pub mod common;
 pub mod cycle;
+pub mod history;
 pub mod issue;
 pub mod label;
 pub mod milestone;
+pub mod notification;
 pub mod project;
 pub mod relation;
+pub mod report;
 pub mod team;
 pub mod user;
 pub mod workflow;
@@ -19,12 +22,16 @@ pub mod workflow;
 // Re-export all types for convenience since they're used throughout the codebase.
 pub use attachment::*;
 pub use comment::*;
+pub use common::*;
 pub use cycle::*;
+pub use history::*;
 pub use issue::*;
 pub use label::*;
 pub use milestone::*;
+pub use notification::*;
 pub use project::*;
 pub use relation::*;
+pub use report::*;
 pub use team::*;
 pub use user::*;
 pub use workflow::*;
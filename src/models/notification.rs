@@ -0,0 +1,201 @@
+//! Notification-related types for the Linear API.
+//!
+//! This module contains types for representing entries in a user's Linear
+//! inbox (mentions, assignments, status changes, comments) and
+//! notification-related API responses and mutations.
+
+use serde::{Deserialize, Serialize};
+
+use super::relation::RelatedIssue;
+use super::user::User;
+
+/// A comment referenced by a notification.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationComment {
+    /// Unique identifier for the comment.
+    pub id: String,
+    /// The comment body/content.
+    pub body: String,
+}
+
+/// A notification in the user's inbox.
+///
+/// Only issue notifications carry an `issue` and `comment`; for other
+/// notification kinds (e.g., project updates) these fields are absent.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Notification {
+    /// Unique identifier for the notification.
+    pub id: String,
+    /// The notification type (e.g., "issueMention", "issueAssignedToYou").
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// ISO 8601 timestamp of when the notification was created.
+    pub created_at: String,
+    /// ISO 8601 timestamp of when the notification was read (null if unread).
+    pub read_at: Option<String>,
+    /// ISO 8601 timestamp until which the notification is snoozed.
+    pub snoozed_until_at: Option<String>,
+    /// The user who caused the notification.
+    pub actor: Option<User>,
+    /// The issue the notification is about.
+    #[serde(default)]
+    pub issue: Option<RelatedIssue>,
+    /// The comment the notification is about.
+    #[serde(default)]
+    pub comment: Option<NotificationComment>,
+}
+
+impl Notification {
+    /// Whether the notification has not been read yet.
+    pub fn is_unread(&self) -> bool {
+        self.read_at.is_none()
+    }
+
+    /// Whether the notification is snoozed at the given point in time.
+    ///
+    /// Snooze timestamps that fail to parse are treated as not snoozed so
+    /// that notifications are never silently hidden.
+    pub fn is_snoozed_at(&self, now: &chrono::DateTime<chrono::Utc>) -> bool {
+        self.snoozed_until_at
+            .as_deref()
+            .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok())
+            .is_some_and(|until| until > *now)
+    }
+}
+
+/// A paginated list of notifications.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationConnection {
+    /// List of notifications.
+    pub nodes: Vec<Notification>,
+    /// Cursor for the next page.
+    #[serde(default)]
+    pub page_info: super::PageInfo,
+}
+
+/// Response wrapper for the notifications query.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationsResponse {
+    /// Paginated list of notifications.
+    pub notifications: NotificationConnection,
+}
+
+/// Response for notification update mutation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationUpdatePayload {
+    /// Whether the mutation was successful.
+    pub success: bool,
+    /// The updated notification.
+    pub notification: Option<Notification>,
+}
+
+/// Response wrapper for notification update.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationUpdateResponse {
+    /// The mutation payload.
+    pub notification_update: NotificationUpdatePayload,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_issue_notification_deserialization() {
+        let json = r#"{
+            "id": "notif-1",
+            "type": "issueMention",
+            "createdAt": "2024-01-01T10:00:00.000Z",
+            "readAt": null,
+            "snoozedUntilAt": null,
+            "actor": {
+                "id": "user-1",
+                "name": "Jane Doe",
+                "email": "jane@example.com",
+                "displayName": "jane",
+                "active": true
+            },
+            "issue": {
+                "id": "issue-1",
+                "identifier": "ENG-123",
+                "title": "Fix login"
+            },
+            "comment": {
+                "id": "comment-1",
+                "body": "@john can you take a look?"
+            }
+        }"#;
+        let notification: Notification = serde_json::from_str(json).unwrap();
+        assert_eq!(notification.id, "notif-1");
+        assert_eq!(notification.type_, "issueMention");
+        assert!(notification.is_unread());
+        assert_eq!(notification.issue.unwrap().identifier, "ENG-123");
+        assert_eq!(notification.comment.unwrap().id, "comment-1");
+    }
+
+    #[test]
+    fn test_non_issue_notification_deserialization() {
+        let json = r#"{
+            "id": "notif-2",
+            "type": "projectUpdateCreated",
+            "createdAt": "2024-01-01T10:00:00.000Z",
+            "readAt": "2024-01-02T10:00:00.000Z",
+            "snoozedUntilAt": null,
+            "actor": null
+        }"#;
+        let notification: Notification = serde_json::from_str(json).unwrap();
+        assert!(!notification.is_unread());
+        assert!(notification.issue.is_none());
+        assert!(notification.comment.is_none());
+    }
+
+    #[test]
+    fn test_notification_is_snoozed_at() {
+        let json = r#"{
+            "id": "notif-3",
+            "type": "issueAssignedToYou",
+            "createdAt": "2024-01-01T10:00:00.000Z",
+            "readAt": null,
+            "snoozedUntilAt": "2024-01-05T00:00:00.000Z",
+            "actor": null
+        }"#;
+        let notification: Notification = serde_json::from_str(json).unwrap();
+        let before: chrono::DateTime<chrono::Utc> = "2024-01-03T00:00:00Z".parse().unwrap();
+        let after: chrono::DateTime<chrono::Utc> = "2024-01-06T00:00:00Z".parse().unwrap();
+        assert!(notification.is_snoozed_at(&before));
+        assert!(!notification.is_snoozed_at(&after));
+    }
+
+    #[test]
+    fn test_notification_update_response_deserialization() {
+        let json = r#"{
+            "notificationUpdate": {
+                "success": true,
+                "notification": {
+                    "id": "notif-1",
+                    "type": "issueNewComment",
+                    "createdAt": "2024-01-01T10:00:00.000Z",
+                    "readAt": "2024-01-02T10:00:00.000Z",
+                    "snoozedUntilAt": null,
+                    "actor": null
+                }
+            }
+        }"#;
+        let response: NotificationUpdateResponse = serde_json::from_str(json).unwrap();
+        assert!(response.notification_update.success);
+        assert!(
+            response
+                .notification_update
+                .notification
+                .unwrap()
+                .read_at
+                .is_some()
+        );
+    }
+}
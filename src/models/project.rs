@@ -55,6 +55,64 @@ pub struct ProjectsResponse {
     pub projects: ProjectConnection,
 }
 
+/// A milestone marker on the roadmap.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoadmapMilestone {
+    /// Unique identifier for the milestone.
+    pub id: String,
+    /// The milestone's name.
+    pub name: String,
+    /// Target date for the milestone (optional).
+    pub target_date: Option<String>,
+    /// The milestone's status (done, next, overdue, unstarted).
+    pub status: String,
+}
+
+/// A paginated list of roadmap milestones.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoadmapMilestoneConnection {
+    /// List of milestones.
+    pub nodes: Vec<RoadmapMilestone>,
+}
+
+/// A project with the fields needed to draw it on the roadmap.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoadmapProject {
+    /// Unique identifier for the project.
+    pub id: String,
+    /// The project's name.
+    pub name: String,
+    /// The project's state (planned, started, paused, completed, canceled).
+    pub state: String,
+    /// Start date for the project (optional).
+    pub start_date: Option<String>,
+    /// Target date for the project (optional).
+    pub target_date: Option<String>,
+    /// Progress percentage of the project (0-100).
+    pub progress: f64,
+    /// Milestones of the project.
+    pub project_milestones: RoadmapMilestoneConnection,
+}
+
+/// A paginated list of roadmap projects.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoadmapProjectConnection {
+    /// List of projects.
+    pub nodes: Vec<RoadmapProject>,
+}
+
+/// Response wrapper for the roadmap projects query.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoadmapProjectsResponse {
+    /// Paginated list of projects.
+    pub projects: RoadmapProjectConnection,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
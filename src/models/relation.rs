@@ -5,6 +5,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::workflow::WorkflowState;
+
 /// A simplified issue reference for relations.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -15,6 +17,9 @@ pub struct RelatedIssue {
     pub identifier: String,
     /// Issue title.
     pub title: String,
+    /// Current workflow state (only fetched by some queries).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<WorkflowState>,
 }
 
 /// An issue relation between two issues.
@@ -90,6 +95,12 @@ pub struct IssueWithRelations {
     pub id: String,
     /// Human-readable identifier (e.g., "ENG-123").
     pub identifier: String,
+    /// Issue title.
+    #[serde(default)]
+    pub title: String,
+    /// Current workflow state.
+    #[serde(default)]
+    pub state: Option<WorkflowState>,
     /// Outgoing relations from this issue.
     pub relations: IssueRelationConnection,
     /// Incoming relations to this issue.
@@ -187,6 +198,56 @@ pub struct NormalizedRelation {
     pub related_issue: RelatedIssue,
 }
 
+/// An issue with its state, estimate, and relations, as fetched for graph export.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GraphIssue {
+    /// Unique identifier for the issue.
+    pub id: String,
+    /// Human-readable identifier (e.g., "ENG-123").
+    pub identifier: String,
+    /// Issue title.
+    pub title: String,
+    /// Estimate in points (if set).
+    pub estimate: Option<f64>,
+    /// Current workflow state.
+    pub state: Option<WorkflowState>,
+    /// Parent issue (if this is a sub-issue).
+    pub parent: Option<RelatedIssue>,
+    /// Outgoing relations from this issue.
+    pub relations: IssueRelationConnection,
+    /// Incoming relations to this issue.
+    pub inverse_relations: InverseIssueRelationConnection,
+}
+
+/// A paginated list of graph issues.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GraphIssueConnection {
+    /// List of issues.
+    pub nodes: Vec<GraphIssue>,
+}
+
+/// A project with all of its issues and their relations.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectIssueGraph {
+    /// Unique identifier for the project.
+    pub id: String,
+    /// Project name.
+    pub name: String,
+    /// Issues in the project.
+    pub issues: GraphIssueConnection,
+}
+
+/// Response wrapper for the project issue graph query.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectIssueGraphResponse {
+    /// The project with its issues.
+    pub project: ProjectIssueGraph,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -302,6 +363,7 @@ mod tests {
                 id: "issue-456".to_string(),
                 identifier: "ENG-456".to_string(),
                 title: "Blocked issue".to_string(),
+                state: None,
             },
         };
         let json = serde_json::to_string(&relation).unwrap();
@@ -0,0 +1,324 @@
+//! Reporting types for the Linear API.
+//!
+//! This module contains the API response types used to compute reports
+//! such as flow (lead time / cycle time) analytics, cycle velocity and
+//! standup summaries.
+
+use serde::{Deserialize, Serialize};
+
+use super::comment::CommentConnection;
+use super::history::{HistoryLabel, IssueHistoryConnection};
+use super::relation::InverseIssueRelationConnection;
+use super::user::User;
+use super::workflow::WorkflowState;
+
+/// A paginated list of label references.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryLabelConnection {
+    /// List of labels.
+    pub nodes: Vec<HistoryLabel>,
+}
+
+/// An issue with the timestamps and state transitions needed for flow analytics.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FlowIssue {
+    /// Unique identifier for the issue.
+    pub id: String,
+    /// Human-readable identifier (e.g., "ENG-123").
+    pub identifier: String,
+    /// Issue title.
+    pub title: String,
+    /// Estimate value (story points or t-shirt size value).
+    pub estimate: Option<f64>,
+    /// ISO 8601 timestamp of when the issue was created.
+    pub created_at: String,
+    /// ISO 8601 timestamp of when the issue first moved to a started state.
+    pub started_at: Option<String>,
+    /// ISO 8601 timestamp of when the issue was completed.
+    pub completed_at: Option<String>,
+    /// Labels on the issue.
+    pub labels: HistoryLabelConnection,
+    /// History entries (only state fields are fetched).
+    pub history: IssueHistoryConnection,
+}
+
+/// A paginated list of flow issues.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FlowIssueConnection {
+    /// List of issues.
+    pub nodes: Vec<FlowIssue>,
+}
+
+/// Response wrapper for the flow issues query.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FlowIssuesResponse {
+    /// Paginated list of issues.
+    pub issues: FlowIssueConnection,
+}
+
+/// An issue with the fields needed for velocity reporting.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VelocityIssue {
+    /// Unique identifier for the issue.
+    pub id: String,
+    /// Human-readable identifier (e.g., "ENG-123").
+    pub identifier: String,
+    /// Estimate value (story points or t-shirt size value).
+    pub estimate: Option<f64>,
+    /// ISO 8601 timestamp of when the issue was completed.
+    pub completed_at: Option<String>,
+    /// ISO 8601 timestamp of when the issue was canceled.
+    pub canceled_at: Option<String>,
+}
+
+/// A paginated list of velocity issues.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VelocityIssueConnection {
+    /// List of issues.
+    pub nodes: Vec<VelocityIssue>,
+}
+
+/// A cycle with its scope history and issues for velocity reporting.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VelocityCycle {
+    /// Unique identifier for the cycle.
+    pub id: String,
+    /// The cycle's number within the team.
+    pub number: i32,
+    /// Optional cycle name.
+    pub name: Option<String>,
+    /// ISO 8601 timestamp of when the cycle starts.
+    pub starts_at: String,
+    /// ISO 8601 timestamp of when the cycle ends.
+    pub ends_at: String,
+    /// ISO 8601 timestamp of when the cycle was closed.
+    pub completed_at: Option<String>,
+    /// Total scope (estimate points) per day of the cycle.
+    #[serde(default)]
+    pub scope_history: Vec<f64>,
+    /// Completed scope (estimate points) per day of the cycle.
+    #[serde(default)]
+    pub completed_scope_history: Vec<f64>,
+    /// Issues currently in the cycle.
+    pub issues: VelocityIssueConnection,
+    /// Issues that were still open when the cycle closed.
+    pub uncompleted_issues_upon_close: VelocityIssueConnection,
+}
+
+/// A paginated list of velocity cycles.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VelocityCycleConnection {
+    /// List of cycles.
+    pub nodes: Vec<VelocityCycle>,
+}
+
+/// Response wrapper for the velocity cycles query.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VelocityCyclesResponse {
+    /// Paginated list of cycles.
+    pub cycles: VelocityCycleConnection,
+}
+
+/// An issue with the activity needed for a standup summary.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StandupIssue {
+    /// Unique identifier for the issue.
+    pub id: String,
+    /// Human-readable identifier (e.g., "ENG-123").
+    pub identifier: String,
+    /// Issue title.
+    pub title: String,
+    /// ISO 8601 timestamp of when the issue was created.
+    pub created_at: String,
+    /// ISO 8601 timestamp of when the issue was completed.
+    pub completed_at: Option<String>,
+    /// Current workflow state.
+    pub state: WorkflowState,
+    /// User assigned to the issue.
+    pub assignee: Option<User>,
+    /// User who created the issue.
+    pub creator: Option<User>,
+    /// History entries (only state fields are fetched).
+    pub history: IssueHistoryConnection,
+    /// Comments on the issue.
+    pub comments: CommentConnection,
+}
+
+/// A paginated list of standup issues.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StandupIssueConnection {
+    /// List of issues.
+    pub nodes: Vec<StandupIssue>,
+}
+
+/// Response wrapper for the standup activity query.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StandupIssuesResponse {
+    /// Paginated list of issues.
+    pub issues: StandupIssueConnection,
+}
+
+/// An open issue with the relations pointing at it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenIssue {
+    /// Unique identifier for the issue.
+    pub id: String,
+    /// Human-readable identifier (e.g., "ENG-123").
+    pub identifier: String,
+    /// Issue title.
+    pub title: String,
+    /// Current workflow state.
+    pub state: WorkflowState,
+    /// User assigned to the issue.
+    pub assignee: Option<User>,
+    /// Relations where this issue is the target (e.g., "blocks" from another issue).
+    pub inverse_relations: InverseIssueRelationConnection,
+}
+
+/// A paginated list of open issues.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenIssueConnection {
+    /// List of issues.
+    pub nodes: Vec<OpenIssue>,
+}
+
+/// Response wrapper for the open issues with blockers query.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenIssuesResponse {
+    /// Paginated list of issues.
+    pub issues: OpenIssueConnection,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flow_issues_response_deserialization() {
+        let json = r##"{
+            "issues": {
+                "nodes": [
+                    {
+                        "id": "issue-1",
+                        "identifier": "ENG-1",
+                        "title": "Fix login",
+                        "estimate": 3,
+                        "createdAt": "2024-01-01T00:00:00.000Z",
+                        "startedAt": "2024-01-02T00:00:00.000Z",
+                        "completedAt": "2024-01-04T00:00:00.000Z",
+                        "labels": { "nodes": [{ "id": "l1", "name": "bug" }] },
+                        "history": {
+                            "nodes": [
+                                {
+                                    "id": "h1",
+                                    "createdAt": "2024-01-02T00:00:00.000Z",
+                                    "fromState": { "id": "s1", "name": "Todo", "color": "#ccc", "type": "unstarted" },
+                                    "toState": { "id": "s2", "name": "In Progress", "color": "#ff0", "type": "started" }
+                                }
+                            ]
+                        }
+                    }
+                ]
+            }
+        }"##;
+        let response: FlowIssuesResponse = serde_json::from_str(json).unwrap();
+        let issue = &response.issues.nodes[0];
+        assert_eq!(issue.identifier, "ENG-1");
+        assert_eq!(issue.estimate, Some(3.0));
+        assert_eq!(issue.labels.nodes[0].name, "bug");
+        let entry = &issue.history.nodes[0];
+        assert_eq!(entry.to_state.as_ref().unwrap().type_, "started");
+        assert!(entry.to_assignee.is_none());
+    }
+
+    #[test]
+    fn test_velocity_cycles_response_deserialization() {
+        let json = r#"{
+            "cycles": {
+                "nodes": [
+                    {
+                        "id": "cycle-1",
+                        "number": 7,
+                        "name": null,
+                        "startsAt": "2024-01-01T00:00:00.000Z",
+                        "endsAt": "2024-01-15T00:00:00.000Z",
+                        "completedAt": "2024-01-15T00:00:00.000Z",
+                        "scopeHistory": [10, 12, 13],
+                        "completedScopeHistory": [0, 4, 9],
+                        "issues": {
+                            "nodes": [
+                                { "id": "i1", "identifier": "ENG-1", "estimate": 5, "completedAt": "2024-01-10T00:00:00.000Z", "canceledAt": null }
+                            ]
+                        },
+                        "uncompletedIssuesUponClose": {
+                            "nodes": [
+                                { "id": "i2", "identifier": "ENG-2", "estimate": null, "completedAt": null, "canceledAt": null }
+                            ]
+                        }
+                    }
+                ]
+            }
+        }"#;
+        let response: VelocityCyclesResponse = serde_json::from_str(json).unwrap();
+        let cycle = &response.cycles.nodes[0];
+        assert_eq!(cycle.number, 7);
+        assert_eq!(cycle.scope_history, vec![10.0, 12.0, 13.0]);
+        assert_eq!(cycle.issues.nodes[0].estimate, Some(5.0));
+        assert_eq!(
+            cycle.uncompleted_issues_upon_close.nodes[0].identifier,
+            "ENG-2"
+        );
+    }
+
+    #[test]
+    fn test_open_issues_response_deserialization() {
+        let json = r##"{
+            "issues": {
+                "nodes": [
+                    {
+                        "id": "issue-1",
+                        "identifier": "ENG-1",
+                        "title": "Ship OAuth",
+                        "state": { "id": "s1", "name": "In Progress", "color": "#ff0", "type": "started" },
+                        "assignee": null,
+                        "inverseRelations": {
+                            "nodes": [
+                                {
+                                    "id": "rel-1",
+                                    "type": "blocks",
+                                    "issue": {
+                                        "id": "issue-2",
+                                        "identifier": "ENG-2",
+                                        "title": "Provision client IDs",
+                                        "state": { "id": "s2", "name": "Todo", "color": "#ccc", "type": "unstarted" }
+                                    }
+                                }
+                            ]
+                        }
+                    }
+                ]
+            }
+        }"##;
+        let response: OpenIssuesResponse = serde_json::from_str(json).unwrap();
+        let issue = &response.issues.nodes[0];
+        assert_eq!(issue.state.type_, "started");
+        let blocker = issue.inverse_relations.nodes[0].issue.as_ref().unwrap();
+        assert_eq!(blocker.identifier, "ENG-2");
+        assert_eq!(blocker.state.as_ref().unwrap().type_, "unstarted");
+    }
+}
@@ -5,6 +5,9 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::user::User;
+use super::workflow::WorkflowState;
+
 /// A Linear team.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -20,6 +23,58 @@ pub struct Team {
     /// Issue estimation type (e.g., "linear", "fibonacci", "tShirt", "exponential", "notUsed").
     #[serde(rename = "issueEstimationType")]
     pub issue_estimate_type: Option<String>,
+    /// Whether zero is an allowed estimate.
+    #[serde(rename = "issueEstimationAllowZero", default)]
+    pub issue_estimate_allow_zero: bool,
+    /// Whether the extended scale is enabled (e.g., 13 and 21 for Fibonacci).
+    #[serde(rename = "issueEstimationExtended", default)]
+    pub issue_estimate_extended: bool,
+}
+
+/// T-shirt sizes and the points Linear assigns them; the last two are the extended scale.
+pub const TSHIRT_SIZES: [(&str, f64); 7] = [
+    ("XS", 1.0),
+    ("S", 2.0),
+    ("M", 3.0),
+    ("L", 5.0),
+    ("XL", 8.0),
+    ("XXL", 13.0),
+    ("XXXL", 21.0),
+];
+
+impl Team {
+    /// The estimates this team allows, as (name, value) pairs in ascending order.
+    ///
+    /// Numeric scales use the value as the name (e.g., "8"). Zero is named "0"
+    /// on every scale. Returns an empty list for "notUsed" or unknown types.
+    pub fn estimate_scale(&self) -> Vec<(String, f64)> {
+        let (base, extended): (&[f64], &[f64]) = match self.issue_estimate_type.as_deref() {
+            Some("exponential") => (&[1.0, 2.0, 4.0, 8.0, 16.0], &[32.0, 64.0]),
+            Some("fibonacci") => (&[1.0, 2.0, 3.0, 5.0, 8.0], &[13.0, 21.0]),
+            Some("linear") => (&[1.0, 2.0, 3.0, 4.0, 5.0], &[6.0, 7.0]),
+            Some("tShirt") => (&[], &[]),
+            _ => return Vec::new(),
+        };
+
+        let mut scale = Vec::new();
+        if self.issue_estimate_allow_zero {
+            scale.push(("0".to_string(), 0.0));
+        }
+        if self.issue_estimate_type.as_deref() == Some("tShirt") {
+            let sizes = if self.issue_estimate_extended { 7 } else { 5 };
+            scale.extend(
+                TSHIRT_SIZES[..sizes]
+                    .iter()
+                    .map(|(name, value)| (name.to_string(), *value)),
+            );
+        } else {
+            let values = base
+                .iter()
+                .chain(extended.iter().filter(|_| self.issue_estimate_extended));
+            scale.extend(values.map(|value| (format!("{}", *value as i64), *value)));
+        }
+        scale
+    }
 }
 
 /// Basic team information (ID and key only).
@@ -56,6 +111,139 @@ pub struct TeamsResponse {
     pub teams: TeamConnection,
 }
 
+/// A user's membership in a team.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamMembership {
+    /// Unique identifier for the membership.
+    pub id: String,
+    /// Whether the user is an owner of the team.
+    #[serde(default)]
+    pub owner: bool,
+    /// The member.
+    pub user: User,
+}
+
+/// A paginated list of team memberships.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamMembershipConnection {
+    /// List of memberships.
+    pub nodes: Vec<TeamMembership>,
+}
+
+/// A team with its memberships.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamWithMemberships {
+    /// Unique identifier for the team.
+    pub id: String,
+    /// The team's key/prefix (e.g., "ENG").
+    pub key: String,
+    /// The team's name.
+    pub name: String,
+    /// The team's memberships.
+    pub memberships: TeamMembershipConnection,
+}
+
+/// Response wrapper for the team memberships query.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamMembershipsResponse {
+    /// The requested team.
+    pub team: TeamWithMemberships,
+}
+
+/// Payload returned by the team membership create mutation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamMembershipPayload {
+    /// Whether the mutation succeeded.
+    pub success: bool,
+    /// The created membership.
+    pub team_membership: Option<TeamMembership>,
+}
+
+/// Response wrapper for the team membership create mutation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamMembershipCreateResponse {
+    /// The mutation payload.
+    pub team_membership_create: TeamMembershipPayload,
+}
+
+/// Payload returned by the team membership delete mutation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamMembershipDeletePayload {
+    /// Whether the mutation succeeded.
+    pub success: bool,
+}
+
+/// Response wrapper for the team membership delete mutation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamMembershipDeleteResponse {
+    /// The mutation payload.
+    pub team_membership_delete: TeamMembershipDeletePayload,
+}
+
+/// A team's cycle, estimation, triage and automation settings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamSettings {
+    /// Unique identifier for the team.
+    pub id: String,
+    /// The team's key/prefix (e.g., "ENG").
+    pub key: String,
+    /// The team's name.
+    pub name: String,
+    /// Whether cycles are enabled.
+    #[serde(default)]
+    pub cycles_enabled: bool,
+    /// Cycle duration in weeks.
+    pub cycle_duration: Option<f64>,
+    /// Cooldown between cycles in weeks.
+    pub cycle_cooldown_time: Option<f64>,
+    /// Issue estimation type (e.g., "linear", "fibonacci", "tShirt", "exponential", "notUsed").
+    pub issue_estimation_type: Option<String>,
+    /// Whether the triage workflow is enabled.
+    #[serde(default)]
+    pub triage_enabled: bool,
+    /// Months after which completed issues are archived.
+    pub auto_archive_period: Option<f64>,
+    /// Months after which stale issues are closed (none when disabled).
+    pub auto_close_period: Option<f64>,
+    /// State assigned to new issues.
+    pub default_issue_state: Option<WorkflowState>,
+}
+
+/// Response wrapper for the team settings query.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamSettingsResponse {
+    /// The requested team.
+    pub team: TeamSettings,
+}
+
+/// Payload returned by the team update mutation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamSettingsPayload {
+    /// Whether the mutation succeeded.
+    pub success: bool,
+    /// The updated team.
+    pub team: Option<TeamSettings>,
+}
+
+/// Response wrapper for the team update mutation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamSettingsUpdateResponse {
+    /// The mutation payload.
+    pub team_update: TeamSettingsPayload,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -140,4 +328,136 @@ mod tests {
         let team: Team = serde_json::from_str(json).unwrap();
         assert_eq!(team.issue_estimate_type, Some("fibonacci".to_string()));
     }
+
+    #[test]
+    fn test_team_memberships_response_deserialization() {
+        let json = r#"{
+            "team": {
+                "id": "team-1",
+                "key": "ENG",
+                "name": "Engineering",
+                "memberships": {
+                    "nodes": [
+                        {
+                            "id": "membership-1",
+                            "owner": true,
+                            "user": {
+                                "id": "user-1",
+                                "name": "Alice",
+                                "email": "alice@example.com",
+                                "displayName": "alice",
+                                "active": true
+                            }
+                        }
+                    ]
+                }
+            }
+        }"#;
+        let response: TeamMembershipsResponse = serde_json::from_str(json).unwrap();
+        let membership = &response.team.memberships.nodes[0];
+        assert!(membership.owner);
+        assert_eq!(membership.user.email, "alice@example.com");
+    }
+
+    #[test]
+    fn test_team_settings_deserialization() {
+        let json = r#"{
+            "id": "team-1",
+            "key": "ENG",
+            "name": "Engineering",
+            "cyclesEnabled": true,
+            "cycleDuration": 2,
+            "cycleCooldownTime": 0,
+            "issueEstimationType": "fibonacci",
+            "triageEnabled": false,
+            "autoArchivePeriod": 6,
+            "autoClosePeriod": null,
+            "defaultIssueState": null
+        }"#;
+        let settings: TeamSettings = serde_json::from_str(json).unwrap();
+        assert_eq!(settings.cycle_duration, Some(2.0));
+        assert_eq!(settings.auto_archive_period, Some(6.0));
+        assert!(settings.auto_close_period.is_none());
+        assert!(settings.default_issue_state.is_none());
+    }
+
+    fn team_with_scale(estimate_type: &str, allow_zero: bool, extended: bool) -> Team {
+        Team {
+            id: "team-1".to_string(),
+            key: "ENG".to_string(),
+            name: "Engineering".to_string(),
+            description: None,
+            issue_estimate_type: Some(estimate_type.to_string()),
+            issue_estimate_allow_zero: allow_zero,
+            issue_estimate_extended: extended,
+        }
+    }
+
+    fn values(scale: &[(String, f64)]) -> Vec<f64> {
+        scale.iter().map(|(_, value)| *value).collect()
+    }
+
+    #[test]
+    fn test_estimate_scale_tshirt() {
+        let scale = team_with_scale("tShirt", false, false).estimate_scale();
+        let names: Vec<&str> = scale.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, vec!["XS", "S", "M", "L", "XL"]);
+        assert_eq!(values(&scale), vec![1.0, 2.0, 3.0, 5.0, 8.0]);
+
+        let extended = team_with_scale("tShirt", true, true).estimate_scale();
+        assert_eq!(extended.first().unwrap(), &("0".to_string(), 0.0));
+        assert_eq!(extended.last().unwrap(), &("XXXL".to_string(), 21.0));
+    }
+
+    #[test]
+    fn test_estimate_scale_numeric() {
+        let linear = team_with_scale("linear", false, false).estimate_scale();
+        assert_eq!(values(&linear), vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+        assert_eq!(linear[2].0, "3");
+
+        let fibonacci = team_with_scale("fibonacci", false, false).estimate_scale();
+        assert_eq!(values(&fibonacci), vec![1.0, 2.0, 3.0, 5.0, 8.0]);
+
+        let fibonacci = team_with_scale("fibonacci", true, true).estimate_scale();
+        assert_eq!(
+            values(&fibonacci),
+            vec![0.0, 1.0, 2.0, 3.0, 5.0, 8.0, 13.0, 21.0]
+        );
+
+        let exponential = team_with_scale("exponential", false, true).estimate_scale();
+        assert_eq!(
+            values(&exponential),
+            vec![1.0, 2.0, 4.0, 8.0, 16.0, 32.0, 64.0]
+        );
+    }
+
+    #[test]
+    fn test_estimate_scale_not_used() {
+        assert!(
+            team_with_scale("notUsed", true, true)
+                .estimate_scale()
+                .is_empty()
+        );
+
+        let mut team = team_with_scale("linear", false, false);
+        team.issue_estimate_type = None;
+        assert!(team.estimate_scale().is_empty());
+    }
+
+    #[test]
+    fn test_team_estimation_settings_deserialization() {
+        let json = r#"{
+            "id": "team-1",
+            "key": "ENG",
+            "name": "Engineering",
+            "description": null,
+            "issueEstimationType": "linear",
+            "issueEstimationAllowZero": true,
+            "issueEstimationExtended": true
+        }"#;
+        let team: Team = serde_json::from_str(json).unwrap();
+        assert!(team.issue_estimate_allow_zero);
+        assert!(team.issue_estimate_extended);
+        assert_eq!(team.estimate_scale().len(), 8);
+    }
 }
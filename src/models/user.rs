@@ -5,6 +5,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::team::TeamMembershipConnection;
+
 /// A Linear user.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -37,6 +39,14 @@ pub struct ViewerResponse {
     pub viewer: User,
 }
 
+/// Response wrapper for the single user query.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserResponse {
+    /// The requested user.
+    pub user: User,
+}
+
 /// Response wrapper for the users query.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -45,6 +55,70 @@ pub struct UsersResponse {
     pub users: UserConnection,
 }
 
+/// A cycle reference used in workload analysis.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkloadCycle {
+    /// Unique identifier for the cycle.
+    pub id: String,
+    /// The cycle's number within the team.
+    pub number: i32,
+    /// Optional cycle name.
+    pub name: Option<String>,
+}
+
+/// A team with its members and active cycle.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkloadTeam {
+    /// Unique identifier for the team.
+    pub id: String,
+    /// The team's key/prefix (e.g., "ENG").
+    pub key: String,
+    /// The team's name.
+    pub name: String,
+    /// The team's currently running cycle, if any.
+    pub active_cycle: Option<WorkloadCycle>,
+    /// The team's memberships.
+    pub memberships: TeamMembershipConnection,
+}
+
+/// An open issue as seen by workload analysis.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkloadIssue {
+    /// Unique identifier for the issue.
+    pub id: String,
+    /// Human-readable identifier (e.g., "ENG-123").
+    pub identifier: String,
+    /// Estimate points, if estimated.
+    pub estimate: Option<f64>,
+    /// Due date (YYYY-MM-DD), if set.
+    pub due_date: Option<String>,
+    /// The assigned user, if any.
+    pub assignee: Option<User>,
+    /// The cycle the issue belongs to, if any.
+    pub cycle: Option<WorkloadCycle>,
+}
+
+/// A paginated list of workload issues.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkloadIssueConnection {
+    /// List of issues.
+    pub nodes: Vec<WorkloadIssue>,
+}
+
+/// Response wrapper for the user workload query.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserWorkloadResponse {
+    /// The team being analyzed.
+    pub team: WorkloadTeam,
+    /// The team's open issues.
+    pub issues: WorkloadIssueConnection,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
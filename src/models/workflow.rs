@@ -47,6 +47,82 @@ pub struct WorkflowStatesResponse {
     pub team: TeamWithWorkflowStates,
 }
 
+/// A workflow state's position within its team's workflow.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkflowStatePosition {
+    /// Unique identifier for the state.
+    pub id: String,
+    /// Position used to order the team's states.
+    pub position: f64,
+}
+
+/// A team's workflow state positions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamWorkflowStatePositions {
+    /// The team's workflow states.
+    pub states: WorkflowStatePositionConnection,
+}
+
+/// A paginated list of workflow state positions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkflowStatePositionConnection {
+    /// List of state positions.
+    pub nodes: Vec<WorkflowStatePosition>,
+}
+
+/// Response wrapper for the workflow state positions query.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkflowStatePositionsResponse {
+    /// The team with its state positions.
+    pub team: TeamWorkflowStatePositions,
+}
+
+/// Payload for workflow state create and update mutations.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkflowStatePayload {
+    /// Whether the mutation was successful.
+    pub success: bool,
+    /// The created or updated state.
+    pub workflow_state: Option<WorkflowState>,
+}
+
+/// Response wrapper for workflow state creation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkflowStateCreateResponse {
+    /// The mutation payload.
+    pub workflow_state_create: WorkflowStatePayload,
+}
+
+/// Response wrapper for workflow state update.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkflowStateUpdateResponse {
+    /// The mutation payload.
+    pub workflow_state_update: WorkflowStatePayload,
+}
+
+/// Payload for workflow state archival.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkflowStateArchivePayload {
+    /// Whether the mutation was successful.
+    pub success: bool,
+}
+
+/// Response wrapper for workflow state archival.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkflowStateArchiveResponse {
+    /// The mutation payload.
+    pub workflow_state_archive: WorkflowStateArchivePayload,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,161 @@
+//! Issue history/timeline output formatting.
+
+use colored::Colorize;
+
+use super::HumanDisplay;
+use crate::models::{HistoryChange, IssueTimeline, TimelineEvent, TimelineEventKind};
+
+/// Format an ISO 8601 timestamp as "YYYY-MM-DD HH:MM".
+fn format_timestamp(timestamp: &str) -> String {
+    if timestamp.len() >= 16 {
+        timestamp[..16].replace('T', " ")
+    } else {
+        timestamp.to_string()
+    }
+}
+
+/// Capitalize the first letter of a field name (e.g., "state" → "State").
+fn field_label(field: &str) -> String {
+    let mut chars = field.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+impl HumanDisplay for HistoryChange {
+    fn human_fmt(&self) -> String {
+        let label = field_label(&self.field);
+        if self.field == "label" {
+            // Labels are added or removed rather than changed
+            return match (&self.from, &self.to) {
+                (None, Some(to)) => format!("{}: {} {}", label.dimmed(), "+".green(), to),
+                (Some(from), None) => format!("{}: {} {}", label.dimmed(), "-".red(), from),
+                _ => format!("{}: ?", label.dimmed()),
+            };
+        }
+
+        let from = self.from.as_deref().unwrap_or("none");
+        let to = self.to.as_deref().unwrap_or("none");
+        format!("{}: {} → {}", label.dimmed(), from.dimmed(), to.bold())
+    }
+}
+
+impl HumanDisplay for TimelineEvent {
+    fn human_fmt(&self) -> String {
+        let timestamp = format_timestamp(&self.created_at);
+        let actor = self.actor.as_deref().unwrap_or("Linear");
+        let prefix = format!("{}  {}", timestamp.dimmed(), actor.bold());
+
+        match self.kind {
+            TimelineEventKind::Created => format!("{} created the issue", prefix),
+            TimelineEventKind::Change => {
+                let mut lines = vec![prefix];
+                for change in &self.changes {
+                    lines.push(format!("    {}", change.human_fmt()));
+                }
+                lines.join("\n")
+            }
+            TimelineEventKind::Comment => {
+                let mut lines = vec![format!("{} {}", prefix, "commented".cyan())];
+                for line in self.body.as_deref().unwrap_or_default().lines() {
+                    lines.push(format!("    {}", line));
+                }
+                lines.join("\n")
+            }
+        }
+    }
+}
+
+impl HumanDisplay for IssueTimeline {
+    fn human_fmt(&self) -> String {
+        let mut parts = vec![format!("{} {}", self.identifier.bold().cyan(), self.title)];
+
+        for event in &self.events {
+            parts.push(String::new());
+            for line in event.human_fmt().lines() {
+                parts.push(format!("  {}", line));
+            }
+        }
+
+        parts.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn change(field: &str, from: Option<&str>, to: Option<&str>) -> HistoryChange {
+        HistoryChange {
+            field: field.to_string(),
+            from: from.map(String::from),
+            to: to.map(String::from),
+        }
+    }
+
+    #[test]
+    fn test_history_change_human_display() {
+        let output = change("state", Some("Todo"), Some("In Progress")).human_fmt();
+        assert!(output.contains("State: Todo → In Progress"));
+
+        let output = change("assignee", Some("Jane Doe"), None).human_fmt();
+        assert!(output.contains("Assignee: Jane Doe → none"));
+
+        let output = change("label", None, Some("bug")).human_fmt();
+        assert!(output.contains("Label: + bug"));
+
+        let output = change("label", Some("bug"), None).human_fmt();
+        assert!(output.contains("Label: - bug"));
+    }
+
+    #[test]
+    fn test_issue_timeline_human_display() {
+        let timeline = IssueTimeline {
+            id: "issue-1".to_string(),
+            identifier: "ENG-123".to_string(),
+            title: "Fix login".to_string(),
+            events: vec![
+                TimelineEvent {
+                    kind: TimelineEventKind::Created,
+                    created_at: "2024-01-01T09:00:00.000Z".to_string(),
+                    actor: Some("Jane Doe".to_string()),
+                    changes: Vec::new(),
+                    body: None,
+                },
+                TimelineEvent {
+                    kind: TimelineEventKind::Change,
+                    created_at: "2024-01-02T10:30:00.000Z".to_string(),
+                    actor: None,
+                    changes: vec![change("priority", Some("Normal"), Some("Urgent"))],
+                    body: None,
+                },
+                TimelineEvent {
+                    kind: TimelineEventKind::Comment,
+                    created_at: "2024-01-03T11:00:00.000Z".to_string(),
+                    actor: Some("John Roe".to_string()),
+                    changes: Vec::new(),
+                    body: Some("Fixed in PR #42".to_string()),
+                },
+            ],
+        };
+
+        let output = timeline.human_fmt();
+        assert!(output.contains("ENG-123"));
+        assert!(output.contains("2024-01-01 09:00"));
+        assert!(output.contains("Jane Doe created the issue"));
+        assert!(output.contains("Linear"));
+        assert!(output.contains("Priority: Normal → Urgent"));
+        assert!(output.contains("John Roe commented"));
+        assert!(output.contains("Fixed in PR #42"));
+    }
+
+    #[test]
+    fn test_format_timestamp() {
+        assert_eq!(
+            format_timestamp("2024-01-02T10:30:00.000Z"),
+            "2024-01-02 10:30"
+        );
+        assert_eq!(format_timestamp("2024-01-02"), "2024-01-02");
+    }
+}
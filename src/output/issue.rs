@@ -3,7 +3,9 @@
 use colored::Colorize;
 
 use super::HumanDisplay;
-use crate::models::{Comment, FullIssueRelation, Issue, IssueWithComments, NormalizedRelation};
+use crate::models::{
+    Comment, FullIssueRelation, Issue, IssueWithComments, NormalizedRelation, UserConnection,
+};
 
 /// Format an estimate value using the team's estimate scale name.
 ///
@@ -35,6 +37,18 @@ fn format_estimate(value: f64, estimate_type: Option<&str>) -> String {
     }
 }
 
+/// Format the subscriber list as a comma-separated list of names.
+///
+/// Returns `None` when subscribers weren't fetched or nobody is subscribed.
+fn format_subscribers(subscribers: Option<&UserConnection>) -> Option<String> {
+    let names: Vec<&str> = subscribers?.nodes.iter().map(|u| u.name.as_str()).collect();
+    if names.is_empty() {
+        None
+    } else {
+        Some(names.join(", "))
+    }
+}
+
 impl HumanDisplay for Issue {
     fn human_fmt(&self) -> String {
         let identifier = self.identifier.bold().cyan();
@@ -91,6 +105,10 @@ impl HumanDisplay for Issue {
             parts.push(format!("  {}: {}", "Milestone".dimmed(), milestone_text));
         }
 
+        if let Some(subscribers) = format_subscribers(self.subscribers.as_ref()) {
+            parts.push(format!("  {}: {}", "Subscribers".dimmed(), subscribers));
+        }
+
         parts.join("\n")
     }
 }
@@ -178,6 +196,10 @@ impl HumanDisplay for IssueWithComments {
             parts.push(format!("  {}: {}", "Milestone".dimmed(), milestone_text));
         }
 
+        if let Some(subscribers) = format_subscribers(self.subscribers.as_ref()) {
+            parts.push(format!("  {}: {}", "Subscribers".dimmed(), subscribers));
+        }
+
         // Add comments section
         let comment_count = self.comments.nodes.len();
         parts.push(format!("\n  {} ({})", "Comments".bold(), comment_count));
@@ -269,6 +291,8 @@ mod tests {
             team: None,
             assignee: None,
             project_milestone: None,
+            subscribers: None,
+            due_date: None,
             created_at: "2024-01-01".to_string(),
             updated_at: "2024-01-02".to_string(),
         };
@@ -288,6 +312,7 @@ mod tests {
                 id: "issue-456".to_string(),
                 identifier: "ENG-456".to_string(),
                 title: "Blocked issue".to_string(),
+                state: None,
             },
         };
         let output = relation.human_fmt();
@@ -305,6 +330,7 @@ mod tests {
                 id: "issue-100".to_string(),
                 identifier: "ENG-100".to_string(),
                 title: "Parent issue".to_string(),
+                state: None,
             },
         };
         let output = relation.human_fmt();
@@ -356,9 +382,13 @@ mod tests {
                 name: "Engineering".to_string(),
                 description: None,
                 issue_estimate_type: Some("tShirt".to_string()),
+                issue_estimate_allow_zero: false,
+                issue_estimate_extended: false,
             }),
             assignee: None,
             project_milestone: None,
+            subscribers: None,
+            due_date: None,
             created_at: "2024-01-01".to_string(),
             updated_at: "2024-01-02".to_string(),
         };
@@ -375,11 +405,13 @@ mod tests {
                 id: "issue-1".to_string(),
                 identifier: "ENG-1".to_string(),
                 title: "Source issue".to_string(),
+                state: None,
             }),
             related_issue: Some(RelatedIssue {
                 id: "issue-2".to_string(),
                 identifier: "ENG-2".to_string(),
                 title: "Target issue".to_string(),
+                state: None,
             }),
         };
         let output = relation.human_fmt();
@@ -389,4 +421,47 @@ mod tests {
         assert!(output.contains("ENG-2"));
         assert!(output.contains("Target issue"));
     }
+
+    #[test]
+    fn test_issue_human_display_with_subscribers() {
+        let subscriber = |id: &str, name: &str| crate::models::User {
+            id: id.to_string(),
+            name: name.to_string(),
+            email: format!("{}@example.com", id),
+            display_name: None,
+            active: true,
+        };
+        let issue = Issue {
+            id: "issue-123".to_string(),
+            identifier: "ENG-123".to_string(),
+            title: "Fix the bug".to_string(),
+            description: None,
+            priority: 0,
+            estimate: None,
+            state: None,
+            team: None,
+            assignee: None,
+            project_milestone: None,
+            subscribers: Some(UserConnection {
+                nodes: vec![
+                    subscriber("jane", "Jane Doe"),
+                    subscriber("john", "John Roe"),
+                ],
+            }),
+            due_date: None,
+            created_at: "2024-01-01".to_string(),
+            updated_at: "2024-01-02".to_string(),
+        };
+        let output = issue.human_fmt();
+        assert!(output.contains("Subscribers: Jane Doe, John Roe"));
+    }
+
+    #[test]
+    fn test_format_subscribers_empty() {
+        assert_eq!(format_subscribers(None), None);
+        assert_eq!(
+            format_subscribers(Some(&UserConnection { nodes: vec![] })),
+            None
+        );
+    }
 }
@@ -17,6 +17,10 @@ impl HumanDisplay for Label {
         parts.push(format!("  {}: {}", "ID".dimmed(), self.id));
         parts.push(format!("  {}: {}", "Color".dimmed(), self.color));
 
+        if let Some(parent) = &self.parent {
+            parts.push(format!("  {}: {}", "Group".dimmed(), parent.name));
+        }
+
         if let Some(desc) = &self.description {
             parts.push(format!("  {}: {}", "Description".dimmed(), desc));
         }
@@ -37,6 +41,7 @@ mod tests {
             description: Some("Bug reports".to_string()),
             color: "#ff0000".to_string(),
             is_group: false,
+            parent: None,
             created_at: "2024-01-01".to_string(),
             updated_at: "2024-01-02".to_string(),
         };
@@ -56,6 +61,7 @@ mod tests {
             description: None,
             color: "#00ff00".to_string(),
             is_group: true,
+            parent: None,
             created_at: "2024-01-01".to_string(),
             updated_at: "2024-01-01".to_string(),
         };
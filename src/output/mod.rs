@@ -5,13 +5,17 @@
 
 mod attachment;
 mod cycle;
+mod history;
 mod issue;
 mod label;
 mod milestone;
+mod notification;
 mod project;
 mod team;
 mod user;
 
+use std::cell::{Cell, RefCell};
+
 use colored::Colorize;
 use serde::Serialize;
 
@@ -20,6 +24,23 @@ use crate::error::LinError;
 // Note: Submodules contain HumanDisplay implementations for domain types.
 // The implementations are automatically available when the HumanDisplay trait is in scope.
 
+thread_local! {
+    /// Whether [`output`] keeps a copy of what it prints (used by `lin shell`).
+    static RECORD_RESULTS: Cell<bool> = const { Cell::new(false) };
+    /// The last value passed to [`output`] while recording.
+    static LAST_RESULT: RefCell<Option<serde_json::Value>> = const { RefCell::new(None) };
+}
+
+/// Start or stop keeping a copy of each result passed to [`output`].
+pub fn record_results(enabled: bool) {
+    RECORD_RESULTS.set(enabled);
+}
+
+/// Take the last recorded result, if any.
+pub fn take_last_result() -> Option<serde_json::Value> {
+    LAST_RESULT.take()
+}
+
 /// Initialize color support based on terminal capabilities.
 ///
 /// Respects the NO_COLOR environment variable (https://no-color.org/).
@@ -100,6 +121,9 @@ impl<T: HumanDisplay> HumanDisplay for Vec<T> {
 /// * `data` - The data to output. Must implement `Serialize` and `HumanDisplay`.
 /// * `format` - The output format (Human or Json).
 pub fn output<T: Serialize + HumanDisplay>(data: &T, format: OutputFormat) {
+    if RECORD_RESULTS.get() {
+        LAST_RESULT.set(serde_json::to_value(data).ok());
+    }
     match format {
         OutputFormat::Human => {
             println!("{}", data.human_fmt());
@@ -219,6 +243,31 @@ mod tests {
         assert!(json.contains("\"value\":42"));
     }
 
+    impl HumanDisplay for TestData {
+        fn human_fmt(&self) -> String {
+            self.id.clone()
+        }
+    }
+
+    #[test]
+    fn test_record_results() {
+        let data = TestData {
+            id: "test-123".into(),
+            value: 42,
+        };
+        output(&data, OutputFormat::Human);
+        assert_eq!(take_last_result(), None);
+
+        record_results(true);
+        output(&data, OutputFormat::Human);
+        record_results(false);
+        assert_eq!(
+            take_last_result(),
+            Some(serde_json::json!({"id": "test-123", "value": 42}))
+        );
+        assert_eq!(take_last_result(), None);
+    }
+
     #[test]
     fn test_error_response_serialization() {
         let err = LinError::config("test error");
@@ -0,0 +1,140 @@
+//! Notification output formatting.
+
+use colored::Colorize;
+
+use super::HumanDisplay;
+use crate::models::Notification;
+
+/// Convert a Linear notification type into a short human-readable label.
+fn notification_label(type_: &str) -> String {
+    match type_ {
+        "issueMention" => "Mentioned".to_string(),
+        "issueCommentMention" => "Mentioned in comment".to_string(),
+        "issueAssignedToYou" => "Assigned to you".to_string(),
+        "issueUnassignedFromYou" => "Unassigned from you".to_string(),
+        "issueStatusChanged" => "Status changed".to_string(),
+        "issueNewComment" => "New comment".to_string(),
+        "issueCommentReaction" => "Reaction".to_string(),
+        "issueCreated" => "Issue created".to_string(),
+        "issuePriorityUrgent" => "Marked urgent".to_string(),
+        "issueDue" => "Due soon".to_string(),
+        "issueBlocking" => "Blocking".to_string(),
+        "issueUnblocked" => "Unblocked".to_string(),
+        "issueSubscribed" => "Subscribed".to_string(),
+        other => other.to_string(),
+    }
+}
+
+impl HumanDisplay for Notification {
+    fn human_fmt(&self) -> String {
+        let marker = if self.is_unread() {
+            "●".cyan().to_string()
+        } else {
+            " ".to_string()
+        };
+        let label = notification_label(&self.type_);
+
+        let header = match &self.issue {
+            Some(issue) => format!(
+                "{} {} {} {}",
+                marker,
+                label.bold(),
+                issue.identifier.bold().cyan(),
+                issue.title
+            ),
+            None => format!("{} {}", marker, label.bold()),
+        };
+
+        let mut parts = vec![header];
+
+        let date = if self.created_at.len() >= 10 {
+            &self.created_at[..10]
+        } else {
+            &self.created_at
+        };
+        match &self.actor {
+            Some(actor) => parts.push(format!("  {} {}", actor.name, date.dimmed())),
+            None => parts.push(format!("  {}", date.dimmed())),
+        }
+
+        if let Some(comment) = &self.comment {
+            // Show only the first line of the comment to keep the inbox compact
+            if let Some(first_line) = comment.body.lines().find(|l| !l.trim().is_empty()) {
+                parts.push(format!("  {}", first_line.dimmed()));
+            }
+        }
+
+        if let Some(until) = &self.snoozed_until_at {
+            let until_date = if until.len() >= 10 {
+                &until[..10]
+            } else {
+                until
+            };
+            parts.push(format!("  {}: {}", "Snoozed until".dimmed(), until_date));
+        }
+
+        parts.push(format!("  {}: {}", "ID".dimmed(), self.id));
+
+        parts.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{NotificationComment, RelatedIssue, User};
+
+    fn make_notification() -> Notification {
+        Notification {
+            id: "notif-1".to_string(),
+            type_: "issueNewComment".to_string(),
+            created_at: "2024-01-01T10:00:00.000Z".to_string(),
+            read_at: None,
+            snoozed_until_at: None,
+            actor: Some(User {
+                id: "user-1".to_string(),
+                name: "Jane Doe".to_string(),
+                email: "jane@example.com".to_string(),
+                display_name: None,
+                active: true,
+            }),
+            issue: Some(RelatedIssue {
+                id: "issue-1".to_string(),
+                identifier: "ENG-123".to_string(),
+                title: "Fix login".to_string(),
+                state: None,
+            }),
+            comment: Some(NotificationComment {
+                id: "comment-1".to_string(),
+                body: "Looks good to me\nSecond line".to_string(),
+            }),
+        }
+    }
+
+    #[test]
+    fn test_notification_human_display() {
+        let output = make_notification().human_fmt();
+        assert!(output.contains("New comment"));
+        assert!(output.contains("ENG-123"));
+        assert!(output.contains("Fix login"));
+        assert!(output.contains("Jane Doe"));
+        assert!(output.contains("2024-01-01"));
+        assert!(output.contains("Looks good to me"));
+        assert!(!output.contains("Second line"));
+        assert!(output.contains("notif-1"));
+    }
+
+    #[test]
+    fn test_notification_human_display_snoozed() {
+        let mut notification = make_notification();
+        notification.snoozed_until_at = Some("2024-02-01T00:00:00.000Z".to_string());
+        let output = notification.human_fmt();
+        assert!(output.contains("Snoozed until: 2024-02-01"));
+    }
+
+    #[test]
+    fn test_notification_label_unknown_type() {
+        assert_eq!(notification_label("issueMention"), "Mentioned");
+        assert_eq!(notification_label("somethingNew"), "somethingNew");
+    }
+}
@@ -3,7 +3,7 @@
 use colored::Colorize;
 
 use super::HumanDisplay;
-use crate::models::{Team, WorkflowState};
+use crate::models::{Team, TeamMembership, TeamSettings, WorkflowState};
 
 impl HumanDisplay for Team {
     fn human_fmt(&self) -> String {
@@ -14,16 +14,12 @@ impl HumanDisplay for Team {
             .unwrap_or_default();
 
         // Add estimate scale info if present
-        let estimates = match self.issue_estimate_type.as_deref() {
-            Some("tShirt") => format!("\n  Estimates: {}", "XS, S, M, L, XL".dimmed()),
-            Some("linear") => format!("\n  Estimates: {}", "1, 2, 3, 4, 5".dimmed()),
-            Some("fibonacci") => {
-                format!("\n  Estimates: {}", "1, 2, 3, 5, 8, 13, 21".dimmed())
-            }
-            Some("exponential") => {
-                format!("\n  Estimates: {}", "1, 2, 4, 8, 16, 32, 64".dimmed())
-            }
-            _ => String::new(),
+        let scale = self.estimate_scale();
+        let estimates = if scale.is_empty() {
+            String::new()
+        } else {
+            let names: Vec<String> = scale.into_iter().map(|(name, _)| name).collect();
+            format!("\n  Estimates: {}", names.join(", ").dimmed())
         };
 
         format!(
@@ -53,6 +49,84 @@ impl HumanDisplay for WorkflowState {
     }
 }
 
+impl HumanDisplay for TeamMembership {
+    fn human_fmt(&self) -> String {
+        let role = if self.owner {
+            "owner".yellow()
+        } else {
+            "member".normal()
+        };
+        let status = if self.user.active {
+            String::new()
+        } else {
+            format!(" {}", "(inactive)".red())
+        };
+        format!(
+            "{} [{}]{}\n  {}",
+            self.user.name.bold(),
+            role,
+            status,
+            self.user.email.dimmed()
+        )
+    }
+}
+
+/// Format a period as "N unit(s)", dropping a zero fraction.
+fn format_period(value: f64, unit: &str) -> String {
+    let plural = if value == 1.0 { "" } else { "s" };
+    if value.fract() == 0.0 {
+        format!("{} {}{}", value as i64, unit, plural)
+    } else {
+        format!("{} {}{}", value, unit, plural)
+    }
+}
+
+impl HumanDisplay for TeamSettings {
+    fn human_fmt(&self) -> String {
+        let cycles = if self.cycles_enabled {
+            let duration = self
+                .cycle_duration
+                .map(|d| format_period(d, "week"))
+                .unwrap_or_else(|| "-".to_string());
+            let cooldown = self
+                .cycle_cooldown_time
+                .map(|c| format_period(c, "week"))
+                .unwrap_or_else(|| "none".to_string());
+            format!("{}, cooldown {}", duration, cooldown)
+        } else {
+            "disabled".dimmed().to_string()
+        };
+        let enabled = |on: bool| {
+            if on {
+                "enabled".green().to_string()
+            } else {
+                "disabled".dimmed().to_string()
+            }
+        };
+        let after_months = |period: Option<f64>| match period {
+            Some(months) if months > 0.0 => format!("after {}", format_period(months, "month")),
+            _ => "disabled".dimmed().to_string(),
+        };
+        let default_state = self
+            .default_issue_state
+            .as_ref()
+            .map(|s| s.name.clone())
+            .unwrap_or_else(|| "-".to_string());
+
+        format!(
+            "{} {}\n  Cycles:        {}\n  Estimates:     {}\n  Triage:        {}\n  Auto-archive:  {}\n  Auto-close:    {}\n  Default state: {}",
+            format!("[{}]", self.key).cyan(),
+            self.name.bold(),
+            cycles,
+            self.issue_estimation_type.as_deref().unwrap_or("notUsed"),
+            enabled(self.triage_enabled),
+            after_months(self.auto_archive_period),
+            after_months(self.auto_close_period),
+            default_state
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -65,6 +139,8 @@ mod tests {
             name: "Engineering".to_string(),
             description: Some("The engineering team".to_string()),
             issue_estimate_type: None,
+            issue_estimate_allow_zero: false,
+            issue_estimate_extended: false,
         };
         let output = team.human_fmt();
         assert!(output.contains("[ENG]"));
@@ -80,6 +156,8 @@ mod tests {
             name: "Engineering".to_string(),
             description: Some("The engineering team".to_string()),
             issue_estimate_type: Some("tShirt".to_string()),
+            issue_estimate_allow_zero: false,
+            issue_estimate_extended: false,
         };
         let output = team.human_fmt();
         assert!(output.contains("[ENG]"));
@@ -102,4 +180,45 @@ mod tests {
         assert!(output.contains("state-123"));
         assert!(output.contains("#0066ff"));
     }
+
+    #[test]
+    fn test_team_membership_human_display() {
+        let membership = TeamMembership {
+            id: "membership-1".to_string(),
+            owner: true,
+            user: crate::models::User {
+                id: "user-1".to_string(),
+                name: "Alice".to_string(),
+                email: "alice@example.com".to_string(),
+                display_name: None,
+                active: true,
+            },
+        };
+        let output = membership.human_fmt();
+        assert!(output.contains("Alice"));
+        assert!(output.contains("owner"));
+        assert!(output.contains("alice@example.com"));
+    }
+
+    #[test]
+    fn test_team_settings_human_display() {
+        let settings = TeamSettings {
+            id: "team-1".to_string(),
+            key: "ENG".to_string(),
+            name: "Engineering".to_string(),
+            cycles_enabled: true,
+            cycle_duration: Some(2.0),
+            cycle_cooldown_time: Some(1.0),
+            issue_estimation_type: Some("fibonacci".to_string()),
+            triage_enabled: true,
+            auto_archive_period: Some(6.0),
+            auto_close_period: None,
+            default_issue_state: None,
+        };
+        let output = settings.human_fmt();
+        assert!(output.contains("2 weeks, cooldown 1 week"));
+        assert!(output.contains("fibonacci"));
+        assert!(output.contains("after 6 months"));
+        assert!(output.contains("Auto-close:    "));
+    }
 }
@@ -0,0 +1,267 @@
+//! External subcommand plugins.
+//!
+//! Like git and cargo, `lin <name>` runs an executable called `lin-<name>`
+//! from `PATH` when `<name>` isn't a built-in command or an alias. The
+//! plugin gets the remaining arguments, and lin's resolved context in the
+//! environment:
+//!
+//! | Variable | Value |
+//! |----------|-------|
+//! | `LINEAR_API_TOKEN` | Authorization header value for the organization |
+//! | `LIN_ORG` | Organization name |
+//! | `LIN_TEAM` | Current team key |
+//! | `LIN_OUTPUT` | `human` or `json` |
+//! | `LIN_BIN` | Path to the lin executable |
+//!
+//! Variables lin can't resolve (e.g., no team is set) are left unset.
+//! Because `LINEAR_API_TOKEN` is set, a plugin that runs `lin` itself uses
+//! the same credentials.
+
+use std::env;
+use std::ffi::{OsStr, OsString};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use serde::Serialize;
+
+use crate::Result;
+use crate::auth::LINEAR_API_TOKEN_ENV;
+use crate::error::LinError;
+use crate::output::OutputFormat;
+
+/// Prefix of plugin executable names.
+pub const PLUGIN_PREFIX: &str = "lin-";
+
+/// Environment variable holding the organization name.
+pub const LIN_ORG_ENV: &str = "LIN_ORG";
+
+/// Environment variable holding the current team key.
+pub const LIN_TEAM_ENV: &str = "LIN_TEAM";
+
+/// Environment variable holding the output format (`human` or `json`).
+pub const LIN_OUTPUT_ENV: &str = "LIN_OUTPUT";
+
+/// Environment variable holding the path to the lin executable.
+pub const LIN_BIN_ENV: &str = "LIN_BIN";
+
+/// A plugin executable found on `PATH`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Plugin {
+    /// Subcommand name (the executable name without `lin-`)
+    pub name: String,
+    /// Full path to the executable
+    pub path: PathBuf,
+}
+
+/// Context passed to a plugin through its environment.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PluginContext {
+    /// Authorization header value (API key, or `Bearer <token>` for OAuth)
+    pub token: Option<String>,
+    /// Organization name
+    pub org: Option<String>,
+    /// Current team key
+    pub team: Option<String>,
+    /// Output format requested with `--json`
+    pub format: OutputFormat,
+}
+
+impl PluginContext {
+    /// Environment variables to set for the plugin.
+    pub fn env(&self) -> Vec<(&'static str, String)> {
+        let format = match self.format {
+            OutputFormat::Human => "human",
+            OutputFormat::Json => "json",
+        };
+        let mut vars = vec![(LIN_OUTPUT_ENV, format.to_string())];
+        let optional = [
+            (LINEAR_API_TOKEN_ENV, &self.token),
+            (LIN_ORG_ENV, &self.org),
+            (LIN_TEAM_ENV, &self.team),
+        ];
+        vars.extend(
+            optional
+                .into_iter()
+                .filter_map(|(name, value)| Some((name, value.clone()?))),
+        );
+        vars
+    }
+}
+
+/// Whether `path` is a file the current user could execute.
+fn is_executable(path: &Path) -> bool {
+    let Ok(metadata) = fs::metadata(path) else {
+        return false;
+    };
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        metadata.is_file() && metadata.permissions().mode() & 0o111 != 0
+    }
+    #[cfg(not(unix))]
+    {
+        metadata.is_file()
+    }
+}
+
+/// Plugin name for an executable file name, if it is one.
+fn plugin_name(file_name: &OsStr) -> Option<String> {
+    let file_name = file_name.to_str()?;
+    let name = file_name.strip_prefix(PLUGIN_PREFIX)?;
+    let name = name
+        .strip_suffix(env::consts::EXE_SUFFIX)
+        .filter(|_| !env::consts::EXE_SUFFIX.is_empty())
+        .unwrap_or(name);
+    (!name.is_empty()).then(|| name.to_string())
+}
+
+/// Find all plugins in the directories of a `PATH`-style list.
+///
+/// Sorted by name; when several directories have the same plugin, the
+/// first one (the one that would run) wins.
+pub fn discover(path_var: Option<&OsStr>) -> Vec<Plugin> {
+    let mut plugins: Vec<Plugin> = Vec::new();
+    for dir in path_var.map(env::split_paths).into_iter().flatten() {
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let Some(name) = plugin_name(&entry.file_name()) else {
+                continue;
+            };
+            let path = entry.path();
+            if !plugins.iter().any(|p| p.name == name) && is_executable(&path) {
+                plugins.push(Plugin { name, path });
+            }
+        }
+    }
+    plugins.sort_by(|a, b| a.name.cmp(&b.name));
+    plugins
+}
+
+/// Find the executable for plugin `name` in a `PATH`-style list.
+pub fn find(name: &str, path_var: Option<&OsStr>) -> Option<PathBuf> {
+    let file_name = format!("{}{}{}", PLUGIN_PREFIX, name, env::consts::EXE_SUFFIX);
+    path_var
+        .map(env::split_paths)
+        .into_iter()
+        .flatten()
+        .map(|dir| dir.join(&file_name))
+        .find(|path| is_executable(path))
+}
+
+/// Run a plugin with the given arguments and context, returning its exit code.
+///
+/// # Errors
+///
+/// Returns an error if the plugin can't be started.
+pub fn run(plugin: &Plugin, args: &[OsString], context: &PluginContext) -> Result<i32> {
+    let mut command = Command::new(&plugin.path);
+    command.args(args).envs(context.env());
+    if let Ok(exe) = env::current_exe() {
+        command.env(LIN_BIN_ENV, exe);
+    }
+    let status = command.status().map_err(|e| {
+        LinError::config(format!(
+            "Failed to run plugin '{}' ({}): {}",
+            plugin.name,
+            plugin.path.display(),
+            e
+        ))
+    })?;
+    Ok(status.code().unwrap_or(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(unix)]
+    fn write_executable(dir: &Path, name: &str, script: &str) -> PathBuf {
+        use std::os::unix::fs::PermissionsExt;
+        let path = dir.join(name);
+        fs::write(&path, script).unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_plugin_name() {
+        assert_eq!(
+            plugin_name(OsStr::new("lin-deploy-notes")),
+            Some("deploy-notes".to_string())
+        );
+        assert_eq!(plugin_name(OsStr::new("lin-")), None);
+        assert_eq!(plugin_name(OsStr::new("linear")), None);
+    }
+
+    #[test]
+    fn test_context_env_skips_unresolved_values() {
+        let context = PluginContext {
+            token: Some("lin_api_xxx".to_string()),
+            org: Some("work".to_string()),
+            team: None,
+            format: OutputFormat::Json,
+        };
+        assert_eq!(
+            context.env(),
+            vec![
+                (LIN_OUTPUT_ENV, "json".to_string()),
+                (LINEAR_API_TOKEN_ENV, "lin_api_xxx".to_string()),
+                (LIN_ORG_ENV, "work".to_string()),
+            ]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_discover_first_on_path_wins() {
+        let first = tempfile::tempdir().unwrap();
+        let second = tempfile::tempdir().unwrap();
+        let notes = write_executable(first.path(), "lin-notes", "#!/bin/sh\n");
+        write_executable(second.path(), "lin-notes", "#!/bin/sh\n");
+        let deploy = write_executable(second.path(), "lin-deploy", "#!/bin/sh\n");
+        // Not executable, so not a plugin
+        fs::write(first.path().join("lin-readme"), "").unwrap();
+
+        let path_var = env::join_paths([first.path(), second.path()]).unwrap();
+        assert_eq!(
+            discover(Some(&path_var)),
+            vec![
+                Plugin {
+                    name: "deploy".to_string(),
+                    path: deploy,
+                },
+                Plugin {
+                    name: "notes".to_string(),
+                    path: notes.clone(),
+                },
+            ]
+        );
+        assert_eq!(find("notes", Some(&path_var)), Some(notes));
+        assert_eq!(find("readme", Some(&path_var)), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_passes_arguments_and_context() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = write_executable(
+            dir.path(),
+            "lin-check",
+            "#!/bin/sh\n[ \"$1\" = 'a b' ] && [ \"$LIN_TEAM\" = ENG ] && [ \"$LIN_OUTPUT\" = human ] && exit 7\nexit 1\n",
+        );
+        let plugin = Plugin {
+            name: "check".to_string(),
+            path,
+        };
+        let context = PluginContext {
+            token: None,
+            org: None,
+            team: Some("ENG".to_string()),
+            format: OutputFormat::Human,
+        };
+        assert_eq!(run(&plugin, &[OsString::from("a b")], &context).unwrap(), 7);
+    }
+}
@@ -0,0 +1,339 @@
+//! Per-repository configuration (`.lin.toml`).
+//!
+//! lin looks for a `.lin.toml` file in the working directory and each of its
+//! parents, stopping at the first one found. Its values override the global
+//! config in `~/.config/lin/config.json`.
+//!
+//! Precedence, highest first:
+//! 1. Command-line flags (e.g., `--team`)
+//! 2. Environment variables (`LINEAR_API_TOKEN`)
+//! 3. `.lin.toml` in the repository
+//! 4. The global config file
+//! 5. Built-in defaults
+//!
+//! Example `.lin.toml`:
+//!
+//! ```toml
+//! org = "work"
+//! team = "ENG"
+//! project = "api-v2"
+//! labels = ["backend"]
+//! branch_template = "{identifier}-{title}"
+//! issue_patterns = ["ENG-{number}"]
+//! ```
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::Result;
+use crate::error::LinError;
+
+/// File name lin looks for.
+pub const REPO_CONFIG_FILE: &str = ".lin.toml";
+
+/// Branch name template used when `.lin.toml` doesn't set one.
+pub const DEFAULT_BRANCH_TEMPLATE: &str = "{identifier}-{title}";
+
+/// Maximum length of the title part of a generated branch name.
+const BRANCH_TITLE_MAX_LEN: usize = 50;
+
+/// Values a repository can pin in `.lin.toml`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RepoConfig {
+    /// Organization to use in this repository
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub org: Option<String>,
+    /// Default team key
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<String>,
+    /// Default project (slug or UUID) for new issues
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    /// Labels applied to new issues when none are given
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<String>,
+    /// Branch name template. Placeholders: {identifier}, {team}, {number}, {title}
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch_template: Option<String>,
+    /// Issue ID patterns recognized in branch names, using a {number} placeholder
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub issue_patterns: Vec<String>,
+}
+
+/// A `.lin.toml` together with the path it was loaded from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RepoConfigFile {
+    /// Path to the file
+    pub path: PathBuf,
+    /// Parsed contents
+    pub config: RepoConfig,
+}
+
+impl RepoConfig {
+    /// Parse `.lin.toml` contents.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for invalid TOML, unknown keys or patterns without
+    /// a `{number}` placeholder.
+    pub fn parse(contents: &str) -> Result<Self> {
+        Self::parse_inner(contents)
+            .map_err(|e| LinError::parse(format!("Invalid {}: {}", REPO_CONFIG_FILE, e)))
+    }
+
+    fn parse_inner(contents: &str) -> std::result::Result<Self, String> {
+        let config: RepoConfig = toml::from_str(contents).map_err(|e| e.to_string())?;
+        if let Some(pattern) = config
+            .issue_patterns
+            .iter()
+            .find(|p| !p.contains("{number}"))
+        {
+            return Err(format!(
+                "issue pattern '{}' must contain {{number}}",
+                pattern
+            ));
+        }
+        Ok(config)
+    }
+
+    /// Find the nearest `.lin.toml`, starting at `start` and walking up.
+    ///
+    /// Returns `None` if no file is found.
+    pub fn discover(start: &Path) -> Result<Option<RepoConfigFile>> {
+        for dir in start.ancestors() {
+            let path = dir.join(REPO_CONFIG_FILE);
+            if path.is_file() {
+                let contents = fs::read_to_string(&path)?;
+                let config = Self::parse_inner(&contents)
+                    .map_err(|e| LinError::parse(format!("Invalid {}: {}", path.display(), e)))?;
+                return Ok(Some(RepoConfigFile { path, config }));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Find the nearest `.lin.toml` from the current working directory.
+    pub fn discover_from_cwd() -> Result<Option<RepoConfigFile>> {
+        match std::env::current_dir() {
+            Ok(cwd) => Self::discover(&cwd),
+            Err(_) => Ok(None),
+        }
+    }
+
+    /// The branch template in effect.
+    pub fn branch_template(&self) -> &str {
+        self.branch_template
+            .as_deref()
+            .unwrap_or(DEFAULT_BRANCH_TEMPLATE)
+    }
+
+    /// Render a branch name for an issue from the branch template.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lin::repo_config::RepoConfig;
+    ///
+    /// let config = RepoConfig::default();
+    /// assert_eq!(
+    ///     config.branch_name("ENG-123", "Fix login redirect!"),
+    ///     "eng-123-fix-login-redirect"
+    /// );
+    /// ```
+    pub fn branch_name(&self, identifier: &str, title: &str) -> String {
+        let (team, number) = identifier.rsplit_once('-').unwrap_or((identifier, ""));
+        let mut title = crate::config::Config::slugify(title);
+        if title.len() > BRANCH_TITLE_MAX_LEN {
+            let cut = title[..BRANCH_TITLE_MAX_LEN]
+                .rfind('-')
+                .unwrap_or(BRANCH_TITLE_MAX_LEN);
+            title.truncate(cut);
+        }
+
+        self.branch_template()
+            .replace("{identifier}", &identifier.to_lowercase())
+            .replace("{team}", &team.to_lowercase())
+            .replace("{number}", number)
+            .replace("{title}", &title)
+    }
+
+    /// Find an issue identifier in `text` (e.g., a branch name) using the issue patterns.
+    ///
+    /// Without patterns, any `TEAM-123` style identifier is recognized.
+    /// Matching is case-insensitive; the identifier is returned uppercased.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lin::repo_config::RepoConfig;
+    ///
+    /// let config = RepoConfig {
+    ///     issue_patterns: vec!["ENG-{number}".to_string()],
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(
+    ///     config.find_issue_identifier("alice/eng-42-fix-login"),
+    ///     Some("ENG-42".to_string())
+    /// );
+    /// assert_eq!(config.find_issue_identifier("ops-7-deploy"), None);
+    /// ```
+    pub fn find_issue_identifier(&self, text: &str) -> Option<String> {
+        let text = text.to_uppercase();
+        if self.issue_patterns.is_empty() {
+            return find_any_identifier(&text);
+        }
+
+        self.issue_patterns.iter().find_map(|pattern| {
+            let (prefix, suffix) = pattern.split_once("{number}")?;
+            let (prefix, suffix) = (prefix.to_uppercase(), suffix.to_uppercase());
+            text.match_indices(&prefix).find_map(|(start, _)| {
+                // Don't match in the middle of a word (e.g., "XENG-1" for "ENG-{number}")
+                if start > 0 && text.as_bytes()[start - 1].is_ascii_alphanumeric() {
+                    return None;
+                }
+                let rest = &text[start + prefix.len()..];
+                let digits =
+                    rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+                (digits > 0 && rest[digits..].starts_with(&suffix))
+                    .then(|| text[start..start + prefix.len() + digits + suffix.len()].to_string())
+            })
+        })
+    }
+}
+
+/// Find the first `TEAM-123` identifier in uppercased text.
+fn find_any_identifier(text: &str) -> Option<String> {
+    text.split(|c: char| !(c.is_ascii_alphanumeric() || c == '-'))
+        .flat_map(|word| {
+            let parts: Vec<&str> = word.split('-').collect();
+            parts
+                .windows(2)
+                .map(|pair| (pair[0], pair[1]))
+                .collect::<Vec<_>>()
+        })
+        .find(|(team, number)| {
+            !team.is_empty()
+                && team.chars().all(|c| c.is_ascii_uppercase())
+                && !number.is_empty()
+                && number.chars().all(|c| c.is_ascii_digit())
+        })
+        .map(|(team, number)| format!("{}-{}", team, number))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_full_config() {
+        let config = RepoConfig::parse(
+            r#"
+org = "work"
+team = "ENG"
+project = "api-v2"
+labels = ["backend", "api"]
+branch_template = "{team}/{number}-{title}"
+issue_patterns = ["ENG-{number}", "BE-{number}"]
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(config.org.as_deref(), Some("work"));
+        assert_eq!(config.team.as_deref(), Some("ENG"));
+        assert_eq!(config.project.as_deref(), Some("api-v2"));
+        assert_eq!(config.labels, vec!["backend", "api"]);
+        assert_eq!(config.branch_template(), "{team}/{number}-{title}");
+        assert_eq!(config.issue_patterns.len(), 2);
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_keys_and_bad_patterns() {
+        let err = RepoConfig::parse("tema = \"ENG\"").unwrap_err();
+        assert!(err.to_string().contains("unknown field"));
+
+        let err = RepoConfig::parse("issue_patterns = [\"ENG-\"]").unwrap_err();
+        assert!(err.to_string().contains("{number}"));
+    }
+
+    #[test]
+    fn test_discover_walks_up() {
+        let dir = tempfile::tempdir().unwrap();
+        let nested = dir.path().join("src").join("deep");
+        fs::create_dir_all(&nested).unwrap();
+        fs::write(dir.path().join(REPO_CONFIG_FILE), "team = \"ENG\"\n").unwrap();
+
+        let found = RepoConfig::discover(&nested).unwrap().unwrap();
+        assert_eq!(found.path, dir.path().join(REPO_CONFIG_FILE));
+        assert_eq!(found.config.team.as_deref(), Some("ENG"));
+    }
+
+    #[test]
+    fn test_discover_nearest_file_wins() {
+        let dir = tempfile::tempdir().unwrap();
+        let nested = dir.path().join("service");
+        fs::create_dir_all(&nested).unwrap();
+        fs::write(dir.path().join(REPO_CONFIG_FILE), "team = \"ENG\"\n").unwrap();
+        fs::write(nested.join(REPO_CONFIG_FILE), "team = \"OPS\"\n").unwrap();
+
+        let found = RepoConfig::discover(&nested).unwrap().unwrap();
+        assert_eq!(found.config.team.as_deref(), Some("OPS"));
+    }
+
+    #[test]
+    fn test_discover_reports_invalid_file_path() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join(REPO_CONFIG_FILE), "team = ").unwrap();
+
+        let err = RepoConfig::discover(dir.path()).unwrap_err();
+        assert!(err.to_string().contains(".lin.toml"));
+    }
+
+    #[test]
+    fn test_branch_name_template() {
+        let config = RepoConfig {
+            branch_template: Some("feature/{team}/{number}-{title}".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            config.branch_name("ENG-42", "Add OAuth login"),
+            "feature/eng/42-add-oauth-login"
+        );
+
+        let long = RepoConfig::default().branch_name(
+            "ENG-1",
+            "A very long issue title that keeps going well past the limit for branches",
+        );
+        assert!(long.len() <= "eng-1-".len() + BRANCH_TITLE_MAX_LEN);
+        assert!(!long.ends_with('-'));
+    }
+
+    #[test]
+    fn test_find_issue_identifier_default() {
+        let config = RepoConfig::default();
+        assert_eq!(
+            config.find_issue_identifier("feature/eng-123-login"),
+            Some("ENG-123".to_string())
+        );
+        assert_eq!(config.find_issue_identifier("main"), None);
+    }
+
+    #[test]
+    fn test_find_issue_identifier_with_patterns() {
+        let config = RepoConfig {
+            issue_patterns: vec!["ENG-{number}".to_string(), "BE-{number}".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(
+            config.find_issue_identifier("fix/be-88-crash"),
+            Some("BE-88".to_string())
+        );
+        assert_eq!(config.find_issue_identifier("xeng-5"), None);
+        assert_eq!(
+            config.find_issue_identifier("ENG-7"),
+            Some("ENG-7".to_string())
+        );
+    }
+}
@@ -0,0 +1,668 @@
+//! Pluggable storage for API tokens and OAuth refresh tokens.
+//!
+//! By default secrets live in `config.json` next to everything else. The
+//! config can instead name a [`SecretBackend`], in which case `Config::save`
+//! moves secrets into it and the JSON only holds their absence.
+//!
+//! Backends:
+//! - `plaintext` - tokens stay in `config.json` (mode 0600)
+//! - `secret-service` - the freedesktop Secret Service, via `secret-tool`
+//! - `helper` - an external credential-helper command (like git's `credential.helper`)
+//! - `encrypted-file` - AES-256-GCM file keyed by a passphrase
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::io::{BufRead, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::{Mutex, MutexGuard, OnceLock};
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use ring::aead::{AES_256_GCM, Aad, LessSafeKey, Nonce, UnboundKey};
+use ring::rand::{SecureRandom, SystemRandom};
+use serde::{Deserialize, Serialize};
+
+#[cfg(unix)]
+use std::os::unix::fs::PermissionsExt;
+
+use crate::Result;
+use crate::error::LinError;
+
+/// Environment variable holding the passphrase for the `encrypted-file` backend.
+pub const LIN_SECRETS_PASSPHRASE_ENV: &str = "LIN_SECRETS_PASSPHRASE";
+
+/// Service name secrets are stored under.
+const SERVICE: &str = "lin";
+
+/// PBKDF2-HMAC-SHA256 iterations for new encrypted files.
+#[cfg(not(test))]
+const PBKDF2_ITERATIONS: u32 = 600_000;
+#[cfg(test)]
+const PBKDF2_ITERATIONS: u32 = 1_000;
+
+/// Where secrets are stored.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+pub enum SecretBackend {
+    /// Store tokens in `config.json`.
+    #[default]
+    Plaintext,
+    /// Store tokens in the freedesktop Secret Service (GNOME Keyring, KWallet).
+    SecretService,
+    /// Delegate to an external credential-helper command.
+    Helper {
+        /// Command to run; `get`, `store` or `erase` is appended
+        command: String,
+    },
+    /// Store tokens in a passphrase-encrypted file.
+    EncryptedFile {
+        /// File location (defaults to `~/.config/lin/secrets.enc`)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        path: Option<PathBuf>,
+    },
+}
+
+impl SecretBackend {
+    /// Backend names accepted on the command line.
+    pub const NAMES: &'static [&'static str] =
+        &["plaintext", "secret-service", "helper", "encrypted-file"];
+
+    /// Build a backend from its command-line name.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - One of [`SecretBackend::NAMES`]
+    /// * `helper` - Command for the `helper` backend
+    /// * `path` - File for the `encrypted-file` backend
+    pub fn from_name(name: &str, helper: Option<String>, path: Option<PathBuf>) -> Result<Self> {
+        match name {
+            "plaintext" => Ok(Self::Plaintext),
+            "secret-service" => Ok(Self::SecretService),
+            "helper" => {
+                let command = helper.ok_or_else(|| {
+                    LinError::config("The helper backend needs a command. Pass --helper <command>")
+                })?;
+                Ok(Self::Helper { command })
+            }
+            "encrypted-file" => Ok(Self::EncryptedFile { path }),
+            _ => Err(LinError::config(format!(
+                "Unknown secret backend '{}'. Valid backends: {}",
+                name,
+                Self::NAMES.join(", ")
+            ))),
+        }
+    }
+
+    /// The command-line name of this backend.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Plaintext => "plaintext",
+            Self::SecretService => "secret-service",
+            Self::Helper { .. } => "helper",
+            Self::EncryptedFile { .. } => "encrypted-file",
+        }
+    }
+
+    /// Whether secrets are kept in `config.json`.
+    pub fn is_plaintext(&self) -> bool {
+        matches!(self, Self::Plaintext)
+    }
+
+    /// Open the store for this backend. Returns `None` for `plaintext`.
+    pub fn open(&self) -> Option<Box<dyn SecretStore>> {
+        match self {
+            Self::Plaintext => None,
+            Self::SecretService => Some(Box::new(SecretServiceStore)),
+            Self::Helper { command } => Some(Box::new(HelperStore {
+                command: command.clone(),
+            })),
+            Self::EncryptedFile { path } => Some(Box::new(EncryptedFileStore::new(
+                path.clone().unwrap_or_else(default_encrypted_file_path),
+                None,
+            ))),
+        }
+    }
+}
+
+/// Key under which an organization's API or access token is stored.
+pub fn token_key(org: &str) -> String {
+    format!("{}/token", org)
+}
+
+/// Key under which an organization's OAuth refresh token is stored.
+pub fn refresh_token_key(org: &str) -> String {
+    format!("{}/refresh_token", org)
+}
+
+/// A place secrets can be read from and written to.
+pub trait SecretStore {
+    /// Look up a secret. Returns `None` if it isn't stored.
+    fn get(&self, key: &str) -> Result<Option<String>>;
+    /// Store or replace a secret.
+    fn set(&self, key: &str, value: &str) -> Result<()>;
+    /// Remove a secret. Removing a missing secret is not an error.
+    fn delete(&self, key: &str) -> Result<()>;
+}
+
+/// Run a command, feeding `input` on stdin, and return its stdout.
+fn run_with_input(mut command: Command, input: &str, what: &str) -> Result<(bool, String)> {
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .map_err(|e| LinError::config(format!("Failed to run {}: {}", what, e)))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(input.as_bytes())?;
+    }
+    let output = child.wait_with_output()?;
+    Ok((
+        output.status.success(),
+        String::from_utf8_lossy(&output.stdout).into_owned(),
+    ))
+}
+
+/// Secret Service backend using libsecret's `secret-tool`.
+struct SecretServiceStore;
+
+impl SecretServiceStore {
+    fn command(action: &str, key: &str) -> Command {
+        let mut command = Command::new("secret-tool");
+        command.arg(action);
+        if action == "store" {
+            command.arg(format!("--label=lin: {}", key));
+        }
+        command.args(["service", SERVICE, "account", key]);
+        command
+    }
+}
+
+impl SecretStore for SecretServiceStore {
+    fn get(&self, key: &str) -> Result<Option<String>> {
+        // secret-tool exits non-zero when nothing matches
+        let (found, stdout) = run_with_input(Self::command("lookup", key), "", "secret-tool")?;
+        Ok(found.then(|| stdout.trim_end_matches('\n').to_string()))
+    }
+
+    fn set(&self, key: &str, value: &str) -> Result<()> {
+        let (ok, _) = run_with_input(Self::command("store", key), value, "secret-tool")?;
+        if !ok {
+            return Err(LinError::config(format!(
+                "secret-tool failed to store '{}'",
+                key
+            )));
+        }
+        Ok(())
+    }
+
+    fn delete(&self, key: &str) -> Result<()> {
+        run_with_input(Self::command("clear", key), "", "secret-tool")?;
+        Ok(())
+    }
+}
+
+/// Credential-helper backend.
+///
+/// The command is run through the shell with `get`, `store` or `erase`
+/// appended. Attributes are written to stdin as `name=value` lines ending
+/// with a blank line: `service` and `key` always, `secret` for `store`.
+/// For `get`, the helper prints `secret=<value>` (or nothing if unknown).
+struct HelperStore {
+    command: String,
+}
+
+impl HelperStore {
+    fn run(&self, action: &str, key: &str, secret: Option<&str>) -> Result<(bool, String)> {
+        let script = format!("{} {}", self.command, action);
+        #[cfg(windows)]
+        let command = {
+            let mut c = Command::new("cmd");
+            c.args(["/C", &script]);
+            c
+        };
+        #[cfg(not(windows))]
+        let command = {
+            let mut c = Command::new("sh");
+            c.args(["-c", &script]);
+            c
+        };
+
+        let mut input = format!("service={}\nkey={}\n", SERVICE, key);
+        if let Some(secret) = secret {
+            input.push_str(&format!("secret={}\n", secret));
+        }
+        input.push('\n');
+        run_with_input(command, &input, "credential helper")
+    }
+}
+
+impl SecretStore for HelperStore {
+    fn get(&self, key: &str) -> Result<Option<String>> {
+        let (ok, stdout) = self.run("get", key, None)?;
+        if !ok {
+            return Err(LinError::config(format!(
+                "Credential helper failed to look up '{}'",
+                key
+            )));
+        }
+        Ok(stdout
+            .lines()
+            .find_map(|line| line.strip_prefix("secret="))
+            .map(str::to_string))
+    }
+
+    fn set(&self, key: &str, value: &str) -> Result<()> {
+        if value.contains('\n') {
+            return Err(LinError::config("Secrets cannot contain newlines"));
+        }
+        let (ok, _) = self.run("store", key, Some(value))?;
+        if !ok {
+            return Err(LinError::config(format!(
+                "Credential helper failed to store '{}'",
+                key
+            )));
+        }
+        Ok(())
+    }
+
+    fn delete(&self, key: &str) -> Result<()> {
+        self.run("erase", key, None)?;
+        Ok(())
+    }
+}
+
+/// Default location of the encrypted secrets file.
+pub fn default_encrypted_file_path() -> PathBuf {
+    let home_dir = dirs::home_dir().unwrap_or_else(|| PathBuf::from("."));
+    home_dir.join(".config").join("lin").join("secrets.enc")
+}
+
+/// On-disk format of the encrypted secrets file.
+#[derive(Serialize, Deserialize)]
+struct EncryptedFile {
+    version: u32,
+    iterations: u32,
+    salt: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+/// Encrypted-file backend.
+///
+/// Secrets are kept as a JSON map encrypted with AES-256-GCM under a key
+/// derived from the passphrase with PBKDF2-HMAC-SHA256. A fresh salt and
+/// nonce are used on every write.
+pub struct EncryptedFileStore {
+    path: PathBuf,
+    passphrase: Option<String>,
+}
+
+impl EncryptedFileStore {
+    /// Open the file at `path`.
+    ///
+    /// Without an explicit passphrase, it is read from `LIN_SECRETS_PASSPHRASE`
+    /// or prompted for on the terminal (twice when the file is created). A
+    /// prompted passphrase is remembered for the file once it has worked.
+    pub fn new(path: PathBuf, passphrase: Option<String>) -> Self {
+        Self { path, passphrase }
+    }
+
+    /// Get the passphrase, asking for it twice when `confirm` is set because
+    /// a new file is about to be created with it.
+    fn passphrase(&self, confirm: bool) -> Result<String> {
+        if let Some(passphrase) = &self.passphrase {
+            return Ok(passphrase.clone());
+        }
+        if let Some(passphrase) = passphrase_cache().get(&self.path) {
+            return Ok(passphrase.clone());
+        }
+
+        let passphrase = match std::env::var(LIN_SECRETS_PASSPHRASE_ENV) {
+            Ok(value) if !value.is_empty() => value,
+            _ => {
+                if !atty::is(atty::Stream::Stdin) {
+                    return Err(LinError::config(format!(
+                        "The encrypted secrets file needs a passphrase. Set {}",
+                        LIN_SECRETS_PASSPHRASE_ENV
+                    )));
+                }
+                let passphrase = read_hidden("Passphrase for lin secrets: ")?;
+                if confirm && read_hidden("Confirm passphrase: ")? != passphrase {
+                    return Err(LinError::config("Passphrases don't match"));
+                }
+                passphrase
+            }
+        };
+        if passphrase.is_empty() {
+            return Err(LinError::config("Passphrase cannot be empty"));
+        }
+        Ok(passphrase)
+    }
+
+    fn key(passphrase: &str, salt: &[u8], iterations: u32) -> Result<LessSafeKey> {
+        let iterations = std::num::NonZeroU32::new(iterations)
+            .ok_or_else(|| LinError::parse("Corrupt secrets file: zero iterations"))?;
+        let mut key = [0u8; 32];
+        ring::pbkdf2::derive(
+            ring::pbkdf2::PBKDF2_HMAC_SHA256,
+            iterations,
+            salt,
+            passphrase.as_bytes(),
+            &mut key,
+        );
+        let key = UnboundKey::new(&AES_256_GCM, &key)
+            .map_err(|_| LinError::config("Failed to derive encryption key"))?;
+        Ok(LessSafeKey::new(key))
+    }
+
+    fn read_all(&self) -> Result<BTreeMap<String, String>> {
+        if !self.path.exists() {
+            return Ok(BTreeMap::new());
+        }
+        let contents = fs::read_to_string(&self.path)?;
+        let file: EncryptedFile = serde_json::from_str(&contents)
+            .map_err(|e| LinError::parse(format!("Failed to parse secrets file: {}", e)))?;
+        let decode = |value: &str| {
+            STANDARD
+                .decode(value)
+                .map_err(|e| LinError::parse(format!("Corrupt secrets file: {}", e)))
+        };
+
+        let salt = decode(&file.salt)?;
+        let nonce = Nonce::try_assume_unique_for_key(&decode(&file.nonce)?)
+            .map_err(|_| LinError::parse("Corrupt secrets file: bad nonce"))?;
+        let mut data = decode(&file.ciphertext)?;
+
+        let passphrase = self.passphrase(false)?;
+        let key = Self::key(&passphrase, &salt, file.iterations)?;
+        let Ok(plaintext) = key.open_in_place(nonce, Aad::empty(), &mut data) else {
+            // Ask again next time instead of reusing a mistyped passphrase
+            passphrase_cache().remove(&self.path);
+            return Err(LinError::config(
+                "Could not decrypt the secrets file. Wrong passphrase?",
+            ));
+        };
+        passphrase_cache().insert(self.path.clone(), passphrase);
+        serde_json::from_slice(plaintext)
+            .map_err(|e| LinError::parse(format!("Failed to parse secrets file: {}", e)))
+    }
+
+    fn write_all(&self, secrets: &BTreeMap<String, String>) -> Result<()> {
+        let rng = SystemRandom::new();
+        let mut salt = [0u8; 16];
+        let mut nonce = [0u8; 12];
+        rng.fill(&mut salt)
+            .and_then(|_| rng.fill(&mut nonce))
+            .map_err(|_| LinError::config("Failed to generate random bytes"))?;
+
+        let mut data = serde_json::to_vec(secrets)
+            .map_err(|e| LinError::parse(format!("Failed to serialize secrets: {}", e)))?;
+        let passphrase = self.passphrase(!self.path.exists())?;
+        Self::key(&passphrase, &salt, PBKDF2_ITERATIONS)?
+            .seal_in_place_append_tag(Nonce::assume_unique_for_key(nonce), Aad::empty(), &mut data)
+            .map_err(|_| LinError::config("Failed to encrypt secrets"))?;
+
+        let file = EncryptedFile {
+            version: 1,
+            iterations: PBKDF2_ITERATIONS,
+            salt: STANDARD.encode(salt),
+            nonce: STANDARD.encode(nonce),
+            ciphertext: STANDARD.encode(data),
+        };
+        let contents = serde_json::to_string_pretty(&file)
+            .map_err(|e| LinError::parse(format!("Failed to serialize secrets: {}", e)))?;
+        write_private(&self.path, contents.as_bytes())?;
+        passphrase_cache().insert(self.path.clone(), passphrase);
+        Ok(())
+    }
+}
+
+/// Passphrases known to open each secrets file, for the rest of the process.
+fn passphrase_cache() -> MutexGuard<'static, HashMap<PathBuf, String>> {
+    static PASSPHRASES: OnceLock<Mutex<HashMap<PathBuf, String>>> = OnceLock::new();
+    PASSPHRASES
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+}
+
+impl SecretStore for EncryptedFileStore {
+    fn get(&self, key: &str) -> Result<Option<String>> {
+        Ok(self.read_all()?.remove(key))
+    }
+
+    fn set(&self, key: &str, value: &str) -> Result<()> {
+        let mut secrets = self.read_all()?;
+        secrets.insert(key.to_string(), value.to_string());
+        self.write_all(&secrets)
+    }
+
+    fn delete(&self, key: &str) -> Result<()> {
+        let mut secrets = self.read_all()?;
+        if secrets.remove(key).is_some() {
+            self.write_all(&secrets)?;
+        }
+        Ok(())
+    }
+}
+
+/// Atomically write a file readable only by the owner.
+fn write_private(path: &Path, contents: &[u8]) -> Result<()> {
+    let dir = path.parent().unwrap_or_else(|| Path::new("."));
+    fs::create_dir_all(dir)?;
+    let mut temp_file = tempfile::NamedTempFile::new_in(dir)?;
+    temp_file.write_all(contents)?;
+    temp_file.flush()?;
+
+    #[cfg(unix)]
+    {
+        let mut permissions = temp_file.as_file().metadata()?.permissions();
+        permissions.set_mode(0o600);
+        temp_file.as_file().set_permissions(permissions)?;
+    }
+
+    temp_file
+        .persist(path)
+        .map_err(|e| std::io::Error::other(format!("Failed to persist secrets: {}", e)))?;
+    Ok(())
+}
+
+/// Prompt on stderr and read a line from the terminal without echoing it.
+fn read_hidden(prompt: &str) -> Result<String> {
+    eprint!("{}", prompt);
+    std::io::stderr().flush()?;
+
+    crossterm::terminal::enable_raw_mode()?;
+    let result = read_raw_line(std::io::stdin().lock());
+    crossterm::terminal::disable_raw_mode()?;
+    eprintln!();
+    result
+}
+
+/// Read bytes until Enter, handling backspace and Ctrl-C. Expects raw mode.
+fn read_raw_line(reader: impl BufRead) -> Result<String> {
+    let mut input = Vec::new();
+    for byte in reader.bytes() {
+        match byte? {
+            b'\r' | b'\n' => break,
+            3 => return Err(LinError::config("Cancelled")),
+            8 | 127 => {
+                // Remove a whole character: continuation bytes, then its first byte
+                while let Some(byte) = input.pop() {
+                    if byte & 0b1100_0000 != 0b1000_0000 {
+                        break;
+                    }
+                }
+            }
+            byte => input.push(byte),
+        }
+    }
+    String::from_utf8(input).map_err(|_| LinError::parse("Input is not valid UTF-8"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backend_from_name() {
+        assert_eq!(
+            SecretBackend::from_name("plaintext", None, None).unwrap(),
+            SecretBackend::Plaintext
+        );
+        assert_eq!(
+            SecretBackend::from_name("helper", Some("pass-lin".to_string()), None).unwrap(),
+            SecretBackend::Helper {
+                command: "pass-lin".to_string()
+            }
+        );
+        assert!(SecretBackend::from_name("helper", None, None).is_err());
+        assert!(
+            SecretBackend::from_name("keychain", None, None)
+                .unwrap_err()
+                .to_string()
+                .contains("Valid backends")
+        );
+    }
+
+    #[test]
+    fn test_backend_serialization() {
+        let backend = SecretBackend::EncryptedFile { path: None };
+        let json = serde_json::to_string(&backend).unwrap();
+        assert_eq!(json, r#"{"type":"encrypted-file"}"#);
+
+        let parsed: SecretBackend =
+            serde_json::from_str(r#"{"type":"helper","command":"my-helper"}"#).unwrap();
+        assert_eq!(parsed.name(), "helper");
+        assert!(SecretBackend::Plaintext.open().is_none());
+    }
+
+    #[test]
+    fn test_encrypted_file_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("secrets.enc");
+        let store = EncryptedFileStore::new(path.clone(), Some("correct horse".to_string()));
+
+        assert_eq!(store.get("work/token").unwrap(), None);
+        store.set("work/token", "lin_api_secret").unwrap();
+        store.set("work/refresh_token", "refresh").unwrap();
+        assert_eq!(
+            store.get("work/token").unwrap().as_deref(),
+            Some("lin_api_secret")
+        );
+
+        let contents = fs::read_to_string(&path).unwrap();
+        assert!(!contents.contains("lin_api_secret"));
+
+        store.delete("work/token").unwrap();
+        assert_eq!(store.get("work/token").unwrap(), None);
+        assert_eq!(
+            store.get("work/refresh_token").unwrap().as_deref(),
+            Some("refresh")
+        );
+    }
+
+    #[test]
+    fn test_encrypted_file_wrong_passphrase() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("secrets.enc");
+        EncryptedFileStore::new(path.clone(), Some("right".to_string()))
+            .set("work/token", "secret")
+            .unwrap();
+
+        let err = EncryptedFileStore::new(path, Some("wrong".to_string()))
+            .get("work/token")
+            .unwrap_err();
+        assert!(err.to_string().contains("Wrong passphrase"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_read_raw_line_keeps_utf8() {
+        let line = read_raw_line("pässwörd\rignored".as_bytes()).unwrap();
+        assert_eq!(line, "pässwörd");
+
+        // Backspace removes the whole multi-byte character
+        let line = read_raw_line("abé\x7fc\n".as_bytes()).unwrap();
+        assert_eq!(line, "abc");
+
+        let err = read_raw_line(&[b'a', 3][..]).unwrap_err();
+        assert!(err.to_string().contains("Cancelled"));
+    }
+
+    #[test]
+    fn test_encrypted_file_forgets_wrong_passphrase() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("secrets.enc");
+        let other = dir.path().join("other.enc");
+        EncryptedFileStore::new(path.clone(), Some("right".to_string()))
+            .set("work/token", "secret")
+            .unwrap();
+        EncryptedFileStore::new(other.clone(), Some("other".to_string()))
+            .set("work/token", "other-secret")
+            .unwrap();
+
+        // A mistyped passphrase isn't kept, and doesn't affect other files
+        passphrase_cache().insert(path.clone(), "typo".to_string());
+        let store = EncryptedFileStore::new(path.clone(), None);
+        assert!(store.get("work/token").is_err());
+        assert!(!passphrase_cache().contains_key(&path));
+        assert_eq!(
+            EncryptedFileStore::new(other, None)
+                .get("work/token")
+                .unwrap()
+                .as_deref(),
+            Some("other-secret")
+        );
+
+        passphrase_cache().insert(path.clone(), "right".to_string());
+        assert_eq!(store.get("work/token").unwrap().as_deref(), Some("secret"));
+    }
+
+    #[test]
+    fn test_helper_protocol() {
+        let dir = tempfile::tempdir().unwrap();
+        let script = dir.path().join("helper.sh");
+        // Stores each secret in a file named after its key
+        fs::write(
+            &script,
+            format!(
+                r#"#!/bin/sh
+store="{}"
+while IFS='=' read -r name value; do
+  [ -z "$name" ] && break
+  eval "attr_$name=\$value"
+done
+file="$store/$(echo "$attr_key" | tr / _)"
+case "$1" in
+  get) if [ -f "$file" ]; then printf 'secret=%s\n' "$(cat "$file")"; fi ;;
+  store) printf '%s' "$attr_secret" > "$file" ;;
+  erase) rm -f "$file" ;;
+esac
+"#,
+                dir.path().display()
+            ),
+        )
+        .unwrap();
+        let mut permissions = fs::metadata(&script).unwrap().permissions();
+        permissions.set_mode(0o755);
+        fs::set_permissions(&script, permissions).unwrap();
+
+        let store = SecretBackend::Helper {
+            command: script.display().to_string(),
+        }
+        .open()
+        .unwrap();
+
+        assert_eq!(store.get("work/token").unwrap(), None);
+        store.set("work/token", "lin_api_secret").unwrap();
+        assert_eq!(
+            store.get("work/token").unwrap().as_deref(),
+            Some("lin_api_secret")
+        );
+        store.delete("work/token").unwrap();
+        assert_eq!(store.get("work/token").unwrap(), None);
+    }
+}
@@ -9,6 +9,7 @@ use lin::models::IssueDeleteResponse;
 
 /// Test prefix for issues created during tests.
 /// This helps identify and clean up test issues if needed.
+#[allow(dead_code)]
 pub const TEST_ISSUE_PREFIX: &str = "[lin-test]";
 
 /// Get the API token from environment variable.
@@ -23,6 +24,7 @@ pub fn create_client() -> GraphQLClient {
 }
 
 /// Helper to delete an issue by ID (for cleanup).
+#[allow(dead_code)]
 pub fn delete_issue(client: &GraphQLClient, issue_id: &str) -> Result<bool, String> {
     let variables = serde_json::json!({
         "id": issue_id
@@ -63,8 +63,7 @@ fn test_cycles_list() {
             "First cycle: {} (progress: {:.1}%)",
             first_cycle
                 .name
-                .as_ref()
-                .map(|n| n.as_str())
+                .as_deref()
                 .unwrap_or(&format!("Cycle {}", first_cycle.number)),
             first_cycle.progress
         );
@@ -133,8 +132,7 @@ fn test_cycle_get() {
         "Testing get for cycle {} (ID: {})",
         first_cycle
             .name
-            .as_ref()
-            .map(|n| n.as_str())
+            .as_deref()
             .unwrap_or(&format!("Cycle {}", first_cycle.number)),
         cycle_id
     );
@@ -0,0 +1,31 @@
+//! Integration tests for inbox (notification) commands.
+//!
+//! These tests require a valid LINEAR_API_TOKEN environment variable.
+//! Run with: cargo test --test inbox_tests -- --ignored
+
+mod common;
+
+use lin::api::queries::notification::NOTIFICATIONS_QUERY;
+use lin::models::NotificationsResponse;
+
+/// Test that we can list notifications from the inbox.
+#[test]
+#[ignore]
+fn test_notifications_query() {
+    let client = common::create_client();
+    let variables = serde_json::json!({ "first": 10 });
+
+    let result: Result<NotificationsResponse, _> = client.query(NOTIFICATIONS_QUERY, variables);
+    assert!(
+        result.is_ok(),
+        "Failed to fetch notifications: {:?}",
+        result.err()
+    );
+
+    let response = result.unwrap();
+    assert!(response.notifications.nodes.len() <= 10);
+    for notification in &response.notifications.nodes {
+        assert!(!notification.id.is_empty());
+        assert!(!notification.type_.is_empty());
+    }
+}